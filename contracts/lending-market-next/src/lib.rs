@@ -0,0 +1,52 @@
+#![no_std]
+
+//! Lending Market, next schema
+//!
+//! The lending market built from its own sources, except that it stores loans and
+//! offers one schema version ahead of the release. The market's tests upgrade a
+//! live release to this build and migrate it, the same way an admin would ship a
+//! layout change.
+
+#[path = "../../lending-market/src/auction.rs"]
+mod auction;
+#[path = "../../lending-market/src/collateral.rs"]
+mod collateral;
+#[path = "../../lending-market/src/contract.rs"]
+mod contract;
+#[path = "../../lending-market/src/dex.rs"]
+mod dex;
+#[path = "../../lending-market/src/error.rs"]
+mod error;
+#[path = "../../lending-market/src/events.rs"]
+mod events;
+#[path = "../../lending-market/src/interest.rs"]
+mod interest;
+#[path = "../../lending-market/src/liquidation.rs"]
+mod liquidation;
+#[path = "../../lending-market/src/matching.rs"]
+mod matching;
+mod migration;
+#[path = "../../lending-market/src/oracle.rs"]
+mod oracle;
+#[path = "../../lending-market/src/pool.rs"]
+mod pool;
+#[path = "../../lending-market/src/position.rs"]
+mod position;
+#[path = "../../lending-market/src/query.rs"]
+mod query;
+#[path = "../../lending-market/src/reflector.rs"]
+mod reflector;
+#[path = "../../lending-market/src/request.rs"]
+mod request;
+#[path = "../../lending-market/src/storage.rs"]
+mod storage;
+#[path = "../../lending-market/src/timelock.rs"]
+mod timelock;
+#[path = "../../lending-market/src/types.rs"]
+mod types;
+#[path = "../../lending-market/src/validation.rs"]
+mod validation;
+
+// Re-export the contract
+pub use contract::LendingMarket;
+pub use contract::LendingMarketClient;
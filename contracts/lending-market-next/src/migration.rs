@@ -0,0 +1,28 @@
+//! Storage schema migrations of the next build
+//!
+//! Schema 2 keeps the release's layouts. Its step decodes every loan and offer and
+//! stores it again, in the same batches and behind the same cursor a layout change
+//! would use, so an upgrade of a live market can be tested end to end.
+
+use crate::error::Error;
+use crate::types::{LendingOffer, Loan};
+use soroban_sdk::Env;
+
+#[path = "../../lending-market/src/migration.rs"]
+mod release;
+
+pub const SCHEMA_VERSION: u32 = release::SCHEMA_VERSION + 1;
+
+/// Rewrite up to `limit` records for the step from `version` to `version + 1`
+/// Returns true once the step has covered every loan and offer
+pub fn step(env: &Env, version: u32, limit: u32) -> Result<bool, Error> {
+    if version == release::SCHEMA_VERSION {
+        return Ok(release::rewrite(
+            env,
+            limit,
+            |loan: Loan| loan,
+            |offer: LendingOffer| offer,
+        ));
+    }
+    release::step(env, version, limit)
+}
@@ -0,0 +1,238 @@
+//! Dutch-auction liquidation for undercollateralized loans
+//!
+//! Once a loan is liquidatable anyone can start an auction. The discount on its
+//! collateral then grows linearly from 0 up to the configured maximum, and
+//! bidders repay debt in exchange for collateral at the current discount.
+//! Once the duration has passed the discount holds at the maximum until the
+//! auction ends. Each bid repays at most the close factor of the debt, as a
+//! direct liquidation does.
+
+use crate::error::Error;
+use crate::events::{self, AuctionBidData};
+use crate::interest;
+use crate::liquidation;
+use crate::oracle::{self, Valuation};
+use crate::position;
+use crate::storage;
+use crate::types::{Auction, AuctionConfig, Loan, BASIS_POINTS};
+use soroban_sdk::{token, Address, Env};
+
+/// Discount in basis points `elapsed` seconds into an auction
+pub fn discount_at(config: &AuctionConfig, start_time: u64, current_time: u64) -> u32 {
+    let elapsed = current_time.saturating_sub(start_time);
+    if elapsed >= config.duration {
+        return config.max_discount_bps;
+    }
+
+    // max_discount × elapsed / duration, which fits in u64 and is below max_discount
+    ((config.max_discount_bps as u64) * elapsed / config.duration) as u32
+}
+
+/// Current discount of a running auction
+pub fn current_discount(env: &Env, auction: &Auction) -> u32 {
+    let config = storage::get_auction_config(env);
+    discount_at(&config, auction.start_time, env.ledger().timestamp())
+}
+
+/// Start an auction for a liquidatable loan
+pub fn start_auction(env: &Env, loan: &Loan, initiator: &Address) -> Result<Auction, Error> {
+    if !loan.is_active {
+        return Err(Error::LoanNotActive);
+    }
+
+    if storage::has_auction(env, loan.loan_id) {
+        return Err(Error::AuctionAlreadyActive);
+    }
+
+    if !liquidation::is_liquidatable(env, loan)? {
+        return Err(Error::NotLiquidatable);
+    }
+
+    let auction = Auction {
+        loan_id: loan.loan_id,
+        initiator: initiator.clone(),
+        start_time: env.ledger().timestamp(),
+    };
+    storage::set_auction(env, &auction);
+
+    Ok(auction)
+}
+
+/// Fill a bid: the bidder repays up to `amount` of debt to the lender and
+/// receives collateral worth the repaid amount at the current discount
+/// A single bid repays at most the close factor of the debt
+///
+/// Returns the amounts moved so the caller can publish them.
+pub fn execute_bid(
+    env: &Env,
+    loan: &mut Loan,
+    bidder: &Address,
+    amount: i128,
+) -> Result<AuctionBidData, Error> {
+    let auction = storage::get_auction(env, loan.loan_id)?;
+    let discount_bps = current_discount(env, &auction);
+
+    if !loan.is_active {
+        return Err(Error::LoanNotActive);
+    }
+
+    if !liquidation::is_liquidatable(env, loan)? {
+        return Err(Error::NotLiquidatable);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidRepayAmount);
+    }
+
+    // Bids repay at most close_factor of the outstanding debt
+    let current_time = env.ledger().timestamp();
+    let total_debt = interest::calculate_total_debt(
+        loan.borrowed_amount,
+        loan.accumulated_interest,
+        loan.interest_rate,
+        loan.interest_mode,
+        loan.last_interest_update,
+        current_time,
+    )?;
+    let max_repay =
+        liquidation::max_liquidation_repay(total_debt, storage::get_close_factor(env))?;
+    let mut amount_paid = amount.min(max_repay);
+
+    // Collateral at the oracle price, grossed up by the discount
+    // collateral = collateral_for(amount_paid) * BASIS_POINTS / (BASIS_POINTS - discount)
+    let config = storage::get_collateral_config(env, &loan.collateral_token)?;
+    let loan_asset = storage::get_loan_asset(env, &loan.loan_token)?;
+    let oracle_address = storage::get_oracle_address(env)?;
+    let discounted_bps = BASIS_POINTS
+        .checked_sub(discount_bps)
+        .ok_or(Error::ArithmeticUnderflow)? as i128;
+
+    let collateral_at_oracle = oracle::collateral_amount_for_value(
+        env,
+        &oracle_address,
+        &config,
+        &loan_asset,
+        amount_paid,
+        Valuation::Spot,
+    )?;
+    let mut collateral_sold = collateral_at_oracle
+        .checked_mul(BASIS_POINTS as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(discounted_bps)
+        .ok_or(Error::DivisionByZero)?;
+
+    // If the collateral runs out, the bidder only pays for what is left
+    if collateral_sold > loan.collateral_amount {
+        collateral_sold = loan.collateral_amount;
+        let collateral_value = oracle::collateral_value(
+            env,
+            &oracle_address,
+            &config,
+            &loan_asset,
+            collateral_sold,
+            Valuation::Spot,
+        )?;
+        amount_paid = collateral_value
+            .checked_mul(discounted_bps)
+            .ok_or(Error::ArithmeticOverflow)?
+            .checked_div(BASIS_POINTS as i128)
+            .ok_or(Error::DivisionByZero)?;
+
+        if amount_paid <= 0 {
+            return Err(Error::InsufficientCollateralValue);
+        }
+    }
+
+    // Bidder repays the lender (less the protocol fee) and receives the collateral
+    let loan_client = token::TokenClient::new(env, &loan.loan_token);
+    let collateral_client = token::TokenClient::new(env, &loan.collateral_token);
+    let contract_address = env.current_contract_address();
+
+    // Update the loan: interest is repaid before principal
+    let (interest_paid, principal_paid) = interest::apply_payment(loan, amount_paid, current_time)?;
+    let (lender_amount, protocol_fee) = interest::split_payment(
+        amount_paid,
+        interest_paid,
+        storage::get_fee_config(env).protocol_fee_bps,
+    )?;
+
+    let lender = position::lender_of(env, loan)?;
+    loan_client.transfer(bidder, &lender, &lender_amount);
+    if protocol_fee > 0 {
+        loan_client.transfer(bidder, &contract_address, &protocol_fee);
+        storage::add_protocol_fees(env, &loan.loan_token, protocol_fee)?;
+    }
+    collateral_client.transfer(&contract_address, bidder, &collateral_sold);
+
+    loan.collateral_amount = loan
+        .collateral_amount
+        .checked_sub(collateral_sold)
+        .ok_or(Error::ArithmeticUnderflow)?;
+
+    let remaining_debt = loan
+        .borrowed_amount
+        .checked_add(loan.accumulated_interest)
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    // Close the loan once nothing is owed or nothing is left to sell
+    let mut collateral_returned = 0;
+    if remaining_debt == 0 || loan.collateral_amount == 0 {
+        loan.is_active = false;
+
+        if loan.collateral_amount > 0 {
+            collateral_client.transfer(&contract_address, &loan.borrower, &loan.collateral_amount);
+            collateral_returned = loan.collateral_amount;
+            loan.collateral_amount = 0;
+        }
+    }
+
+    Ok(AuctionBidData {
+        amount_paid,
+        interest_paid,
+        principal_paid,
+        protocol_fee,
+        collateral_sold,
+        discount_bps,
+        collateral_returned,
+        remaining_debt,
+    })
+}
+
+/// End a loan's auction once the loan has closed or is healthy again
+/// Only queries the oracle when an auction is actually running
+pub fn end_if_settled(env: &Env, loan: &Loan) {
+    if !storage::has_auction(env, loan.loan_id) {
+        return;
+    }
+
+    let settled = !loan.is_active || liquidation::is_liquidatable(env, loan) == Ok(false);
+    if !settled {
+        return;
+    }
+
+    if let Ok(auction) = storage::get_auction(env, loan.loan_id) {
+        storage::remove_auction(env, loan.loan_id);
+        events::auction_ended(env, loan.loan_id, auction.start_time);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discount_grows_linearly_to_cap() {
+        let config = AuctionConfig {
+            max_discount_bps: 2000,
+            duration: 3600,
+        };
+
+        assert_eq!(discount_at(&config, 1000, 1000), 0);
+        assert_eq!(discount_at(&config, 1000, 1900), 500);
+        assert_eq!(discount_at(&config, 1000, 2800), 1000);
+        assert_eq!(discount_at(&config, 1000, 4600), 2000);
+
+        // Capped after the duration
+        assert_eq!(discount_at(&config, 1000, 100_000), 2000);
+    }
+}
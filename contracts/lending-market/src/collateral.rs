@@ -0,0 +1,58 @@
+//! Per-asset collateral risk parameters
+//!
+//! Offers set their own collateral ratio and liquidation threshold, but a loan
+//! never runs looser than the limits registered for the collateral it posts.
+
+use crate::reflector::Asset as ReflectorAsset;
+use crate::storage;
+use crate::types::{
+    CollateralConfig, LoanAssetConfig, BASIS_POINTS, USDC_DECIMALS, XLM_LIQUIDATION_BONUS_BPS,
+    XLM_LIQUIDATION_THRESHOLD_CAP, XLM_MAX_LTV_BPS,
+};
+use soroban_sdk::{symbol_short, Address, Env};
+
+/// List the assets every market starts with: USDC to lend and XLM as collateral
+pub fn list_launch_assets(env: &Env, usdc_token: &Address, xlm_token: &Address) {
+    storage::set_loan_asset(
+        env,
+        usdc_token,
+        &LoanAssetConfig {
+            oracle_asset: ReflectorAsset::Other(symbol_short!("USDC")),
+            decimals: USDC_DECIMALS,
+        },
+    );
+    storage::set_collateral_config(
+        env,
+        xlm_token,
+        &CollateralConfig {
+            oracle_asset: xlm_oracle_asset(),
+            decimals: 7,
+            max_ltv_bps: XLM_MAX_LTV_BPS,
+            liquidation_threshold_cap: XLM_LIQUIDATION_THRESHOLD_CAP,
+            liquidation_bonus_bps: XLM_LIQUIDATION_BONUS_BPS,
+        },
+    );
+}
+
+/// Reflector asset of the XLM collateral listed at launch
+pub fn xlm_oracle_asset() -> ReflectorAsset {
+    ReflectorAsset::Other(symbol_short!("XLM"))
+}
+
+/// Collateral ratio equivalent to a loan-to-value (both in basis points, rounded up)
+/// ratio = BASIS_POINTS^2 / ltv
+pub fn ratio_for_ltv(ltv_bps: u32) -> u32 {
+    let squared = (BASIS_POINTS as u64) * (BASIS_POINTS as u64);
+    let ltv = ltv_bps.max(1) as u64;
+    squared.div_ceil(ltv).min(u32::MAX as u64) as u32
+}
+
+/// Minimum collateral ratio for a loan: the stricter of the offer's and the asset's
+pub fn min_collateral_ratio(config: &CollateralConfig, offer_ratio: u32) -> u32 {
+    offer_ratio.max(ratio_for_ltv(config.max_ltv_bps))
+}
+
+/// Liquidation threshold for a loan: the stricter of the offer's and the asset's
+pub fn liquidation_threshold(config: &CollateralConfig, offer_threshold: u32) -> u32 {
+    offer_threshold.max(ratio_for_ltv(config.liquidation_threshold_cap))
+}
@@ -1,13 +1,33 @@
 //! Main contract implementation for the Lending Market
 
+use crate::auction;
+use crate::collateral;
 use crate::error::Error;
+use crate::events::{
+    self, CollateralData, DefaultClaimData, LoanOpenedData, LoanRepaidData, OfferCreatedData,
+    OfferFundsData, PoolLoanOpenedData, PoolLoanRepaidData, PoolSharesData,
+};
 use crate::interest;
 use crate::liquidation;
+use crate::matching;
+use crate::migration::{self, SCHEMA_VERSION};
 use crate::oracle;
+use crate::pool;
+use crate::position;
+use crate::query;
+use crate::request;
+use crate::timelock;
 use crate::storage;
 use crate::validation;
-use crate::types::{Loan, LendingOffer, LoanHealth};
+use crate::types::{
+    Auction, AuctionConfig, BorrowFill, BorrowRequest, CollateralConfig, FeeConfig, InterestMode,
+    LendingOffer, Loan, LoanAssetConfig, LoanHealth, OfferFilter, Operation, OracleSource,
+    PauseState, PoolLoan, PoolRates, PoolState, QueuedAction, RateModel, RiskConfig, Role,
+    SortOption, TimelockAction, TtlConfig,
+};
 use soroban_sdk::{contract, contractimpl, token, Address, Env, Vec};
+use stellar_macros::default_impl;
+use stellar_tokens::fungible::{Base, FungibleToken};
 
 #[contract]
 pub struct LendingMarket;
@@ -23,6 +43,7 @@ impl LendingMarket {
         xlm_token: Address,
         oracle_address: Address,
         max_interest_rate: u32,
+        dex_router: Address,
     ) -> Result<(), Error> {
         // Require admin authorization
         admin.require_auth();
@@ -31,59 +52,72 @@ impl LendingMarket {
         if storage::get_admin(&env).is_ok() {
             return Err(Error::AlreadyInitialized);
         }
+        validation::validate_max_interest_rate(max_interest_rate)?;
 
         // Store configuration
         storage::set_admin(&env, &admin);
         storage::set_usdc_token(&env, &usdc_token);
-        storage::set_xlm_token(&env, &xlm_token);
+        collateral::list_launch_assets(&env, &usdc_token, &xlm_token);
         storage::set_oracle_address(&env, &oracle_address);
         storage::set_max_interest_rate(&env, max_interest_rate);
-        storage::set_paused(&env, false);
+        storage::set_dex_router(&env, &dex_router);
+        storage::set_pause_state(&env, &PauseState::default());
+        storage::set_schema_version(&env, SCHEMA_VERSION);
+        pool::set_share_metadata(&env);
+        storage::extend_instance_ttl(&env);
 
         Ok(())
     }
 
     // ========== LENDER FUNCTIONS ==========
 
-    /// Create a new lending offer
+    /// Create a new lending offer of `amount` of `loan_token`
     pub fn create_offer(
         env: Env,
         lender: Address,
-        usdc_amount: i128,
+        loan_token: Address,
+        amount: i128,
         weekly_interest_rate: u32,
+        interest_mode: InterestMode,
         min_collateral_ratio: u32,
         liquidation_threshold: u32,
         max_duration_weeks: u32,
+        collateral_assets: Vec<Address>,
     ) -> Result<u64, Error> {
         // Authorization and guards
         lender.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Offers)?;
         storage::lock(&env)?;
 
         // Validate inputs
-        validation::validate_offer_amount(usdc_amount)?;
+        validation::validate_offer_amount(amount)?;
         validation::validate_interest_rate(&env, weekly_interest_rate)?;
-        validation::validate_collateral_ratio(min_collateral_ratio)?;
+        let risk = storage::get_risk_config(&env);
+        validation::validate_collateral_ratio(min_collateral_ratio, risk.max_collateral_ratio)?;
         validation::validate_liquidation_threshold(liquidation_threshold, min_collateral_ratio)?;
+        storage::get_loan_asset(&env, &loan_token)?;
+        validation::validate_offer_collateral(&env, &loan_token, &collateral_assets)?;
         validation::validate_offer_limit(&env, &lender)?;
+        validation::validate_offer_rate_limit(&env, &loan_token, weekly_interest_rate)?;
 
-        // Get USDC token
-        let usdc_token = storage::get_usdc_token(&env)?;
-        let token_client = token::TokenClient::new(&env, &usdc_token);
-
-        // Transfer USDC from lender to contract
-        token_client.transfer(&lender, &env.current_contract_address(), &usdc_amount);
+        // Transfer the loan token from lender to contract
+        let token_client = token::TokenClient::new(&env, &loan_token);
+        token_client.transfer(&lender, &env.current_contract_address(), &amount);
 
         // Create offer
         let offer_id = storage::get_next_offer_id(&env);
         let offer = LendingOffer {
             offer_id,
             lender: lender.clone(),
-            usdc_amount,
+            loan_token: loan_token.clone(),
+            amount,
             weekly_interest_rate,
+            interest_mode,
             min_collateral_ratio,
             liquidation_threshold,
             max_duration_weeks,
+            collateral_assets: collateral_assets.clone(),
             is_active: true,
             created_at: env.ledger().timestamp(),
         };
@@ -92,15 +126,35 @@ impl LendingMarket {
         storage::set_offer(&env, &offer);
         storage::add_user_offer(&env, &lender, offer_id);
         storage::add_active_offer(&env, offer_id);
+        storage::add_offer_by_rate(&env, &offer);
+
+        events::offer_created(
+            &env,
+            offer_id,
+            &lender,
+            OfferCreatedData {
+                loan_token,
+                amount,
+                weekly_interest_rate,
+                interest_mode,
+                min_collateral_ratio,
+                liquidation_threshold,
+                max_duration_weeks,
+                collateral_assets,
+            },
+        );
 
         storage::unlock(&env);
         Ok(offer_id)
     }
 
-    /// Cancel an offer (only if no active loans against it)
+    /// Cancel an offer
+    /// If loans against the offer are still outstanding, only its idle funds are returned
+    /// and the offer stays open until they are closed
     pub fn cancel_offer(env: Env, lender: Address, offer_id: u64) -> Result<(), Error> {
         lender.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Withdrawals)?;
         storage::lock(&env)?;
 
         // Get offer
@@ -118,21 +172,41 @@ impl LendingMarket {
             return Err(Error::OfferNotActive);
         }
 
-        // Check if there are active loans against this offer
-        // For simplicity, we'll allow cancellation if amount is still available
-        // In production, track loans per offer
+        let idle_amount = offer.amount;
+        let has_active_loans = !storage::get_offer_loans(&env, offer_id).is_empty();
 
-        // Mark offer as inactive
-        offer.is_active = false;
-        storage::set_offer(&env, &offer);
+        // With loans still outstanding the offer stays open and only its idle funds are returned
+        if has_active_loans && idle_amount == 0 {
+            storage::unlock(&env);
+            return Err(Error::OfferHasActiveLoans);
+        }
 
-        // Remove from active offers
-        storage::remove_active_offer(&env, offer_id);
+        offer.amount = 0;
+        storage::remove_offer_by_rate(&env, &offer);
+        if !has_active_loans {
+            // Mark offer as inactive
+            offer.is_active = false;
+
+            // Remove from active offers
+            storage::remove_active_offer(&env, offer_id);
+        }
+        storage::set_offer(&env, &offer);
 
         // Return funds to lender
-        let usdc_token = storage::get_usdc_token(&env)?;
-        let token_client = token::TokenClient::new(&env, &usdc_token);
-        token_client.transfer(&env.current_contract_address(), &lender, &offer.usdc_amount);
+        if idle_amount > 0 {
+            let token_client = token::TokenClient::new(&env, &offer.loan_token);
+            token_client.transfer(&env.current_contract_address(), &lender, &idle_amount);
+        }
+
+        let data = OfferFundsData {
+            amount: idle_amount,
+            remaining: 0,
+        };
+        if has_active_loans {
+            events::offer_withdrawn(&env, offer_id, &lender, data);
+        } else {
+            events::offer_cancelled(&env, offer_id, &lender, data);
+        }
 
         storage::unlock(&env);
         Ok(())
@@ -146,7 +220,8 @@ impl LendingMarket {
         amount: i128,
     ) -> Result<(), Error> {
         lender.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Withdrawals)?;
         storage::lock(&env)?;
 
         // Get offer
@@ -159,40 +234,59 @@ impl LendingMarket {
         }
 
         // Verify amount
-        if amount <= 0 || amount > offer.usdc_amount {
+        if amount <= 0 || amount > offer.amount {
             storage::unlock(&env);
             return Err(Error::InvalidInput);
         }
 
         // Update offer amount
-        offer.usdc_amount = offer
-            .usdc_amount
+        offer.amount = offer
+            .amount
             .checked_sub(amount)
             .ok_or(Error::ArithmeticUnderflow)?;
+        if offer.amount == 0 {
+            storage::remove_offer_by_rate(&env, &offer);
+        }
 
         storage::set_offer(&env, &offer);
 
-        // Transfer USDC to lender
-        let usdc_token = storage::get_usdc_token(&env)?;
-        let token_client = token::TokenClient::new(&env, &usdc_token);
+        // Transfer the loan token to lender
+        let token_client = token::TokenClient::new(&env, &offer.loan_token);
         token_client.transfer(&env.current_contract_address(), &lender, &amount);
 
+        events::offer_withdrawn(
+            &env,
+            offer_id,
+            &lender,
+            OfferFundsData {
+                amount,
+                remaining: offer.amount,
+            },
+        );
+
         storage::unlock(&env);
         Ok(())
     }
 
     // ========== BORROWER FUNCTIONS ==========
 
-    /// Borrow USDC against XLM collateral
+    /// Borrow the offer's loan token against `collateral_token` for `duration_weeks`
+    /// (at most the offer's `max_duration_weeks`)
+    ///
+    /// The loan uses the stricter of the offer's and the collateral asset's
+    /// collateral ratio and liquidation threshold.
     pub fn borrow(
         env: Env,
         borrower: Address,
         offer_id: u64,
+        collateral_token: Address,
         collateral_amount: i128,
         borrow_amount: i128,
+        duration_weeks: u32,
     ) -> Result<u64, Error> {
         borrower.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Borrows)?;
         storage::lock(&env)?;
 
         // Get offer
@@ -205,72 +299,155 @@ impl LendingMarket {
         }
 
         // Verify sufficient funds in offer
-        if borrow_amount > offer.usdc_amount {
+        if borrow_amount > offer.amount {
             storage::unlock(&env);
             return Err(Error::InsufficientOfferFunds);
         }
 
+        // Verify the offer takes this collateral
+        if !offer.collateral_assets.contains(&collateral_token) {
+            storage::unlock(&env);
+            return Err(Error::CollateralNotAccepted);
+        }
+        let config = storage::get_collateral_config(&env, &collateral_token)?;
+        let loan_asset = storage::get_loan_asset(&env, &offer.loan_token)?;
+
         // Validate inputs
         validation::validate_collateral_amount(collateral_amount)?;
         validation::validate_borrow_amount(borrow_amount)?;
+        validation::validate_loan_duration(duration_weeks, offer.max_duration_weeks)?;
         validation::validate_loan_limit(&env, &borrower)?;
         validation::validate_sufficient_collateral(
             &env,
+            &config,
+            &loan_asset,
             collateral_amount,
             borrow_amount,
-            offer.min_collateral_ratio,
+            collateral::min_collateral_ratio(&config, offer.min_collateral_ratio),
         )?;
 
-        // Transfer XLM collateral from borrower to contract
-        let xlm_token = storage::get_xlm_token(&env)?;
-        let xlm_client = token::TokenClient::new(&env, &xlm_token);
-        xlm_client.transfer(&borrower, &env.current_contract_address(), &collateral_amount);
+        // Transfer collateral from borrower to contract
+        let collateral_client = token::TokenClient::new(&env, &collateral_token);
+        collateral_client.transfer(&borrower, &env.current_contract_address(), &collateral_amount);
 
-        // Create loan
-        let loan_id = storage::get_next_loan_id(&env);
-        let current_time = env.ledger().timestamp();
+        // Create loan and take its amount out of the offer
+        let loan = matching::open_loan(
+            &env,
+            &borrower,
+            &mut offer,
+            &config,
+            &collateral_token,
+            collateral_amount,
+            borrow_amount,
+            duration_weeks,
+        )?;
 
-        let loan = Loan {
-            loan_id,
+        // Transfer the loan token to borrower
+        let loan_client = token::TokenClient::new(&env, &offer.loan_token);
+        loan_client.transfer(&env.current_contract_address(), &borrower, &borrow_amount);
+
+        events::loan_opened(
+            &env,
+            loan.loan_id,
             offer_id,
-            borrower: borrower.clone(),
-            lender: offer.lender.clone(),
-            collateral_amount,
-            borrowed_amount: borrow_amount,
-            interest_rate: offer.weekly_interest_rate,
-            start_time: current_time,
-            last_interest_update: current_time,
-            accumulated_interest: 0,
-            liquidation_threshold: offer.liquidation_threshold,
-            is_active: true,
-        };
+            &borrower,
+            LoanOpenedData {
+                lender: loan.lender.clone(),
+                collateral_token,
+                collateral_amount,
+                loan_token: offer.loan_token,
+                borrowed_amount: borrow_amount,
+                interest_rate: loan.interest_rate,
+                due_time: loan.due_time,
+            },
+        );
 
-        // Store loan
-        storage::set_loan(&env, &loan);
-        storage::add_user_loan_as_borrower(&env, &borrower, loan_id);
-        storage::add_user_loan_as_lender(&env, &offer.lender, loan_id);
-        storage::add_active_loan(&env, loan_id);
-
-        // Update offer (reduce available amount)
-        offer.usdc_amount = offer
-            .usdc_amount
-            .checked_sub(borrow_amount)
-            .ok_or(Error::ArithmeticUnderflow)?;
-        storage::set_offer(&env, &offer);
+        storage::unlock(&env);
+        Ok(loan.loan_id)
+    }
 
-        // Transfer USDC to borrower
-        let usdc_token = storage::get_usdc_token(&env)?;
-        let usdc_client = token::TokenClient::new(&env, &usdc_token);
-        usdc_client.transfer(&env.current_contract_address(), &borrower, &borrow_amount);
+    /// Borrow `amount` of `loan_token` from the cheapest offers at or below `max_rate`,
+    /// opening one loan per offer, and return the loan IDs
+    ///
+    /// The collateral is split across the loans in proportion to what each borrows;
+    /// `quote_borrow` shows the split without borrowing. Offers are read cheapest first,
+    /// at most 20 of them, and at most 5 loans are opened per call.
+    pub fn borrow_best(
+        env: Env,
+        borrower: Address,
+        loan_token: Address,
+        collateral_token: Address,
+        collateral_amount: i128,
+        amount: i128,
+        max_rate: u32,
+        duration_weeks: u32,
+    ) -> Result<Vec<u64>, Error> {
+        borrower.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Borrows)?;
+        storage::lock(&env)?;
+
+        let fills = matching::plan(
+            &env,
+            &borrower,
+            &loan_token,
+            &collateral_token,
+            collateral_amount,
+            amount,
+            max_rate,
+            duration_weeks,
+        )?;
+        let config = storage::get_collateral_config(&env, &collateral_token)?;
+
+        // Transfer collateral from borrower to contract
+        let collateral_client = token::TokenClient::new(&env, &collateral_token);
+        collateral_client.transfer(&borrower, &env.current_contract_address(), &collateral_amount);
+
+        let mut loan_ids = Vec::new(&env);
+        for fill in fills.iter() {
+            let mut offer = storage::get_offer(&env, fill.offer_id)?;
+            let loan = matching::open_loan(
+                &env,
+                &borrower,
+                &mut offer,
+                &config,
+                &collateral_token,
+                fill.collateral_amount,
+                fill.amount,
+                duration_weeks,
+            )?;
+
+            events::loan_opened(
+                &env,
+                loan.loan_id,
+                fill.offer_id,
+                &borrower,
+                LoanOpenedData {
+                    lender: loan.lender,
+                    collateral_token: collateral_token.clone(),
+                    collateral_amount: fill.collateral_amount,
+                    loan_token: loan_token.clone(),
+                    borrowed_amount: fill.amount,
+                    interest_rate: loan.interest_rate,
+                    due_time: loan.due_time,
+                },
+            );
+            loan_ids.push_back(loan.loan_id);
+        }
+
+        // Transfer the loan token to borrower
+        let loan_client = token::TokenClient::new(&env, &loan_token);
+        loan_client.transfer(&env.current_contract_address(), &borrower, &amount);
 
         storage::unlock(&env);
-        Ok(loan_id)
+        Ok(loan_ids)
     }
 
     /// Repay a loan (partial or full)
     pub fn repay(env: Env, borrower: Address, loan_id: u64, repay_amount: i128) -> Result<(), Error> {
         borrower.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Repayments)?;
         storage::lock(&env)?;
 
         // Get loan
@@ -294,6 +471,7 @@ impl LendingMarket {
             loan.borrowed_amount,
             loan.accumulated_interest,
             loan.interest_rate,
+            loan.interest_mode,
             loan.last_interest_update,
             current_time,
         )?;
@@ -301,57 +479,61 @@ impl LendingMarket {
         // Validate repayment amount
         validation::validate_repay_amount(repay_amount, total_debt)?;
 
-        // Transfer USDC from borrower to lender
-        let usdc_token = storage::get_usdc_token(&env)?;
-        let usdc_client = token::TokenClient::new(&env, &usdc_token);
-        usdc_client.transfer(&borrower, &loan.lender, &repay_amount);
-
-        // Calculate how much principal and interest is being paid
-        let new_interest = interest::calculate_interest(
-            loan.borrowed_amount,
-            loan.interest_rate,
-            loan.last_interest_update,
-            current_time,
-        )?;
-
-        let total_interest = loan
-            .accumulated_interest
-            .checked_add(new_interest)
-            .ok_or(Error::ArithmeticOverflow)?;
-
         // Pay interest first, then principal
-        if repay_amount >= total_interest {
-            // Paying all interest and some/all principal
-            let principal_payment = repay_amount
-                .checked_sub(total_interest)
-                .ok_or(Error::ArithmeticUnderflow)?;
-
-            loan.accumulated_interest = 0;
-            loan.borrowed_amount = loan
-                .borrowed_amount
-                .checked_sub(principal_payment)
-                .ok_or(Error::ArithmeticUnderflow)?;
-        } else {
-            // Only paying partial interest
-            loan.accumulated_interest = total_interest
-                .checked_sub(repay_amount)
-                .ok_or(Error::ArithmeticUnderflow)?;
+        let (interest_paid, principal_paid) =
+            interest::apply_payment(&mut loan, repay_amount, current_time)?;
+
+        // The protocol keeps its cut of the interest, the lender gets the rest
+        let fee_config = storage::get_fee_config(&env);
+        let (lender_amount, protocol_fee) =
+            interest::split_payment(repay_amount, interest_paid, fee_config.protocol_fee_bps)?;
+
+        // Transfer the loan token from borrower to lender and treasury
+        let loan_client = token::TokenClient::new(&env, &loan.loan_token);
+        let lender = position::lender_of(&env, &loan)?;
+        loan_client.transfer(&borrower, &lender, &lender_amount);
+        if protocol_fee > 0 {
+            loan_client.transfer(&borrower, &env.current_contract_address(), &protocol_fee);
+            storage::add_protocol_fees(&env, &loan.loan_token, protocol_fee)?;
         }
 
-        loan.last_interest_update = current_time;
-
         // If fully repaid, close loan and return collateral
+        let mut collateral_returned = 0;
         if loan.borrowed_amount == 0 && loan.accumulated_interest == 0 {
             loan.is_active = false;
             storage::remove_active_loan(&env, loan_id);
+            storage::remove_offer_loan(&env, loan.offer_id, loan_id);
+            storage::remove_user_loan_as_borrower(&env, &loan.borrower, loan_id);
 
             // Return collateral to borrower
-            let xlm_token = storage::get_xlm_token(&env)?;
-            let xlm_client = token::TokenClient::new(&env, &xlm_token);
-            xlm_client.transfer(&env.current_contract_address(), &borrower, &loan.collateral_amount);
+            let collateral_client = token::TokenClient::new(&env, &loan.collateral_token);
+            collateral_client.transfer(
+                &env.current_contract_address(),
+                &borrower,
+                &loan.collateral_amount,
+            );
+            collateral_returned = loan.collateral_amount;
+            loan.collateral_amount = 0;
         }
 
         storage::set_loan(&env, &loan);
+
+        events::loan_repaid(
+            &env,
+            loan_id,
+            &borrower,
+            LoanRepaidData {
+                amount: repay_amount,
+                interest_paid,
+                principal_paid,
+                protocol_fee,
+                remaining_principal: loan.borrowed_amount,
+                collateral_returned,
+            },
+        );
+
+        auction::end_if_settled(&env, &loan);
+
         storage::unlock(&env);
         Ok(())
     }
@@ -364,7 +546,7 @@ impl LendingMarket {
         additional_collateral: i128,
     ) -> Result<(), Error> {
         borrower.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
         storage::lock(&env)?;
 
         // Get loan
@@ -385,10 +567,13 @@ impl LendingMarket {
         // Validate amount
         validation::validate_collateral_amount(additional_collateral)?;
 
-        // Transfer XLM from borrower to contract
-        let xlm_token = storage::get_xlm_token(&env)?;
-        let xlm_client = token::TokenClient::new(&env, &xlm_token);
-        xlm_client.transfer(&borrower, &env.current_contract_address(), &additional_collateral);
+        // Transfer collateral from borrower to contract
+        let collateral_client = token::TokenClient::new(&env, &loan.collateral_token);
+        collateral_client.transfer(
+            &borrower,
+            &env.current_contract_address(),
+            &additional_collateral,
+        );
 
         // Update loan
         loan.collateral_amount = loan
@@ -397,6 +582,19 @@ impl LendingMarket {
             .ok_or(Error::ArithmeticOverflow)?;
 
         storage::set_loan(&env, &loan);
+
+        events::collateral_added(
+            &env,
+            loan_id,
+            &borrower,
+            CollateralData {
+                amount: additional_collateral,
+                collateral_amount: loan.collateral_amount,
+            },
+        );
+
+        auction::end_if_settled(&env, &loan);
+
         storage::unlock(&env);
         Ok(())
     }
@@ -409,7 +607,8 @@ impl LendingMarket {
         amount: i128,
     ) -> Result<(), Error> {
         borrower.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Withdrawals)?;
         storage::lock(&env)?;
 
         // Get loan
@@ -433,23 +632,27 @@ impl LendingMarket {
             loan.borrowed_amount,
             loan.accumulated_interest,
             loan.interest_rate,
+            loan.interest_mode,
             loan.last_interest_update,
             current_time,
         )?;
 
         // Validate withdrawal won't breach health
+        let config = storage::get_collateral_config(&env, &loan.collateral_token)?;
+        let loan_asset = storage::get_loan_asset(&env, &loan.loan_token)?;
         validation::validate_collateral_withdrawal(
             &env,
+            &config,
+            &loan_asset,
             loan.collateral_amount,
             amount,
             total_debt,
             loan.liquidation_threshold,
         )?;
 
-        // Transfer XLM to borrower
-        let xlm_token = storage::get_xlm_token(&env)?;
-        let xlm_client = token::TokenClient::new(&env, &xlm_token);
-        xlm_client.transfer(&env.current_contract_address(), &borrower, &amount);
+        // Transfer collateral to borrower
+        let collateral_client = token::TokenClient::new(&env, &loan.collateral_token);
+        collateral_client.transfer(&env.current_contract_address(), &borrower, &amount);
 
         // Update loan
         loan.collateral_amount = loan
@@ -458,10 +661,160 @@ impl LendingMarket {
             .ok_or(Error::ArithmeticUnderflow)?;
 
         storage::set_loan(&env, &loan);
+
+        events::collateral_withdrawn(
+            &env,
+            loan_id,
+            &borrower,
+            CollateralData {
+                amount,
+                collateral_amount: loan.collateral_amount,
+            },
+        );
+
+        storage::unlock(&env);
+        Ok(())
+    }
+
+    // ========== BORROW REQUESTS ==========
+
+    /// Ask to borrow `desired_amount` of `loan_token` for `duration_weeks` at a weekly
+    /// rate of at most `max_rate`, escrowing the collateral until the request is
+    /// filled or cancelled
+    ///
+    /// The loan uses the collateral asset's collateral ratio and liquidation threshold.
+    pub fn create_borrow_request(
+        env: Env,
+        borrower: Address,
+        loan_token: Address,
+        collateral_token: Address,
+        collateral_amount: i128,
+        desired_amount: i128,
+        max_rate: u32,
+        duration_weeks: u32,
+    ) -> Result<u64, Error> {
+        borrower.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Borrows)?;
+        storage::lock(&env)?;
+
+        let mut borrow_request = BorrowRequest {
+            request_id: 0,
+            borrower: borrower.clone(),
+            loan_token,
+            collateral_token: collateral_token.clone(),
+            collateral_amount,
+            desired_amount,
+            max_rate,
+            duration_weeks,
+            is_active: true,
+            created_at: env.ledger().timestamp(),
+        };
+        request::validate(&env, &borrow_request)?;
+
+        // Escrow the collateral
+        let collateral_client = token::TokenClient::new(&env, &collateral_token);
+        collateral_client.transfer(&borrower, &env.current_contract_address(), &collateral_amount);
+
+        borrow_request.request_id = storage::get_next_request_id(&env);
+        storage::set_borrow_request(&env, &borrow_request);
+        storage::add_user_borrow_request(&env, &borrower, borrow_request.request_id);
+        storage::add_active_request(&env, borrow_request.request_id);
+
+        events::borrow_request_created(&env, borrow_request.clone());
+
+        storage::unlock(&env);
+        Ok(borrow_request.request_id)
+    }
+
+    /// Cancel an open borrow request and return its collateral
+    pub fn cancel_borrow_request(
+        env: Env,
+        borrower: Address,
+        request_id: u64,
+    ) -> Result<(), Error> {
+        borrower.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Withdrawals)?;
+        storage::lock(&env)?;
+
+        let mut borrow_request = storage::get_borrow_request(&env, request_id)?;
+        if borrow_request.borrower != borrower {
+            storage::unlock(&env);
+            return Err(Error::OnlyBorrower);
+        }
+        if !borrow_request.is_active {
+            storage::unlock(&env);
+            return Err(Error::BorrowRequestNotActive);
+        }
+
+        request::close(&env, &mut borrow_request);
+
+        let collateral_client = token::TokenClient::new(&env, &borrow_request.collateral_token);
+        collateral_client.transfer(
+            &env.current_contract_address(),
+            &borrower,
+            &borrow_request.collateral_amount,
+        );
+
+        events::borrow_request_cancelled(
+            &env,
+            request_id,
+            &borrower,
+            borrow_request.collateral_amount,
+        );
+
         storage::unlock(&env);
         Ok(())
     }
 
+    /// Fund a borrow request at `interest_rate` (at most its `max_rate`), opening a loan
+    /// The loan tokens go straight from the lender to the borrower
+    pub fn fill_borrow_request(
+        env: Env,
+        lender: Address,
+        request_id: u64,
+        interest_rate: u32,
+    ) -> Result<u64, Error> {
+        lender.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Borrows)?;
+        storage::lock(&env)?;
+
+        let mut borrow_request = storage::get_borrow_request(&env, request_id)?;
+        if !borrow_request.is_active {
+            storage::unlock(&env);
+            return Err(Error::BorrowRequestNotActive);
+        }
+
+        let loan = request::open_loan(&env, &borrow_request, &lender, interest_rate)?;
+        request::close(&env, &mut borrow_request);
+
+        // Transfer the loan token from lender to borrower
+        let loan_client = token::TokenClient::new(&env, &loan.loan_token);
+        loan_client.transfer(&lender, &loan.borrower, &loan.borrowed_amount);
+
+        events::loan_opened(
+            &env,
+            loan.loan_id,
+            loan.offer_id,
+            &loan.borrower,
+            LoanOpenedData {
+                lender: lender.clone(),
+                collateral_token: loan.collateral_token,
+                collateral_amount: loan.collateral_amount,
+                loan_token: loan.loan_token,
+                borrowed_amount: loan.borrowed_amount,
+                interest_rate,
+                due_time: loan.due_time,
+            },
+        );
+        events::borrow_request_filled(&env, request_id, &lender, loan.loan_id);
+
+        storage::unlock(&env);
+        Ok(loan.loan_id)
+    }
+
     // ========== LIQUIDATION FUNCTIONS ==========
 
     /// Check if a loan is liquidatable
@@ -471,142 +824,1160 @@ impl LendingMarket {
     }
 
     /// Liquidate an undercollateralized loan
+    /// Repays up to `debt_to_cover` of debt, capped by the close factor
     /// Anyone can call this function
-    pub fn liquidate(env: Env, liquidator: Address, loan_id: u64) -> Result<(), Error> {
+    pub fn liquidate(
+        env: Env,
+        liquidator: Address,
+        loan_id: u64,
+        debt_to_cover: i128,
+    ) -> Result<(), Error> {
         liquidator.require_auth();
-        storage::require_not_paused(&env)?;
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Liquidations)?;
         storage::lock(&env)?;
 
         // Get loan
         let mut loan = storage::get_loan(&env, loan_id)?;
 
-        // Execute liquidation
-        liquidation::execute_liquidation(&env, &loan, &liquidator)?;
+        // Execute liquidation (closes the loan once its debt or collateral is exhausted)
+        let outcome =
+            liquidation::execute_liquidation(&env, &mut loan, &liquidator, debt_to_cover)?;
 
-        // Mark loan as inactive
-        loan.is_active = false;
         storage::set_loan(&env, &loan);
-        storage::remove_active_loan(&env, loan_id);
+        if !loan.is_active {
+            storage::remove_active_loan(&env, loan_id);
+            storage::remove_offer_loan(&env, loan.offer_id, loan_id);
+            storage::remove_user_loan_as_borrower(&env, &loan.borrower, loan_id);
+        }
+
+        events::loan_liquidated(&env, loan_id, &liquidator, outcome);
+
+        auction::end_if_settled(&env, &loan);
 
         storage::unlock(&env);
         Ok(())
     }
 
-    /// Batch check which loans are liquidatable
-    pub fn batch_check_liquidations(env: Env, loan_ids: Vec<u64>) -> Result<Vec<u64>, Error> {
-        liquidation::batch_check_liquidatable_vec(&env, loan_ids)
-    }
+    /// Start a Dutch auction for a liquidatable loan's collateral
+    /// Anyone can call this function
+    pub fn start_auction(env: Env, initiator: Address, loan_id: u64) -> Result<(), Error> {
+        initiator.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Liquidations)?;
+        storage::lock(&env)?;
 
-    // ========== QUERY FUNCTIONS ==========
+        let loan = storage::get_loan(&env, loan_id)?;
+        let auction = auction::start_auction(&env, &loan, &initiator)?;
 
-    /// Get offer details
-    pub fn get_offer(env: Env, offer_id: u64) -> Result<LendingOffer, Error> {
-        storage::get_offer(&env, offer_id)
-    }
+        events::auction_started(&env, loan_id, &initiator, auction.start_time);
 
-    /// Get loan details
-    pub fn get_loan(env: Env, loan_id: u64) -> Result<Loan, Error> {
-        storage::get_loan(&env, loan_id)
+        storage::unlock(&env);
+        Ok(())
     }
 
-    /// Get loan health information
-    pub fn get_loan_health(env: Env, loan_id: u64) -> Result<LoanHealth, Error> {
-        let loan = storage::get_loan(&env, loan_id)?;
-        liquidation::calculate_loan_health(&env, &loan)
+    /// Bid on a loan's auction: repay up to `amount` of its debt, capped at the
+    /// close factor, and receive collateral at the auction's current discount
+    pub fn bid(env: Env, bidder: Address, loan_id: u64, amount: i128) -> Result<(), Error> {
+        bidder.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Liquidations)?;
+        storage::lock(&env)?;
+
+        let mut loan = storage::get_loan(&env, loan_id)?;
+        let outcome = auction::execute_bid(&env, &mut loan, &bidder, amount)?;
+
+        storage::set_loan(&env, &loan);
+        if !loan.is_active {
+            storage::remove_active_loan(&env, loan_id);
+            storage::remove_offer_loan(&env, loan.offer_id, loan_id);
+            storage::remove_user_loan_as_borrower(&env, &loan.borrower, loan_id);
+        }
+
+        events::auction_bid(&env, loan_id, &bidder, outcome);
+
+        auction::end_if_settled(&env, &loan);
+
+        storage::unlock(&env);
+        Ok(())
     }
 
-    /// Calculate accumulated interest for a loan
-    pub fn calculate_interest(env: Env, loan_id: u64) -> Result<i128, Error> {
-        let loan = storage::get_loan(&env, loan_id)?;
+    /// Claim the collateral of a loan that is past its due time
+    /// Only the holder of the loan's position NFT can call this function
+    pub fn claim_defaulted_loan(env: Env, lender: Address, loan_id: u64) -> Result<(), Error> {
+        lender.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Liquidations)?;
+        storage::lock(&env)?;
+
+        // Get loan
+        let mut loan = storage::get_loan(&env, loan_id)?;
+
+        // Verify lender
+        if position::lender_of(&env, &loan)? != lender {
+            storage::unlock(&env);
+            return Err(Error::OnlyLender);
+        }
+
+        // Verify loan is active
+        if !loan.is_active {
+            storage::unlock(&env);
+            return Err(Error::LoanNotActive);
+        }
+
+        // Verify loan is past due
         let current_time = env.ledger().timestamp();
+        if !liquidation::is_overdue(&loan, current_time) {
+            storage::unlock(&env);
+            return Err(Error::LoanNotOverdue);
+        }
 
-        let new_interest = interest::calculate_interest(
+        // A compounding loan left overdue long enough outgrows i128. The debt is only
+        // reported, so it saturates rather than locking the collateral in the contract
+        let debt_outstanding = match interest::calculate_total_debt(
             loan.borrowed_amount,
+            loan.accumulated_interest,
             loan.interest_rate,
+            loan.interest_mode,
             loan.last_interest_update,
             current_time,
-        )?;
+        ) {
+            Err(Error::ArithmeticOverflow) => i128::MAX,
+            debt => debt?,
+        };
 
-        let total_interest = loan
-            .accumulated_interest
-            .checked_add(new_interest)
-            .ok_or(Error::ArithmeticOverflow)?;
+        // Transfer all collateral to the lender
+        let collateral_seized = loan.collateral_amount;
+        let collateral_client = token::TokenClient::new(&env, &loan.collateral_token);
+        collateral_client.transfer(
+            &env.current_contract_address(),
+            &lender,
+            &collateral_seized,
+        );
 
-        Ok(total_interest)
-    }
+        // Mark loan as inactive
+        loan.is_active = false;
+        loan.collateral_amount = 0;
+        storage::set_loan(&env, &loan);
+        storage::remove_active_loan(&env, loan_id);
+        storage::remove_offer_loan(&env, loan.offer_id, loan_id);
+        storage::remove_user_loan_as_borrower(&env, &loan.borrower, loan_id);
 
-    /// Get current XLM price
-    pub fn get_xlm_price(env: Env) -> Result<i128, Error> {
-        let oracle_address = storage::get_oracle_address(&env)?;
-        let price_data = oracle::get_xlm_price(&env, &oracle_address)?;
-        Ok(price_data.price)
-    }
+        events::loan_defaulted(
+            &env,
+            loan_id,
+            &lender,
+            DefaultClaimData {
+                debt_outstanding,
+                collateral_seized,
+            },
+        );
 
-    /// Get user's offers
-    pub fn get_user_offers(env: Env, user: Address) -> Vec<u64> {
-        storage::get_user_offers(&env, &user)
-    }
+        auction::end_if_settled(&env, &loan);
 
-    /// Get user's loans as borrower
-    pub fn get_user_loans_as_borrower(env: Env, user: Address) -> Vec<u64> {
-        storage::get_user_loans_as_borrower(&env, &user)
+        storage::unlock(&env);
+        Ok(())
     }
 
-    /// Get user's loans as lender
-    pub fn get_user_loans_as_lender(env: Env, user: Address) -> Vec<u64> {
-        storage::get_user_loans_as_lender(&env, &user)
+    /// Batch check which loans are liquidatable
+    pub fn batch_check_liquidations(env: Env, loan_ids: Vec<u64>) -> Result<Vec<u64>, Error> {
+        liquidation::batch_check_liquidatable_vec(&env, loan_ids)
     }
 
-    /// Get all active offers
-    pub fn get_active_offers(env: Env) -> Vec<u64> {
-        storage::get_active_offers(&env)
-    }
+    // ========== POOL FUNCTIONS ==========
 
-    /// Get all active loans
-    pub fn get_active_loans(env: Env) -> Vec<u64> {
-        storage::get_active_loans(&env)
-    }
+    /// Deposit USDC into the variable-rate pool
+    ///
+    /// Mints pool shares (this contract's SEP-41 token) at the current share
+    /// price and returns the shares minted
+    pub fn deposit(env: Env, lender: Address, amount: i128) -> Result<i128, Error> {
+        lender.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Offers)?;
+        storage::lock(&env)?;
 
-    // ========== ADMIN FUNCTIONS ==========
+        validation::validate_offer_amount(amount)?;
 
-    /// Update maximum interest rate
-    pub fn set_max_interest_rate(env: Env, admin: Address, max_rate: u32) -> Result<(), Error> {
-        admin.require_auth();
-        storage::require_admin(&env, &admin)?;
+        let mut state = pool::accrue(&env)?;
+        let shares = pool::assets_to_shares(&env, &state, amount)?;
+        if shares <= 0 {
+            storage::unlock(&env);
+            return Err(Error::InvalidOfferAmount);
+        }
 
-        storage::set_max_interest_rate(&env, max_rate);
-        Ok(())
-    }
+        let usdc_token = storage::get_usdc_token(&env)?;
+        let usdc_client = token::TokenClient::new(&env, &usdc_token);
+        usdc_client.transfer(&lender, &env.current_contract_address(), &amount);
 
-    /// Update oracle address
-    pub fn set_oracle_address(env: Env, admin: Address, oracle: Address) -> Result<(), Error> {
-        admin.require_auth();
-        storage::require_admin(&env, &admin)?;
+        state.cash = state
+            .cash
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticOverflow)?;
+        storage::set_pool_state(&env, &state);
+        Base::mint(&env, &lender, shares);
 
-        storage::set_oracle_address(&env, &oracle);
+        events::pool_deposited(&env, &lender, PoolSharesData { amount, shares });
+
+        storage::unlock(&env);
+        Ok(shares)
+    }
+
+    /// Burn pool shares for their USDC value, limited by the pool's idle cash
+    ///
+    /// Returns the USDC redeemed
+    pub fn redeem(env: Env, lender: Address, shares: i128) -> Result<i128, Error> {
+        // The lender's authorization is required by `Base::burn` below
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Withdrawals)?;
+        storage::lock(&env)?;
+
+        if shares <= 0 {
+            storage::unlock(&env);
+            return Err(Error::InvalidInput);
+        }
+
+        if shares > Base::balance(&env, &lender) {
+            storage::unlock(&env);
+            return Err(Error::InsufficientShares);
+        }
+
+        let mut state = pool::accrue(&env)?;
+        let amount = pool::shares_to_assets(&env, &state, shares)?;
+        if amount > state.cash {
+            storage::unlock(&env);
+            return Err(Error::InsufficientPoolLiquidity);
+        }
+
+        state.cash -= amount;
+        storage::set_pool_state(&env, &state);
+        Base::burn(&env, &lender, shares);
+
+        let usdc_token = storage::get_usdc_token(&env)?;
+        let usdc_client = token::TokenClient::new(&env, &usdc_token);
+        usdc_client.transfer(&env.current_contract_address(), &lender, &amount);
+
+        events::pool_redeemed(&env, &lender, PoolSharesData { amount, shares });
+
+        storage::unlock(&env);
+        Ok(amount)
+    }
+
+    /// Borrow USDC from the pool against `collateral_token` at the variable rate
+    ///
+    /// Returns the pool loan ID
+    pub fn pool_borrow(
+        env: Env,
+        borrower: Address,
+        collateral_token: Address,
+        collateral_amount: i128,
+        borrow_amount: i128,
+    ) -> Result<u64, Error> {
+        borrower.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Borrows)?;
+        storage::lock(&env)?;
+
+        let config = storage::get_collateral_config(&env, &collateral_token)?;
+        let (usdc_token, loan_asset) = pool::pool_asset(&env)?;
+        validation::validate_collateral_amount(collateral_amount)?;
+        validation::validate_borrow_amount(borrow_amount)?;
+        validation::validate_pool_loan_limit(&env, &borrower)?;
+        validation::validate_sufficient_collateral(
+            &env,
+            &config,
+            &loan_asset,
+            collateral_amount,
+            borrow_amount,
+            collateral::min_collateral_ratio(
+                &config,
+                storage::get_risk_config(&env).pool_min_collateral_ratio,
+            ),
+        )?;
+
+        let mut state = pool::accrue(&env)?;
+        let scaled_debt = pool::borrow(&mut state, borrow_amount)?;
+        storage::set_pool_state(&env, &state);
+
+        // Transfer collateral in and USDC out
+        let collateral_client = token::TokenClient::new(&env, &collateral_token);
+        collateral_client.transfer(&borrower, &env.current_contract_address(), &collateral_amount);
+
+        let usdc_client = token::TokenClient::new(&env, &usdc_token);
+        usdc_client.transfer(&env.current_contract_address(), &borrower, &borrow_amount);
+
+        let loan = PoolLoan {
+            loan_id: storage::get_next_pool_loan_id(&env),
+            borrower: borrower.clone(),
+            collateral_token: collateral_token.clone(),
+            collateral_amount,
+            scaled_debt,
+            start_time: env.ledger().timestamp(),
+            is_active: true,
+        };
+        storage::set_pool_loan(&env, &loan);
+        storage::add_active_pool_loan(&env, loan.loan_id);
+        storage::add_user_pool_loan(&env, &borrower, loan.loan_id);
+
+        events::pool_loan_opened(
+            &env,
+            loan.loan_id,
+            &borrower,
+            PoolLoanOpenedData {
+                collateral_token,
+                collateral_amount,
+                borrowed_amount: borrow_amount,
+                borrow_rate: pool::rates(&env, &state)?.borrow_rate,
+            },
+        );
+
+        storage::unlock(&env);
+        Ok(loan.loan_id)
+    }
+
+    /// Repay part or all of a pool loan
+    /// Collateral is returned once the debt is fully repaid
+    pub fn pool_repay(
+        env: Env,
+        borrower: Address,
+        loan_id: u64,
+        repay_amount: i128,
+    ) -> Result<(), Error> {
+        borrower.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Repayments)?;
+        storage::lock(&env)?;
+
+        let mut loan = storage::get_pool_loan(&env, loan_id)?;
+        if loan.borrower != borrower {
+            storage::unlock(&env);
+            return Err(Error::OnlyBorrower);
+        }
+
+        if !loan.is_active {
+            storage::unlock(&env);
+            return Err(Error::LoanNotActive);
+        }
+
+        let mut state = pool::accrue(&env)?;
+        let total_debt = pool::loan_debt(&state, &loan)?;
+        validation::validate_repay_amount(repay_amount, total_debt)?;
+
+        let usdc_token = storage::get_usdc_token(&env)?;
+        let usdc_client = token::TokenClient::new(&env, &usdc_token);
+        usdc_client.transfer(&borrower, &env.current_contract_address(), &repay_amount);
+
+        let remaining_debt = pool::repay(&mut state, &mut loan, repay_amount)?;
+        storage::set_pool_state(&env, &state);
+
+        // If fully repaid, close loan and return collateral
+        let mut collateral_returned = 0;
+        if loan.scaled_debt == 0 {
+            loan.is_active = false;
+
+            let collateral_client = token::TokenClient::new(&env, &loan.collateral_token);
+            collateral_client.transfer(
+                &env.current_contract_address(),
+                &borrower,
+                &loan.collateral_amount,
+            );
+            collateral_returned = loan.collateral_amount;
+            loan.collateral_amount = 0;
+
+            storage::remove_active_pool_loan(&env, loan_id);
+            storage::remove_user_pool_loan(&env, &borrower, loan_id);
+        }
+
+        storage::set_pool_loan(&env, &loan);
+
+        events::pool_loan_repaid(
+            &env,
+            loan_id,
+            &borrower,
+            PoolLoanRepaidData {
+                amount: repay_amount,
+                remaining_debt,
+                collateral_returned,
+            },
+        );
+
+        storage::unlock(&env);
         Ok(())
     }
 
-    /// Pause contract
-    pub fn pause_contract(env: Env, admin: Address) -> Result<(), Error> {
+    /// Add more collateral to a pool loan
+    pub fn pool_add_collateral(
+        env: Env,
+        borrower: Address,
+        loan_id: u64,
+        additional_collateral: i128,
+    ) -> Result<(), Error> {
+        borrower.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::lock(&env)?;
+
+        let mut loan = storage::get_pool_loan(&env, loan_id)?;
+        if loan.borrower != borrower {
+            storage::unlock(&env);
+            return Err(Error::OnlyBorrower);
+        }
+
+        if !loan.is_active {
+            storage::unlock(&env);
+            return Err(Error::LoanNotActive);
+        }
+
+        validation::validate_collateral_amount(additional_collateral)?;
+
+        let collateral_client = token::TokenClient::new(&env, &loan.collateral_token);
+        collateral_client.transfer(
+            &borrower,
+            &env.current_contract_address(),
+            &additional_collateral,
+        );
+
+        loan.collateral_amount = loan
+            .collateral_amount
+            .checked_add(additional_collateral)
+            .ok_or(Error::ArithmeticOverflow)?;
+        storage::set_pool_loan(&env, &loan);
+
+        events::pool_collateral_added(
+            &env,
+            loan_id,
+            &borrower,
+            CollateralData {
+                amount: additional_collateral,
+                collateral_amount: loan.collateral_amount,
+            },
+        );
+
+        storage::unlock(&env);
+        Ok(())
+    }
+
+    /// Withdraw excess collateral from a pool loan
+    pub fn pool_withdraw_collateral(
+        env: Env,
+        borrower: Address,
+        loan_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        borrower.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Withdrawals)?;
+        storage::lock(&env)?;
+
+        let mut loan = storage::get_pool_loan(&env, loan_id)?;
+        if loan.borrower != borrower {
+            storage::unlock(&env);
+            return Err(Error::OnlyBorrower);
+        }
+
+        if !loan.is_active {
+            storage::unlock(&env);
+            return Err(Error::LoanNotActive);
+        }
+
+        let state = pool::accrue(&env)?;
+        let config = storage::get_collateral_config(&env, &loan.collateral_token)?;
+        let (_, loan_asset) = pool::pool_asset(&env)?;
+        validation::validate_collateral_withdrawal(
+            &env,
+            &config,
+            &loan_asset,
+            loan.collateral_amount,
+            amount,
+            pool::loan_debt(&state, &loan)?,
+            collateral::liquidation_threshold(
+                &config,
+                storage::get_risk_config(&env).pool_liquidation_threshold,
+            ),
+        )?;
+
+        let collateral_client = token::TokenClient::new(&env, &loan.collateral_token);
+        collateral_client.transfer(&env.current_contract_address(), &borrower, &amount);
+
+        loan.collateral_amount = loan
+            .collateral_amount
+            .checked_sub(amount)
+            .ok_or(Error::ArithmeticUnderflow)?;
+        storage::set_pool_loan(&env, &loan);
+
+        events::pool_collateral_withdrawn(
+            &env,
+            loan_id,
+            &borrower,
+            CollateralData {
+                amount,
+                collateral_amount: loan.collateral_amount,
+            },
+        );
+
+        storage::unlock(&env);
+        Ok(())
+    }
+
+    /// Liquidate an undercollateralized pool loan, repaying up to the close
+    /// factor of its debt by selling collateral on the DEX
+    /// Anyone can call this function
+    pub fn pool_liquidate(
+        env: Env,
+        liquidator: Address,
+        loan_id: u64,
+        debt_to_cover: i128,
+    ) -> Result<(), Error> {
+        liquidator.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_not_paused(&env, Operation::Liquidations)?;
+        storage::lock(&env)?;
+
+        let mut loan = storage::get_pool_loan(&env, loan_id)?;
+        let outcome = pool::execute_liquidation(&env, &mut loan, &liquidator, debt_to_cover)?;
+        storage::set_pool_loan(&env, &loan);
+        if !loan.is_active {
+            storage::remove_active_pool_loan(&env, loan_id);
+            storage::remove_user_pool_loan(&env, &loan.borrower, loan_id);
+        }
+
+        events::pool_loan_liquidated(&env, loan_id, &liquidator, outcome);
+
+        storage::unlock(&env);
+        Ok(())
+    }
+
+    /// Get the pool accounting with interest accrued up to now
+    pub fn get_pool_state(env: Env) -> Result<PoolState, Error> {
+        pool::current_state(&env)
+    }
+
+    /// Get the pool's utilization and weekly borrow and supply rates
+    pub fn get_pool_rates(env: Env) -> Result<PoolRates, Error> {
+        let state = pool::current_state(&env)?;
+        pool::rates(&env, &state)
+    }
+
+    /// Get the USDC value of one whole pool share, with interest accrued up to now
+    pub fn get_share_price(env: Env) -> Result<i128, Error> {
+        let state = pool::current_state(&env)?;
+        pool::share_price(&env, &state)
+    }
+
+    /// Get the USDC value of a lender's pool shares
+    pub fn get_pool_balance(env: Env, lender: Address) -> Result<i128, Error> {
+        let state = pool::current_state(&env)?;
+        pool::shares_to_assets(&env, &state, Base::balance(&env, &lender))
+    }
+
+    /// Get pool loan details by ID
+    pub fn get_pool_loan(env: Env, loan_id: u64) -> Result<PoolLoan, Error> {
+        storage::get_pool_loan(&env, loan_id)
+    }
+
+    /// Get the current debt of a pool loan
+    pub fn get_pool_loan_debt(env: Env, loan_id: u64) -> Result<i128, Error> {
+        let loan = storage::get_pool_loan(&env, loan_id)?;
+        let state = pool::current_state(&env)?;
+        pool::loan_debt(&state, &loan)
+    }
+
+    /// Get a user's open pool loans
+    pub fn get_user_pool_loans(env: Env, user: Address) -> Vec<u64> {
+        storage::get_user_pool_loans(&env, &user)
+    }
+
+    /// Get one page of active pool loan IDs (up to 50 per page, pages start at 0)
+    pub fn get_active_pool_loans_page(env: Env, page: u32) -> Vec<u64> {
+        storage::get_active_pool_loans_page(&env, page)
+    }
+
+    /// Get the number of active pool loans
+    pub fn get_active_pool_loan_count(env: Env) -> u32 {
+        storage::get_active_pool_loan_count(&env)
+    }
+
+    /// Get pool loan health information
+    /// Pool loans have no due time, so `due_time` is `u64::MAX`
+    pub fn get_pool_loan_health(env: Env, loan_id: u64) -> Result<LoanHealth, Error> {
+        let loan = storage::get_pool_loan(&env, loan_id)?;
+        pool::loan_health(&env, &loan)
+    }
+
+    // ========== STORAGE TTL ==========
+
+    /// Extend the TTL of a loan and its index entries
+    /// Anyone can call this function (e.g. keepers for long-lived loans)
+    pub fn bump_loan(env: Env, loan_id: u64) -> Result<(), Error> {
+        storage::extend_instance_ttl(&env);
+        let loan = storage::get_loan(&env, loan_id)?;
+        storage::extend_loan_ttl(&env, &loan);
+        Ok(())
+    }
+
+    /// Extend the TTL of an offer and its index entries
+    /// Anyone can call this function
+    pub fn bump_offer(env: Env, offer_id: u64) -> Result<(), Error> {
+        storage::extend_instance_ttl(&env);
+        let offer = storage::get_offer(&env, offer_id)?;
+        storage::extend_offer_ttl(&env, &offer);
+        Ok(())
+    }
+
+    /// Extend the TTL of a pool loan and its index entries
+    /// Anyone can call this function (e.g. keepers for long-lived loans)
+    pub fn bump_pool_loan(env: Env, loan_id: u64) -> Result<(), Error> {
+        storage::extend_instance_ttl(&env);
+        let loan = storage::get_pool_loan(&env, loan_id)?;
+        storage::extend_pool_loan_ttl(&env, &loan);
+        Ok(())
+    }
+
+    /// Extend the TTL of a borrow request and its index entries
+    /// Anyone can call this function
+    pub fn bump_borrow_request(env: Env, request_id: u64) -> Result<(), Error> {
+        storage::extend_instance_ttl(&env);
+        let borrow_request = storage::get_borrow_request(&env, request_id)?;
+        storage::extend_request_ttl(&env, &borrow_request);
+        Ok(())
+    }
+
+    // ========== QUERY FUNCTIONS ==========
+
+    /// Get offer details
+    pub fn get_offer(env: Env, offer_id: u64) -> Result<LendingOffer, Error> {
+        storage::get_offer(&env, offer_id)
+    }
+
+    /// Get loan details
+    pub fn get_loan(env: Env, loan_id: u64) -> Result<Loan, Error> {
+        storage::get_loan(&env, loan_id)
+    }
+
+    /// Get borrow request details
+    pub fn get_borrow_request(env: Env, request_id: u64) -> Result<BorrowRequest, Error> {
+        storage::get_borrow_request(&env, request_id)
+    }
+
+    /// Get the auction running for a loan
+    pub fn get_auction(env: Env, loan_id: u64) -> Result<Auction, Error> {
+        storage::get_auction(&env, loan_id)
+    }
+
+    /// Get the current collateral discount of a loan's auction in basis points
+    pub fn get_auction_discount(env: Env, loan_id: u64) -> Result<u32, Error> {
+        let auction = storage::get_auction(&env, loan_id)?;
+        Ok(auction::current_discount(&env, &auction))
+    }
+
+    /// Get the annual percentage yield of an offer in basis points
+    pub fn get_offer_apy(env: Env, offer_id: u64) -> Result<u32, Error> {
+        let offer = storage::get_offer(&env, offer_id)?;
+        Ok(interest::calculate_apy(
+            offer.weekly_interest_rate,
+            offer.interest_mode,
+        ))
+    }
+
+    /// Preview how `borrow_best` would split a loan across offers
+    pub fn quote_borrow(
+        env: Env,
+        borrower: Address,
+        loan_token: Address,
+        collateral_token: Address,
+        collateral_amount: i128,
+        amount: i128,
+        max_rate: u32,
+        duration_weeks: u32,
+    ) -> Result<Vec<BorrowFill>, Error> {
+        matching::plan(
+            &env,
+            &borrower,
+            &loan_token,
+            &collateral_token,
+            collateral_amount,
+            amount,
+            max_rate,
+            duration_weeks,
+        )
+    }
+
+    /// Get loan health information
+    pub fn get_loan_health(env: Env, loan_id: u64) -> Result<LoanHealth, Error> {
+        let loan = storage::get_loan(&env, loan_id)?;
+        liquidation::calculate_loan_health(&env, &loan)
+    }
+
+    /// Calculate accumulated interest for a loan
+    pub fn calculate_interest(env: Env, loan_id: u64) -> Result<i128, Error> {
+        let loan = storage::get_loan(&env, loan_id)?;
+        let current_time = env.ledger().timestamp();
+
+        let new_interest = interest::accrue_interest(
+            loan.borrowed_amount,
+            loan.accumulated_interest,
+            loan.interest_rate,
+            loan.interest_mode,
+            loan.last_interest_update,
+            current_time,
+        )?;
+
+        let total_interest = loan
+            .accumulated_interest
+            .checked_add(new_interest)
+            .ok_or(Error::ArithmeticOverflow)?;
+
+        Ok(total_interest)
+    }
+
+    /// Get the current USD price of a collateral token (with oracle decimals)
+    /// from the oracle that served the latest price
+    pub fn get_collateral_price(env: Env, token: Address) -> Result<i128, Error> {
+        let config = storage::get_collateral_config(&env, &token)?;
+        let oracle_address = oracle::active_oracle(&env)?;
+        let price_data = oracle::get_price(&env, &oracle_address, &config.oracle_asset)?;
+        Ok(price_data.price)
+    }
+
+    /// Get current XLM price, kept for clients written against the XLM-only market
+    pub fn get_xlm_price(env: Env) -> Result<i128, Error> {
+        let oracle_address = oracle::active_oracle(&env)?;
+        let price_data = oracle::get_price(&env, &oracle_address, &collateral::xlm_oracle_asset())?;
+        Ok(price_data.price)
+    }
+
+    /// Get user's offers
+    pub fn get_user_offers(env: Env, user: Address) -> Vec<u64> {
+        storage::get_user_offers(&env, &user)
+    }
+
+    /// Get user's open borrow requests
+    pub fn get_user_borrow_requests(env: Env, user: Address) -> Vec<u64> {
+        storage::get_user_borrow_requests(&env, &user)
+    }
+
+    /// Get user's open loans as borrower
+    pub fn get_user_loans_as_borrower(env: Env, user: Address) -> Vec<u64> {
+        storage::get_user_loans_as_borrower(&env, &user)
+    }
+
+    /// Get user's loans as lender: loans the user funded before the position NFT
+    /// was set, then every loan whose position NFT the user holds
+    /// `limit` must be between 1 and 50
+    pub fn get_user_loans_as_lender(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, Error> {
+        validation::validate_pagination(limit, offset)?;
+        Ok(position::loans_of(&env, &user, offset, limit))
+    }
+
+    /// Get the outstanding loans taken against an offer
+    pub fn get_offer_loans(env: Env, offer_id: u64) -> Vec<u64> {
+        storage::get_offer_loans(&env, offer_id)
+    }
+
+    /// List active offers with available funds, filtered, sorted and paginated
+    /// The call walks the offers in listing order and reads at most 80 index entries and
+    /// offers, so deep pages or narrow filters can come back short. `HighestAmount`
+    /// ranks every active offer and fails with `InvalidSortOption` once there are more
+    /// than it can read. `limit` must be between 1 and 50
+    pub fn list_offers(
+        env: Env,
+        sort: SortOption,
+        offset: u32,
+        limit: u32,
+        filter: OfferFilter,
+    ) -> Result<Vec<LendingOffer>, Error> {
+        query::list_offers(&env, sort, offset, limit, filter)
+    }
+
+    /// Get one page of open borrow request IDs (up to 50 per page, pages start at 0)
+    pub fn get_active_borrow_requests_page(env: Env, page: u32) -> Vec<u64> {
+        storage::get_active_requests_page(&env, page)
+    }
+
+    /// Get one page of active offer IDs (up to 50 per page, pages start at 0)
+    pub fn get_active_offers_page(env: Env, page: u32) -> Vec<u64> {
+        storage::get_active_offers_page(&env, page)
+    }
+
+    /// Get one page of active loan IDs (up to 50 per page, pages start at 0)
+    pub fn get_active_loans_page(env: Env, page: u32) -> Vec<u64> {
+        storage::get_active_loans_page(&env, page)
+    }
+
+    /// Get the number of active offers
+    pub fn get_active_offer_count(env: Env) -> u32 {
+        storage::get_active_offer_count(&env)
+    }
+
+    /// Get the number of active loans
+    pub fn get_active_loan_count(env: Env) -> u32 {
+        storage::get_active_loan_count(&env)
+    }
+
+    // ========== ADMIN FUNCTIONS ==========
+
+    /// Set the loan position NFT contract (once)
+    /// The NFT contract must name this contract as its minter
+    pub fn set_position_nft(env: Env, admin: Address, nft: Address) -> Result<(), Error> {
         admin.require_auth();
+        storage::extend_instance_ttl(&env);
         storage::require_admin(&env, &admin)?;
 
-        storage::set_paused(&env, true);
+        // Positions are never migrated, so the contract cannot be swapped out
+        if storage::get_position_nft(&env).is_ok() {
+            return Err(Error::InvalidPositionNft);
+        }
+        if position::PositionNftClient::new(&env, &nft).minter() != env.current_contract_address() {
+            return Err(Error::InvalidPositionNft);
+        }
+
+        storage::set_position_nft(&env, &nft);
+        events::position_nft_set(&env, &admin, &nft);
+        Ok(())
+    }
+
+    /// Get the risk parameters of a collateral token
+    pub fn get_collateral_config(env: Env, token: Address) -> Result<CollateralConfig, Error> {
+        storage::get_collateral_config(&env, &token)
+    }
+
+    /// Get every token listed as collateral
+    pub fn get_collateral_assets(env: Env) -> Vec<Address> {
+        storage::get_collateral_assets(&env)
+    }
+
+    /// Get the oracle asset and decimals of a loan token
+    pub fn get_loan_asset(env: Env, token: Address) -> Result<LoanAssetConfig, Error> {
+        storage::get_loan_asset(&env, &token)
+    }
+
+    /// Get every token offers may lend
+    pub fn get_loan_assets(env: Env) -> Vec<Address> {
+        storage::get_loan_assets(&env)
+    }
+
+    /// Pause every operation except repayments, which keep their current flag
+    pub fn pause_contract(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_role(&env, &caller, Role::Pauser)?;
+
+        let state = PauseState {
+            offers: true,
+            borrows: true,
+            withdrawals: true,
+            liquidations: true,
+            repayments: storage::get_pause_state(&env).repayments,
+        };
+        storage::set_pause_state(&env, &state);
+        events::pause_updated(&env, &caller, state);
+        Ok(())
+    }
+
+    /// Clear every pause flag
+    pub fn unpause_contract(env: Env, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_role(&env, &caller, Role::Pauser)?;
+
+        storage::set_pause_state(&env, &PauseState::default());
+        events::pause_updated(&env, &caller, PauseState::default());
         Ok(())
     }
 
-    /// Unpause contract
-    pub fn unpause_contract(env: Env, admin: Address) -> Result<(), Error> {
+    /// Pause or resume individual operations
+    pub fn set_pause_state(env: Env, caller: Address, state: PauseState) -> Result<(), Error> {
+        caller.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_role(&env, &caller, Role::Pauser)?;
+
+        storage::set_pause_state(&env, &state);
+        events::pause_updated(&env, &caller, state);
+        Ok(())
+    }
+
+    /// Get which operations are paused
+    pub fn get_pause_state(env: Env) -> PauseState {
+        storage::get_pause_state(&env)
+    }
+
+    /// Update the storage TTL policy (in ledgers)
+    pub fn set_ttl_config(env: Env, admin: Address, config: TtlConfig) -> Result<(), Error> {
         admin.require_auth();
         storage::require_admin(&env, &admin)?;
+        validation::validate_ttl_config(&env, &config)?;
 
-        storage::set_paused(&env, false);
+        storage::set_ttl_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+        events::ttl_config_updated(&env, &admin, config);
         Ok(())
     }
 
+    /// Get the storage TTL policy
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        storage::get_ttl_config(&env)
+    }
+
+    /// Get the DEX router used on liquidation
+    pub fn get_dex_router(env: Env) -> Result<Address, Error> {
+        storage::get_dex_router(&env)
+    }
+
+    /// Get the loan position NFT contract
+    pub fn get_position_nft(env: Env) -> Result<Address, Error> {
+        storage::get_position_nft(&env)
+    }
+
+    /// Get the maximum liquidation swap slippage in basis points
+    pub fn get_max_slippage(env: Env) -> u32 {
+        storage::get_max_slippage(&env)
+    }
+
+    /// Get the secondary oracle, if any
+    pub fn get_fallback_oracle(env: Env) -> Option<Address> {
+        storage::get_fallback_oracle(&env)
+    }
+
+    /// Get the oracle that served the latest price
+    pub fn get_oracle_source(env: Env) -> OracleSource {
+        storage::get_oracle_source(&env)
+    }
+
+    /// Get the maximum primary/fallback price deviation in basis points
+    pub fn get_max_oracle_deviation(env: Env) -> u32 {
+        storage::get_max_oracle_deviation(&env)
+    }
+
+    /// Get the number of oracle records averaged into the TWAP
+    pub fn get_twap_records(env: Env) -> u32 {
+        storage::get_twap_records(&env)
+    }
+
+    /// Get the liquidation close factor in basis points
+    pub fn get_close_factor(env: Env) -> u32 {
+        storage::get_close_factor(&env)
+    }
+
+    /// Get the Dutch auction discount curve
+    pub fn get_auction_config(env: Env) -> AuctionConfig {
+        storage::get_auction_config(&env)
+    }
+
+    /// Get the pool's utilization curve
+    pub fn get_rate_model(env: Env) -> RateModel {
+        storage::get_rate_model(&env)
+    }
+
+    /// Get the protocol fee configuration
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        storage::get_fee_config(&env)
+    }
+
+    /// Get the market-wide risk limits
+    pub fn get_risk_config(env: Env) -> RiskConfig {
+        storage::get_risk_config(&env)
+    }
+
+    /// Transfer all protocol fees accrued in `token` to `to`. Claiming USDC
+    /// also releases the pool's interest reserves that are backed by idle pool cash
+    ///
+    /// Returns the amount claimed
+    pub fn claim_protocol_fees(
+        env: Env,
+        caller: Address,
+        token: Address,
+        to: Address,
+    ) -> Result<i128, Error> {
+        caller.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_role(&env, &caller, Role::FeeCollector)?;
+        storage::lock(&env)?;
+
+        let mut amount = storage::get_protocol_fees(&env, &token);
+        // Pool reserves are released as far as the pool's idle cash allows
+        if token == storage::get_usdc_token(&env)? {
+            amount = amount
+                .checked_add(pool::take_reserves(&env)?)
+                .ok_or(Error::ArithmeticOverflow)?;
+        }
+        if amount > 0 {
+            storage::set_protocol_fees(&env, &token, 0);
+
+            let token_client = token::TokenClient::new(&env, &token);
+            token_client.transfer(&env.current_contract_address(), &to, &amount);
+        }
+
+        events::protocol_fees_claimed(&env, &caller, &token, &to, amount);
+
+        storage::unlock(&env);
+        Ok(amount)
+    }
+
+    /// Get the unclaimed protocol fees held by the contract in `token`
+    pub fn get_protocol_fees(env: Env, token: Address) -> i128 {
+        storage::get_protocol_fees(&env, &token)
+    }
+
     /// Get admin address
     pub fn admin(env: Env) -> Result<Address, Error> {
         storage::get_admin(&env)
     }
+
+    // ========== ACCESS CONTROL ==========
+
+    /// Propose `new_admin` as the next admin, who must accept before `live_until_ledger`
+    /// A `live_until_ledger` of 0 withdraws a pending proposal
+    pub fn propose_admin(
+        env: Env,
+        admin: Address,
+        new_admin: Address,
+        live_until_ledger: u32,
+    ) -> Result<(), Error> {
+        // The access control module requires the admin's authorization itself
+        storage::extend_instance_ttl(&env);
+        storage::require_admin(&env, &admin)?;
+
+        storage::propose_admin(&env, &new_admin, live_until_ledger);
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal, authorized by the proposed admin
+    pub fn accept_admin(env: Env) {
+        storage::extend_instance_ttl(&env);
+        storage::accept_admin(&env);
+    }
+
+    /// Grant `role` to `account`
+    pub fn grant_role(env: Env, admin: Address, account: Address, role: Role) -> Result<(), Error> {
+        admin.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_admin(&env, &admin)?;
+
+        storage::grant_role(&env, &admin, &account, role);
+        Ok(())
+    }
+
+    /// Revoke `role` from `account`
+    pub fn revoke_role(env: Env, admin: Address, account: Address, role: Role) -> Result<(), Error> {
+        admin.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_admin(&env, &admin)?;
+        if !storage::has_role(&env, &account, role) {
+            return Err(Error::RoleNotHeld);
+        }
+
+        storage::revoke_role(&env, &admin, &account, role);
+        Ok(())
+    }
+
+    /// Check whether `account` holds `role`
+    pub fn has_role(env: Env, account: Address, role: Role) -> bool {
+        storage::has_role(&env, &account, role)
+    }
+
+    // ========== TIMELOCK ==========
+
+    /// Queue a sensitive admin action to run once the timelock delay has passed
+    /// Oracle actions need the oracle manager role, risk config the risk manager
+    /// role, and upgrades and delay changes the admin
+    ///
+    /// Returns the action ID
+    pub fn queue_action(env: Env, caller: Address, action: TimelockAction) -> Result<u64, Error> {
+        caller.require_auth();
+        storage::extend_instance_ttl(&env);
+        timelock::require_authorized(&env, &caller, &action)?;
+
+        let queued = timelock::queue(&env, &caller, action)?;
+        let action_id = queued.action_id;
+        events::action_queued(&env, queued);
+        Ok(action_id)
+    }
+
+    /// Drop a queued action before it runs
+    pub fn cancel_action(env: Env, caller: Address, action_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+        storage::extend_instance_ttl(&env);
+        let queued = storage::get_queued_action(&env, action_id)?;
+        timelock::require_authorized(&env, &caller, &queued.action)?;
+
+        storage::remove_queued_action(&env, action_id);
+        events::action_cancelled(&env, action_id, &caller);
+        Ok(())
+    }
+
+    /// Run a queued action between its ETA and the end of the grace period
+    pub fn execute_action(env: Env, caller: Address, action_id: u64) -> Result<(), Error> {
+        caller.require_auth();
+        storage::extend_instance_ttl(&env);
+        let queued = storage::get_queued_action(&env, action_id)?;
+        timelock::require_authorized(&env, &caller, &queued.action)?;
+
+        timelock::execute(&env, &caller, &queued)?;
+        events::action_executed(&env, action_id, &caller);
+        Ok(())
+    }
+
+    /// Get a queued action by ID
+    pub fn get_queued_action(env: Env, action_id: u64) -> Result<QueuedAction, Error> {
+        storage::get_queued_action(&env, action_id)
+    }
+
+    /// Get every action waiting in the timelock queue, oldest first
+    pub fn get_queued_actions(env: Env) -> Vec<QueuedAction> {
+        let mut actions = Vec::new(&env);
+        for action_id in storage::get_queued_action_ids(&env).iter() {
+            if let Ok(queued) = storage::get_queued_action(&env, action_id) {
+                actions.push_back(queued);
+            }
+        }
+        actions
+    }
+
+    /// Get the delay between queueing an action and running it (in seconds)
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        storage::get_timelock_delay(&env)
+    }
+
+    // ========== UPGRADES ==========
+
+    /// Rewrite up to `limit` stored loans and offers towards the schema of this build
+    /// Call repeatedly after a timelocked upgrade until it returns `SCHEMA_VERSION`
+    ///
+    /// Returns the stored schema version after the batch
+    pub fn migrate(env: Env, admin: Address, limit: u32) -> Result<u32, Error> {
+        admin.require_auth();
+        storage::extend_instance_ttl(&env);
+        storage::require_admin(&env, &admin)?;
+        if limit == 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        let version = storage::get_schema_version(&env);
+        if version > SCHEMA_VERSION {
+            return Err(Error::UnsupportedSchemaVersion);
+        }
+        if version == SCHEMA_VERSION || !migration::step(&env, version, limit)? {
+            return Ok(version);
+        }
+
+        storage::set_schema_version(&env, version + 1);
+        events::schema_migrated(&env, &admin, version + 1);
+        Ok(version + 1)
+    }
+
+    /// Get the schema version of the stored loans and offers
+    pub fn get_schema_version(env: Env) -> u32 {
+        storage::get_schema_version(&env)
+    }
+}
+
+/// Pool shares are this contract's own SEP-41 token, so lenders can transfer
+/// or approve their position without redeeming it
+#[default_impl]
+#[contractimpl]
+impl FungibleToken for LendingMarket {
+    type ContractType = Base;
 }
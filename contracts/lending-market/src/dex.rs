@@ -0,0 +1,87 @@
+//! DEX router interface and the collateral swap used by liquidations
+//! Follows the Soroswap (Uniswap V2 style) router entry points
+
+use crate::error::Error;
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Symbol, Vec};
+
+/// DEX router contract interface exported as DexRouterClient
+#[soroban_sdk::contractclient(name = "DexRouterClient")]
+pub trait DexRouter {
+    /// Pair contract that receives the input tokens of a swap between two tokens
+    fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Address;
+
+    /// Output amounts for each step of `path` when selling `amount_in`
+    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128>;
+
+    /// Sell exactly `amount_in` of `path[0]`, reverting if less than `amount_out_min` is received
+    fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128>;
+}
+
+/// Sell exactly `amount_in` of `token_in` held by this contract for `token_out`
+/// Returns the amount received, measured from this contract's balance
+pub fn swap_exact_in(
+    env: &Env,
+    router: &Address,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    min_out: i128,
+) -> Result<i128, Error> {
+    let client = DexRouterClient::new(env, router);
+    let contract_address = env.current_contract_address();
+
+    let pair = client
+        .try_router_pair_for(token_in, token_out)
+        .map_err(|_| Error::LiquidationSwapFailed)?
+        .map_err(|_| Error::LiquidationSwapFailed)?;
+
+    let out_client = token::TokenClient::new(env, token_out);
+    let balance_before = out_client.balance(&contract_address);
+
+    let path = vec![env, token_in.clone(), token_out.clone()];
+
+    // The router pulls the input tokens from us into the pair contract
+    // Must be the last call before the swap, as any other call consumes it
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token_in.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (contract_address.clone(), pair, amount_in).into_val(env),
+            },
+            sub_invocations: Vec::new(env),
+        }),
+    ]);
+
+    client
+        .try_swap_exact_tokens_for_tokens(
+            &amount_in,
+            &min_out,
+            &path,
+            &contract_address,
+            &env.ledger().timestamp(),
+        )
+        .map_err(|_| Error::LiquidationSwapFailed)?
+        .map_err(|_| Error::LiquidationSwapFailed)?;
+
+    let amount_out = out_client
+        .balance(&contract_address)
+        .checked_sub(balance_before)
+        .ok_or(Error::ArithmeticUnderflow)?;
+
+    // Don't trust the router's own accounting for the slippage bound
+    if amount_out < min_out {
+        return Err(Error::LiquidationSwapFailed);
+    }
+
+    Ok(amount_out)
+}
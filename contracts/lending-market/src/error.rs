@@ -21,6 +21,8 @@ pub enum Error {
     OnlyLender = 12,
     /// Only borrower can perform this operation
     OnlyBorrower = 13,
+    /// Account does not hold the role
+    RoleNotHeld = 14,
 
     // Offer errors (20-39)
     /// Offer not found
@@ -41,6 +43,8 @@ pub enum Error {
     InsufficientOfferFunds = 27,
     /// Cannot cancel offer with active loans
     OfferHasActiveLoans = 28,
+    /// Loan token already has offers at the maximum number of distinct rates
+    TooManyOfferRates = 29,
 
     // Loan errors (40-59)
     /// Loan not found
@@ -63,6 +67,8 @@ pub enum Error {
     WithdrawalBreachesHealth = 48,
     /// Loan duration exceeds maximum allowed
     LoanDurationExceeded = 49,
+    /// Loan is not past its due time yet
+    LoanNotOverdue = 50,
 
     // Liquidation errors (60-79)
     /// Loan is not liquidatable (health is above threshold)
@@ -73,6 +79,18 @@ pub enum Error {
     LiquidationSwapFailed = 62,
     /// Insufficient collateral value to cover debt
     InsufficientCollateralValue = 63,
+    /// DEX router address not set
+    DexRouterNotSet = 64,
+    /// Invalid slippage tolerance
+    InvalidSlippage = 65,
+    /// Invalid close factor (must be between 0 and 100%)
+    InvalidCloseFactor = 66,
+    /// No auction is running for the loan
+    AuctionNotFound = 67,
+    /// An auction is already running for the loan
+    AuctionAlreadyActive = 68,
+    /// Invalid auction configuration
+    InvalidAuctionConfig = 69,
 
     // Oracle errors (80-99)
     /// Oracle address not set
@@ -83,19 +101,38 @@ pub enum Error {
     StalePriceData = 82,
     /// Invalid price data from oracle
     InvalidPriceData = 83,
+    /// TWAP window outside 1..=MAX_TWAP_RECORDS records
+    InvalidTwapRecords = 84,
+    /// Primary and fallback oracles disagree by more than the allowed deviation
+    OraclePriceDeviation = 85,
+    /// Oracle deviation limit out of range
+    InvalidOracleDeviation = 86,
 
     // Token errors (100-119)
     /// USDC token not set
     UsdcTokenNotSet = 100,
-    /// XLM token not set
-    XlmTokenNotSet = 101,
+    // 101 was XlmTokenNotSet in the XLM-only market and stays unassigned
     /// Token transfer failed
     TokenTransferFailed = 102,
     /// Insufficient token balance
     InsufficientBalance = 103,
+    /// Loan position NFT contract not set
+    PositionNftNotSet = 104,
+    /// Loan position NFT contract is already set or does not let this contract mint
+    InvalidPositionNft = 105,
+    /// Offer does not accept the collateral token
+    CollateralNotAccepted = 106,
+    /// Invalid collateral risk parameters
+    InvalidCollateralConfig = 107,
+    /// Token is not registered as a loan asset
+    UnsupportedLoanAsset = 108,
+    /// Invalid loan asset parameters
+    InvalidLoanAsset = 109,
+    /// Collateral token is not in the registry
+    UnsupportedCollateral = 110,
 
     // Contract state errors (120-139)
-    /// Contract is paused
+    /// The requested operation is paused
     ContractPaused = 120,
     /// Reentrancy detected
     Reentrant = 121,
@@ -107,6 +144,14 @@ pub enum Error {
     ArithmeticUnderflow = 124,
     /// Division by zero
     DivisionByZero = 125,
+    /// Invalid storage TTL configuration
+    InvalidTtlConfig = 126,
+    /// Invalid protocol fee configuration
+    InvalidFeeConfig = 127,
+    /// Invalid risk configuration
+    InvalidRiskConfig = 128,
+    /// Stored schema is newer than this build can migrate
+    UnsupportedSchemaVersion = 129,
 
     // Query errors (140-159)
     /// Invalid sort option
@@ -117,4 +162,36 @@ pub enum Error {
     NoOffersAvailable = 142,
     /// No loans found
     NoLoansFound = 143,
+
+    // Pool errors (160-179)
+    /// Pool loan not found
+    PoolLoanNotFound = 160,
+    /// Not enough idle USDC in the pool
+    InsufficientPoolLiquidity = 161,
+    /// Invalid pool utilization curve
+    InvalidRateModel = 162,
+    /// Lender holds fewer pool shares than requested
+    InsufficientShares = 163,
+    /// Bad debt has wiped out the pool's assets while shares are outstanding
+    PoolInsolvent = 164,
+
+    // Timelock errors (180-199)
+    /// Timelocked action not found
+    ActionNotFound = 180,
+    /// Timelock delay has not passed yet
+    ActionNotReady = 181,
+    /// Grace period to run the action has passed
+    ActionExpired = 182,
+    /// Invalid timelock delay
+    InvalidTimelockDelay = 183,
+    /// Timelock queue is full
+    TooManyQueuedActions = 184,
+
+    // Borrow request errors (200-219)
+    /// Borrow request not found
+    BorrowRequestNotFound = 200,
+    /// Borrow request is no longer open
+    BorrowRequestNotActive = 201,
+    /// User has too many open borrow requests
+    TooManyBorrowRequests = 202,
 }
@@ -0,0 +1,1064 @@
+//! Contract events published on every lending market state change
+//!
+//! Each event carries the affected offer/loan IDs (and the acting address) as
+//! topics so indexers can filter on them, and a typed payload as event data.
+
+use crate::types::{
+    AuctionConfig, BorrowRequest, CollateralConfig, FeeConfig, InterestMode, LoanAssetConfig,
+    OracleSource, PauseState, QueuedAction, RateModel, RiskConfig, TtlConfig,
+};
+use soroban_sdk::{contractevent, contracttype, Address, BytesN, Env, Vec};
+
+// ========== Payloads ==========
+
+/// Terms of a newly created offer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCreatedData {
+    /// Token being lent
+    pub loan_token: Address,
+    /// Amount of the loan token escrowed into the offer
+    pub amount: i128,
+    /// Weekly interest rate in basis points
+    pub weekly_interest_rate: u32,
+    /// How interest accrues on loans taken against the offer
+    pub interest_mode: InterestMode,
+    /// Minimum collateral ratio in basis points
+    pub min_collateral_ratio: u32,
+    /// Liquidation threshold in basis points
+    pub liquidation_threshold: u32,
+    /// Maximum loan duration in weeks
+    pub max_duration_weeks: u32,
+    /// Collateral tokens accepted by the offer
+    pub collateral_assets: Vec<Address>,
+}
+
+/// Loan tokens moved out of an offer back to its lender
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferFundsData {
+    /// Amount returned to the lender
+    pub amount: i128,
+    /// Amount still available in the offer afterwards
+    pub remaining: i128,
+}
+
+/// Terms of a newly opened loan
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanOpenedData {
+    /// Address of the lender
+    pub lender: Address,
+    /// Token posted as collateral
+    pub collateral_token: Address,
+    /// Amount of collateral deposited
+    pub collateral_amount: i128,
+    /// Token borrowed
+    pub loan_token: Address,
+    /// Amount of the loan token borrowed
+    pub borrowed_amount: i128,
+    /// Weekly interest rate in basis points
+    pub interest_rate: u32,
+    /// Timestamp after which the loan is in default
+    pub due_time: u64,
+}
+
+/// Breakdown of a repayment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanRepaidData {
+    /// Total amount paid by the borrower
+    pub amount: i128,
+    /// Portion of the payment applied to interest
+    pub interest_paid: i128,
+    /// Portion of the payment applied to principal
+    pub principal_paid: i128,
+    /// Share of the interest kept by the protocol treasury
+    pub protocol_fee: i128,
+    /// Principal still outstanding
+    pub remaining_principal: i128,
+    /// Collateral released back to the borrower (non-zero only on full repayment)
+    pub collateral_returned: i128,
+}
+
+/// Collateral moved into or out of a loan
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralData {
+    /// Amount of collateral moved
+    pub amount: i128,
+    /// Collateral held by the loan afterwards
+    pub collateral_amount: i128,
+}
+
+/// Outcome of a (possibly partial) liquidation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiquidationData {
+    /// Debt repaid to the lender (principal + interest, before the protocol fee)
+    pub debt_repaid: i128,
+    /// Portion of the repaid debt that was interest
+    pub interest_paid: i128,
+    /// Portion of the repaid debt that was principal
+    pub principal_paid: i128,
+    /// Collateral sold on the DEX
+    pub collateral_seized: i128,
+    /// Loan tokens received from the swap
+    pub swap_proceeds: i128,
+    /// Bonus paid to the liquidator (in the loan token)
+    pub liquidator_bonus: i128,
+    /// Interest and liquidation fees kept by the protocol treasury
+    pub protocol_fee: i128,
+    /// Excess loan tokens returned to the borrower
+    pub borrower_refund: i128,
+    /// Unsold collateral returned to the borrower (non-zero only when the loan closes)
+    pub collateral_returned: i128,
+    /// Debt left on the loan (written off if the loan closed without collateral)
+    pub remaining_debt: i128,
+}
+
+/// Collateral claimed by a lender from a loan past its due time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefaultClaimData {
+    /// Debt outstanding at the time of the claim (principal + interest)
+    pub debt_outstanding: i128,
+    /// Collateral transferred to the lender
+    pub collateral_seized: i128,
+}
+
+/// Fill of a Dutch auction bid
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionBidData {
+    /// Loan tokens paid by the bidder (to the lender, less the protocol fee)
+    pub amount_paid: i128,
+    /// Portion of the payment applied to interest
+    pub interest_paid: i128,
+    /// Portion of the payment applied to principal
+    pub principal_paid: i128,
+    /// Share of the interest kept by the protocol treasury
+    pub protocol_fee: i128,
+    /// Collateral transferred to the bidder
+    pub collateral_sold: i128,
+    /// Discount below the oracle price the bid filled at, in basis points
+    pub discount_bps: u32,
+    /// Unsold collateral returned to the borrower (non-zero only when the loan closes)
+    pub collateral_returned: i128,
+    /// Debt left on the loan (written off if the loan closed without collateral)
+    pub remaining_debt: i128,
+}
+
+/// USDC moved into or out of the pool by a lender
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolSharesData {
+    /// USDC deposited or redeemed
+    pub amount: i128,
+    /// Pool shares minted or burned
+    pub shares: i128,
+}
+
+/// Terms of a newly opened pool loan
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolLoanOpenedData {
+    /// Token posted as collateral
+    pub collateral_token: Address,
+    /// Collateral locked
+    pub collateral_amount: i128,
+    /// USDC borrowed from the pool
+    pub borrowed_amount: i128,
+    /// Weekly borrow rate in basis points after the borrow
+    pub borrow_rate: u32,
+}
+
+/// Repayment applied to a pool loan
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolLoanRepaidData {
+    /// USDC paid by the borrower
+    pub amount: i128,
+    /// Debt still outstanding
+    pub remaining_debt: i128,
+    /// Collateral released back to the borrower (non-zero only on full repayment)
+    pub collateral_returned: i128,
+}
+
+/// Outcome of a (possibly partial) pool loan liquidation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolLiquidationData {
+    /// Debt repaid to the pool
+    pub debt_repaid: i128,
+    /// Collateral sold on the DEX
+    pub collateral_seized: i128,
+    /// USDC received from the swap
+    pub swap_proceeds: i128,
+    /// Bonus paid to the liquidator (in USDC)
+    pub liquidator_bonus: i128,
+    /// Liquidation fee kept by the protocol treasury
+    pub protocol_fee: i128,
+    /// Excess USDC returned to the borrower
+    pub borrower_refund: i128,
+    /// Unsold collateral returned to the borrower (non-zero only when the loan closes)
+    pub collateral_returned: i128,
+    /// Debt left on the loan
+    pub remaining_debt: i128,
+    /// Debt written off against the pool when the collateral ran out
+    pub bad_debt: i128,
+}
+
+// ========== Events ==========
+
+#[contractevent(topics = ["offer_created"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCreated {
+    #[topic]
+    pub offer_id: u64,
+    #[topic]
+    pub lender: Address,
+    pub data: OfferCreatedData,
+}
+
+#[contractevent(topics = ["offer_cancelled"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCancelled {
+    #[topic]
+    pub offer_id: u64,
+    #[topic]
+    pub lender: Address,
+    pub data: OfferFundsData,
+}
+
+#[contractevent(topics = ["offer_withdrawn"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferWithdrawn {
+    #[topic]
+    pub offer_id: u64,
+    #[topic]
+    pub lender: Address,
+    pub data: OfferFundsData,
+}
+
+#[contractevent(topics = ["loan_opened"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanOpened {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub offer_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: LoanOpenedData,
+}
+
+#[contractevent(topics = ["loan_repaid"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanRepaid {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: LoanRepaidData,
+}
+
+#[contractevent(topics = ["collateral_added"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralAdded {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: CollateralData,
+}
+
+#[contractevent(topics = ["collateral_withdrawn"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralWithdrawn {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: CollateralData,
+}
+
+#[contractevent(topics = ["loan_liquidated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanLiquidated {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub liquidator: Address,
+    pub data: LiquidationData,
+}
+
+#[contractevent(topics = ["loan_defaulted"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanDefaulted {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub lender: Address,
+    pub data: DefaultClaimData,
+}
+
+#[contractevent(topics = ["auction_started"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionStarted {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub initiator: Address,
+    pub start_time: u64,
+}
+
+#[contractevent(topics = ["auction_bid"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionBid {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub bidder: Address,
+    pub data: AuctionBidData,
+}
+
+#[contractevent(topics = ["auction_ended"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionEnded {
+    #[topic]
+    pub loan_id: u64,
+    pub start_time: u64,
+}
+
+#[contractevent(topics = ["max_rate_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxInterestRateUpdated {
+    #[topic]
+    pub admin: Address,
+    pub max_rate: u32,
+}
+
+#[contractevent(topics = ["oracle_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleUpdated {
+    #[topic]
+    pub admin: Address,
+    pub oracle: Address,
+}
+
+#[contractevent(topics = ["fallback_oracle_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FallbackOracleUpdated {
+    #[topic]
+    pub admin: Address,
+    pub oracle: Option<Address>,
+}
+
+#[contractevent(topics = ["oracle_deviation_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleDeviationUpdated {
+    #[topic]
+    pub admin: Address,
+    pub deviation_bps: u32,
+}
+
+#[contractevent(topics = ["oracle_switched"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleSwitched {
+    #[topic]
+    pub oracle: Address,
+    pub source: OracleSource,
+}
+
+#[contractevent(topics = ["twap_records_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TwapRecordsUpdated {
+    #[topic]
+    pub admin: Address,
+    pub records: u32,
+}
+
+#[contractevent(topics = ["pause_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseUpdated {
+    #[topic]
+    pub admin: Address,
+    pub state: PauseState,
+}
+
+#[contractevent(topics = ["dex_router_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DexRouterUpdated {
+    #[topic]
+    pub admin: Address,
+    pub router: Address,
+}
+
+#[contractevent(topics = ["position_nft_set"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionNftSet {
+    #[topic]
+    pub admin: Address,
+    pub nft: Address,
+}
+
+#[contractevent(topics = ["slippage_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxSlippageUpdated {
+    #[topic]
+    pub admin: Address,
+    pub slippage_bps: u32,
+}
+
+#[contractevent(topics = ["close_factor_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CloseFactorUpdated {
+    #[topic]
+    pub admin: Address,
+    pub close_factor_bps: u32,
+}
+
+#[contractevent(topics = ["auction_config_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    pub config: AuctionConfig,
+}
+
+#[contractevent(topics = ["ttl_config_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    pub config: TtlConfig,
+}
+
+#[contractevent(topics = ["fee_config_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    pub config: FeeConfig,
+}
+
+#[contractevent(topics = ["risk_config_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    pub config: RiskConfig,
+}
+
+#[contractevent(topics = ["contract_upgraded"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+#[contractevent(topics = ["schema_migrated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    #[topic]
+    pub admin: Address,
+    pub version: u32,
+}
+
+#[contractevent(topics = ["timelock_delay_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimelockDelayUpdated {
+    #[topic]
+    pub admin: Address,
+    pub delay: u64,
+}
+
+#[contractevent(topics = ["action_queued"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionQueued {
+    #[topic]
+    pub action_id: u64,
+    pub queued: QueuedAction,
+}
+
+#[contractevent(topics = ["action_cancelled"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionCancelled {
+    #[topic]
+    pub action_id: u64,
+    pub caller: Address,
+}
+
+#[contractevent(topics = ["action_executed"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionExecuted {
+    #[topic]
+    pub action_id: u64,
+    pub caller: Address,
+}
+
+#[contractevent(topics = ["protocol_fees_claimed"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolFeesClaimed {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub token: Address,
+    #[topic]
+    pub to: Address,
+    pub amount: i128,
+}
+
+#[contractevent(topics = ["pool_deposited"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolDeposited {
+    #[topic]
+    pub lender: Address,
+    pub data: PoolSharesData,
+}
+
+#[contractevent(topics = ["pool_redeemed"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolRedeemed {
+    #[topic]
+    pub lender: Address,
+    pub data: PoolSharesData,
+}
+
+#[contractevent(topics = ["pool_loan_opened"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolLoanOpened {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: PoolLoanOpenedData,
+}
+
+#[contractevent(topics = ["pool_loan_repaid"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolLoanRepaid {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: PoolLoanRepaidData,
+}
+
+#[contractevent(topics = ["pool_collateral_added"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolCollateralAdded {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: CollateralData,
+}
+
+#[contractevent(topics = ["pool_collateral_withdrawn"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolCollateralWithdrawn {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub data: CollateralData,
+}
+
+#[contractevent(topics = ["pool_loan_liquidated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolLoanLiquidated {
+    #[topic]
+    pub loan_id: u64,
+    #[topic]
+    pub liquidator: Address,
+    pub data: PoolLiquidationData,
+}
+
+#[contractevent(topics = ["rate_model_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateModelUpdated {
+    #[topic]
+    pub admin: Address,
+    pub model: RateModel,
+}
+
+#[contractevent(topics = ["loan_asset_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanAssetUpdated {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub token: Address,
+    pub config: LoanAssetConfig,
+}
+
+#[contractevent(topics = ["collateral_config_updated"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    #[topic]
+    pub token: Address,
+    pub config: CollateralConfig,
+}
+
+#[contractevent(topics = ["borrow_request_created"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BorrowRequestCreated {
+    #[topic]
+    pub request_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub request: BorrowRequest,
+}
+
+#[contractevent(topics = ["borrow_request_cancelled"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BorrowRequestCancelled {
+    #[topic]
+    pub request_id: u64,
+    #[topic]
+    pub borrower: Address,
+    pub collateral_returned: i128,
+}
+
+#[contractevent(topics = ["borrow_request_filled"], data_format = "single-value")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BorrowRequestFilled {
+    #[topic]
+    pub request_id: u64,
+    #[topic]
+    pub lender: Address,
+    pub loan_id: u64,
+}
+
+// ========== Publishers ==========
+
+pub fn offer_created(env: &Env, offer_id: u64, lender: &Address, data: OfferCreatedData) {
+    OfferCreated {
+        offer_id,
+        lender: lender.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn offer_cancelled(env: &Env, offer_id: u64, lender: &Address, data: OfferFundsData) {
+    OfferCancelled {
+        offer_id,
+        lender: lender.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn offer_withdrawn(env: &Env, offer_id: u64, lender: &Address, data: OfferFundsData) {
+    OfferWithdrawn {
+        offer_id,
+        lender: lender.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn loan_opened(env: &Env, loan_id: u64, offer_id: u64, borrower: &Address, data: LoanOpenedData) {
+    LoanOpened {
+        loan_id,
+        offer_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn loan_repaid(env: &Env, loan_id: u64, borrower: &Address, data: LoanRepaidData) {
+    LoanRepaid {
+        loan_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn collateral_added(env: &Env, loan_id: u64, borrower: &Address, data: CollateralData) {
+    CollateralAdded {
+        loan_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn collateral_withdrawn(env: &Env, loan_id: u64, borrower: &Address, data: CollateralData) {
+    CollateralWithdrawn {
+        loan_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn loan_liquidated(env: &Env, loan_id: u64, liquidator: &Address, data: LiquidationData) {
+    LoanLiquidated {
+        loan_id,
+        liquidator: liquidator.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn loan_defaulted(env: &Env, loan_id: u64, lender: &Address, data: DefaultClaimData) {
+    LoanDefaulted {
+        loan_id,
+        lender: lender.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn auction_started(env: &Env, loan_id: u64, initiator: &Address, start_time: u64) {
+    AuctionStarted {
+        loan_id,
+        initiator: initiator.clone(),
+        start_time,
+    }
+    .publish(env);
+}
+
+pub fn auction_bid(env: &Env, loan_id: u64, bidder: &Address, data: AuctionBidData) {
+    AuctionBid {
+        loan_id,
+        bidder: bidder.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn auction_ended(env: &Env, loan_id: u64, start_time: u64) {
+    AuctionEnded {
+        loan_id,
+        start_time,
+    }
+    .publish(env);
+}
+
+pub fn max_interest_rate_updated(env: &Env, admin: &Address, max_rate: u32) {
+    MaxInterestRateUpdated {
+        admin: admin.clone(),
+        max_rate,
+    }
+    .publish(env);
+}
+
+pub fn oracle_updated(env: &Env, admin: &Address, oracle: &Address) {
+    OracleUpdated {
+        admin: admin.clone(),
+        oracle: oracle.clone(),
+    }
+    .publish(env);
+}
+
+pub fn fallback_oracle_updated(env: &Env, admin: &Address, oracle: Option<Address>) {
+    FallbackOracleUpdated {
+        admin: admin.clone(),
+        oracle,
+    }
+    .publish(env);
+}
+
+pub fn oracle_deviation_updated(env: &Env, admin: &Address, deviation_bps: u32) {
+    OracleDeviationUpdated {
+        admin: admin.clone(),
+        deviation_bps,
+    }
+    .publish(env);
+}
+
+pub fn oracle_switched(env: &Env, oracle: &Address, source: OracleSource) {
+    OracleSwitched {
+        oracle: oracle.clone(),
+        source,
+    }
+    .publish(env);
+}
+
+pub fn twap_records_updated(env: &Env, admin: &Address, records: u32) {
+    TwapRecordsUpdated {
+        admin: admin.clone(),
+        records,
+    }
+    .publish(env);
+}
+
+pub fn pause_updated(env: &Env, admin: &Address, state: PauseState) {
+    PauseUpdated {
+        admin: admin.clone(),
+        state,
+    }
+    .publish(env);
+}
+
+pub fn dex_router_updated(env: &Env, admin: &Address, router: &Address) {
+    DexRouterUpdated {
+        admin: admin.clone(),
+        router: router.clone(),
+    }
+    .publish(env);
+}
+
+pub fn position_nft_set(env: &Env, admin: &Address, nft: &Address) {
+    PositionNftSet {
+        admin: admin.clone(),
+        nft: nft.clone(),
+    }
+    .publish(env);
+}
+
+pub fn max_slippage_updated(env: &Env, admin: &Address, slippage_bps: u32) {
+    MaxSlippageUpdated {
+        admin: admin.clone(),
+        slippage_bps,
+    }
+    .publish(env);
+}
+
+pub fn close_factor_updated(env: &Env, admin: &Address, close_factor_bps: u32) {
+    CloseFactorUpdated {
+        admin: admin.clone(),
+        close_factor_bps,
+    }
+    .publish(env);
+}
+
+pub fn auction_config_updated(env: &Env, admin: &Address, config: AuctionConfig) {
+    AuctionConfigUpdated {
+        admin: admin.clone(),
+        config,
+    }
+    .publish(env);
+}
+
+pub fn ttl_config_updated(env: &Env, admin: &Address, config: TtlConfig) {
+    TtlConfigUpdated {
+        admin: admin.clone(),
+        config,
+    }
+    .publish(env);
+}
+
+pub fn fee_config_updated(env: &Env, admin: &Address, config: FeeConfig) {
+    FeeConfigUpdated {
+        admin: admin.clone(),
+        config,
+    }
+    .publish(env);
+}
+
+pub fn risk_config_updated(env: &Env, admin: &Address, config: RiskConfig) {
+    RiskConfigUpdated {
+        admin: admin.clone(),
+        config,
+    }
+    .publish(env);
+}
+
+pub fn protocol_fees_claimed(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) {
+    ProtocolFeesClaimed {
+        admin: admin.clone(),
+        token: token.clone(),
+        to: to.clone(),
+        amount,
+    }
+    .publish(env);
+}
+
+pub fn pool_deposited(env: &Env, lender: &Address, data: PoolSharesData) {
+    PoolDeposited {
+        lender: lender.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn pool_redeemed(env: &Env, lender: &Address, data: PoolSharesData) {
+    PoolRedeemed {
+        lender: lender.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn pool_loan_opened(env: &Env, loan_id: u64, borrower: &Address, data: PoolLoanOpenedData) {
+    PoolLoanOpened {
+        loan_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn pool_loan_repaid(env: &Env, loan_id: u64, borrower: &Address, data: PoolLoanRepaidData) {
+    PoolLoanRepaid {
+        loan_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn pool_collateral_added(env: &Env, loan_id: u64, borrower: &Address, data: CollateralData) {
+    PoolCollateralAdded {
+        loan_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn pool_collateral_withdrawn(
+    env: &Env,
+    loan_id: u64,
+    borrower: &Address,
+    data: CollateralData,
+) {
+    PoolCollateralWithdrawn {
+        loan_id,
+        borrower: borrower.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn pool_loan_liquidated(
+    env: &Env,
+    loan_id: u64,
+    liquidator: &Address,
+    data: PoolLiquidationData,
+) {
+    PoolLoanLiquidated {
+        loan_id,
+        liquidator: liquidator.clone(),
+        data,
+    }
+    .publish(env);
+}
+
+pub fn rate_model_updated(env: &Env, admin: &Address, model: RateModel) {
+    RateModelUpdated {
+        admin: admin.clone(),
+        model,
+    }
+    .publish(env);
+}
+
+pub fn collateral_config_updated(
+    env: &Env,
+    admin: &Address,
+    token: &Address,
+    config: CollateralConfig,
+) {
+    CollateralConfigUpdated {
+        admin: admin.clone(),
+        token: token.clone(),
+        config,
+    }
+    .publish(env);
+}
+
+pub fn loan_asset_updated(env: &Env, admin: &Address, token: &Address, config: LoanAssetConfig) {
+    LoanAssetUpdated {
+        admin: admin.clone(),
+        token: token.clone(),
+        config,
+    }
+    .publish(env);
+}
+
+pub fn contract_upgraded(env: &Env, admin: &Address, wasm_hash: &BytesN<32>) {
+    ContractUpgraded {
+        admin: admin.clone(),
+        wasm_hash: wasm_hash.clone(),
+    }
+    .publish(env);
+}
+
+pub fn schema_migrated(env: &Env, admin: &Address, version: u32) {
+    SchemaMigrated {
+        admin: admin.clone(),
+        version,
+    }
+    .publish(env);
+}
+
+pub fn timelock_delay_updated(env: &Env, admin: &Address, delay: u64) {
+    TimelockDelayUpdated {
+        admin: admin.clone(),
+        delay,
+    }
+    .publish(env);
+}
+
+pub fn action_queued(env: &Env, queued: QueuedAction) {
+    ActionQueued {
+        action_id: queued.action_id,
+        queued,
+    }
+    .publish(env);
+}
+
+pub fn action_cancelled(env: &Env, action_id: u64, caller: &Address) {
+    ActionCancelled {
+        action_id,
+        caller: caller.clone(),
+    }
+    .publish(env);
+}
+
+pub fn action_executed(env: &Env, action_id: u64, caller: &Address) {
+    ActionExecuted {
+        action_id,
+        caller: caller.clone(),
+    }
+    .publish(env);
+}
+
+pub fn borrow_request_created(env: &Env, request: BorrowRequest) {
+    BorrowRequestCreated {
+        request_id: request.request_id,
+        borrower: request.borrower.clone(),
+        request,
+    }
+    .publish(env);
+}
+
+pub fn borrow_request_cancelled(
+    env: &Env,
+    request_id: u64,
+    borrower: &Address,
+    collateral_returned: i128,
+) {
+    BorrowRequestCancelled {
+        request_id,
+        borrower: borrower.clone(),
+        collateral_returned,
+    }
+    .publish(env);
+}
+
+pub fn borrow_request_filled(env: &Env, request_id: u64, lender: &Address, loan_id: u64) {
+    BorrowRequestFilled {
+        request_id,
+        lender: lender.clone(),
+        loan_id,
+    }
+    .publish(env);
+}
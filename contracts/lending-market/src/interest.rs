@@ -1,7 +1,12 @@
 //! Interest calculation logic for loans
 
 use crate::error::Error;
-use crate::types::{BASIS_POINTS, SECONDS_PER_WEEK};
+use crate::types::{
+    InterestMode, Loan, RateModel, BASIS_POINTS, SECONDS_PER_WEEK, WEEKS_PER_YEAR,
+};
+
+/// Fixed-point scale used for compounding and the pool borrow index (18 decimals)
+pub const WAD: i128 = 1_000_000_000_000_000_000;
 
 /// Calculate accumulated interest using simple per-second interest
 ///
@@ -52,17 +57,122 @@ pub fn calculate_interest(
     Ok(interest)
 }
 
+/// Multiply two non-negative numbers and divide by `WAD`, rounding down
+///
+/// Both operands are split into integer and fractional parts so intermediate
+/// products only overflow when the result itself does.
+pub fn wad_mul(a: i128, b: i128) -> Result<i128, Error> {
+    let (a_int, a_frac) = (a / WAD, a % WAD);
+    let (b_int, b_frac) = (b / WAD, b % WAD);
+
+    let int_part = a_int
+        .checked_mul(b_int)
+        .and_then(|product| product.checked_mul(WAD))
+        .ok_or(Error::ArithmeticOverflow)?;
+    let cross = a_int
+        .checked_mul(b_frac)
+        .and_then(|product| product.checked_add(a_frac.checked_mul(b_int)?))
+        .ok_or(Error::ArithmeticOverflow)?;
+    // Both fractions are below WAD, so this product always fits
+    let frac_part = a_frac * b_frac / WAD;
+
+    int_part
+        .checked_add(cross)
+        .and_then(|sum| sum.checked_add(frac_part))
+        .ok_or(Error::ArithmeticOverflow)
+}
+
+/// Raise a `WAD` fixed-point number to an integer power by repeated squaring
+fn wad_pow(base: i128, exp: u64) -> Result<i128, Error> {
+    let mut result = WAD;
+    let mut base = base;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = wad_mul(result, base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = wad_mul(base, base)?;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Growth of one unit of debt over `seconds` as a `WAD` fixed-point number
+///
+/// * `Simple` - 1 + r × t
+/// * `CompoundPerSecond` - (1 + r / SECONDS_PER_WEEK)^seconds
+/// * `CompoundPerWeek` - (1 + r)^weeks, with simple interest for the partial week
+fn growth_factor(weekly_interest_rate: u32, mode: InterestMode, seconds: u64) -> Result<i128, Error> {
+    let weekly_rate = (weekly_interest_rate as i128)
+        .checked_mul(WAD)
+        .ok_or(Error::ArithmeticOverflow)?
+        / BASIS_POINTS as i128;
+    let seconds_per_week = SECONDS_PER_WEEK as i128;
+
+    let simple_growth = |seconds: u64| -> Result<i128, Error> {
+        weekly_rate
+            .checked_mul(seconds as i128)
+            .map(|interest| WAD + interest / seconds_per_week)
+            .ok_or(Error::ArithmeticOverflow)
+    };
+
+    match mode {
+        InterestMode::Simple => simple_growth(seconds),
+        InterestMode::CompoundPerSecond => wad_pow(WAD + weekly_rate / seconds_per_week, seconds),
+        InterestMode::CompoundPerWeek => {
+            let full_weeks = wad_pow(WAD + weekly_rate, seconds / SECONDS_PER_WEEK)?;
+            wad_mul(full_weeks, simple_growth(seconds % SECONDS_PER_WEEK)?)
+        }
+    }
+}
+
+/// Calculate interest accrued since `start_time` in the given mode
+///
+/// Simple loans accrue on the principal only. Compounding loans also accrue on
+/// unpaid interest carried in `accumulated_interest`.
+pub fn accrue_interest(
+    principal: i128,
+    accumulated_interest: i128,
+    weekly_interest_rate: u32,
+    mode: InterestMode,
+    start_time: u64,
+    current_time: u64,
+) -> Result<i128, Error> {
+    if mode == InterestMode::Simple {
+        return calculate_interest(principal, weekly_interest_rate, start_time, current_time);
+    }
+
+    if current_time < start_time {
+        return Err(Error::InvalidInput);
+    }
+
+    let debt = principal
+        .checked_add(accumulated_interest)
+        .ok_or(Error::ArithmeticOverflow)?;
+    let growth = growth_factor(weekly_interest_rate, mode, current_time - start_time)?;
+
+    // debt × (growth - 1)
+    wad_mul(debt, growth - WAD)
+}
+
 /// Calculate total debt (principal + accumulated interest + new interest)
 pub fn calculate_total_debt(
     principal: i128,
     accumulated_interest: i128,
     weekly_interest_rate: u32,
+    mode: InterestMode,
     last_update: u64,
     current_time: u64,
 ) -> Result<i128, Error> {
-    let new_interest = calculate_interest(
+    let new_interest = accrue_interest(
         principal,
+        accumulated_interest,
         weekly_interest_rate,
+        mode,
         last_update,
         current_time,
     )?;
@@ -76,13 +186,153 @@ pub fn calculate_total_debt(
     Ok(total_debt)
 }
 
-/// Calculate the APY (Annual Percentage Yield) from weekly rate
-/// This is for display purposes only
-pub fn calculate_apy(weekly_rate: u32) -> u32 {
-    // Simple approximation: APY ≈ weekly_rate × 52
-    // For compound interest: APY = (1 + rate)^52 - 1
-    // We use simple calculation for gas efficiency
-    weekly_rate.saturating_mul(52)
+/// Apply a payment to a loan: accrued interest is paid first, then principal
+///
+/// Accrues interest up to `current_time` and updates the loan's
+/// `accumulated_interest`, `borrowed_amount` and `last_interest_update`.
+///
+/// # Returns
+/// * `Ok((interest_paid, principal_paid))`
+pub fn apply_payment(
+    loan: &mut Loan,
+    amount: i128,
+    current_time: u64,
+) -> Result<(i128, i128), Error> {
+    let new_interest = accrue_interest(
+        loan.borrowed_amount,
+        loan.accumulated_interest,
+        loan.interest_rate,
+        loan.interest_mode,
+        loan.last_interest_update,
+        current_time,
+    )?;
+
+    let total_interest = loan
+        .accumulated_interest
+        .checked_add(new_interest)
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    let (interest_paid, principal_paid) = if amount >= total_interest {
+        // Paying all interest and some/all principal
+        let principal_payment = amount
+            .checked_sub(total_interest)
+            .ok_or(Error::ArithmeticUnderflow)?;
+
+        loan.accumulated_interest = 0;
+        loan.borrowed_amount = loan
+            .borrowed_amount
+            .checked_sub(principal_payment)
+            .ok_or(Error::ArithmeticUnderflow)?;
+
+        (total_interest, principal_payment)
+    } else {
+        // Only paying partial interest
+        loan.accumulated_interest = total_interest
+            .checked_sub(amount)
+            .ok_or(Error::ArithmeticUnderflow)?;
+
+        (amount, 0)
+    };
+
+    loan.last_interest_update = current_time;
+
+    Ok((interest_paid, principal_paid))
+}
+
+/// Pool utilization in basis points: debt / (cash + debt)
+pub fn utilization(cash: i128, debt: i128) -> Result<u32, Error> {
+    if debt <= 0 {
+        return Ok(0);
+    }
+
+    let total = cash.checked_add(debt).ok_or(Error::ArithmeticOverflow)?;
+    let utilization = debt
+        .checked_mul(BASIS_POINTS as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(total)
+        .ok_or(Error::DivisionByZero)?;
+
+    Ok(utilization.min(BASIS_POINTS as i128) as u32)
+}
+
+/// Weekly borrow rate in basis points on a kinked utilization curve
+///
+/// Up to `optimal_utilization` the rate climbs from `base_rate` by `slope1`;
+/// above it, the rest of the utilization range adds up to `slope2` on top.
+pub fn variable_borrow_rate(model: &RateModel, utilization_bps: u32) -> u32 {
+    let utilization = utilization_bps.min(BASIS_POINTS) as u64;
+    let optimal = model.optimal_utilization as u64;
+
+    let rate = if utilization <= optimal {
+        model.base_rate as u64 + model.slope1 as u64 * utilization / optimal.max(1)
+    } else {
+        let excess = utilization - optimal;
+        let excess_range = BASIS_POINTS as u64 - optimal;
+        model.base_rate as u64 + model.slope1 as u64 + model.slope2 as u64 * excess / excess_range
+    };
+
+    rate.min(u32::MAX as u64) as u32
+}
+
+/// Weekly rate earned by pool lenders in basis points: the borrow rate paid on
+/// the utilized share of the pool, less the protocol fee
+pub fn variable_supply_rate(borrow_rate: u32, utilization_bps: u32, protocol_fee_bps: u32) -> u32 {
+    let bps = BASIS_POINTS as u64;
+    let rate = borrow_rate as u64 * utilization_bps as u64 / bps
+        * (bps - (protocol_fee_bps as u64).min(bps))
+        / bps;
+
+    rate as u32
+}
+
+/// Grow a `WAD` borrow index by `weekly_rate` of simple interest over `seconds`
+pub fn accrue_index(index: i128, weekly_rate: u32, seconds: u64) -> Result<i128, Error> {
+    wad_mul(index, growth_factor(weekly_rate, InterestMode::Simple, seconds)?)
+}
+
+/// Calculate a fee of `fee_bps` on an amount (rounded down)
+pub fn calculate_fee(amount: i128, fee_bps: u32) -> Result<i128, Error> {
+    amount
+        .checked_mul(fee_bps as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(BASIS_POINTS as i128)
+        .ok_or(Error::DivisionByZero)
+}
+
+/// Split a payment between the lender and the protocol treasury
+///
+/// Only the interest portion of the payment is subject to the protocol fee;
+/// principal always goes back to the lender in full.
+///
+/// # Returns
+/// * `Ok((lender_amount, protocol_fee))`
+pub fn split_payment(
+    amount: i128,
+    interest_paid: i128,
+    protocol_fee_bps: u32,
+) -> Result<(i128, i128), Error> {
+    let protocol_fee = calculate_fee(interest_paid, protocol_fee_bps)?;
+    let lender_amount = amount
+        .checked_sub(protocol_fee)
+        .ok_or(Error::ArithmeticUnderflow)?;
+
+    Ok((lender_amount, protocol_fee))
+}
+
+/// Calculate the APY (Annual Percentage Yield) in basis points from a weekly rate
+///
+/// Simple interest earns 52 × the weekly rate; compounding interest earns
+/// (1 + r)^n - 1 over the compounding periods in 52 weeks.
+/// Saturates at `u32::MAX`. This is for display purposes only.
+pub fn calculate_apy(weekly_rate: u32, mode: InterestMode) -> u32 {
+    let seconds_per_year = SECONDS_PER_WEEK * WEEKS_PER_YEAR as u64;
+
+    growth_factor(weekly_rate, mode, seconds_per_year)
+        .ok()
+        .and_then(|growth| (growth - WAD).checked_mul(BASIS_POINTS as i128))
+        .map(|apy| apy / WAD)
+        .and_then(|apy| u32::try_from(apy).ok())
+        .unwrap_or(u32::MAX)
 }
 
 /// Calculate interest for a specific time period
@@ -100,7 +350,35 @@ pub fn calculate_interest_for_period(
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
     use super::*;
+    use proptest::prelude::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    const MODES: [InterestMode; 3] = [
+        InterestMode::Simple,
+        InterestMode::CompoundPerSecond,
+        InterestMode::CompoundPerWeek,
+    ];
+
+    /// Floating-point reference for the growth of one unit of debt
+    fn reference_growth(weekly_rate: u32, mode: InterestMode, seconds: u64) -> f64 {
+        let rate = weekly_rate as f64 / BASIS_POINTS as f64;
+        let weeks = seconds as f64 / SECONDS_PER_WEEK as f64;
+
+        match mode {
+            InterestMode::Simple => 1.0 + rate * weeks,
+            InterestMode::CompoundPerSecond => {
+                (seconds as f64 * (rate / SECONDS_PER_WEEK as f64).ln_1p()).exp()
+            }
+            InterestMode::CompoundPerWeek => {
+                let full_weeks = (seconds / SECONDS_PER_WEEK) as f64;
+                let partial = (seconds % SECONDS_PER_WEEK) as f64 / SECONDS_PER_WEEK as f64;
+                (full_weeks * rate.ln_1p()).exp() * (1.0 + rate * partial)
+            }
+        }
+    }
 
     #[test]
     fn test_calculate_interest_one_week() {
@@ -217,6 +495,7 @@ mod tests {
             principal,
             accumulated,
             rate,
+            InterestMode::Simple,
             last_update,
             current_time,
         );
@@ -228,16 +507,96 @@ mod tests {
         assert_eq!(total, 110_0000000_i128);
     }
 
+    #[test]
+    fn test_apply_payment() {
+        let env = Env::default();
+        let address = Address::generate(&env);
+        let mut loan = Loan {
+            loan_id: 0,
+            offer_id: 0,
+            borrower: address.clone(),
+            lender: address.clone(),
+            loan_token: address.clone(),
+            collateral_token: address,
+            collateral_amount: 1000_0000000,
+            borrowed_amount: 100_0000000,
+            interest_rate: 500,
+            start_time: 0,
+            last_interest_update: 0,
+            accumulated_interest: 1_0000000,
+            interest_mode: InterestMode::Simple,
+            liquidation_threshold: 12500,
+            due_time: SECONDS_PER_WEEK * 4,
+            is_active: true,
+        };
+
+        // 1 week accrues 5 USDC on top of the 1 USDC already accumulated
+        // A 3 USDC payment only covers interest
+        let paid = apply_payment(&mut loan, 3_0000000, SECONDS_PER_WEEK).unwrap();
+        assert_eq!(paid, (3_0000000, 0));
+        assert_eq!(loan.accumulated_interest, 3_0000000);
+        assert_eq!(loan.borrowed_amount, 100_0000000);
+        assert_eq!(loan.last_interest_update, SECONDS_PER_WEEK);
+
+        // The next payment clears the interest before reducing principal
+        let paid = apply_payment(&mut loan, 13_0000000, SECONDS_PER_WEEK).unwrap();
+        assert_eq!(paid, (3_0000000, 10_0000000));
+        assert_eq!(loan.accumulated_interest, 0);
+        assert_eq!(loan.borrowed_amount, 90_0000000);
+    }
+
+    #[test]
+    fn test_calculate_fee() {
+        // 10% of 5 USDC
+        assert_eq!(calculate_fee(5_0000000, 1000), Ok(5000000));
+
+        // Rounds down in the payer's favour
+        assert_eq!(calculate_fee(9, 1000), Ok(0));
+        assert_eq!(calculate_fee(5_0000000, 0), Ok(0));
+    }
+
+    #[test]
+    fn test_split_payment_fee_only_on_interest() {
+        // 105 USDC repayment of which 5 USDC is interest, 10% protocol fee
+        let (lender_amount, protocol_fee) = split_payment(105_0000000, 5_0000000, 1000).unwrap();
+        assert_eq!(protocol_fee, 5000000);
+        assert_eq!(lender_amount, 104_5000000);
+        assert_eq!(lender_amount + protocol_fee, 105_0000000);
+
+        // Principal-only payments are fee free
+        assert_eq!(split_payment(50_0000000, 0, 1000), Ok((50_0000000, 0)));
+
+        // Interest-only payments
+        assert_eq!(split_payment(2_0000000, 2_0000000, 2500), Ok((1_5000000, 5000000)));
+
+        // No fee configured
+        assert_eq!(split_payment(105_0000000, 5_0000000, 0), Ok((105_0000000, 0)));
+    }
+
+    #[test]
+    fn test_split_payment_after_accrual() {
+        // 100 USDC at 5% weekly repaid in full after 1 week with a 20% fee:
+        // 5 USDC of interest, 1 USDC of which goes to the protocol
+        let total_debt =
+            calculate_total_debt(100_0000000, 0, 500, InterestMode::Simple, 0, SECONDS_PER_WEEK)
+                .unwrap();
+        let interest = total_debt - 100_0000000;
+        let (lender_amount, protocol_fee) = split_payment(total_debt, interest, 2000).unwrap();
+
+        assert_eq!(protocol_fee, 1_0000000);
+        assert_eq!(lender_amount, 104_0000000);
+    }
+
     #[test]
     fn test_calculate_apy() {
         // 5% weekly = 260% APY (approximately)
         let weekly_rate = 500_u32;
-        let apy = calculate_apy(weekly_rate);
+        let apy = calculate_apy(weekly_rate, InterestMode::Simple);
         assert_eq!(apy, 26000_u32); // 260%
 
         // 1% weekly = 52% APY
         let weekly_rate = 100_u32;
-        let apy = calculate_apy(weekly_rate);
+        let apy = calculate_apy(weekly_rate, InterestMode::Simple);
         assert_eq!(apy, 5200_u32); // 52%
     }
 
@@ -260,4 +619,244 @@ mod tests {
         // Expected: 1000 USDC
         assert_eq!(interest, 1000_0000000_i128);
     }
+
+    #[test]
+    fn test_compound_per_week_interest() {
+        // 100 USDC at 5% weekly for 2 weeks: 100 × 1.05² = 110.25 USDC
+        let interest = accrue_interest(
+            100_0000000,
+            0,
+            500,
+            InterestMode::CompoundPerWeek,
+            0,
+            SECONDS_PER_WEEK * 2,
+        );
+        assert_eq!(interest, Ok(10_2500000));
+
+        // Half a week later the partial week accrues simple interest on 110.25
+        let interest = accrue_interest(
+            100_0000000,
+            0,
+            500,
+            InterestMode::CompoundPerWeek,
+            0,
+            SECONDS_PER_WEEK * 5 / 2,
+        );
+        assert_eq!(interest, Ok(13_0062500));
+
+        // Accumulated interest compounds too
+        let interest = accrue_interest(
+            100_0000000,
+            10_0000000,
+            500,
+            InterestMode::CompoundPerWeek,
+            0,
+            SECONDS_PER_WEEK,
+        );
+        assert_eq!(interest, Ok(5_5000000));
+    }
+
+    #[test]
+    fn test_compound_per_second_interest() {
+        // 100 × (1 + 0.05 / 604800)^604800 ≈ 105.1271094 USDC after one week
+        let interest = accrue_interest(
+            100_0000000,
+            0,
+            500,
+            InterestMode::CompoundPerSecond,
+            0,
+            SECONDS_PER_WEEK,
+        )
+        .unwrap();
+        assert_eq!(interest, 5_1271094);
+
+        assert_eq!(
+            accrue_interest(100_0000000, 0, 500, InterestMode::CompoundPerSecond, 10, 5),
+            Err(Error::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_simple_mode_ignores_accumulated_interest() {
+        let interest =
+            accrue_interest(100_0000000, 10_0000000, 500, InterestMode::Simple, 0, SECONDS_PER_WEEK);
+        assert_eq!(interest, calculate_interest(100_0000000, 500, 0, SECONDS_PER_WEEK));
+    }
+
+    #[test]
+    fn test_calculate_compound_apy() {
+        // 1% weekly compounded weekly: 1.01^52 - 1 = 67.77%
+        assert_eq!(calculate_apy(100, InterestMode::CompoundPerWeek), 6776);
+
+        // Compounding every second: e^0.52 - 1 = 68.20%
+        assert_eq!(calculate_apy(100, InterestMode::CompoundPerSecond), 6820);
+
+        // 30% weekly compounded weekly: 1.3^52 - 1, far above simple interest
+        assert_eq!(calculate_apy(3000, InterestMode::CompoundPerWeek), u32::MAX);
+        assert_eq!(calculate_apy(3000, InterestMode::Simple), 156000);
+    }
+
+    #[test]
+    fn test_wad_pow_large_exponents() {
+        // A year of per-second compounding at the maximum rate stays in range
+        let seconds = SECONDS_PER_WEEK * WEEKS_PER_YEAR as u64;
+        let growth = growth_factor(3000, InterestMode::CompoundPerSecond, seconds).unwrap();
+        let expected = reference_growth(3000, InterestMode::CompoundPerSecond, seconds);
+        let actual = growth as f64 / WAD as f64;
+        assert!((actual - expected).abs() / expected < 1e-9);
+
+        // Overflow is reported instead of wrapping
+        assert_eq!(wad_pow(WAD * 1_000_000, 10), Err(Error::ArithmeticOverflow));
+    }
+
+    const RATE_MODEL: RateModel = RateModel {
+        base_rate: 10,
+        slope1: 100,
+        optimal_utilization: 8000,
+        slope2: 1000,
+    };
+
+    #[test]
+    fn test_utilization() {
+        assert_eq!(utilization(100_0000000, 0), Ok(0));
+        assert_eq!(utilization(0, 0), Ok(0));
+        assert_eq!(utilization(75_0000000, 25_0000000), Ok(2500));
+        assert_eq!(utilization(0, 25_0000000), Ok(10000));
+    }
+
+    #[test]
+    fn test_variable_borrow_rate_kink() {
+        // Base rate at zero utilization
+        assert_eq!(variable_borrow_rate(&RATE_MODEL, 0), 10);
+
+        // Halfway to the kink: base + slope1 / 2
+        assert_eq!(variable_borrow_rate(&RATE_MODEL, 4000), 60);
+
+        // At the kink: base + slope1
+        assert_eq!(variable_borrow_rate(&RATE_MODEL, 8000), 110);
+
+        // Halfway above the kink: base + slope1 + slope2 / 2
+        assert_eq!(variable_borrow_rate(&RATE_MODEL, 9000), 610);
+
+        // Fully utilized: base + slope1 + slope2
+        assert_eq!(variable_borrow_rate(&RATE_MODEL, 10000), 1110);
+    }
+
+    #[test]
+    fn test_variable_supply_rate() {
+        // 110 bps borrow rate at 80% utilization with a 10% protocol fee
+        assert_eq!(variable_supply_rate(110, 8000, 1000), 79);
+        assert_eq!(variable_supply_rate(110, 8000, 0), 88);
+        assert_eq!(variable_supply_rate(110, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_accrue_index() {
+        // One week at 1.1% weekly
+        let index = accrue_index(WAD, 110, SECONDS_PER_WEEK).unwrap();
+        assert_eq!(index, WAD + WAD / 10000 * 110);
+
+        assert_eq!(accrue_index(WAD, 110, 0), Ok(WAD));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_borrow_rate_is_monotonic(
+            utilization in 0_u32..10000,
+            step in 1_u32..1000,
+            base_rate in 0_u32..500,
+            slope1 in 0_u32..1000,
+            optimal_utilization in 1_u32..10000,
+            slope2 in 0_u32..2000,
+        ) {
+            let model = RateModel { base_rate, slope1, optimal_utilization, slope2 };
+            let lower = variable_borrow_rate(&model, utilization);
+            let higher = variable_borrow_rate(&model, (utilization + step).min(10000));
+            prop_assert!(higher >= lower);
+            prop_assert!(higher <= base_rate + slope1 + slope2);
+        }
+
+        #[test]
+        fn prop_accrual_matches_reference(
+            principal in 1_i128..1_000_000_000_0000000,
+            accumulated in 0_i128..1_000_000_0000000,
+            rate in 1_u32..=3000,
+            seconds in 0_u64..SECONDS_PER_WEEK * 16,
+            mode_index in 0_usize..3,
+        ) {
+            let mode = MODES[mode_index];
+            let interest =
+                accrue_interest(principal, accumulated, rate, mode, 0, seconds).unwrap();
+
+            let base = if mode == InterestMode::Simple {
+                principal
+            } else {
+                principal + accumulated
+            };
+            let expected = base as f64 * (reference_growth(rate, mode, seconds) - 1.0);
+            // The per-second rate is truncated to 18 decimals, which at 0.01% weekly
+            // is a relative error of up to ~6e-9 on the interest
+            let tolerance = expected * 1e-8 + 2.0;
+
+            prop_assert!(interest >= 0);
+            prop_assert!(
+                (interest as f64 - expected).abs() <= tolerance,
+                "interest {} expected {}",
+                interest,
+                expected
+            );
+        }
+
+        #[test]
+        fn prop_compounding_never_earns_less_than_simple(
+            principal in 1_i128..1_000_000_000_0000000,
+            rate in 1_u32..=3000,
+            seconds in 0_u64..SECONDS_PER_WEEK * 52,
+        ) {
+            let simple = accrue_interest(principal, 0, rate, InterestMode::Simple, 0, seconds).unwrap();
+            let weekly =
+                accrue_interest(principal, 0, rate, InterestMode::CompoundPerWeek, 0, seconds).unwrap();
+            let per_second =
+                accrue_interest(principal, 0, rate, InterestMode::CompoundPerSecond, 0, seconds)
+                    .unwrap();
+
+            // Allow for rounding in the fixed-point rate
+            prop_assert!(weekly + 1 >= simple);
+            prop_assert!(per_second + 1 >= weekly);
+        }
+
+        #[test]
+        fn prop_accrual_is_monotonic_in_time(
+            principal in 1_i128..1_000_000_000_0000000,
+            rate in 1_u32..=3000,
+            seconds in 0_u64..SECONDS_PER_WEEK * 16,
+            extra in 0_u64..SECONDS_PER_WEEK * 4,
+            mode_index in 0_usize..3,
+        ) {
+            let mode = MODES[mode_index];
+            let earlier = accrue_interest(principal, 0, rate, mode, 0, seconds).unwrap();
+            let later = accrue_interest(principal, 0, rate, mode, 0, seconds + extra).unwrap();
+            prop_assert!(later >= earlier);
+        }
+
+        #[test]
+        fn prop_apy_matches_reference(rate in 1_u32..=3000, mode_index in 0_usize..3) {
+            let mode = MODES[mode_index];
+            let seconds = SECONDS_PER_WEEK * WEEKS_PER_YEAR as u64;
+            let expected = (reference_growth(rate, mode, seconds) - 1.0) * BASIS_POINTS as f64;
+            let apy = calculate_apy(rate, mode);
+
+            if expected >= u32::MAX as f64 {
+                prop_assert_eq!(apy, u32::MAX);
+            } else {
+                let tolerance = expected * 1e-9 + 1.0;
+                prop_assert!(
+                    (apy as f64 - expected).abs() <= tolerance,
+                    "apy {} expected {}",
+                    apy,
+                    expected
+                );
+            }
+        }
+    }
 }
@@ -3,25 +3,43 @@
 //! Lending Market - Peer-to-Peer Lending Platform on Stellar
 //!
 //! This contract allows lenders to offer USDC loans at competitive interest rates,
-//! and borrowers to take loans by depositing XLM as collateral.
+//! and borrowers to take loans by depositing any listed asset as collateral.
 //!
 //! Features:
 //! - Variable interest rates set by lenders (0.1% - 30% weekly)
-//! - XLM collateral with max 50% LTV (200% minimum collateral ratio)
-//! - Automated liquidation using Reflector oracle price feeds
-//! - Per-second simple interest calculation
+//! - Admin-managed collateral registry with per-asset LTV, liquidation threshold and bonus
+//! - Automated liquidation using Reflector oracle price feeds, selling collateral on a DEX
+//! - Dutch-auction liquidation mode for price discovery when DEX liquidity is thin
+//! - Borrow requests that lenders fill, alongside lender offers
+//! - Pooled variable-rate market priced on a kinked utilization curve
+//! - Per-second simple interest, or compounding per second or per week
 //! - Position management with health monitoring
+//! - Lender positions held as transferable NFTs
 
+mod auction;
+mod collateral;
 mod contract;
+mod dex;
 mod error;
+mod events;
 mod interest;
 mod liquidation;
+mod matching;
+mod migration;
 mod oracle;
+mod pool;
+mod position;
+mod query;
 mod reflector;
+mod request;
 mod storage;
+mod timelock;
 mod types;
 mod validation;
 
 // Re-export the contract
 pub use contract::LendingMarket;
 pub use contract::LendingMarketClient;
+
+#[cfg(test)]
+mod test;
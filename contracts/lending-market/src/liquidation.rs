@@ -1,10 +1,13 @@
 //! Liquidation engine and logic for undercollateralized loans
 
+use crate::dex;
 use crate::error::Error;
+use crate::events::LiquidationData;
 use crate::interest;
-use crate::oracle;
+use crate::oracle::{self, Valuation};
+use crate::position;
 use crate::storage;
-use crate::types::{Loan, LoanHealth, BASIS_POINTS, LIQUIDATION_BONUS_BPS};
+use crate::types::{CollateralConfig, Loan, LoanAssetConfig, LoanHealth, BASIS_POINTS};
 use soroban_sdk::{token, Address, Env, Vec};
 
 /// Check if a loan is liquidatable
@@ -17,24 +20,65 @@ pub fn is_liquidatable(env: &Env, loan: &Loan) -> Result<bool, Error> {
     Ok(health.is_liquidatable)
 }
 
+/// Check if a loan is past its due time
+pub fn is_overdue(loan: &Loan, current_time: u64) -> bool {
+    loan.is_active && current_time > loan.due_time
+}
+
 /// Calculate comprehensive health metrics for a loan
 pub fn calculate_loan_health(env: &Env, loan: &Loan) -> Result<LoanHealth, Error> {
-    // Get oracle address
-    let oracle_address = storage::get_oracle_address(env)?;
-
     // Calculate current total debt (principal + accumulated + new interest)
     let current_time = env.ledger().timestamp();
     let total_debt = interest::calculate_total_debt(
         loan.borrowed_amount,
         loan.accumulated_interest,
         loan.interest_rate,
+        loan.interest_mode,
         loan.last_interest_update,
         current_time,
     )?;
 
-    // Calculate current collateral value in USDC
-    let collateral_value =
-        oracle::xlm_to_usdc_value(env, &oracle_address, loan.collateral_amount)?;
+    let config = storage::get_collateral_config(env, &loan.collateral_token)?;
+    let loan_asset = storage::get_loan_asset(env, &loan.loan_token)?;
+    let mut health = position_health(
+        env,
+        loan.loan_id,
+        &config,
+        &loan_asset,
+        loan.collateral_amount,
+        total_debt,
+        loan.liquidation_threshold,
+        loan.due_time,
+    )?;
+    health.is_overdue = is_overdue(loan, current_time);
+
+    Ok(health)
+}
+
+/// Calculate health metrics for any collateralized debt
+pub fn position_health(
+    env: &Env,
+    loan_id: u64,
+    config: &CollateralConfig,
+    loan_asset: &LoanAssetConfig,
+    collateral_amount: i128,
+    total_debt: i128,
+    liquidation_threshold: u32,
+    due_time: u64,
+) -> Result<LoanHealth, Error> {
+    // Get oracle address
+    let oracle_address = storage::get_oracle_address(env)?;
+    let current_time = env.ledger().timestamp();
+
+    // Eligibility uses the TWAP, so a single bad tick cannot trigger a liquidation
+    let collateral_value = oracle::collateral_value(
+        env,
+        &oracle_address,
+        config,
+        loan_asset,
+        collateral_amount,
+        Valuation::Twap,
+    )?;
 
     // Calculate collateralization ratio (in basis points)
     // ratio = (collateral_value / total_debt) * BASIS_POINTS
@@ -56,17 +100,17 @@ pub fn calculate_loan_health(env: &Env, loan: &Loan) -> Result<LoanHealth, Error
     };
 
     // Check if liquidatable
-    let is_liquidatable = collateralization_ratio <= loan.liquidation_threshold;
+    let is_liquidatable = collateralization_ratio <= liquidation_threshold;
 
     // Calculate health factor (collateralization_ratio / liquidation_threshold * 10000)
     // Health > 10000 means safe, < 10000 means at risk
-    let health_factor = if loan.liquidation_threshold == 0 {
+    let health_factor = if liquidation_threshold == 0 {
         u32::MAX
     } else {
         let factor = (collateralization_ratio as u64)
             .checked_mul(BASIS_POINTS as u64)
             .ok_or(Error::ArithmeticOverflow)?
-            .checked_div(loan.liquidation_threshold as u64)
+            .checked_div(liquidation_threshold as u64)
             .ok_or(Error::DivisionByZero)?;
 
         if factor > u32::MAX as u64 {
@@ -76,38 +120,44 @@ pub fn calculate_loan_health(env: &Env, loan: &Loan) -> Result<LoanHealth, Error
         }
     };
 
-    // Calculate liquidation price
-    let oracle_decimals = oracle::get_oracle_decimals(env, &oracle_address);
+    // Calculate liquidation price in the decimals of the oracle that valued the collateral
+    let oracle_decimals = oracle::get_oracle_decimals(env, &oracle::active_oracle(env)?)?;
     let liquidation_price = oracle::calculate_liquidation_price(
         total_debt,
-        loan.collateral_amount,
-        loan.liquidation_threshold,
+        oracle::normalize_decimals(collateral_amount, config.decimals, loan_asset.decimals)?,
+        liquidation_threshold,
         oracle_decimals,
     )?;
 
     Ok(LoanHealth {
-        loan_id: loan.loan_id,
-        collateral_value_usd: collateral_value,
-        debt_value_usd: total_debt,
+        loan_id,
+        collateral_value,
+        debt_value: total_debt,
         collateralization_ratio,
         liquidation_price,
         health_factor,
         is_liquidatable,
+        due_time,
+        time_remaining: due_time.saturating_sub(current_time),
+        is_overdue: current_time > due_time,
     })
 }
 
 /// Execute liquidation of an undercollateralized loan
 /// This function performs the actual liquidation by:
 /// 1. Verifying the loan is liquidatable
-/// 2. Swapping XLM collateral for USDC
-/// 3. Paying the lender (principal + interest)
-/// 4. Paying liquidator bonus
-/// 5. Returning excess to borrower (if any)
+/// 2. Capping the debt repaid at the close factor
+/// 3. Selling just enough collateral on the DEX to cover that debt + bonus
+/// 4. Paying the lender, the liquidator bonus and the protocol fees
+/// 5. Updating the loan, which stays open until its debt or collateral runs out
+///
+/// Returns the amounts moved so the caller can publish them.
 pub fn execute_liquidation(
     env: &Env,
-    loan: &Loan,
+    loan: &mut Loan,
     liquidator: &Address,
-) -> Result<(), Error> {
+    debt_to_cover: i128,
+) -> Result<LiquidationData, Error> {
     // Verify loan is active
     if !loan.is_active {
         return Err(Error::LoanNotActive);
@@ -118,81 +168,291 @@ pub fn execute_liquidation(
         return Err(Error::NotLiquidatable);
     }
 
+    if debt_to_cover <= 0 {
+        return Err(Error::InvalidRepayAmount);
+    }
+
     // Calculate total debt
     let current_time = env.ledger().timestamp();
     let total_debt = interest::calculate_total_debt(
         loan.borrowed_amount,
         loan.accumulated_interest,
         loan.interest_rate,
+        loan.interest_mode,
         loan.last_interest_update,
         current_time,
     )?;
 
-    // Get token addresses
-    let usdc_token = storage::get_usdc_token(env)?;
-    let xlm_token = storage::get_xlm_token(env)?;
+    // A single liquidation repays at most close_factor of the debt
+    let max_repay = max_liquidation_repay(total_debt, storage::get_close_factor(env))?;
+    let repay_amount = debt_to_cover.min(max_repay);
+
+    // Ensure the collateral can cover the repaid debt at the oracle price
+    let config = storage::get_collateral_config(env, &loan.collateral_token)?;
+    let loan_asset = storage::get_loan_asset(env, &loan.loan_token)?;
+    let oracle_address = storage::get_oracle_address(env)?;
+    let collateral_value = oracle::collateral_value(
+        env,
+        &oracle_address,
+        &config,
+        &loan_asset,
+        loan.collateral_amount,
+        Valuation::Spot,
+    )?;
+    if collateral_value < repay_amount {
+        return Err(Error::InsufficientCollateralValue);
+    }
+
+    let fee_config = storage::get_fee_config(env);
+    let (liquidator_bonus, liquidation_fee) =
+        liquidation_incentives(env, &config, repay_amount)?;
+
+    let target = repay_amount
+        .checked_add(liquidator_bonus)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_add(liquidation_fee)
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    // Sell enough collateral that the slippage floor still covers debt + bonus + fee
+    let (collateral_sold, proceeds) = sell_collateral(
+        env,
+        &loan.collateral_token,
+        &config,
+        &loan.loan_token,
+        &loan_asset,
+        target,
+        loan.collateral_amount,
+    )?;
+
+    // The lender must be repaid in full from the swap
+    if proceeds < repay_amount {
+        return Err(Error::LiquidationSwapFailed);
+    }
 
     let contract_address = env.current_contract_address();
+    let collateral_client = token::TokenClient::new(env, &loan.collateral_token);
+    let loan_client = token::TokenClient::new(env, &loan.loan_token);
 
-    // Transfer XLM collateral from contract to liquidator temporarily
-    // In production, this would swap via DEX, but for now we'll use simplified logic
-    let xlm_client = token::TokenClient::new(env, &xlm_token);
-    let usdc_client = token::TokenClient::new(env, &usdc_token);
+    // Update the loan: interest is repaid before principal
+    let (interest_paid, principal_paid) =
+        interest::apply_payment(loan, repay_amount, current_time)?;
+    let (lender_amount, interest_fee) =
+        interest::split_payment(repay_amount, interest_paid, fee_config.protocol_fee_bps)?;
 
-    // Calculate how much USDC we'd get from selling XLM
-    let oracle_address = storage::get_oracle_address(env)?;
-    let usdc_received =
-        oracle::xlm_to_usdc_value(env, &oracle_address, loan.collateral_amount)?;
+    // Distribution:
+    // 1. Pay lender the repaid debt, less the protocol's cut of the interest
+    // 2. Split the rest between liquidator bonus, liquidation fee and borrower refund
+    let lender = position::lender_of(env, loan)?;
+    loan_client.transfer(&contract_address, &lender, &lender_amount);
+
+    let surplus = proceeds
+        .checked_sub(repay_amount)
+        .ok_or(Error::ArithmeticUnderflow)?;
+    let (liquidator_bonus, liquidation_fee, borrower_refund) = distribute_surplus(
+        env,
+        &loan.loan_token,
+        surplus,
+        liquidator_bonus,
+        liquidation_fee,
+        liquidator,
+        &loan.borrower,
+    )?;
 
-    // Ensure we have enough to cover the debt
-    if usdc_received < total_debt {
-        return Err(Error::InsufficientCollateralValue);
+    // Both fees stay in the contract as treasury balance
+    let protocol_fee = interest_fee
+        .checked_add(liquidation_fee)
+        .ok_or(Error::ArithmeticOverflow)?;
+    storage::add_protocol_fees(env, &loan.loan_token, protocol_fee)?;
+
+    loan.collateral_amount = loan
+        .collateral_amount
+        .checked_sub(collateral_sold)
+        .ok_or(Error::ArithmeticUnderflow)?;
+
+    let remaining_debt = loan
+        .borrowed_amount
+        .checked_add(loan.accumulated_interest)
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    // Close the loan once nothing is owed or nothing is left to seize
+    let mut collateral_returned = 0;
+    if remaining_debt == 0 || loan.collateral_amount == 0 {
+        loan.is_active = false;
+
+        if loan.collateral_amount > 0 {
+            collateral_client.transfer(&contract_address, &loan.borrower, &loan.collateral_amount);
+            collateral_returned = loan.collateral_amount;
+            loan.collateral_amount = 0;
+        }
     }
 
-    // In a real implementation, we would:
-    // 1. Transfer XLM to DEX or liquidator
-    // 2. Liquidator swaps XLM → USDC
-    // 3. Receive USDC back
-    // For now, we'll simulate this by calculating values
+    Ok(LiquidationData {
+        debt_repaid: repay_amount,
+        interest_paid,
+        principal_paid,
+        collateral_seized: collateral_sold,
+        swap_proceeds: proceeds,
+        liquidator_bonus,
+        protocol_fee,
+        borrower_refund,
+        collateral_returned,
+        remaining_debt,
+    })
+}
 
-    // Calculate liquidator bonus (5% of collateral value)
-    let liquidator_bonus = usdc_received
-        .checked_mul(LIQUIDATION_BONUS_BPS as i128)
+/// Liquidator bonus and protocol liquidation fee owed on `repay_amount` of debt
+///
+/// Returns `(liquidator_bonus, liquidation_fee)`.
+pub fn liquidation_incentives(
+    env: &Env,
+    config: &CollateralConfig,
+    repay_amount: i128,
+) -> Result<(i128, i128), Error> {
+    // Liquidator bonus set for the collateral asset
+    let liquidator_bonus = repay_amount
+        .checked_mul(config.liquidation_bonus_bps as i128)
         .ok_or(Error::ArithmeticOverflow)?
         .checked_div(BASIS_POINTS as i128)
         .ok_or(Error::DivisionByZero)?;
 
-    // Distribution:
-    // 1. Pay lender the total debt
-    // 2. Pay liquidator their bonus
-    // 3. Return excess to borrower (if any)
-
-    let total_paid = total_debt
-        .checked_add(liquidator_bonus)
-        .ok_or(Error::ArithmeticOverflow)?;
+    // Protocol liquidation fee on the debt repaid
+    let liquidation_fee =
+        interest::calculate_fee(repay_amount, storage::get_fee_config(env).liquidation_fee_bps)?;
 
-    // Transfer collateral to liquidator (in real implementation, this goes to DEX)
-    xlm_client.transfer(&contract_address, liquidator, &loan.collateral_amount);
+    Ok((liquidator_bonus, liquidation_fee))
+}
 
-    // Liquidator needs to provide USDC to cover debt + bonus
-    // In production, they get this from swapping the XLM
-    // For testing, liquidator must have USDC balance
+/// Pay out swap proceeds left after the debt is repaid, in order:
+/// 1. The liquidator bonus (reduced if the swap came in short)
+/// 2. The liquidation fee, which stays in the contract
+/// 3. A refund of anything left to the borrower
+///
+/// Returns `(liquidator_bonus, liquidation_fee, borrower_refund)` as paid.
+pub fn distribute_surplus(
+    env: &Env,
+    loan_token: &Address,
+    surplus: i128,
+    liquidator_bonus: i128,
+    liquidation_fee: i128,
+    liquidator: &Address,
+    borrower: &Address,
+) -> Result<(i128, i128, i128), Error> {
+    let contract_address = env.current_contract_address();
+    let loan_client = token::TokenClient::new(env, loan_token);
 
-    // Transfer debt payment to lender
-    usdc_client.transfer(liquidator, &loan.lender, &total_debt);
+    let liquidator_bonus = liquidator_bonus.min(surplus);
+    if liquidator_bonus > 0 {
+        loan_client.transfer(&contract_address, liquidator, &liquidator_bonus);
+    }
 
-    // Calculate and handle excess
-    if usdc_received > total_paid {
-        let excess = usdc_received
-            .checked_sub(total_paid)
-            .ok_or(Error::ArithmeticUnderflow)?;
+    let surplus = surplus
+        .checked_sub(liquidator_bonus)
+        .ok_or(Error::ArithmeticUnderflow)?;
+    let liquidation_fee = liquidation_fee.min(surplus);
 
-        // Return excess to borrower (in USDC or could be converted back to XLM)
-        // For simplicity, we return USDC
-        usdc_client.transfer(liquidator, &loan.borrower, &excess);
+    let borrower_refund = surplus
+        .checked_sub(liquidation_fee)
+        .ok_or(Error::ArithmeticUnderflow)?;
+    if borrower_refund > 0 {
+        loan_client.transfer(&contract_address, borrower, &borrower_refund);
     }
 
-    Ok(())
+    Ok((liquidator_bonus, liquidation_fee, borrower_refund))
+}
+
+/// Sell `collateral_token` on the DEX for `loan_token` so that `target` is still
+/// received at the worst accepted price, selling at most `collateral_available`
+///
+/// Returns `(collateral_sold, proceeds)`.
+pub fn sell_collateral(
+    env: &Env,
+    collateral_token: &Address,
+    config: &CollateralConfig,
+    loan_token: &Address,
+    loan_asset: &LoanAssetConfig,
+    target: i128,
+    collateral_available: i128,
+) -> Result<(i128, i128), Error> {
+    let router = storage::get_dex_router(env)?;
+    let oracle_address = storage::get_oracle_address(env)?;
+
+    let slippage = storage::get_max_slippage(env);
+    let amount_to_sell =
+        collateral_to_sell(env, &oracle_address, config, loan_asset, target, slippage)?
+            .min(collateral_available);
+
+    let expected_out = oracle::collateral_value(
+        env,
+        &oracle_address,
+        config,
+        loan_asset,
+        amount_to_sell,
+        Valuation::Spot,
+    )?;
+    let min_out = apply_slippage(expected_out, slippage)?;
+
+    let proceeds = dex::swap_exact_in(
+        env,
+        &router,
+        collateral_token,
+        loan_token,
+        amount_to_sell,
+        min_out,
+    )?;
+
+    Ok((amount_to_sell, proceeds))
+}
+
+/// Maximum debt a single liquidation may repay: `close_factor` of the total debt
+/// Always allows at least 1 unit so dust debts can be cleared
+pub fn max_liquidation_repay(total_debt: i128, close_factor_bps: u32) -> Result<i128, Error> {
+    let max_repay = total_debt
+        .checked_mul(close_factor_bps as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(BASIS_POINTS as i128)
+        .ok_or(Error::DivisionByZero)?;
+
+    Ok(max_repay.max(total_debt.min(1)))
+}
+
+/// Collateral to sell so that `target` is still received at the worst accepted price
+/// amount = collateral_for(target) * BASIS_POINTS / (BASIS_POINTS - slippage), rounded up
+fn collateral_to_sell(
+    env: &Env,
+    oracle_address: &Address,
+    config: &CollateralConfig,
+    loan_asset: &LoanAssetConfig,
+    target: i128,
+    slippage_bps: u32,
+) -> Result<i128, Error> {
+    let amount_at_oracle = oracle::collateral_amount_for_value(
+        env,
+        oracle_address,
+        config,
+        loan_asset,
+        target,
+        Valuation::Spot,
+    )?;
+    let worst_case_bps = BASIS_POINTS
+        .checked_sub(slippage_bps)
+        .ok_or(Error::ArithmeticUnderflow)? as i128;
+
+    amount_at_oracle
+        .checked_mul(BASIS_POINTS as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_add(worst_case_bps - 1)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(worst_case_bps)
+        .ok_or(Error::DivisionByZero)
+}
+
+/// Minimum swap output: the oracle value less the slippage tolerance
+fn apply_slippage(expected_out: i128, slippage_bps: u32) -> Result<i128, Error> {
+    expected_out
+        .checked_mul(BASIS_POINTS.saturating_sub(slippage_bps) as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(BASIS_POINTS as i128)
+        .ok_or(Error::DivisionByZero)
 }
 
 /// Batch check which loans are liquidatable
@@ -251,6 +511,19 @@ mod tests {
         assert_eq!(health_factor, 12000_u32);
     }
 
+    #[test]
+    fn test_max_liquidation_repay() {
+        // 50% close factor on 75 USDC of debt
+        assert_eq!(max_liquidation_repay(75_0000000, 5000), Ok(37_5000000));
+
+        // 100% close factor repays everything
+        assert_eq!(max_liquidation_repay(75_0000000, 10000), Ok(75_0000000));
+
+        // Dust debt can always be cleared
+        assert_eq!(max_liquidation_repay(1, 5000), Ok(1));
+        assert_eq!(max_liquidation_repay(0, 5000), Ok(0));
+    }
+
     #[test]
     fn test_is_liquidatable_logic() {
         // Scenario 1: Healthy loan (200% collateral, 125% threshold)
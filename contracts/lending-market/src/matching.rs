@@ -0,0 +1,200 @@
+//! Borrowing against offers
+//!
+//! `borrow` takes a single loan against an offer picked by hand. `borrow_best`
+//! instead walks the loan token's offers from the lowest rate up and splits the
+//! amount across as many as it needs, one loan per offer. The collateral is split in
+//! proportion to the amount taken from each offer, so every loan carries the same
+//! collateral ratio; offers that demand a higher ratio than that are passed over.
+//!
+//! The walk follows the rate index, which only holds offers with funds left, and
+//! reads at most `MAX_BEST_OFFER_SCAN` offers. At most `MAX_BEST_FILLS` loans are
+//! opened and the collateral is priced once, so a flood of small offers cannot push
+//! a call past the network limits.
+
+use crate::collateral;
+use crate::error::Error;
+use crate::oracle::{self, Valuation};
+use crate::position;
+use crate::storage::{self, ACTIVE_INDEX_PAGE_SIZE};
+use crate::types::{
+    BorrowFill, CollateralConfig, LendingOffer, Loan, MAX_BEST_FILLS, MAX_BEST_OFFER_SCAN,
+    SECONDS_PER_WEEK,
+};
+use crate::validation;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Open and store a loan against `offer`, taking `borrow_amount` out of it
+/// The caller checks the terms and moves the tokens
+pub fn open_loan(
+    env: &Env,
+    borrower: &Address,
+    offer: &mut LendingOffer,
+    config: &CollateralConfig,
+    collateral_token: &Address,
+    collateral_amount: i128,
+    borrow_amount: i128,
+    duration_weeks: u32,
+) -> Result<Loan, Error> {
+    let loan_id = storage::get_next_loan_id(env);
+    let current_time = env.ledger().timestamp();
+    let due_time = (duration_weeks as u64)
+        .checked_mul(SECONDS_PER_WEEK)
+        .and_then(|duration| current_time.checked_add(duration))
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    let loan = Loan {
+        loan_id,
+        offer_id: offer.offer_id,
+        borrower: borrower.clone(),
+        lender: offer.lender.clone(),
+        loan_token: offer.loan_token.clone(),
+        collateral_token: collateral_token.clone(),
+        collateral_amount,
+        borrowed_amount: borrow_amount,
+        interest_rate: offer.weekly_interest_rate,
+        start_time: current_time,
+        last_interest_update: current_time,
+        accumulated_interest: 0,
+        interest_mode: offer.interest_mode,
+        liquidation_threshold: collateral::liquidation_threshold(
+            config,
+            offer.liquidation_threshold,
+        ),
+        due_time,
+        is_active: true,
+    };
+
+    // Store loan
+    storage::set_loan(env, &loan);
+    storage::add_user_loan_as_borrower(env, borrower, loan_id);
+    position::mint(env, loan_id, &offer.lender)?;
+    storage::add_offer_loan(env, offer.offer_id, loan_id);
+    storage::add_active_loan(env, loan_id);
+
+    // Update offer (reduce available amount)
+    offer.amount = offer
+        .amount
+        .checked_sub(borrow_amount)
+        .ok_or(Error::ArithmeticUnderflow)?;
+    if offer.amount == 0 {
+        storage::remove_offer_by_rate(env, offer);
+    }
+    storage::set_offer(env, offer);
+
+    Ok(loan)
+}
+
+/// Split `amount` across the cheapest offers that accept the collateral and duration
+///
+/// Fails with `NoOffersAvailable` if no offer qualifies, `InsufficientOfferFunds` if
+/// the qualifying offers cannot cover the whole amount, and `TooManyLoans` if covering
+/// it would take more loans than the borrower may hold or one call may open.
+pub fn plan(
+    env: &Env,
+    borrower: &Address,
+    loan_token: &Address,
+    collateral_token: &Address,
+    collateral_amount: i128,
+    amount: i128,
+    max_rate: u32,
+    duration_weeks: u32,
+) -> Result<Vec<BorrowFill>, Error> {
+    validation::validate_collateral_amount(collateral_amount)?;
+    validation::validate_borrow_amount(amount)?;
+    // Each offer caps the duration below, so only the lower bound is checked here
+    validation::validate_loan_duration(duration_weeks, u32::MAX)?;
+    let config = storage::get_collateral_config(env, collateral_token)?;
+    let loan_asset = storage::get_loan_asset(env, loan_token)?;
+    // Price the collateral once; each loan's share is valued pro rata
+    let collateral_value = oracle::collateral_value(
+        env,
+        &storage::get_oracle_address(env)?,
+        &config,
+        &loan_asset,
+        collateral_amount,
+        Valuation::Conservative,
+    )?;
+
+    let held_loans = storage::get_user_loans_as_borrower(env, borrower).len();
+    let open_slots = storage::get_risk_config(env)
+        .max_loans_per_user
+        .saturating_sub(held_loans)
+        .min(MAX_BEST_FILLS);
+
+    let mut fills = Vec::new(env);
+    let mut remaining = amount;
+    let mut collateral_remaining = collateral_amount;
+    let mut scanned = 0;
+    'rates: for rate in storage::get_offer_rates(env, loan_token).iter() {
+        if rate > max_rate {
+            break;
+        }
+        let mut page_number = 0;
+        loop {
+            let offer_ids = storage::get_offers_at_rate_page(env, loan_token, rate, page_number);
+            for offer_id in offer_ids.iter() {
+                if remaining == 0 || scanned == MAX_BEST_OFFER_SCAN {
+                    break 'rates;
+                }
+                scanned += 1;
+
+                let offer = storage::get_offer(env, offer_id)?;
+                if offer.max_duration_weeks < duration_weeks
+                    || !offer.collateral_assets.contains(collateral_token)
+                {
+                    continue;
+                }
+
+                let take = remaining.min(offer.amount);
+                // The last loan takes whatever collateral rounding left over
+                let share = if take == remaining {
+                    collateral_remaining
+                } else {
+                    collateral_amount
+                        .checked_mul(take)
+                        .ok_or(Error::ArithmeticOverflow)?
+                        / amount
+                };
+
+                let share_value = collateral_value
+                    .checked_mul(share)
+                    .ok_or(Error::ArithmeticOverflow)?
+                    / collateral_amount;
+                match validation::validate_collateral_value(
+                    share_value,
+                    take,
+                    collateral::min_collateral_ratio(&config, offer.min_collateral_ratio),
+                ) {
+                    Ok(()) => {}
+                    Err(Error::InsufficientCollateral) => continue,
+                    Err(error) => return Err(error),
+                }
+
+                if fills.len() == open_slots {
+                    return Err(Error::TooManyLoans);
+                }
+                fills.push_back(BorrowFill {
+                    offer_id,
+                    amount: take,
+                    collateral_amount: share,
+                    interest_rate: rate,
+                });
+                remaining -= take;
+                collateral_remaining -= share;
+            }
+            // Only a full page can have another after it
+            if offer_ids.len() < ACTIVE_INDEX_PAGE_SIZE {
+                break;
+            }
+            page_number += 1;
+        }
+    }
+
+    if fills.is_empty() {
+        return Err(Error::NoOffersAvailable);
+    }
+    if remaining > 0 {
+        return Err(Error::InsufficientOfferFunds);
+    }
+    Ok(fills)
+}
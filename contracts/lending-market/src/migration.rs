@@ -0,0 +1,67 @@
+//! Storage schema migrations
+//!
+//! `upgrade` swaps the contract code but leaves storage exactly as the old code
+//! wrote it. `migrate` then walks the stored schema forward one version at a
+//! time. A step that changes the `Loan` or `LendingOffer` layout decodes each
+//! record with the previous layout and writes it back in the new one, in batches
+//! so markets with many positions stay within the per-transaction entry limits.
+//!
+//! Schema 1 is the layout the first upgradable build stores, so no release step
+//! has been needed yet. The `lending-market-next` contract builds this market one
+//! schema version ahead, and the tests upgrade a live market to it.
+
+use crate::error::Error;
+use crate::storage;
+use crate::types::{LendingOffer, Loan};
+use soroban_sdk::{Env, TryFromVal, Val};
+
+pub const SCHEMA_VERSION: u32 = 1; // bump with a migration step whenever a stored layout changes
+
+/// Rewrite up to `limit` records for the step from `version` to `version + 1`
+/// Returns true once the step has covered every loan and offer
+pub fn step(_env: &Env, _version: u32, _limit: u32) -> Result<bool, Error> {
+    // No release has changed a stored layout yet
+    Err(Error::UnsupportedSchemaVersion)
+}
+
+/// Decode loans as `L` and offers as `O`, oldest first, and store them converted
+/// Until a release adds a step of its own, only the next build calls this
+#[allow(dead_code)]
+pub fn rewrite<L, O>(
+    env: &Env,
+    limit: u32,
+    convert_loan: impl Fn(L) -> Loan,
+    convert_offer: impl Fn(O) -> LendingOffer,
+) -> bool
+where
+    L: TryFromVal<Env, Val>,
+    O: TryFromVal<Env, Val>,
+{
+    let last_loan_id = storage::get_last_loan_id(env);
+    let last_offer_id = storage::get_last_offer_id(env);
+    let (mut loan_id, mut offer_id) = storage::get_migration_cursor(env);
+    let mut remaining = limit;
+
+    while remaining > 0 && loan_id < last_loan_id {
+        loan_id += 1;
+        remaining -= 1;
+        if let Some(loan) = storage::get_loan_as::<L>(env, loan_id) {
+            storage::set_loan(env, &convert_loan(loan));
+        }
+    }
+    while remaining > 0 && offer_id < last_offer_id {
+        offer_id += 1;
+        remaining -= 1;
+        if let Some(offer) = storage::get_offer_as::<O>(env, offer_id) {
+            storage::set_offer(env, &convert_offer(offer));
+        }
+    }
+
+    let done = loan_id == last_loan_id && offer_id == last_offer_id;
+    if done {
+        storage::remove_migration_cursor(env);
+    } else {
+        storage::set_migration_cursor(env, &(loan_id, offer_id));
+    }
+    done
+}
@@ -1,27 +1,219 @@
 //! Price oracle wrapper with validation and safety checks
 
 use crate::error::Error;
+use crate::events;
 use crate::reflector::{Asset as ReflectorAsset, PriceData, ReflectorClient};
-use crate::types::PRICE_STALENESS_THRESHOLD;
+use crate::storage;
+use crate::types::{CollateralConfig, LoanAssetConfig, OracleSource, BASIS_POINTS};
 use soroban_sdk::{Address, Env};
 
-/// Get XLM price in USDC from Reflector oracle with validation
-pub fn get_xlm_price(env: &Env, oracle_address: &Address) -> Result<PriceData, Error> {
+/// Which oracle price a collateral valuation uses
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Valuation {
+    /// Latest record, for collateral that actually changes hands
+    Spot,
+    /// Time-weighted average of the last `TwapRecords` records, for liquidation eligibility
+    Twap,
+    /// The lower of spot and TWAP, for opening debt or releasing collateral
+    Conservative,
+}
+
+/// Get the USD price of a Reflector asset with validation
+pub fn get_price(
+    env: &Env,
+    oracle_address: &Address,
+    asset: &ReflectorAsset,
+) -> Result<PriceData, Error> {
     // Create Reflector client
     let client = ReflectorClient::new(env, oracle_address);
 
-    // XLM as a symbol - this works with the CEX/DEX Reflector oracle on testnet
-    // Oracle address: CCYOZJCOPG34LLQQ7N24YXBM7LL62R7ONMZ3G6WZAAYPB5OYKOMJRN63
-    let xlm_asset = ReflectorAsset::Other(soroban_sdk::symbol_short!("XLM"));
+    // Fetch the most recent price; an oracle that traps has no price to give
+    let price_data = match client.try_lastprice(asset) {
+        Ok(Ok(price_data)) => price_data,
+        _ => None,
+    };
+    validate_price(env, price_data)
+}
+
+/// Get the price of `base` quoted in `quote` with validation
+pub fn get_cross_price(
+    env: &Env,
+    oracle_address: &Address,
+    base: &ReflectorAsset,
+    quote: &ReflectorAsset,
+) -> Result<PriceData, Error> {
+    let client = ReflectorClient::new(env, oracle_address);
 
-    // Fetch the most recent price
-    let price_data = client
-        .lastprice(&xlm_asset)
-        .ok_or(Error::PriceNotAvailable)?;
+    // An asset is always worth exactly one of itself
+    if base == quote {
+        return Ok(PriceData {
+            price: 10_i128.pow(get_oracle_decimals(env, oracle_address)?),
+            timestamp: env.ledger().timestamp(),
+        });
+    }
+
+    let price_data = match client.try_x_last_price(base, quote) {
+        Ok(Ok(price_data)) => price_data,
+        _ => None,
+    };
+    validate_price(env, price_data)
+}
+
+/// Get the time-weighted average price of `base` quoted in `quote` over `records` records
+pub fn get_cross_twap(
+    env: &Env,
+    oracle_address: &Address,
+    base: &ReflectorAsset,
+    quote: &ReflectorAsset,
+    records: u32,
+) -> Result<i128, Error> {
+    let client = ReflectorClient::new(env, oracle_address);
+
+    if base == quote {
+        return Ok(10_i128.pow(get_oracle_decimals(env, oracle_address)?));
+    }
+
+    let price = match client.try_x_twap(base, quote, &records) {
+        Ok(Ok(price)) => price,
+        _ => None,
+    }
+    .ok_or(Error::PriceNotAvailable)?;
+    if price <= 0 {
+        return Err(Error::InvalidPriceData);
+    }
+
+    Ok(price)
+}
+
+/// Get the price of a collateral asset in a loan asset under `valuation`,
+/// along with the decimals of the oracle that served it
+pub fn get_valuation_price(
+    env: &Env,
+    oracle_address: &Address,
+    collateral: &ReflectorAsset,
+    loan_asset: &ReflectorAsset,
+    valuation: Valuation,
+) -> Result<(i128, u32), Error> {
+    // The spot record is always checked, so a halted feed is never averaged over
+    let (source, oracle, spot) =
+        sourced_cross_price(env, oracle_address, collateral, loan_asset, valuation)?;
+    // Only entry points that change state hold the reentrancy lock, so queries
+    // price from the same feed without recording it
+    if storage::is_locked(env) {
+        record_source(env, source, &oracle);
+    }
+    let twap = || {
+        let records = storage::get_twap_records(env);
+        get_cross_twap(env, &oracle, collateral, loan_asset, records)
+    };
+
+    let price = match valuation {
+        Valuation::Spot => spot,
+        Valuation::Twap => twap()?,
+        Valuation::Conservative => spot.min(twap()?),
+    };
+    Ok((price, get_oracle_decimals(env, &oracle)?))
+}
+
+/// Get the spot price of `base` in `quote` from the primary oracle, or from the
+/// fallback oracle while the primary cannot serve a valid price
+///
+/// While both feeds answer, opening debt or releasing collateral requires them to
+/// agree within the allowed deviation. Liquidations keep running on the primary
+/// price instead, so bad debt cannot pile up behind the breaker. Returns the
+/// source and oracle used.
+fn sourced_cross_price(
+    env: &Env,
+    primary: &Address,
+    base: &ReflectorAsset,
+    quote: &ReflectorAsset,
+    valuation: Valuation,
+) -> Result<(OracleSource, Address, i128), Error> {
+    let primary_price = get_cross_price(env, primary, base, quote);
+    let Some(fallback) = storage::get_fallback_oracle(env) else {
+        return Ok((OracleSource::Primary, primary.clone(), primary_price?.price));
+    };
+    let fallback_price = get_cross_price(env, &fallback, base, quote);
+
+    match (primary_price, fallback_price) {
+        (Ok(primary_price), Ok(fallback_price)) => {
+            if valuation == Valuation::Conservative {
+                check_deviation(
+                    env,
+                    primary,
+                    &fallback,
+                    primary_price.price,
+                    fallback_price.price,
+                )?;
+            }
+            Ok((OracleSource::Primary, primary.clone(), primary_price.price))
+        }
+        (Ok(primary_price), Err(_)) => {
+            Ok((OracleSource::Primary, primary.clone(), primary_price.price))
+        }
+        (Err(_), Ok(fallback_price)) => {
+            Ok((OracleSource::Fallback, fallback, fallback_price.price))
+        }
+        (Err(err), Err(_)) => Err(err),
+    }
+}
+
+/// Reject primary and fallback prices further apart than the allowed deviation
+fn check_deviation(
+    env: &Env,
+    primary: &Address,
+    fallback: &Address,
+    primary_price: i128,
+    fallback_price: i128,
+) -> Result<(), Error> {
+    let fallback_price = normalize_decimals(
+        fallback_price,
+        get_oracle_decimals(env, fallback)?,
+        get_oracle_decimals(env, primary)?,
+    )?;
+
+    // deviation = |primary - fallback| * BASIS_POINTS / primary
+    let deviation_bps = primary_price
+        .checked_sub(fallback_price)
+        .ok_or(Error::ArithmeticUnderflow)?
+        .abs()
+        .checked_mul(BASIS_POINTS as i128)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(primary_price)
+        .ok_or(Error::DivisionByZero)?;
+
+    if deviation_bps > storage::get_max_oracle_deviation(env) as i128 {
+        return Err(Error::OraclePriceDeviation);
+    }
+
+    Ok(())
+}
+
+/// Remember which oracle served the latest price, announcing every switch
+fn record_source(env: &Env, source: OracleSource, oracle: &Address) {
+    if storage::get_oracle_source(env) != source {
+        storage::set_oracle_source(env, source);
+        events::oracle_switched(env, oracle, source);
+    }
+}
+
+/// Get the oracle that served the latest price
+pub fn active_oracle(env: &Env) -> Result<Address, Error> {
+    let primary = storage::get_oracle_address(env)?;
+    match storage::get_oracle_source(env) {
+        OracleSource::Primary => Ok(primary),
+        OracleSource::Fallback => Ok(storage::get_fallback_oracle(env).unwrap_or(primary)),
+    }
+}
+
+/// Reject missing, stale and non-positive prices
+fn validate_price(env: &Env, price_data: Option<PriceData>) -> Result<PriceData, Error> {
+    let price_data = price_data.ok_or(Error::PriceNotAvailable)?;
 
     // Validate price is not stale
     let current_time = env.ledger().timestamp();
-    if current_time - price_data.timestamp > PRICE_STALENESS_THRESHOLD {
+    let staleness_threshold = storage::get_risk_config(env).price_staleness_threshold;
+    if current_time - price_data.timestamp > staleness_threshold {
         return Err(Error::StalePriceData);
     }
 
@@ -30,67 +222,93 @@ pub fn get_xlm_price(env: &Env, oracle_address: &Address) -> Result<PriceData, E
         return Err(Error::InvalidPriceData);
     }
 
-    // Additional sanity check: XLM price should be between $0.01 and $100
-    // Oracle uses 14 decimals typically, so $0.01 = 10^12, $100 = 10^16
-    // Adjust these based on actual oracle decimals
-    let decimals = client.decimals();
-    let min_price = 10_i128.pow(decimals - 2); // $0.01
-    let max_price = 100 * 10_i128.pow(decimals); // $100
-
-    if price_data.price < min_price || price_data.price > max_price {
-        return Err(Error::InvalidPriceData);
-    }
-
     Ok(price_data)
 }
 
 /// Get oracle decimals
-pub fn get_oracle_decimals(env: &Env, oracle_address: &Address) -> u32 {
+pub fn get_oracle_decimals(env: &Env, oracle_address: &Address) -> Result<u32, Error> {
     let client = ReflectorClient::new(env, oracle_address);
-    client.decimals()
+    match client.try_decimals() {
+        Ok(Ok(decimals)) => Ok(decimals),
+        _ => Err(Error::PriceNotAvailable),
+    }
 }
 
-/// Calculate USD value of XLM amount
-pub fn xlm_to_usdc_value(
+/// Rescale `amount` from `from_decimals` to `to_decimals` (rounded down)
+pub fn normalize_decimals(
+    amount: i128,
+    from_decimals: u32,
+    to_decimals: u32,
+) -> Result<i128, Error> {
+    if from_decimals >= to_decimals {
+        amount
+            .checked_div(10_i128.pow(from_decimals - to_decimals))
+            .ok_or(Error::DivisionByZero)
+    } else {
+        amount
+            .checked_mul(10_i128.pow(to_decimals - from_decimals))
+            .ok_or(Error::ArithmeticOverflow)
+    }
+}
+
+/// Calculate the value of a collateral amount in the loan token
+pub fn collateral_value(
     env: &Env,
     oracle_address: &Address,
-    xlm_amount: i128,
+    collateral: &CollateralConfig,
+    loan_asset: &LoanAssetConfig,
+    amount: i128,
+    valuation: Valuation,
 ) -> Result<i128, Error> {
-    let price_data = get_xlm_price(env, oracle_address)?;
-    let decimals = get_oracle_decimals(env, oracle_address);
+    let (price, decimals) = get_valuation_price(
+        env,
+        oracle_address,
+        &collateral.oracle_asset,
+        &loan_asset.oracle_asset,
+        valuation,
+    )?;
 
-    // USDC value = (xlm_amount * price) / 10^decimals
+    // Value in collateral decimals = (amount * price) / 10^decimals
     // Need to handle potential overflow
-    let value = xlm_amount
-        .checked_mul(price_data.price)
+    let value = amount
+        .checked_mul(price)
         .ok_or(Error::ArithmeticOverflow)?
         .checked_div(10_i128.pow(decimals))
         .ok_or(Error::DivisionByZero)?;
 
-    Ok(value)
+    normalize_decimals(value, collateral.decimals, loan_asset.decimals)
 }
 
-/// Calculate XLM amount needed for specific USD value
-pub fn usdc_to_xlm_amount(
+/// Calculate the collateral amount worth a specific amount of the loan token
+pub fn collateral_amount_for_value(
     env: &Env,
     oracle_address: &Address,
-    usdc_amount: i128,
+    collateral: &CollateralConfig,
+    loan_asset: &LoanAssetConfig,
+    value: i128,
+    valuation: Valuation,
 ) -> Result<i128, Error> {
-    let price_data = get_xlm_price(env, oracle_address)?;
-    let decimals = get_oracle_decimals(env, oracle_address);
+    let (price, decimals) = get_valuation_price(
+        env,
+        oracle_address,
+        &collateral.oracle_asset,
+        &loan_asset.oracle_asset,
+        valuation,
+    )?;
 
-    // XLM amount = (usdc_amount * 10^decimals) / price
-    let amount = usdc_amount
+    // Amount = (value in collateral decimals * 10^decimals) / price
+    let amount = normalize_decimals(value, loan_asset.decimals, collateral.decimals)?
         .checked_mul(10_i128.pow(decimals))
         .ok_or(Error::ArithmeticOverflow)?
-        .checked_div(price_data.price)
+        .checked_div(price)
         .ok_or(Error::DivisionByZero)?;
 
     Ok(amount)
 }
 
 /// Calculate the liquidation price for a loan
-/// This is the XLM price at which the loan becomes liquidatable
+/// This is the collateral price at which the loan becomes liquidatable
+/// (`collateral_amount` is expected in the debt's decimals)
 pub fn calculate_liquidation_price(
     total_debt: i128,
     collateral_amount: i128,
@@ -146,4 +364,15 @@ mod tests {
         let expected = 62500000000000000_i128;
         assert_eq!(result.unwrap(), expected);
     }
+
+    #[test]
+    fn test_normalize_decimals() {
+        // 1.5 of an 18-decimal token in 7 decimals, and back
+        assert_eq!(normalize_decimals(1_500_000_000_000_000_000, 18, 7), Ok(1_5000000));
+        assert_eq!(normalize_decimals(1_5000000, 7, 18), Ok(1_500_000_000_000_000_000));
+
+        // Precision below the target decimals is dropped
+        assert_eq!(normalize_decimals(1_2345678, 7, 2), Ok(123));
+        assert_eq!(normalize_decimals(1_2345678, 7, 7), Ok(1_2345678));
+    }
 }
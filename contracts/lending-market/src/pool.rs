@@ -0,0 +1,379 @@
+//! Variable-rate USDC pool priced on a kinked utilization curve
+//!
+//! Lenders deposit into a single reserve and receive shares of it, minted as
+//! the contract's own SEP-41 token so positions can be moved freely. Borrowers
+//! draw from the reserve against any listed collateral without picking an offer and
+//! pay a rate that follows pool utilization. Debt is tracked as scaled debt
+//! against a cumulative borrow index, so interest accrues for every pool loan
+//! at once whenever the pool is touched.
+
+use crate::collateral;
+use crate::error::Error;
+use crate::events::PoolLiquidationData;
+use crate::interest::{self, WAD};
+use crate::liquidation;
+use crate::oracle::{self, Valuation};
+use crate::storage;
+use crate::types::{
+    LoanAssetConfig, LoanHealth, PoolLoan, PoolRates, PoolState, SHARE_TOKEN_DECIMALS,
+    SHARE_TOKEN_NAME, SHARE_TOKEN_SYMBOL, SHARE_UNIT,
+};
+use soroban_sdk::{token, Address, Env, String};
+use stellar_tokens::fungible::Base;
+
+/// Pool state with interest accrued up to the current ledger time (not persisted)
+pub fn current_state(env: &Env) -> Result<PoolState, Error> {
+    let mut state = storage::get_pool_state(env);
+    let current_time = env.ledger().timestamp();
+    if current_time <= state.last_update {
+        return Ok(state);
+    }
+
+    if state.total_scaled_debt > 0 {
+        let debt_before = total_debt(&state)?;
+        let rate = rates(env, &state)?.borrow_rate;
+        state.borrow_index =
+            interest::accrue_index(state.borrow_index, rate, current_time - state.last_update)?;
+
+        // The protocol keeps its cut of the interest as pool reserves
+        let interest_accrued = total_debt(&state)?
+            .checked_sub(debt_before)
+            .ok_or(Error::ArithmeticUnderflow)?;
+        let protocol_fee = interest::calculate_fee(
+            interest_accrued,
+            storage::get_fee_config(env).protocol_fee_bps,
+        )?;
+        state.reserves = state
+            .reserves
+            .checked_add(protocol_fee)
+            .ok_or(Error::ArithmeticOverflow)?;
+    }
+
+    state.last_update = current_time;
+    Ok(state)
+}
+
+/// Accrue pool interest up to now and persist it
+pub fn accrue(env: &Env) -> Result<PoolState, Error> {
+    let state = current_state(env)?;
+    storage::set_pool_state(env, &state);
+    Ok(state)
+}
+
+/// Total debt owed to the pool
+pub fn total_debt(state: &PoolState) -> Result<i128, Error> {
+    interest::wad_mul(state.total_scaled_debt, state.borrow_index)
+}
+
+/// USDC owned by lenders: idle cash plus debt owed, less protocol reserves
+pub fn total_assets(state: &PoolState) -> Result<i128, Error> {
+    state
+        .cash
+        .checked_add(total_debt(state)?)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_sub(state.reserves)
+        .ok_or(Error::ArithmeticUnderflow)
+}
+
+/// Current utilization, borrow rate and supply rate
+pub fn rates(env: &Env, state: &PoolState) -> Result<PoolRates, Error> {
+    let utilization = interest::utilization(state.cash, total_debt(state)?)?;
+    let borrow_rate = interest::variable_borrow_rate(&storage::get_rate_model(env), utilization);
+    let supply_rate = interest::variable_supply_rate(
+        borrow_rate,
+        utilization,
+        storage::get_fee_config(env).protocol_fee_bps,
+    );
+
+    Ok(PoolRates {
+        utilization,
+        borrow_rate,
+        supply_rate,
+    })
+}
+
+/// Debt currently owed on a pool loan
+pub fn loan_debt(state: &PoolState, loan: &PoolLoan) -> Result<i128, Error> {
+    interest::wad_mul(loan.scaled_debt, state.borrow_index)
+}
+
+/// The pool's USDC token and its loan asset pricing
+pub fn pool_asset(env: &Env) -> Result<(Address, LoanAssetConfig), Error> {
+    let usdc_token = storage::get_usdc_token(env)?;
+    let loan_asset = storage::get_loan_asset(env, &usdc_token)?;
+    Ok((usdc_token, loan_asset))
+}
+
+/// Health metrics for a pool loan (pool loans have no due time)
+pub fn loan_health(env: &Env, loan: &PoolLoan) -> Result<LoanHealth, Error> {
+    let state = current_state(env)?;
+    let config = storage::get_collateral_config(env, &loan.collateral_token)?;
+    let (_, loan_asset) = pool_asset(env)?;
+    liquidation::position_health(
+        env,
+        loan.loan_id,
+        &config,
+        &loan_asset,
+        loan.collateral_amount,
+        loan_debt(&state, loan)?,
+        collateral::liquidation_threshold(
+            &config,
+            storage::get_risk_config(env).pool_liquidation_threshold,
+        ),
+        u64::MAX,
+    )
+}
+
+/// Name the share token the contract mints to depositors
+pub fn set_share_metadata(env: &Env) {
+    Base::set_metadata(
+        env,
+        SHARE_TOKEN_DECIMALS,
+        String::from_str(env, SHARE_TOKEN_NAME),
+        String::from_str(env, SHARE_TOKEN_SYMBOL),
+    );
+}
+
+/// USDC value of `shares` at the current exchange rate (rounded down)
+pub fn shares_to_assets(env: &Env, state: &PoolState, shares: i128) -> Result<i128, Error> {
+    let total_shares = Base::total_supply(env);
+    if total_shares == 0 {
+        return Ok(0);
+    }
+
+    shares
+        .checked_mul(total_assets(state)?)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(total_shares)
+        .ok_or(Error::DivisionByZero)
+}
+
+/// Shares minted for depositing `amount` (rounded down); 1:1 for the first deposit
+/// Fails with `PoolInsolvent` once bad debt has left the outstanding shares worth nothing
+pub fn assets_to_shares(env: &Env, state: &PoolState, amount: i128) -> Result<i128, Error> {
+    let total_shares = Base::total_supply(env);
+    if total_shares == 0 {
+        return Ok(amount);
+    }
+
+    let total_assets = total_assets(state)?;
+    if total_assets <= 0 {
+        return Err(Error::PoolInsolvent);
+    }
+
+    amount
+        .checked_mul(total_shares)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(total_assets)
+        .ok_or(Error::DivisionByZero)
+}
+
+/// USDC value of one whole share (1 USDC until the first deposit)
+pub fn share_price(env: &Env, state: &PoolState) -> Result<i128, Error> {
+    if Base::total_supply(env) == 0 {
+        return Ok(SHARE_UNIT);
+    }
+
+    shares_to_assets(env, state, SHARE_UNIT)
+}
+
+/// Add a borrow of `amount` to the pool, returning the scaled debt taken on
+/// Scaled debt is rounded up so the pool is never owed less than it lent
+pub fn borrow(state: &mut PoolState, amount: i128) -> Result<i128, Error> {
+    if amount > state.cash {
+        return Err(Error::InsufficientPoolLiquidity);
+    }
+
+    let scaled = amount
+        .checked_mul(WAD)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_add(state.borrow_index - 1)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_div(state.borrow_index)
+        .ok_or(Error::DivisionByZero)?;
+
+    state.cash -= amount;
+    state.total_scaled_debt = state
+        .total_scaled_debt
+        .checked_add(scaled)
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    Ok(scaled)
+}
+
+/// Apply a repayment of `amount` to a pool loan and return the debt left
+/// Partial repayments reduce scaled debt rounded down, in the pool's favour
+pub fn repay(state: &mut PoolState, loan: &mut PoolLoan, amount: i128) -> Result<i128, Error> {
+    let debt = loan_debt(state, loan)?;
+    let scaled = if amount >= debt {
+        loan.scaled_debt
+    } else {
+        amount
+            .checked_mul(WAD)
+            .ok_or(Error::ArithmeticOverflow)?
+            .checked_div(state.borrow_index)
+            .ok_or(Error::DivisionByZero)?
+    };
+
+    loan.scaled_debt -= scaled;
+    state.total_scaled_debt = state
+        .total_scaled_debt
+        .checked_sub(scaled)
+        .ok_or(Error::ArithmeticUnderflow)?;
+    state.cash = state
+        .cash
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    loan_debt(state, loan)
+}
+
+/// Move as much of the protocol's pool reserves as the idle cash allows out of
+/// the pool, returning the amount released to the treasury
+pub fn take_reserves(env: &Env) -> Result<i128, Error> {
+    let mut state = accrue(env)?;
+    let amount = state.reserves.min(state.cash);
+    if amount > 0 {
+        state.reserves -= amount;
+        state.cash -= amount;
+        storage::set_pool_state(env, &state);
+    }
+
+    Ok(amount)
+}
+
+/// Execute liquidation of an undercollateralized pool loan
+///
+/// Mirrors `liquidation::execute_liquidation`: at most the close factor of the
+/// debt is repaid by selling collateral on the DEX, with the liquidator bonus
+/// and liquidation fee paid from the proceeds. If the collateral runs out
+/// before the debt, the rest is written off against the pool.
+pub fn execute_liquidation(
+    env: &Env,
+    loan: &mut PoolLoan,
+    liquidator: &Address,
+    debt_to_cover: i128,
+) -> Result<PoolLiquidationData, Error> {
+    if !loan.is_active {
+        return Err(Error::LoanNotActive);
+    }
+
+    if !loan_health(env, loan)?.is_liquidatable {
+        return Err(Error::NotLiquidatable);
+    }
+
+    if debt_to_cover <= 0 {
+        return Err(Error::InvalidRepayAmount);
+    }
+
+    let mut state = accrue(env)?;
+    let total_debt = loan_debt(&state, loan)?;
+
+    // A single liquidation repays at most close_factor of the debt
+    let max_repay =
+        liquidation::max_liquidation_repay(total_debt, storage::get_close_factor(env))?;
+    let repay_amount = debt_to_cover.min(max_repay);
+
+    // Ensure the collateral can cover the repaid debt at the oracle price
+    let config = storage::get_collateral_config(env, &loan.collateral_token)?;
+    let (usdc_token, loan_asset) = pool_asset(env)?;
+    let oracle_address = storage::get_oracle_address(env)?;
+    let collateral_value = oracle::collateral_value(
+        env,
+        &oracle_address,
+        &config,
+        &loan_asset,
+        loan.collateral_amount,
+        Valuation::Spot,
+    )?;
+    if collateral_value < repay_amount {
+        return Err(Error::InsufficientCollateralValue);
+    }
+
+    let (liquidator_bonus, liquidation_fee) =
+        liquidation::liquidation_incentives(env, &config, repay_amount)?;
+    let usdc_target = repay_amount
+        .checked_add(liquidator_bonus)
+        .ok_or(Error::ArithmeticOverflow)?
+        .checked_add(liquidation_fee)
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    let (collateral_seized, usdc_received) = liquidation::sell_collateral(
+        env,
+        &loan.collateral_token,
+        &config,
+        &usdc_token,
+        &loan_asset,
+        usdc_target,
+        loan.collateral_amount,
+    )?;
+
+    // The pool must be repaid in full from the swap
+    if usdc_received < repay_amount {
+        return Err(Error::LiquidationSwapFailed);
+    }
+
+    let mut remaining_debt = repay(&mut state, loan, repay_amount)?;
+
+    let surplus = usdc_received
+        .checked_sub(repay_amount)
+        .ok_or(Error::ArithmeticUnderflow)?;
+    let (liquidator_bonus, liquidation_fee, borrower_refund) = liquidation::distribute_surplus(
+        env,
+        &usdc_token,
+        surplus,
+        liquidator_bonus,
+        liquidation_fee,
+        liquidator,
+        &loan.borrower,
+    )?;
+    storage::add_protocol_fees(env, &usdc_token, liquidation_fee)?;
+
+    loan.collateral_amount = loan
+        .collateral_amount
+        .checked_sub(collateral_seized)
+        .ok_or(Error::ArithmeticUnderflow)?;
+
+    // Close the loan once nothing is owed or nothing is left to seize
+    let mut collateral_returned = 0;
+    let mut bad_debt = 0;
+    if remaining_debt == 0 || loan.collateral_amount == 0 {
+        loan.is_active = false;
+
+        if loan.collateral_amount > 0 {
+            let collateral_client = token::TokenClient::new(env, &loan.collateral_token);
+            collateral_client.transfer(
+                &env.current_contract_address(),
+                &loan.borrower,
+                &loan.collateral_amount,
+            );
+            collateral_returned = loan.collateral_amount;
+            loan.collateral_amount = 0;
+        }
+
+        // Debt without collateral behind it is socialized across lenders
+        if loan.scaled_debt > 0 {
+            bad_debt = remaining_debt;
+            state.total_scaled_debt = state
+                .total_scaled_debt
+                .checked_sub(loan.scaled_debt)
+                .ok_or(Error::ArithmeticUnderflow)?;
+            loan.scaled_debt = 0;
+            remaining_debt = 0;
+        }
+    }
+
+    storage::set_pool_state(env, &state);
+
+    Ok(PoolLiquidationData {
+        debt_repaid: repay_amount,
+        collateral_seized,
+        swap_proceeds: usdc_received,
+        liquidator_bonus,
+        protocol_fee: liquidation_fee,
+        borrower_refund,
+        collateral_returned,
+        remaining_debt,
+        bad_debt,
+    })
+}
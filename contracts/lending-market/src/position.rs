@@ -0,0 +1,85 @@
+//! Lender positions held as NFTs
+//!
+//! Every P2P loan has a token in the loan position NFT contract whose token ID
+//! is the loan ID. Whoever holds that token is the loan's lender: repayments,
+//! liquidation proceeds and defaulted collateral go to the current owner, so a
+//! lender can sell a performing loan by transferring the token.
+//!
+//! Loans can be opened before the NFT contract is set. Those never get a token,
+//! keep paying the lender recorded on the loan and are listed in that lender's
+//! loan index instead.
+
+use crate::error::Error;
+use crate::storage;
+use crate::types::Loan;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Loan position NFT contract interface exported as PositionNftClient
+#[soroban_sdk::contractclient(name = "PositionNftClient")]
+pub trait PositionNft {
+    /// Mint the position token for a loan (minter only)
+    fn mint(e: Env, to: Address, token_id: u32);
+
+    /// Contract allowed to mint positions
+    fn minter(e: Env) -> Address;
+
+    /// Current holder of a position
+    fn owner_of(e: Env, token_id: u32) -> Address;
+
+    /// Number of positions held by `account`
+    fn balance(e: Env, account: Address) -> u32;
+
+    /// Position at `index` among those held by `owner`
+    fn get_owner_token_id(e: Env, owner: Address, index: u32) -> u32;
+}
+
+fn token_id(loan_id: u64) -> Result<u32, Error> {
+    u32::try_from(loan_id).map_err(|_| Error::ArithmeticOverflow)
+}
+
+/// Mint the position token for a new loan to the lender who funded it,
+/// or index the loan under the lender if the NFT contract is not set
+pub fn mint(env: &Env, loan_id: u64, lender: &Address) -> Result<(), Error> {
+    let Ok(nft) = storage::get_position_nft(env) else {
+        storage::add_user_loan_as_lender(env, lender, loan_id);
+        return Ok(());
+    };
+    PositionNftClient::new(env, &nft).mint(lender, &token_id(loan_id)?);
+    Ok(())
+}
+
+/// Current lender of a loan: the holder of its position token, or the lender
+/// recorded on the loan if it has no token
+pub fn lender_of(env: &Env, loan: &Loan) -> Result<Address, Error> {
+    match storage::get_position_nft(env) {
+        Ok(nft) if loan.loan_id >= storage::get_position_nft_first_loan(env) => {
+            Ok(PositionNftClient::new(env, &nft).owner_of(&token_id(loan.loan_id)?))
+        }
+        _ => Ok(loan.lender.clone()),
+    }
+}
+
+/// IDs of the loans `owner` funded without a position token, followed by those
+/// whose position token `owner` holds, from `offset` up to `limit` of them
+/// Only the positions on the page are looked up in the NFT contract
+pub fn loans_of(env: &Env, owner: &Address, offset: u32, limit: u32) -> Vec<u64> {
+    let untokened = storage::get_user_loans_as_lender(env, owner);
+    let mut loans = Vec::new(env);
+    for loan_id in untokened.iter().skip(offset as usize).take(limit as usize) {
+        loans.push_back(loan_id);
+    }
+    if loans.len() == limit {
+        return loans;
+    }
+    let Ok(nft) = storage::get_position_nft(env) else {
+        return loans;
+    };
+
+    let client = PositionNftClient::new(env, &nft);
+    let start = offset.saturating_sub(untokened.len());
+    let end = client.balance(owner).min(start + limit - loans.len());
+    for index in start..end {
+        loans.push_back(client.get_owner_token_id(owner, &index) as u64);
+    }
+    loans
+}
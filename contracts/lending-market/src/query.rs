@@ -0,0 +1,313 @@
+//! Offer discovery: filtering, sorting and pagination over active offers
+//!
+//! A query walks the offers in listing order and stops once its page is full, so
+//! its cost does not grow with the number of open offers:
+//! - `BestRate` walks the per-token rate index, lowest rate first. Within a rate,
+//!   offers list in the order of the rate's own paged index, which a removal
+//!   reorders the same way as the active index.
+//! - `Newest` walks offer IDs down from the last one handed out, so offers list in
+//!   the order they were created and cancelled or drained offers take up reads.
+//! - `HighestAmount` has no index of its own. It ranks every offer in the active
+//!   index, and refuses with `InvalidSortOption` once the index holds more offers
+//!   than one call can read instead of ranking only part of them.
+//!
+//! A walk reads at most `MAX_OFFER_SCAN` index entries and offers. Past that point
+//! `BestRate` and `Newest` stop listing, so a deep page, or one whose filter
+//! rejects most offers, can come back short.
+
+use crate::error::Error;
+use crate::storage::{self, ACTIVE_INDEX_PAGE_SIZE};
+use crate::types::{LendingOffer, OfferFilter, SortOption, MAX_OFFER_SCAN};
+use crate::validation;
+use soroban_sdk::{vec, Address, Env, Vec};
+
+/// Check whether an offer can currently be borrowed from and passes the filter
+fn matches_filter(offer: &LendingOffer, filter: &OfferFilter) -> bool {
+    if !offer.is_active || offer.amount <= 0 {
+        return false;
+    }
+
+    if let Some(loan_token) = &filter.loan_token {
+        if offer.loan_token != *loan_token {
+            return false;
+        }
+    }
+
+    if let Some(min_amount) = filter.min_amount {
+        if offer.amount < min_amount {
+            return false;
+        }
+    }
+
+    if let Some(max_rate) = filter.max_rate {
+        if offer.weekly_interest_rate > max_rate {
+            return false;
+        }
+    }
+
+    if let Some(max_collateral_ratio) = filter.max_collateral_ratio {
+        if offer.min_collateral_ratio > max_collateral_ratio {
+            return false;
+        }
+    }
+
+    if let Some(collateral_asset) = &filter.collateral_asset {
+        if !offer.collateral_assets.contains(collateral_asset) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Whether the filter looks at more than the loan token and rate, which the rate
+/// index already tells apart without reading the offer
+fn filters_on_offer(filter: &OfferFilter) -> bool {
+    filter.min_amount.is_some()
+        || filter.max_collateral_ratio.is_some()
+        || filter.collateral_asset.is_some()
+}
+
+/// Stored entries a walk may still read
+struct Scan {
+    left: u32,
+}
+
+impl Scan {
+    fn new() -> Self {
+        Scan {
+            left: MAX_OFFER_SCAN,
+        }
+    }
+
+    /// Take one read, or report that the walk has used them all
+    fn read(&mut self) -> bool {
+        if self.left == 0 {
+            return false;
+        }
+        self.left -= 1;
+        true
+    }
+}
+
+/// Matching offers in listing order, cut down to the requested page
+struct Page {
+    offset: u32,
+    limit: u32,
+    skipped: u32,
+    offers: Vec<LendingOffer>,
+}
+
+impl Page {
+    fn new(env: &Env, offset: u32, limit: u32) -> Self {
+        Page {
+            offset,
+            limit,
+            skipped: 0,
+            offers: Vec::new(env),
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.offers.len() == self.limit
+    }
+
+    /// Pass over the next match without reading it, if it comes before `offset`
+    fn skip(&mut self) -> bool {
+        if self.skipped == self.offset {
+            return false;
+        }
+        self.skipped += 1;
+        true
+    }
+
+    /// Take the next match; returns whether the page has room for more
+    fn push(&mut self, offer: LendingOffer) -> bool {
+        if !self.skip() {
+            self.offers.push_back(offer);
+        }
+        !self.is_full()
+    }
+}
+
+/// Fill `page` walking the rate index of the filtered loan token, or of every loan
+/// asset, from the lowest rate up
+fn walk_best_rate(env: &Env, filter: &OfferFilter, scan: &mut Scan, page: &mut Page) {
+    let tokens = match &filter.loan_token {
+        Some(loan_token) => vec![env, loan_token.clone()],
+        None => storage::get_loan_assets(env),
+    };
+
+    // Every rate with offers, as the rate in the high half and the token's position
+    // in `tokens` in the low half, so keys order by rate and then token
+    let mut keys: Vec<u64> = Vec::new(env);
+    for (position, loan_token) in tokens.iter().enumerate() {
+        if !scan.read() {
+            return;
+        }
+        for rate in storage::get_offer_rates(env, &loan_token).iter() {
+            let key = ((rate as u64) << 32) | position as u64;
+            if let Err(index) = keys.binary_search(key) {
+                keys.insert(index, key);
+            }
+        }
+    }
+
+    let mut i = 0;
+    while i < keys.len() {
+        let rate = (keys.get(i).unwrap() >> 32) as u32;
+        if filter.max_rate.is_some_and(|max_rate| rate > max_rate) {
+            return;
+        }
+
+        // Offers of every token at this rate, in the order of each token's rate index
+        while i < keys.len() && (keys.get(i).unwrap() >> 32) as u32 == rate {
+            let loan_token = tokens.get(keys.get(i).unwrap() as u32).unwrap();
+            if !walk_rate(env, filter, &loan_token, rate, scan, page) {
+                return;
+            }
+            i += 1;
+        }
+    }
+}
+
+/// Fill `page` from the offers of `loan_token` at `rate`, a page of the rate's index
+/// at a time; returns whether the walk should go on
+fn walk_rate(
+    env: &Env,
+    filter: &OfferFilter,
+    loan_token: &Address,
+    rate: u32,
+    scan: &mut Scan,
+    page: &mut Page,
+) -> bool {
+    let reads_offers = filters_on_offer(filter);
+    let mut page_number = 0;
+    loop {
+        if !scan.read() {
+            return false;
+        }
+        let offer_ids = storage::get_offers_at_rate_page(env, loan_token, rate, page_number);
+        for offer_id in offer_ids.iter() {
+            // Indexed offers have funds left, so unless the filter needs the offer
+            // one before the page can be counted without reading it
+            if !reads_offers && page.skip() {
+                continue;
+            }
+            if !scan.read() {
+                return false;
+            }
+            let offer = match storage::get_offer(env, offer_id) {
+                Ok(offer) => offer,
+                Err(_) => continue,
+            };
+            if matches_filter(&offer, filter) && !page.push(offer) {
+                return false;
+            }
+        }
+
+        // Only a full page can have another after it
+        if offer_ids.len() < ACTIVE_INDEX_PAGE_SIZE {
+            return true;
+        }
+        page_number += 1;
+    }
+}
+
+/// Fill `page` walking offer IDs down from the newest, passing over offers that were
+/// cancelled or drained
+fn walk_newest(env: &Env, filter: &OfferFilter, scan: &mut Scan, page: &mut Page) {
+    let mut offer_id = storage::get_last_offer_id(env);
+    while offer_id > 0 {
+        if !scan.read() {
+            return;
+        }
+        if let Ok(offer) = storage::get_offer(env, offer_id) {
+            if matches_filter(&offer, filter) && !page.push(offer) {
+                return;
+            }
+        }
+        offer_id -= 1;
+    }
+}
+
+/// Fill `page` with every active offer matching the filter, largest amount first
+/// Ties are broken by offer ID so results are stable across calls. Fails with
+/// `InvalidSortOption` rather than rank part of the offers when the active index
+/// holds more than one call can read
+fn rank_by_amount(
+    env: &Env,
+    filter: &OfferFilter,
+    scan: &mut Scan,
+    page: &mut Page,
+) -> Result<(), Error> {
+    let mut offers: Vec<LendingOffer> = Vec::new(env);
+    // Sort keys tagged with the offer's position in `offers`, kept in order
+    let mut keys: Vec<(i128, u64, u32)> = Vec::new(env);
+
+    if !scan.read() {
+        return Err(Error::InvalidSortOption);
+    }
+    let page_count = storage::get_active_offer_count(env).div_ceil(ACTIVE_INDEX_PAGE_SIZE);
+    for page_number in 0..page_count {
+        if !scan.read() {
+            return Err(Error::InvalidSortOption);
+        }
+        for offer_id in storage::get_active_offers_page(env, page_number).iter() {
+            if !scan.read() {
+                return Err(Error::InvalidSortOption);
+            }
+            let offer = match storage::get_offer(env, offer_id) {
+                Ok(offer) => offer,
+                Err(_) => continue,
+            };
+            if !matches_filter(&offer, filter) {
+                continue;
+            }
+
+            let key = (-offer.amount, offer.offer_id, offers.len());
+            // Insert the small key rather than moving whole offers around
+            let (mut low, mut high) = (0, keys.len());
+            while low < high {
+                let mid = (low + high) / 2;
+                if keys.get(mid).unwrap() < key {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+            keys.insert(low, key);
+            offers.push_back(offer);
+        }
+    }
+
+    for (_, _, i) in keys.iter() {
+        if !page.push(offers.get(i).unwrap()) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// List active offers matching `filter`, sorted and paginated
+pub fn list_offers(
+    env: &Env,
+    sort: SortOption,
+    offset: u32,
+    limit: u32,
+    filter: OfferFilter,
+) -> Result<Vec<LendingOffer>, Error> {
+    validation::validate_pagination(limit, offset)?;
+    validation::validate_offer_filter(env, &filter)?;
+    validation::validate_sort_option(env, &sort, &filter)?;
+
+    let mut scan = Scan::new();
+    let mut page = Page::new(env, offset, limit);
+    match sort {
+        SortOption::BestRate => walk_best_rate(env, &filter, &mut scan, &mut page),
+        SortOption::Newest => walk_newest(env, &filter, &mut scan, &mut page),
+        SortOption::HighestAmount => rank_by_amount(env, &filter, &mut scan, &mut page)?,
+    }
+
+    Ok(page.offers)
+}
@@ -56,7 +56,7 @@ pub trait Contract {
 }
 
 /// Quoted asset definition
-#[contracttype(export = false)]
+#[contracttype]
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Asset {
     /// For Stellar Classic and Soroban assets
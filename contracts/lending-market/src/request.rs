@@ -0,0 +1,101 @@
+//! Borrow requests
+//!
+//! The borrower's side of an offer: a borrower posts the amount, rate ceiling and
+//! duration they want and escrows the collateral up front. Any lender can fill
+//! the request at or below that rate, which opens an ordinary `Loan` funded
+//! straight from the lender's wallet. Such loans have no offer behind them and
+//! carry offer ID 0; they take the collateral asset's own ratio and threshold.
+
+use crate::collateral;
+use crate::error::Error;
+use crate::position;
+use crate::storage;
+use crate::types::{BorrowRequest, InterestMode, Loan, SECONDS_PER_WEEK};
+use crate::validation;
+use soroban_sdk::{Address, Env};
+
+/// Check a new request's terms and that its collateral covers it at current prices
+pub fn validate(env: &Env, request: &BorrowRequest) -> Result<(), Error> {
+    validation::validate_collateral_amount(request.collateral_amount)?;
+    validation::validate_borrow_amount(request.desired_amount)?;
+    validation::validate_interest_rate(env, request.max_rate)?;
+    // No offer caps the duration of a request
+    validation::validate_loan_duration(request.duration_weeks, u32::MAX)?;
+    if request.collateral_token == request.loan_token {
+        return Err(Error::InvalidInput);
+    }
+    validation::validate_request_limit(env, &request.borrower)?;
+    validation::validate_loan_limit(env, &request.borrower)?;
+    validate_collateral(env, request)
+}
+
+fn validate_collateral(env: &Env, request: &BorrowRequest) -> Result<(), Error> {
+    let config = storage::get_collateral_config(env, &request.collateral_token)?;
+    let loan_asset = storage::get_loan_asset(env, &request.loan_token)?;
+    validation::validate_sufficient_collateral(
+        env,
+        &config,
+        &loan_asset,
+        request.collateral_amount,
+        request.desired_amount,
+        collateral::min_collateral_ratio(&config, 0),
+    )
+}
+
+/// Open and store the loan for a request filled by `lender` at `interest_rate`
+/// The caller moves the loan tokens and closes the request
+pub fn open_loan(
+    env: &Env,
+    request: &BorrowRequest,
+    lender: &Address,
+    interest_rate: u32,
+) -> Result<Loan, Error> {
+    validation::validate_interest_rate(env, interest_rate)?;
+    if interest_rate > request.max_rate {
+        return Err(Error::InvalidInterestRate);
+    }
+    validation::validate_loan_limit(env, &request.borrower)?;
+    // Prices may have moved since the request was posted
+    validate_collateral(env, request)?;
+
+    let config = storage::get_collateral_config(env, &request.collateral_token)?;
+    let current_time = env.ledger().timestamp();
+    let due_time = (request.duration_weeks as u64)
+        .checked_mul(SECONDS_PER_WEEK)
+        .and_then(|duration| current_time.checked_add(duration))
+        .ok_or(Error::ArithmeticOverflow)?;
+
+    let loan = Loan {
+        loan_id: storage::get_next_loan_id(env),
+        offer_id: 0,
+        borrower: request.borrower.clone(),
+        lender: lender.clone(),
+        loan_token: request.loan_token.clone(),
+        collateral_token: request.collateral_token.clone(),
+        collateral_amount: request.collateral_amount,
+        borrowed_amount: request.desired_amount,
+        interest_rate,
+        start_time: current_time,
+        last_interest_update: current_time,
+        accumulated_interest: 0,
+        // Simple interest keeps the cost within the rate the borrower agreed to
+        interest_mode: InterestMode::Simple,
+        liquidation_threshold: collateral::liquidation_threshold(&config, 0),
+        due_time,
+        is_active: true,
+    };
+
+    storage::set_loan(env, &loan);
+    storage::add_user_loan_as_borrower(env, &request.borrower, loan.loan_id);
+    position::mint(env, loan.loan_id, lender)?;
+    storage::add_active_loan(env, loan.loan_id);
+    Ok(loan)
+}
+
+/// Close a request that was filled or cancelled
+pub fn close(env: &Env, request: &mut BorrowRequest) {
+    request.is_active = false;
+    storage::set_borrow_request(env, request);
+    storage::remove_active_request(env, request.request_id);
+    storage::remove_user_borrow_request(env, &request.borrower, request.request_id);
+}
@@ -1,20 +1,33 @@
 //! Storage helpers and utilities for the Lending Market contract
 
 use crate::error::Error;
-use crate::types::{DataKey, Loan, LendingOffer};
-use soroban_sdk::{Address, Env, Vec};
+use crate::interest::WAD;
+use crate::types::{
+    Auction, AuctionConfig, BorrowRequest, CollateralConfig, DataKey, FeeConfig, LendingOffer,
+    Loan, LoanAssetConfig, Operation, OracleSource, PauseState, PoolLoan, PoolState,
+    QueuedAction, RateModel, RiskConfig, Role, TtlConfig, DEFAULT_AUCTION_DURATION,
+    DEFAULT_AUCTION_MAX_DISCOUNT_BPS, DEFAULT_CLOSE_FACTOR_BPS, DEFAULT_MAX_COLLATERAL_RATIO,
+    DEFAULT_MAX_LIQUIDATION_BONUS_BPS, DEFAULT_MAX_LOANS_PER_USER, DEFAULT_MAX_OFFERS_PER_USER,
+    DEFAULT_MAX_ORACLE_DEVIATION_BPS, DEFAULT_MAX_REQUESTS_PER_USER, DEFAULT_MAX_SLIPPAGE_BPS,
+    DEFAULT_POOL_BASE_RATE, DEFAULT_POOL_LIQUIDATION_THRESHOLD,
+    DEFAULT_POOL_MIN_COLLATERAL_RATIO, DEFAULT_POOL_OPTIMAL_UTILIZATION, DEFAULT_POOL_SLOPE1,
+    DEFAULT_POOL_SLOPE2, DEFAULT_PRICE_STALENESS_THRESHOLD, DEFAULT_TIMELOCK_DELAY,
+    DEFAULT_TTL_EXTEND_TO, DEFAULT_TTL_THRESHOLD, DEFAULT_TWAP_RECORDS,
+    DEFAULT_WITHDRAWAL_MARGIN_BPS,
+};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+use stellar_access::access_control;
 
 // ========== Admin ==========
+//
+// The admin and roles live in the stellar-access access control module.
 
 pub fn set_admin(env: &Env, admin: &Address) {
-    env.storage().instance().set(&DataKey::Admin, admin);
+    access_control::set_admin(env, admin);
 }
 
 pub fn get_admin(env: &Env) -> Result<Address, Error> {
-    env.storage()
-        .instance()
-        .get(&DataKey::Admin)
-        .ok_or(Error::NotInitialized)
+    access_control::get_admin(env).ok_or(Error::NotInitialized)
 }
 
 pub fn require_admin(env: &Env, address: &Address) -> Result<(), Error> {
@@ -25,6 +38,45 @@ pub fn require_admin(env: &Env, address: &Address) -> Result<(), Error> {
     Ok(())
 }
 
+/// Requires the current admin's authorization
+pub fn propose_admin(env: &Env, new_admin: &Address, live_until_ledger: u32) {
+    access_control::transfer_admin_role(env, new_admin, live_until_ledger);
+}
+
+/// Requires the proposed admin's authorization
+pub fn accept_admin(env: &Env) {
+    access_control::accept_admin_transfer(env);
+}
+
+fn role_symbol(role: Role) -> Symbol {
+    match role {
+        Role::Pauser => symbol_short!("pauser"),
+        Role::RiskManager => symbol_short!("risk_mgr"),
+        Role::OracleManager => symbol_short!("orcl_mgr"),
+        Role::FeeCollector => symbol_short!("fee_coll"),
+    }
+}
+
+pub fn has_role(env: &Env, account: &Address, role: Role) -> bool {
+    access_control::has_role(env, account, &role_symbol(role)).is_some()
+}
+
+pub fn grant_role(env: &Env, admin: &Address, account: &Address, role: Role) {
+    access_control::grant_role_no_auth(env, admin, account, &role_symbol(role));
+}
+
+pub fn revoke_role(env: &Env, admin: &Address, account: &Address, role: Role) {
+    access_control::revoke_role_no_auth(env, admin, account, &role_symbol(role));
+}
+
+/// Require `address` to be the admin or to hold `role`
+pub fn require_role(env: &Env, address: &Address, role: Role) -> Result<(), Error> {
+    if *address == get_admin(env)? || has_role(env, address, role) {
+        return Ok(());
+    }
+    Err(Error::Unauthorized)
+}
+
 // ========== Token Addresses ==========
 
 pub fn set_usdc_token(env: &Env, token: &Address) {
@@ -38,15 +90,62 @@ pub fn get_usdc_token(env: &Env) -> Result<Address, Error> {
         .ok_or(Error::UsdcTokenNotSet)
 }
 
-pub fn set_xlm_token(env: &Env, token: &Address) {
-    env.storage().instance().set(&DataKey::XlmToken, token);
+// ========== Collateral Registry ==========
+
+pub fn set_collateral_config(env: &Env, token: &Address, config: &CollateralConfig) {
+    let mut assets = get_collateral_assets(env);
+    if !assets.contains(token) {
+        assets.push_back(token.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralAssets, &assets);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::CollateralConfig(token.clone()), config);
+}
+
+pub fn get_collateral_config(env: &Env, token: &Address) -> Result<CollateralConfig, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CollateralConfig(token.clone()))
+        .ok_or(Error::UnsupportedCollateral)
+}
+
+pub fn get_collateral_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::CollateralAssets)
+        .unwrap_or(Vec::new(env))
+}
+
+// ========== Loan Assets ==========
+
+pub fn set_loan_asset(env: &Env, token: &Address, config: &LoanAssetConfig) {
+    let mut assets = get_loan_assets(env);
+    if !assets.contains(token) {
+        assets.push_back(token.clone());
+        env.storage().instance().set(&DataKey::LoanAssets, &assets);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::LoanAsset(token.clone()), config);
+}
+
+pub fn get_loan_asset(env: &Env, token: &Address) -> Result<LoanAssetConfig, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::LoanAsset(token.clone()))
+        .ok_or(Error::UnsupportedLoanAsset)
 }
 
-pub fn get_xlm_token(env: &Env) -> Result<Address, Error> {
+pub fn get_loan_assets(env: &Env) -> Vec<Address> {
     env.storage()
         .instance()
-        .get(&DataKey::XlmToken)
-        .ok_or(Error::XlmTokenNotSet)
+        .get(&DataKey::LoanAssets)
+        .unwrap_or(Vec::new(env))
 }
 
 // ========== Oracle ==========
@@ -64,6 +163,304 @@ pub fn get_oracle_address(env: &Env) -> Result<Address, Error> {
         .ok_or(Error::OracleNotSet)
 }
 
+pub fn set_fallback_oracle(env: &Env, oracle: &Option<Address>) {
+    match oracle {
+        Some(oracle) => env.storage().instance().set(&DataKey::FallbackOracle, oracle),
+        None => env.storage().instance().remove(&DataKey::FallbackOracle),
+    }
+}
+
+pub fn get_fallback_oracle(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::FallbackOracle)
+}
+
+pub fn set_oracle_source(env: &Env, source: OracleSource) {
+    env.storage().instance().set(&DataKey::OracleSource, &source);
+}
+
+pub fn get_oracle_source(env: &Env) -> OracleSource {
+    env.storage()
+        .instance()
+        .get(&DataKey::OracleSource)
+        .unwrap_or(OracleSource::Primary)
+}
+
+pub fn set_max_oracle_deviation(env: &Env, deviation_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxOracleDeviation, &deviation_bps);
+}
+
+pub fn get_max_oracle_deviation(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxOracleDeviation)
+        .unwrap_or(DEFAULT_MAX_ORACLE_DEVIATION_BPS)
+}
+
+pub fn set_twap_records(env: &Env, records: u32) {
+    env.storage().instance().set(&DataKey::TwapRecords, &records);
+}
+
+pub fn get_twap_records(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TwapRecords)
+        .unwrap_or(DEFAULT_TWAP_RECORDS)
+}
+
+// ========== DEX ==========
+
+pub fn set_dex_router(env: &Env, router: &Address) {
+    env.storage().instance().set(&DataKey::DexRouter, router);
+}
+
+pub fn get_dex_router(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::DexRouter)
+        .ok_or(Error::DexRouterNotSet)
+}
+
+pub fn set_position_nft(env: &Env, nft: &Address) {
+    env.storage().instance().set(&DataKey::PositionNft, nft);
+    // Loans opened before now were never minted a token
+    let next_loan_id: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextLoanId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::PositionNftFirstLoan, &next_loan_id);
+}
+
+pub fn get_position_nft_first_loan(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::PositionNftFirstLoan)
+        .unwrap_or(1)
+}
+
+pub fn get_position_nft(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PositionNft)
+        .ok_or(Error::PositionNftNotSet)
+}
+
+pub fn set_max_slippage(env: &Env, slippage_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxSlippage, &slippage_bps);
+}
+
+pub fn get_max_slippage(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxSlippage)
+        .unwrap_or(DEFAULT_MAX_SLIPPAGE_BPS)
+}
+
+// ========== Liquidation ==========
+
+pub fn set_close_factor(env: &Env, close_factor_bps: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CloseFactor, &close_factor_bps);
+}
+
+pub fn get_close_factor(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CloseFactor)
+        .unwrap_or(DEFAULT_CLOSE_FACTOR_BPS)
+}
+
+pub fn set_auction_config(env: &Env, config: &AuctionConfig) {
+    env.storage().instance().set(&DataKey::AuctionConfig, config);
+}
+
+pub fn get_auction_config(env: &Env) -> AuctionConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::AuctionConfig)
+        .unwrap_or(AuctionConfig {
+            max_discount_bps: DEFAULT_AUCTION_MAX_DISCOUNT_BPS,
+            duration: DEFAULT_AUCTION_DURATION,
+        })
+}
+
+// ========== Risk ==========
+
+pub fn set_risk_config(env: &Env, config: &RiskConfig) {
+    env.storage().instance().set(&DataKey::RiskConfig, config);
+}
+
+pub fn get_risk_config(env: &Env) -> RiskConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::RiskConfig)
+        .unwrap_or(RiskConfig {
+            price_staleness_threshold: DEFAULT_PRICE_STALENESS_THRESHOLD,
+            max_offers_per_user: DEFAULT_MAX_OFFERS_PER_USER,
+            max_requests_per_user: DEFAULT_MAX_REQUESTS_PER_USER,
+            max_loans_per_user: DEFAULT_MAX_LOANS_PER_USER,
+            max_collateral_ratio: DEFAULT_MAX_COLLATERAL_RATIO,
+            withdrawal_margin_bps: DEFAULT_WITHDRAWAL_MARGIN_BPS,
+            max_liquidation_bonus_bps: DEFAULT_MAX_LIQUIDATION_BONUS_BPS,
+            pool_min_collateral_ratio: DEFAULT_POOL_MIN_COLLATERAL_RATIO,
+            pool_liquidation_threshold: DEFAULT_POOL_LIQUIDATION_THRESHOLD,
+        })
+}
+
+// ========== Timelock ==========
+
+pub fn set_timelock_delay(env: &Env, delay: u64) {
+    env.storage().instance().set(&DataKey::TimelockDelay, &delay);
+}
+
+pub fn get_timelock_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TimelockDelay)
+        .unwrap_or(DEFAULT_TIMELOCK_DELAY)
+}
+
+pub fn get_queued_action_ids(env: &Env) -> Vec<u64> {
+    read_persistent(env, &DataKey::QueuedActions).unwrap_or(Vec::new(env))
+}
+
+pub fn add_queued_action(env: &Env, queued: &QueuedAction) {
+    let mut ids = get_queued_action_ids(env);
+    ids.push_back(queued.action_id);
+    write_persistent(env, &DataKey::QueuedActions, &ids);
+    write_persistent(env, &DataKey::QueuedAction(queued.action_id), queued);
+}
+
+pub fn get_queued_action(env: &Env, action_id: u64) -> Result<QueuedAction, Error> {
+    read_persistent(env, &DataKey::QueuedAction(action_id)).ok_or(Error::ActionNotFound)
+}
+
+pub fn remove_queued_action(env: &Env, action_id: u64) {
+    let mut ids = get_queued_action_ids(env);
+    if let Some(index) = ids.first_index_of(action_id) {
+        ids.remove(index);
+        write_persistent(env, &DataKey::QueuedActions, &ids);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::QueuedAction(action_id));
+}
+
+// ========== Protocol Fees ==========
+
+pub fn set_fee_config(env: &Env, config: &FeeConfig) {
+    env.storage().instance().set(&DataKey::FeeConfig, config);
+}
+
+pub fn get_fee_config(env: &Env) -> FeeConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeConfig)
+        .unwrap_or(FeeConfig {
+            protocol_fee_bps: 0,
+            liquidation_fee_bps: 0,
+        })
+}
+
+pub fn get_protocol_fees(env: &Env, token: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProtocolFees(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_protocol_fees(env: &Env, token: &Address, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ProtocolFees(token.clone()), &amount);
+}
+
+/// Credit fees in `token` to the treasury (the tokens must already be held by the contract)
+pub fn add_protocol_fees(env: &Env, token: &Address, amount: i128) -> Result<(), Error> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let total = get_protocol_fees(env, token)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticOverflow)?;
+    set_protocol_fees(env, token, total);
+    Ok(())
+}
+
+// ========== Pool ==========
+
+pub fn set_rate_model(env: &Env, model: &RateModel) {
+    env.storage().instance().set(&DataKey::RateModel, model);
+}
+
+pub fn get_rate_model(env: &Env) -> RateModel {
+    env.storage()
+        .instance()
+        .get(&DataKey::RateModel)
+        .unwrap_or(RateModel {
+            base_rate: DEFAULT_POOL_BASE_RATE,
+            slope1: DEFAULT_POOL_SLOPE1,
+            optimal_utilization: DEFAULT_POOL_OPTIMAL_UTILIZATION,
+            slope2: DEFAULT_POOL_SLOPE2,
+        })
+}
+
+pub fn set_pool_state(env: &Env, state: &PoolState) {
+    env.storage().instance().set(&DataKey::PoolState, state);
+}
+
+pub fn get_pool_state(env: &Env) -> PoolState {
+    env.storage()
+        .instance()
+        .get(&DataKey::PoolState)
+        .unwrap_or(PoolState {
+            cash: 0,
+            total_scaled_debt: 0,
+            borrow_index: WAD,
+            reserves: 0,
+            last_update: env.ledger().timestamp(),
+        })
+}
+
+pub fn set_pool_loan(env: &Env, loan: &PoolLoan) {
+    write_persistent(env, &DataKey::PoolLoan(loan.loan_id), loan);
+}
+
+pub fn get_pool_loan(env: &Env, loan_id: u64) -> Result<PoolLoan, Error> {
+    read_persistent(env, &DataKey::PoolLoan(loan_id)).ok_or(Error::PoolLoanNotFound)
+}
+
+// ========== User Pool Loans ==========
+
+pub fn add_user_pool_loan(env: &Env, user: &Address, loan_id: u64) {
+    let key = DataKey::UserPoolLoans(user.clone());
+    let mut loans: Vec<u64> = read_persistent(env, &key).unwrap_or(Vec::new(env));
+    loans.push_back(loan_id);
+    write_persistent(env, &key, &loans);
+}
+
+pub fn get_user_pool_loans(env: &Env, user: &Address) -> Vec<u64> {
+    let key = DataKey::UserPoolLoans(user.clone());
+    read_persistent(env, &key).unwrap_or(Vec::new(env))
+}
+
+pub fn remove_user_pool_loan(env: &Env, user: &Address, loan_id: u64) {
+    let key = DataKey::UserPoolLoans(user.clone());
+    let mut loans = get_user_pool_loans(env, user);
+    if let Some(index) = loans.first_index_of(loan_id) {
+        loans.remove(index);
+        write_persistent(env, &key, &loans);
+    }
+}
+
 // ========== Contract State ==========
 
 pub fn set_max_interest_rate(env: &Env, rate: u32) {
@@ -79,19 +476,27 @@ pub fn get_max_interest_rate(env: &Env) -> u32 {
         .unwrap_or(3000) // Default 30%
 }
 
-pub fn set_paused(env: &Env, paused: bool) {
-    env.storage().instance().set(&DataKey::IsPaused, &paused);
+pub fn set_pause_state(env: &Env, state: &PauseState) {
+    env.storage().instance().set(&DataKey::PauseState, state);
 }
 
-pub fn is_paused(env: &Env) -> bool {
+pub fn get_pause_state(env: &Env) -> PauseState {
     env.storage()
         .instance()
-        .get(&DataKey::IsPaused)
-        .unwrap_or(false)
+        .get(&DataKey::PauseState)
+        .unwrap_or_default()
 }
 
-pub fn require_not_paused(env: &Env) -> Result<(), Error> {
-    if is_paused(env) {
+pub fn require_not_paused(env: &Env, operation: Operation) -> Result<(), Error> {
+    let state = get_pause_state(env);
+    let paused = match operation {
+        Operation::Offers => state.offers,
+        Operation::Borrows => state.borrows,
+        Operation::Withdrawals => state.withdrawals,
+        Operation::Liquidations => state.liquidations,
+        Operation::Repayments => state.repayments,
+    };
+    if paused {
         return Err(Error::ContractPaused);
     }
     Ok(())
@@ -100,12 +505,7 @@ pub fn require_not_paused(env: &Env) -> Result<(), Error> {
 // ========== Reentrancy Guard ==========
 
 pub fn lock(env: &Env) -> Result<(), Error> {
-    let locked = env
-        .storage()
-        .instance()
-        .get(&DataKey::Locked)
-        .unwrap_or(false);
-    if locked {
+    if is_locked(env) {
         return Err(Error::Reentrant);
     }
     env.storage().instance().set(&DataKey::Locked, &true);
@@ -116,6 +516,13 @@ pub fn unlock(env: &Env) {
     env.storage().instance().set(&DataKey::Locked, &false);
 }
 
+pub fn is_locked(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Locked)
+        .unwrap_or(false)
+}
+
 // ========== ID Counters ==========
 
 pub fn get_next_offer_id(env: &Env) -> u64 {
@@ -130,6 +537,18 @@ pub fn get_next_offer_id(env: &Env) -> u64 {
     current
 }
 
+pub fn get_next_pool_loan_id(env: &Env) -> u64 {
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextPoolLoanId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextPoolLoanId, &(current + 1));
+    current
+}
+
 pub fn get_next_loan_id(env: &Env) -> u64 {
     let current: u64 = env
         .storage()
@@ -142,195 +561,625 @@ pub fn get_next_loan_id(env: &Env) -> u64 {
     current
 }
 
-// ========== Offers ==========
+pub fn get_next_request_id(env: &Env) -> u64 {
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextRequestId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextRequestId, &(current + 1));
+    current
+}
 
-pub fn set_offer(env: &Env, offer: &LendingOffer) {
+pub fn get_next_action_id(env: &Env) -> u64 {
+    let current: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextActionId)
+        .unwrap_or(1);
     env.storage()
-        .persistent()
-        .set(&DataKey::Offer(offer.offer_id), offer);
+        .instance()
+        .set(&DataKey::NextActionId, &(current + 1));
+    current
 }
 
-pub fn get_offer(env: &Env, offer_id: u64) -> Result<LendingOffer, Error> {
+/// Highest offer ID handed out so far (0 before the first offer)
+pub fn get_last_offer_id(env: &Env) -> u64 {
+    let next: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextOfferId)
+        .unwrap_or(1);
+    next - 1
+}
+
+/// Highest loan ID handed out so far (0 before the first loan)
+pub fn get_last_loan_id(env: &Env) -> u64 {
+    let next: u64 = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextLoanId)
+        .unwrap_or(1);
+    next - 1
+}
+
+// ========== Schema ==========
+
+pub fn set_schema_version(env: &Env, version: u32) {
     env.storage()
-        .persistent()
-        .get(&DataKey::Offer(offer_id))
-        .ok_or(Error::OfferNotFound)
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
 }
 
-pub fn has_offer(env: &Env, offer_id: u64) -> bool {
+pub fn get_schema_version(env: &Env) -> u32 {
     env.storage()
-        .persistent()
-        .has(&DataKey::Offer(offer_id))
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+/// Last (loan ID, offer ID) rewritten by the migration step in progress
+pub fn get_migration_cursor(env: &Env) -> (u64, u64) {
+    env.storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or((0, 0))
+}
+
+pub fn set_migration_cursor(env: &Env, cursor: &(u64, u64)) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationCursor, cursor);
+}
+
+pub fn remove_migration_cursor(env: &Env) {
+    env.storage().instance().remove(&DataKey::MigrationCursor);
+}
+
+// ========== TTL ==========
+//
+// Every persistent entry is bumped whenever it is read or written, and
+// instance storage is bumped on every entry point, so anything still in use
+// stays live. Bumps are no-ops while the remaining TTL is above the threshold.
+
+pub fn set_ttl_config(env: &Env, config: &TtlConfig) {
+    env.storage().instance().set(&DataKey::TtlConfig, config);
+}
+
+pub fn get_ttl_config(env: &Env) -> TtlConfig {
+    env.storage()
+        .instance()
+        .get(&DataKey::TtlConfig)
+        .unwrap_or(TtlConfig {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        })
 }
 
-pub fn remove_offer(env: &Env, offer_id: u64) {
+pub fn extend_instance_ttl(env: &Env) {
+    let config = get_ttl_config(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+    let config = get_ttl_config(env);
     env.storage()
         .persistent()
-        .remove(&DataKey::Offer(offer_id));
+        .extend_ttl(key, config.threshold, config.extend_to);
+}
+
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent_ttl(env, key);
+    }
+    value
+}
+
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent_ttl(env, key);
+}
+
+fn extend_persistent_ttl_if_present(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        extend_persistent_ttl(env, key);
+    }
+}
+
+/// Extend a loan and every index entry that references it
+pub fn extend_loan_ttl(env: &Env, loan: &Loan) {
+    extend_persistent_ttl(env, &DataKey::Loan(loan.loan_id));
+    extend_persistent_ttl_if_present(env, &DataKey::ActiveLoanPosition(loan.loan_id));
+    extend_persistent_ttl_if_present(env, &DataKey::Auction(loan.loan_id));
+    extend_persistent_ttl_if_present(env, &DataKey::OfferLoans(loan.offer_id));
+    extend_persistent_ttl_if_present(env, &DataKey::UserLoansAsBorrower(loan.borrower.clone()));
+    extend_persistent_ttl_if_present(env, &DataKey::UserLoansAsLender(loan.lender.clone()));
+}
+
+/// Extend an offer and every index entry that references it
+pub fn extend_offer_ttl(env: &Env, offer: &LendingOffer) {
+    extend_persistent_ttl(env, &DataKey::Offer(offer.offer_id));
+    extend_persistent_ttl_if_present(env, &DataKey::ActiveOfferPosition(offer.offer_id));
+    extend_persistent_ttl_if_present(env, &DataKey::OfferLoans(offer.offer_id));
+    extend_persistent_ttl_if_present(env, &DataKey::UserOffers(offer.lender.clone()));
+    extend_persistent_ttl_if_present(env, &DataKey::OfferRatePosition(offer.offer_id));
+    extend_persistent_ttl_if_present(env, &DataKey::OfferRates(offer.loan_token.clone()));
+}
+
+/// Extend a pool loan and every index entry that references it
+pub fn extend_pool_loan_ttl(env: &Env, loan: &PoolLoan) {
+    extend_persistent_ttl(env, &DataKey::PoolLoan(loan.loan_id));
+    extend_persistent_ttl_if_present(env, &DataKey::ActivePoolLoanPosition(loan.loan_id));
+    extend_persistent_ttl_if_present(env, &DataKey::UserPoolLoans(loan.borrower.clone()));
+}
+
+/// Extend a borrow request and every index entry that references it
+pub fn extend_request_ttl(env: &Env, request: &BorrowRequest) {
+    extend_persistent_ttl(env, &DataKey::BorrowRequest(request.request_id));
+    extend_persistent_ttl_if_present(env, &DataKey::ActiveRequestPosition(request.request_id));
+    extend_persistent_ttl_if_present(env, &DataKey::UserBorrowRequests(request.borrower.clone()));
+}
+
+// ========== Offers ==========
+
+pub fn set_offer(env: &Env, offer: &LendingOffer) {
+    write_persistent(env, &DataKey::Offer(offer.offer_id), offer);
+}
+
+pub fn get_offer(env: &Env, offer_id: u64) -> Result<LendingOffer, Error> {
+    read_persistent(env, &DataKey::Offer(offer_id)).ok_or(Error::OfferNotFound)
+}
+
+/// Read an offer stored in an older layout, for migrations
+pub fn get_offer_as<V: TryFromVal<Env, Val>>(env: &Env, offer_id: u64) -> Option<V> {
+    read_persistent(env, &DataKey::Offer(offer_id))
 }
 
 // ========== Loans ==========
 
 pub fn set_loan(env: &Env, loan: &Loan) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::Loan(loan.loan_id), loan);
+    write_persistent(env, &DataKey::Loan(loan.loan_id), loan);
 }
 
 pub fn get_loan(env: &Env, loan_id: u64) -> Result<Loan, Error> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::Loan(loan_id))
-        .ok_or(Error::LoanNotFound)
+    read_persistent(env, &DataKey::Loan(loan_id)).ok_or(Error::LoanNotFound)
 }
 
-pub fn has_loan(env: &Env, loan_id: u64) -> bool {
-    env.storage().persistent().has(&DataKey::Loan(loan_id))
+/// Read a loan stored in an older layout, for migrations
+pub fn get_loan_as<V: TryFromVal<Env, Val>>(env: &Env, loan_id: u64) -> Option<V> {
+    read_persistent(env, &DataKey::Loan(loan_id))
 }
 
-pub fn remove_loan(env: &Env, loan_id: u64) {
-    env.storage().persistent().remove(&DataKey::Loan(loan_id));
+// ========== Borrow Requests ==========
+
+pub fn set_borrow_request(env: &Env, request: &BorrowRequest) {
+    write_persistent(env, &DataKey::BorrowRequest(request.request_id), request);
+}
+
+pub fn get_borrow_request(env: &Env, request_id: u64) -> Result<BorrowRequest, Error> {
+    read_persistent(env, &DataKey::BorrowRequest(request_id)).ok_or(Error::BorrowRequestNotFound)
+}
+
+// ========== Auctions ==========
+
+pub fn set_auction(env: &Env, auction: &Auction) {
+    write_persistent(env, &DataKey::Auction(auction.loan_id), auction);
+}
+
+pub fn get_auction(env: &Env, loan_id: u64) -> Result<Auction, Error> {
+    read_persistent(env, &DataKey::Auction(loan_id)).ok_or(Error::AuctionNotFound)
+}
+
+pub fn has_auction(env: &Env, loan_id: u64) -> bool {
+    env.storage().persistent().has(&DataKey::Auction(loan_id))
+}
+
+pub fn remove_auction(env: &Env, loan_id: u64) {
+    env.storage().persistent().remove(&DataKey::Auction(loan_id));
 }
 
 // ========== User Offers ==========
 
 pub fn add_user_offer(env: &Env, user: &Address, offer_id: u64) {
     let key = DataKey::UserOffers(user.clone());
-    let mut offers: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    let mut offers: Vec<u64> = read_persistent(env, &key).unwrap_or(Vec::new(env));
     offers.push_back(offer_id);
-    env.storage().persistent().set(&key, &offers);
+    write_persistent(env, &key, &offers);
 }
 
 pub fn get_user_offers(env: &Env, user: &Address) -> Vec<u64> {
     let key = DataKey::UserOffers(user.clone());
-    env.storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or(Vec::new(env))
-}
-
-pub fn remove_user_offer(env: &Env, user: &Address, offer_id: u64) {
-    let key = DataKey::UserOffers(user.clone());
-    let mut offers: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
-
-    // Find and remove the offer_id
-    let mut new_offers = Vec::new(env);
-    for i in 0..offers.len() {
-        let id = offers.get(i).unwrap();
-        if id != offer_id {
-            new_offers.push_back(id);
-        }
-    }
-
-    env.storage().persistent().set(&key, &new_offers);
+    read_persistent(env, &key).unwrap_or(Vec::new(env))
 }
 
 // ========== User Loans (as Borrower) ==========
 
 pub fn add_user_loan_as_borrower(env: &Env, user: &Address, loan_id: u64) {
     let key = DataKey::UserLoansAsBorrower(user.clone());
-    let mut loans: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    let mut loans: Vec<u64> = read_persistent(env, &key).unwrap_or(Vec::new(env));
     loans.push_back(loan_id);
-    env.storage().persistent().set(&key, &loans);
+    write_persistent(env, &key, &loans);
 }
 
 pub fn get_user_loans_as_borrower(env: &Env, user: &Address) -> Vec<u64> {
     let key = DataKey::UserLoansAsBorrower(user.clone());
-    env.storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or(Vec::new(env))
+    read_persistent(env, &key).unwrap_or(Vec::new(env))
+}
+
+pub fn remove_user_loan_as_borrower(env: &Env, user: &Address, loan_id: u64) {
+    let key = DataKey::UserLoansAsBorrower(user.clone());
+    let mut loans = get_user_loans_as_borrower(env, user);
+    if let Some(index) = loans.first_index_of(loan_id) {
+        loans.remove(index);
+        write_persistent(env, &key, &loans);
+    }
 }
 
 // ========== User Loans (as Lender) ==========
 
 pub fn add_user_loan_as_lender(env: &Env, user: &Address, loan_id: u64) {
     let key = DataKey::UserLoansAsLender(user.clone());
-    let mut loans: Vec<u64> = env.storage().persistent().get(&key).unwrap_or(Vec::new(env));
+    let mut loans: Vec<u64> = read_persistent(env, &key).unwrap_or(Vec::new(env));
     loans.push_back(loan_id);
-    env.storage().persistent().set(&key, &loans);
+    write_persistent(env, &key, &loans);
 }
 
 pub fn get_user_loans_as_lender(env: &Env, user: &Address) -> Vec<u64> {
     let key = DataKey::UserLoansAsLender(user.clone());
-    env.storage()
-        .persistent()
-        .get(&key)
-        .unwrap_or(Vec::new(env))
+    read_persistent(env, &key).unwrap_or(Vec::new(env))
+}
+
+// ========== Offer Loans ==========
+
+pub fn add_offer_loan(env: &Env, offer_id: u64, loan_id: u64) {
+    let key = DataKey::OfferLoans(offer_id);
+    let mut loans: Vec<u64> = read_persistent(env, &key).unwrap_or(Vec::new(env));
+    loans.push_back(loan_id);
+    write_persistent(env, &key, &loans);
+}
+
+pub fn get_offer_loans(env: &Env, offer_id: u64) -> Vec<u64> {
+    let key = DataKey::OfferLoans(offer_id);
+    read_persistent(env, &key).unwrap_or(Vec::new(env))
+}
+
+pub fn remove_offer_loan(env: &Env, offer_id: u64, loan_id: u64) {
+    let key = DataKey::OfferLoans(offer_id);
+    // Loans filled from a borrow request have no offer to detach from
+    let loans: Vec<u64> = match read_persistent(env, &key) {
+        Some(loans) => loans,
+        None => return,
+    };
+
+    let mut new_loans = Vec::new(env);
+    for i in 0..loans.len() {
+        let id = loans.get(i).unwrap();
+        if id != loan_id {
+            new_loans.push_back(id);
+        }
+    }
+
+    write_persistent(env, &key, &new_loans);
+}
+
+// ========== User Borrow Requests ==========
+
+pub fn add_user_borrow_request(env: &Env, user: &Address, request_id: u64) {
+    let key = DataKey::UserBorrowRequests(user.clone());
+    let mut requests: Vec<u64> = read_persistent(env, &key).unwrap_or(Vec::new(env));
+    requests.push_back(request_id);
+    write_persistent(env, &key, &requests);
+}
+
+pub fn get_user_borrow_requests(env: &Env, user: &Address) -> Vec<u64> {
+    let key = DataKey::UserBorrowRequests(user.clone());
+    read_persistent(env, &key).unwrap_or(Vec::new(env))
+}
+
+pub fn remove_user_borrow_request(env: &Env, user: &Address, request_id: u64) {
+    let key = DataKey::UserBorrowRequests(user.clone());
+    let mut requests = get_user_borrow_requests(env, user);
+    if let Some(index) = requests.first_index_of(request_id) {
+        requests.remove(index);
+        write_persistent(env, &key, &requests);
+    }
+}
+
+// ========== Active Index ==========
+//
+// Active offer, loan, pool loan and borrow request IDs, and the offers funded at
+// each rate, are stored in fixed-size pages plus a map from ID
+// to position, so inserts and removals touch a constant number of entries no
+// matter how many IDs are indexed. Removal moves the last ID into the freed
+// slot, so listing order is not insertion order.
+
+/// Maximum number of IDs stored in a single index page
+pub const ACTIVE_INDEX_PAGE_SIZE: u32 = 50;
+
+#[derive(Clone)]
+enum ActiveIndex {
+    Offers,
+    Loans,
+    PoolLoans,
+    Requests,
+    /// Offers of a loan token with funds left at a weekly rate
+    OffersAtRate(Address, u32),
+}
+
+impl ActiveIndex {
+    fn len_key(&self) -> DataKey {
+        match self {
+            ActiveIndex::Offers => DataKey::ActiveOffersLen,
+            ActiveIndex::Loans => DataKey::ActiveLoansLen,
+            ActiveIndex::PoolLoans => DataKey::ActivePoolLoansLen,
+            ActiveIndex::Requests => DataKey::ActiveRequestsLen,
+            ActiveIndex::OffersAtRate(token, rate) => {
+                DataKey::OffersAtRateLen(token.clone(), *rate)
+            }
+        }
+    }
+
+    fn page_key(&self, page: u32) -> DataKey {
+        match self {
+            ActiveIndex::Offers => DataKey::ActiveOffersPage(page),
+            ActiveIndex::Loans => DataKey::ActiveLoansPage(page),
+            ActiveIndex::PoolLoans => DataKey::ActivePoolLoansPage(page),
+            ActiveIndex::Requests => DataKey::ActiveRequestsPage(page),
+            ActiveIndex::OffersAtRate(token, rate) => {
+                DataKey::OffersAtRatePage(token.clone(), *rate, page)
+            }
+        }
+    }
+
+    fn position_key(&self, id: u64) -> DataKey {
+        match self {
+            ActiveIndex::Offers => DataKey::ActiveOfferPosition(id),
+            ActiveIndex::Loans => DataKey::ActiveLoanPosition(id),
+            ActiveIndex::PoolLoans => DataKey::ActivePoolLoanPosition(id),
+            ActiveIndex::Requests => DataKey::ActiveRequestPosition(id),
+            // An offer sits at exactly one rate
+            ActiveIndex::OffersAtRate(_, _) => DataKey::OfferRatePosition(id),
+        }
+    }
+}
+
+fn index_len(env: &Env, index: &ActiveIndex) -> u32 {
+    read_persistent(env, &index.len_key()).unwrap_or(0)
+}
+
+fn index_page(env: &Env, index: &ActiveIndex, page: u32) -> Vec<u64> {
+    read_persistent(env, &index.page_key(page)).unwrap_or(Vec::new(env))
+}
+
+fn index_add(env: &Env, index: &ActiveIndex, id: u64) {
+    let position_key = index.position_key(id);
+    if env.storage().persistent().has(&position_key) {
+        return;
+    }
+
+    let len = index_len(env, index);
+    let page_number = len / ACTIVE_INDEX_PAGE_SIZE;
+    let mut page = index_page(env, index, page_number);
+    page.push_back(id);
+
+    write_persistent(env, &index.page_key(page_number), &page);
+    write_persistent(env, &position_key, &len);
+    write_persistent(env, &index.len_key(), &(len + 1));
+}
+
+fn index_remove(env: &Env, index: &ActiveIndex, id: u64) {
+    let position_key = index.position_key(id);
+    let position: u32 = match read_persistent(env, &position_key) {
+        Some(position) => position,
+        None => return,
+    };
+
+    let last = index_len(env, index) - 1;
+    let last_page_number = last / ACTIVE_INDEX_PAGE_SIZE;
+    let mut last_page = index_page(env, index, last_page_number);
+    let last_id = last_page.pop_back().unwrap();
+
+    if last_id != id {
+        // Move the last ID into the removed ID's slot
+        let page_number = position / ACTIVE_INDEX_PAGE_SIZE;
+        let slot = position % ACTIVE_INDEX_PAGE_SIZE;
+        if page_number == last_page_number {
+            last_page.set(slot, last_id);
+        } else {
+            let mut page = index_page(env, index, page_number);
+            page.set(slot, last_id);
+            write_persistent(env, &index.page_key(page_number), &page);
+        }
+        write_persistent(env, &index.position_key(last_id), &position);
+    }
+
+    if last_page.is_empty() {
+        env.storage()
+            .persistent()
+            .remove(&index.page_key(last_page_number));
+    } else {
+        write_persistent(env, &index.page_key(last_page_number), &last_page);
+    }
+    env.storage().persistent().remove(&position_key);
+    write_persistent(env, &index.len_key(), &last);
 }
 
 // ========== Active Offers ==========
 
 pub fn add_active_offer(env: &Env, offer_id: u64) {
-    let mut offers: Vec<u64> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::ActiveOffers)
-        .unwrap_or(Vec::new(env));
-    offers.push_back(offer_id);
-    env.storage().persistent().set(&DataKey::ActiveOffers, &offers);
+    index_add(env, &ActiveIndex::Offers, offer_id);
 }
 
-pub fn get_active_offers(env: &Env) -> Vec<u64> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::ActiveOffers)
-        .unwrap_or(Vec::new(env))
+pub fn get_active_offers_page(env: &Env, page: u32) -> Vec<u64> {
+    index_page(env, &ActiveIndex::Offers, page)
+}
+
+pub fn get_active_offer_count(env: &Env) -> u32 {
+    index_len(env, &ActiveIndex::Offers)
 }
 
 pub fn remove_active_offer(env: &Env, offer_id: u64) {
-    let mut offers: Vec<u64> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::ActiveOffers)
-        .unwrap_or(Vec::new(env));
-
-    let mut new_offers = Vec::new(env);
-    for i in 0..offers.len() {
-        let id = offers.get(i).unwrap();
-        if id != offer_id {
-            new_offers.push_back(id);
-        }
+    index_remove(env, &ActiveIndex::Offers, offer_id);
+}
+
+// ========== Offers By Rate ==========
+//
+// Offers with funds left to lend, grouped by loan token and weekly rate. The
+// rates of each token are kept in ascending order, so the cheapest offers can be
+// walked without reading the active index. Each rate keeps its offers in a paged
+// index of its own, so a popular rate costs no more to update than a rare one.
+
+/// Weekly rates at which `loan_token` has offers with funds left, lowest first
+/// (at most `MAX_OFFER_RATES`)
+pub fn get_offer_rates(env: &Env, loan_token: &Address) -> Vec<u32> {
+    read_persistent(env, &DataKey::OfferRates(loan_token.clone())).unwrap_or(Vec::new(env))
+}
+
+/// One page of the IDs of `loan_token`'s offers with funds left at `rate`
+/// Every page but the last holds `ACTIVE_INDEX_PAGE_SIZE` IDs
+pub fn get_offers_at_rate_page(env: &Env, loan_token: &Address, rate: u32, page: u32) -> Vec<u64> {
+    index_page(env, &ActiveIndex::OffersAtRate(loan_token.clone(), rate), page)
+}
+
+pub fn add_offer_by_rate(env: &Env, offer: &LendingOffer) {
+    let rate = offer.weekly_interest_rate;
+    let mut rates = get_offer_rates(env, &offer.loan_token);
+    if let Err(position) = rates.binary_search(rate) {
+        rates.insert(position, rate);
+        write_persistent(env, &DataKey::OfferRates(offer.loan_token.clone()), &rates);
     }
 
-    env.storage()
-        .persistent()
-        .set(&DataKey::ActiveOffers, &new_offers);
+    let index = ActiveIndex::OffersAtRate(offer.loan_token.clone(), rate);
+    index_add(env, &index, offer.offer_id);
+}
+
+/// Drop an offer that has nothing left to lend; its rate goes with its last offer
+pub fn remove_offer_by_rate(env: &Env, offer: &LendingOffer) {
+    let rate = offer.weekly_interest_rate;
+    let index = ActiveIndex::OffersAtRate(offer.loan_token.clone(), rate);
+    index_remove(env, &index, offer.offer_id);
+    if index_len(env, &index) > 0 {
+        return;
+    }
+
+    env.storage().persistent().remove(&index.len_key());
+    let mut rates = get_offer_rates(env, &offer.loan_token);
+    if let Ok(position) = rates.binary_search(rate) {
+        rates.remove(position);
+        write_persistent(env, &DataKey::OfferRates(offer.loan_token.clone()), &rates);
+    }
 }
 
 // ========== Active Loans ==========
 
 pub fn add_active_loan(env: &Env, loan_id: u64) {
-    let mut loans: Vec<u64> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::ActiveLoans)
-        .unwrap_or(Vec::new(env));
-    loans.push_back(loan_id);
-    env.storage().persistent().set(&DataKey::ActiveLoans, &loans);
+    index_add(env, &ActiveIndex::Loans, loan_id);
 }
 
-pub fn get_active_loans(env: &Env) -> Vec<u64> {
-    env.storage()
-        .persistent()
-        .get(&DataKey::ActiveLoans)
-        .unwrap_or(Vec::new(env))
+pub fn get_active_loans_page(env: &Env, page: u32) -> Vec<u64> {
+    index_page(env, &ActiveIndex::Loans, page)
+}
+
+pub fn get_active_loan_count(env: &Env) -> u32 {
+    index_len(env, &ActiveIndex::Loans)
 }
 
 pub fn remove_active_loan(env: &Env, loan_id: u64) {
-    let mut loans: Vec<u64> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::ActiveLoans)
-        .unwrap_or(Vec::new(env));
+    index_remove(env, &ActiveIndex::Loans, loan_id);
+}
 
-    let mut new_loans = Vec::new(env);
-    for i in 0..loans.len() {
-        let id = loans.get(i).unwrap();
-        if id != loan_id {
-            new_loans.push_back(id);
-        }
+// ========== Active Pool Loans ==========
+
+pub fn add_active_pool_loan(env: &Env, loan_id: u64) {
+    index_add(env, &ActiveIndex::PoolLoans, loan_id);
+}
+
+pub fn get_active_pool_loans_page(env: &Env, page: u32) -> Vec<u64> {
+    index_page(env, &ActiveIndex::PoolLoans, page)
+}
+
+pub fn get_active_pool_loan_count(env: &Env) -> u32 {
+    index_len(env, &ActiveIndex::PoolLoans)
+}
+
+pub fn remove_active_pool_loan(env: &Env, loan_id: u64) {
+    index_remove(env, &ActiveIndex::PoolLoans, loan_id);
+}
+
+// ========== Active Borrow Requests ==========
+
+pub fn add_active_request(env: &Env, request_id: u64) {
+    index_add(env, &ActiveIndex::Requests, request_id);
+}
+
+pub fn get_active_requests_page(env: &Env, page: u32) -> Vec<u64> {
+    index_page(env, &ActiveIndex::Requests, page)
+}
+
+pub fn remove_active_request(env: &Env, request_id: u64) {
+    index_remove(env, &ActiveIndex::Requests, request_id);
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::LendingMarket;
+    use soroban_sdk::testutils::Address as _;
+
+    fn register(env: &Env) -> Address {
+        env.mock_all_auths();
+        let admin = Address::generate(env);
+        let token = Address::generate(env);
+        env.register(LendingMarket, (&admin, &token, &token, &token, 3000_u32, &token))
     }
 
-    env.storage().persistent().set(&DataKey::ActiveLoans, &new_loans);
+    #[test]
+    fn test_active_index_with_thousands_of_loans() {
+        let env = Env::default();
+        env.cost_estimate().budget().reset_unlimited();
+        let contract_id = register(&env);
+        // Apply `f` to the IDs in small batches, one invocation per batch as it would on-chain
+        let for_each = |ids: std::vec::Vec<u64>, f: fn(&Env, u64)| {
+            for batch in ids.chunks(10) {
+                env.as_contract(&contract_id, || batch.iter().for_each(|id| f(&env, *id)));
+            }
+        };
+        let count = || env.as_contract(&contract_id, || get_active_loan_count(&env));
+        let collect = || {
+            let mut ids = std::vec::Vec::new();
+            for page in 0..=count() / ACTIVE_INDEX_PAGE_SIZE {
+                let page_ids = env.as_contract(&contract_id, || get_active_loans_page(&env, page));
+                assert!(page_ids.len() <= ACTIVE_INDEX_PAGE_SIZE);
+                ids.extend(page_ids.iter());
+            }
+            ids.sort();
+            ids
+        };
+
+        for_each((1..=2000).collect(), add_active_loan);
+        // Adding an indexed ID again is a no-op
+        for_each(std::vec![1, 2000], add_active_loan);
+        assert_eq!(count(), 2000);
+        assert_eq!(collect(), (1..=2000).collect::<std::vec::Vec<u64>>());
+
+        // Remove every even loan, including the one at the tail
+        for_each((2..=2000).step_by(2).collect(), remove_active_loan);
+        // Removing an unknown ID is a no-op
+        for_each(std::vec![2, 5000], remove_active_loan);
+        assert_eq!(count(), 1000);
+        assert_eq!(collect(), (1..=2000).step_by(2).collect::<std::vec::Vec<u64>>());
+
+        for_each((1..=2000).step_by(2).collect(), remove_active_loan);
+        assert_eq!(count(), 0);
+        env.as_contract(&contract_id, || {
+            assert!(get_active_loans_page(&env, 0).is_empty());
+        });
+    }
 }
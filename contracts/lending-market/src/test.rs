@@ -0,0 +1,3939 @@
+#![cfg(test)]
+// This lets use reference types in the std library for testing
+extern crate std;
+
+use crate::error::Error;
+use crate::events::{
+    AuctionBidData, CollateralData, DefaultClaimData, LiquidationData, LoanOpenedData, LoanRepaidData,
+    OfferCreatedData, OfferFundsData, PoolLiquidationData, PoolLoanOpenedData, PoolLoanRepaidData,
+    PoolSharesData,
+};
+use crate::migration::SCHEMA_VERSION;
+use crate::reflector::{Asset, PriceData};
+use crate::types::{
+    AuctionConfig, BorrowFill, CollateralConfig, DataKey, FeeConfig, InterestMode, LendingOffer,
+    Loan, LoanAssetConfig, OfferFilter, OracleSource, PauseState, PoolRates, QueuedAction,
+    RateModel, RiskConfig, Role, SortOption, TimelockAction, TtlConfig, DAY_IN_LEDGERS,
+    DEFAULT_TTL_EXTEND_TO,
+};
+use crate::{LendingMarket, LendingMarketClient};
+use loan_position_nft::{LoanPositionNft, LoanPositionNftClient};
+use mock_amm::{MockAmm, MockAmmClient};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{
+        storage::{Instance as _, Persistent as _},
+        Address as _, Events as _, Ledger as _, MockAuth, MockAuthInvoke, Register,
+    },
+    symbol_short, vec, Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+/// The market as released, and the next build that stores its loans and offers one
+/// schema version ahead; `stellar contract build` produces both before the tests run
+mod release_build {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/lending_market.wasm");
+}
+mod next_build {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/lending_market_next.wasm"
+    );
+}
+
+/// 0.15 USDC per XLM with 14 decimals
+const XLM_PRICE: i128 = 15_000_000_000_000;
+
+#[contracttype]
+enum TestKey {
+    Price,
+    AssetPrice(Asset),
+    Ticks(Asset),
+    UpdatedAt,
+    Down,
+    Balance(Address),
+}
+
+/// Minimal Reflector stand-in whose price can be moved by the test
+#[contract]
+pub struct TestOracle;
+
+#[contractimpl]
+impl TestOracle {
+    pub fn set_price(env: Env, price: i128) {
+        env.storage().instance().set(&TestKey::Price, &price);
+    }
+
+    /// Price `asset` separately from the default (XLM) price
+    pub fn set_asset_price(env: Env, asset: Asset, price: i128) {
+        env.storage()
+            .instance()
+            .set(&TestKey::AssetPrice(asset), &price);
+    }
+
+    /// Record one tick for `asset` on top of its steady price, e.g. a spike
+    pub fn push_price(env: Env, asset: Asset, price: i128) {
+        let key = TestKey::Ticks(asset);
+        let mut ticks: Vec<i128> = env
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        ticks.push_back(price);
+        env.storage().instance().set(&key, &ticks);
+    }
+
+    /// Freeze the feed's record timestamp, so it goes stale as the ledger moves on
+    pub fn set_updated_at(env: Env, timestamp: u64) {
+        env.storage().instance().set(&TestKey::UpdatedAt, &timestamp);
+    }
+
+    /// Make every query trap, as an archived or broken oracle would
+    pub fn set_down(env: Env, down: bool) {
+        env.storage().instance().set(&TestKey::Down, &down);
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        Self::ensure_up(&env);
+        14
+    }
+
+    pub fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        Self::ensure_up(&env);
+        Some(PriceData {
+            price: Self::price_of(&env, asset),
+            timestamp: Self::updated_at(&env),
+        })
+    }
+
+    pub fn x_last_price(env: Env, base_asset: Asset, quote_asset: Asset) -> Option<PriceData> {
+        let price = Self::price_of(&env, base_asset) * 10_i128.pow(Self::decimals(env.clone()))
+            / Self::price_of(&env, quote_asset);
+
+        Some(PriceData {
+            price,
+            timestamp: Self::updated_at(&env),
+        })
+    }
+
+    pub fn x_twap(env: Env, base_asset: Asset, quote_asset: Asset, records: u32) -> Option<i128> {
+        Some(
+            Self::twap_of(&env, base_asset, records) * 10_i128.pow(Self::decimals(env.clone()))
+                / Self::twap_of(&env, quote_asset, records),
+        )
+    }
+}
+
+impl TestOracle {
+    fn ensure_up(env: &Env) {
+        if env.storage().instance().get(&TestKey::Down).unwrap_or(false) {
+            panic!("oracle is down");
+        }
+    }
+
+    fn updated_at(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&TestKey::UpdatedAt)
+            .unwrap_or(env.ledger().timestamp())
+    }
+
+    fn price_of(env: &Env, asset: Asset) -> i128 {
+        Self::twap_of(env, asset, 1)
+    }
+
+    /// Average of the last `records` ticks, padded with the steady price
+    fn twap_of(env: &Env, asset: Asset, records: u32) -> i128 {
+        let storage = env.storage().instance();
+        let steady = storage
+            .get(&TestKey::AssetPrice(asset.clone()))
+            .or_else(|| storage.get(&TestKey::Price))
+            .unwrap_or(XLM_PRICE);
+        let ticks: Vec<i128> = storage
+            .get(&TestKey::Ticks(asset))
+            .unwrap_or(Vec::new(env));
+
+        let sum: i128 = (0..records)
+            .map(|i| match ticks.len().checked_sub(i + 1) {
+                Some(index) => ticks.get_unchecked(index),
+                None => steady,
+            })
+            .sum();
+        sum / records as i128
+    }
+}
+
+/// Minimal token that publishes no events, so event assertions only see the market
+#[contract]
+pub struct TestToken;
+
+#[contractimpl]
+impl TestToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&TestKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&TestKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        if from_balance < amount {
+            panic!("insufficient balance");
+        }
+        env.storage()
+            .persistent()
+            .set(&TestKey::Balance(from), &(from_balance - amount));
+        Self::mint(env, to, amount);
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    lender: Address,
+    borrower: Address,
+    usdc: TestTokenClient<'a>,
+    xlm: TestTokenClient<'a>,
+    oracle: TestOracleClient<'a>,
+    amm: MockAmmClient<'a>,
+    nft: LoanPositionNftClient<'a>,
+    client: LendingMarketClient<'a>,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    setup_with(LendingMarket)
+}
+
+/// Set up the market registered as `market`, natively or from a built wasm
+fn setup_with<'a>(market: impl Register) -> Setup<'a> {
+    let env = Env::default();
+    // Liquidation swaps authorize the DEX's token pull as a sub-invocation
+    env.mock_all_auths_allowing_non_root_auth();
+    env.ledger().set_timestamp(1_000_000);
+
+    let admin = Address::generate(&env);
+    let lender = Address::generate(&env);
+    let borrower = Address::generate(&env);
+
+    let usdc = TestTokenClient::new(&env, &env.register(TestToken, ()));
+    let xlm = TestTokenClient::new(&env, &env.register(TestToken, ()));
+    let oracle = TestOracleClient::new(&env, &env.register(TestOracle, ()));
+    // USDC is the $1 unit every other price is quoted against
+    oracle.set_asset_price(&Asset::Other(symbol_short!("USDC")), &100_000_000_000_000);
+
+    let amm = MockAmmClient::new(
+        &env,
+        &env.register(MockAmm, (&usdc.address, &xlm.address)),
+    );
+
+    let contract_id = env.register(
+        market,
+        (&admin, &usdc.address, &xlm.address, &oracle.address, 3000_u32, &amm.address),
+    );
+    let client = LendingMarketClient::new(&env, &contract_id);
+
+    let nft = LoanPositionNftClient::new(&env, &env.register(LoanPositionNft, (&contract_id,)));
+    client.set_position_nft(&admin, &nft.address);
+
+    usdc.mint(&lender, &1_000_0000000);
+    xlm.mint(&borrower, &10_000_0000000);
+
+    Setup {
+        env,
+        admin,
+        lender,
+        borrower,
+        usdc,
+        xlm,
+        oracle,
+        amm,
+        nft,
+        client,
+    }
+}
+
+/// Seed the AMM pool with 1M XLM at `price` (USDC per XLM with 14 decimals)
+fn seed_amm(s: &Setup, price: i128) {
+    let xlm_reserve = 1_000_000_0000000;
+    let usdc_reserve = xlm_reserve * price / 10_i128.pow(14);
+    s.usdc.mint(&s.admin, &usdc_reserve);
+    s.xlm.mint(&s.admin, &xlm_reserve);
+    s.amm.add_liquidity(&s.admin, &usdc_reserve, &xlm_reserve);
+}
+
+/// 100 USDC at 5% weekly, 200% min collateral, 125% liquidation threshold, 4 weeks
+fn create_default_offer(s: &Setup) -> u64 {
+    s.client.create_offer(
+        &s.lender,
+        &s.usdc.address,
+        &100_0000000,
+        &500,
+        &InterestMode::Simple,
+        &20000,
+        &12500,
+        &4,
+        &vec![&s.env, s.xlm.address.clone()],
+    )
+}
+
+fn event<T, D>(s: &Setup, topics: T, data: D) -> (Address, Vec<Val>, Val)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    (
+        s.client.address.clone(),
+        topics.into_val(&s.env),
+        data.into_val(&s.env),
+    )
+}
+
+fn name(s: &Setup, name: &str) -> Symbol {
+    Symbol::new(&s.env, name)
+}
+
+/// Queue `action`, wait out the timelock delay and run it
+fn run_timelocked(s: &Setup, caller: &Address, action: TimelockAction) -> u64 {
+    let action_id = s.client.queue_action(caller, &action);
+    let eta = s.client.get_queued_action(&action_id).eta;
+    s.env.ledger().set_timestamp(eta);
+    s.client.execute_action(caller, &action_id);
+    action_id
+}
+
+#[test]
+fn create_offer_emits_event() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "offer_created"), offer_id, s.lender.clone()),
+                OfferCreatedData {
+                    loan_token: s.usdc.address.clone(),
+                    amount: 100_0000000,
+                    weekly_interest_rate: 500,
+                    interest_mode: InterestMode::Simple,
+                    min_collateral_ratio: 20000,
+                    liquidation_threshold: 12500,
+                    max_duration_weeks: 4,
+                    collateral_assets: vec![&s.env, s.xlm.address.clone()],
+                },
+            ),
+        ]
+    );
+}
+
+#[test]
+fn withdraw_and_cancel_offer_emit_events() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+
+    s.client.withdraw_from_offer(&s.lender, &offer_id, &30_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "offer_withdrawn"), offer_id, s.lender.clone()),
+                OfferFundsData {
+                    amount: 30_0000000,
+                    remaining: 70_0000000,
+                },
+            ),
+        ]
+    );
+
+    s.client.cancel_offer(&s.lender, &offer_id);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "offer_cancelled"), offer_id, s.lender.clone()),
+                OfferFundsData {
+                    amount: 70_0000000,
+                    remaining: 0,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.usdc.balance(&s.lender), 1_000_0000000);
+}
+
+#[test]
+fn borrow_and_repay_emit_events() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+
+    // 1000 XLM at $0.15 = $150 of collateral, 200% ratio allows $75
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            (
+                s.nft.address.clone(),
+                (symbol_short!("mint"), s.lender.clone()).into_val(&s.env),
+                (loan_id as u32).into_val(&s.env),
+            ),
+            event(
+                &s,
+                (name(&s, "loan_opened"), loan_id, offer_id, s.borrower.clone()),
+                LoanOpenedData {
+                    lender: s.lender.clone(),
+                    collateral_token: s.xlm.address.clone(),
+                    collateral_amount: 1_000_0000000,
+                    loan_token: s.usdc.address.clone(),
+                    borrowed_amount: 75_0000000,
+                    interest_rate: 500,
+                    due_time: 1_000_000 + 4 * 604800,
+                },
+            ),
+        ]
+    );
+
+    // One week of 5% interest on 75 USDC = 3.75 USDC
+    s.env.ledger().set_timestamp(1_000_000 + 604800);
+    s.usdc.mint(&s.borrower, &10_0000000);
+
+    s.client.repay(&s.borrower, &loan_id, &2_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "loan_repaid"), loan_id, s.borrower.clone()),
+                LoanRepaidData {
+                    amount: 2_0000000,
+                    interest_paid: 2_0000000,
+                    principal_paid: 0,
+                    protocol_fee: 0,
+                    remaining_principal: 75_0000000,
+                    collateral_returned: 0,
+                },
+            ),
+        ]
+    );
+
+    s.client.repay(&s.borrower, &loan_id, &76_7500000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "loan_repaid"), loan_id, s.borrower.clone()),
+                LoanRepaidData {
+                    amount: 76_7500000,
+                    interest_paid: 1_7500000,
+                    principal_paid: 75_0000000,
+                    protocol_fee: 0,
+                    remaining_principal: 0,
+                    collateral_returned: 1_000_0000000,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.xlm.balance(&s.borrower), 10_000_0000000);
+}
+
+#[test]
+fn borrow_best_splits_across_the_cheapest_eligible_offers() {
+    let s = setup();
+    let offer = |amount: i128, rate: u32, ratio: u32, weeks: u32| {
+        s.client.create_offer(
+            &s.lender,
+            &s.usdc.address,
+            &amount,
+            &rate,
+            &InterestMode::Simple,
+            &ratio,
+            &12500,
+            &weeks,
+            &vec![&s.env, s.xlm.address.clone()],
+        )
+    };
+    let cheap = offer(30_0000000, 300, 20000, 4);
+    let dear = offer(40_0000000, 500, 20000, 4);
+    // Passed over: too short, too strict on collateral, above the borrower's rate
+    offer(100_0000000, 200, 20000, 1);
+    offer(100_0000000, 400, 40000, 4);
+    offer(100_0000000, 900, 20000, 4);
+
+    // 1000 XLM at $0.15 = $150 of collateral for $60, a 250% ratio
+    let quote = s.client.quote_borrow(
+        &s.borrower,
+        &s.usdc.address,
+        &s.xlm.address,
+        &1_000_0000000,
+        &60_0000000,
+        &600,
+        &4,
+    );
+    assert_eq!(
+        quote,
+        vec![
+            &s.env,
+            BorrowFill {
+                offer_id: cheap,
+                amount: 30_0000000,
+                collateral_amount: 500_0000000,
+                interest_rate: 300,
+            },
+            BorrowFill {
+                offer_id: dear,
+                amount: 30_0000000,
+                collateral_amount: 500_0000000,
+                interest_rate: 500,
+            },
+        ]
+    );
+    assert_eq!(
+        s.client.try_quote_borrow(
+            &s.borrower,
+            &s.usdc.address,
+            &s.xlm.address,
+            &2_000_0000000,
+            &150_0000000,
+            &600,
+            &4,
+        ),
+        Err(Ok(Error::InsufficientOfferFunds))
+    );
+    assert_eq!(
+        s.client.try_quote_borrow(
+            &s.borrower,
+            &s.usdc.address,
+            &s.xlm.address,
+            &1_000_0000000,
+            &60_0000000,
+            &100,
+            &4,
+        ),
+        Err(Ok(Error::NoOffersAvailable))
+    );
+
+    let loan_ids = s.client.borrow_best(
+        &s.borrower,
+        &s.usdc.address,
+        &s.xlm.address,
+        &1_000_0000000,
+        &60_0000000,
+        &600,
+        &4,
+    );
+    let mut expected = vec![&s.env];
+    for (loan_id, fill) in loan_ids.iter().zip(quote.iter()) {
+        expected.push_back((
+            s.nft.address.clone(),
+            (symbol_short!("mint"), s.lender.clone()).into_val(&s.env),
+            (loan_id as u32).into_val(&s.env),
+        ));
+        expected.push_back(event(
+            &s,
+            (name(&s, "loan_opened"), loan_id, fill.offer_id, s.borrower.clone()),
+            LoanOpenedData {
+                lender: s.lender.clone(),
+                collateral_token: s.xlm.address.clone(),
+                collateral_amount: fill.collateral_amount,
+                loan_token: s.usdc.address.clone(),
+                borrowed_amount: fill.amount,
+                interest_rate: fill.interest_rate,
+                due_time: 1_000_000 + 4 * 604800,
+            },
+        ));
+    }
+    assert_eq!(s.env.events().all(), expected);
+    assert_eq!(loan_ids.len(), 2);
+
+    let first = s.client.get_loan(&loan_ids.get(0).unwrap());
+    assert_eq!((first.offer_id, first.interest_rate), (cheap, 300));
+    assert_eq!(s.client.get_offer(&cheap).amount, 0);
+    assert_eq!(s.client.get_offer(&dear).amount, 10_0000000);
+    assert_eq!(s.usdc.balance(&s.borrower), 60_0000000);
+    assert_eq!(s.xlm.balance(&s.borrower), 9_000_0000000);
+}
+
+#[test]
+fn borrow_best_walks_many_offers_cheapest_first() {
+    let s = setup();
+    // Later offers are cheaper, so the cheapest sit at the back of the active index
+    create_offers(&s, 120, |i| 1000 - i);
+    // A drained offer leaves the rate index and takes no part in the walk
+    let drained = s.client.get_offer(&120);
+    s.client.withdraw_from_offer(&drained.lender, &120, &10_0000000);
+
+    // A sixth loan would be one more than a call may open
+    assert_eq!(
+        s.client.try_quote_borrow(
+            &s.borrower,
+            &s.usdc.address,
+            &s.xlm.address,
+            &2_000_0000000,
+            &60_0000000,
+            &1000,
+            &4,
+        ),
+        Err(Ok(Error::TooManyLoans))
+    );
+
+    // A call across five offers fits the default network limits
+    s.env.cost_estimate().budget().reset_default();
+    let loan_ids = s.client.borrow_best(
+        &s.borrower,
+        &s.usdc.address,
+        &s.xlm.address,
+        &1_000_0000000,
+        &50_0000000,
+        &1000,
+        &4,
+    );
+    let offer_ids: std::vec::Vec<u64> = loan_ids
+        .iter()
+        .map(|loan_id| s.client.get_loan(&loan_id).offer_id)
+        .collect();
+    assert_eq!(offer_ids, [119, 118, 117, 116, 115]);
+    assert_eq!(s.usdc.balance(&s.borrower), 50_0000000);
+
+    // The offers it emptied are gone from the walk too
+    let quote = s.client.quote_borrow(
+        &s.borrower,
+        &s.usdc.address,
+        &s.xlm.address,
+        &1_000_0000000,
+        &10_0000000,
+        &1000,
+        &4,
+    );
+    assert_eq!(quote.get(0).unwrap().offer_id, 114);
+    assert_eq!(quote.len(), 1);
+}
+
+#[test]
+fn borrow_requests_escrow_collateral_until_filled_or_cancelled() {
+    let s = setup();
+
+    // 1000 XLM at $0.15 = $150 of collateral, XLM's 200% ratio allows $75
+    assert_eq!(
+        s.client.try_create_borrow_request(
+            &s.borrower,
+            &s.usdc.address,
+            &s.xlm.address,
+            &1_000_0000000,
+            &80_0000000,
+            &500,
+            &4,
+        ),
+        Err(Ok(Error::InsufficientCollateral))
+    );
+    let request_id = s.client.create_borrow_request(
+        &s.borrower,
+        &s.usdc.address,
+        &s.xlm.address,
+        &1_000_0000000,
+        &50_0000000,
+        &500,
+        &4,
+    );
+    let events = s.env.events().all();
+    assert_eq!(
+        events,
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "borrow_request_created"), request_id, s.borrower.clone()),
+                s.client.get_borrow_request(&request_id),
+            ),
+        ]
+    );
+    assert_eq!(s.xlm.balance(&s.borrower), 9_000_0000000);
+    assert_eq!(s.client.get_active_borrow_requests_page(&0), vec![&s.env, request_id]);
+    assert_eq!(s.client.get_user_borrow_requests(&s.borrower), vec![&s.env, request_id]);
+
+    // Lenders fill at or below the borrower's rate
+    assert_eq!(
+        s.client.try_fill_borrow_request(&s.lender, &request_id, &600),
+        Err(Ok(Error::InvalidInterestRate))
+    );
+    assert_eq!(
+        s.client.try_cancel_borrow_request(&s.lender, &request_id),
+        Err(Ok(Error::OnlyBorrower))
+    );
+    let loan_id = s.client.fill_borrow_request(&s.lender, &request_id, &400);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            (
+                s.nft.address.clone(),
+                (symbol_short!("mint"), s.lender.clone()).into_val(&s.env),
+                (loan_id as u32).into_val(&s.env),
+            ),
+            event(
+                &s,
+                (name(&s, "loan_opened"), loan_id, 0_u64, s.borrower.clone()),
+                LoanOpenedData {
+                    lender: s.lender.clone(),
+                    collateral_token: s.xlm.address.clone(),
+                    collateral_amount: 1_000_0000000,
+                    loan_token: s.usdc.address.clone(),
+                    borrowed_amount: 50_0000000,
+                    interest_rate: 400,
+                    due_time: 1_000_000 + 4 * 604800,
+                },
+            ),
+            event(
+                &s,
+                (name(&s, "borrow_request_filled"), request_id, s.lender.clone()),
+                loan_id,
+            ),
+        ]
+    );
+    let loan = s.client.get_loan(&loan_id);
+    assert_eq!(loan.offer_id, 0);
+    assert_eq!(loan.interest_mode, InterestMode::Simple);
+    assert_eq!(s.usdc.balance(&s.borrower), 50_0000000);
+    assert_eq!(s.usdc.balance(&s.lender), 950_0000000);
+    assert!(!s.client.get_borrow_request(&request_id).is_active);
+    assert_eq!(s.client.get_active_borrow_requests_page(&0), vec![&s.env]);
+    assert_eq!(s.client.get_user_borrow_requests(&s.borrower), vec![&s.env]);
+    assert_eq!(
+        s.client.try_fill_borrow_request(&s.lender, &request_id, &400),
+        Err(Ok(Error::BorrowRequestNotActive))
+    );
+
+    // The loan closes like any other and releases the escrowed collateral
+    s.client.repay(&s.borrower, &loan_id, &50_0000000);
+    assert!(!s.client.get_loan(&loan_id).is_active);
+    assert_eq!(s.xlm.balance(&s.borrower), 10_000_0000000);
+
+    // Cancelling returns the collateral
+    let request_id = s.client.create_borrow_request(
+        &s.borrower,
+        &s.usdc.address,
+        &s.xlm.address,
+        &500_0000000,
+        &20_0000000,
+        &500,
+        &2,
+    );
+    s.client.cancel_borrow_request(&s.borrower, &request_id);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "borrow_request_cancelled"), request_id, s.borrower.clone()),
+                500_0000000_i128,
+            ),
+        ]
+    );
+    assert_eq!(s.xlm.balance(&s.borrower), 10_000_0000000);
+    assert_eq!(
+        s.client.try_cancel_borrow_request(&s.borrower, &request_id),
+        Err(Ok(Error::BorrowRequestNotActive))
+    );
+}
+
+#[test]
+fn collateral_changes_emit_events() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &4);
+
+    s.client.add_collateral(&s.borrower, &loan_id, &500_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "collateral_added"), loan_id, s.borrower.clone()),
+                CollateralData {
+                    amount: 500_0000000,
+                    collateral_amount: 1_500_0000000,
+                },
+            ),
+        ]
+    );
+
+    s.client.withdraw_collateral(&s.borrower, &loan_id, &200_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "collateral_withdrawn"), loan_id, s.borrower.clone()),
+                CollateralData {
+                    amount: 200_0000000,
+                    collateral_amount: 1_300_0000000,
+                },
+            ),
+        ]
+    );
+}
+
+#[test]
+fn liquidate_emits_event() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+
+    // XLM drops to $0.09: $90 of collateral against $75 of debt = 120%
+    s.oracle.set_price(&9_000_000_000_000);
+    seed_amm(&s, 9_000_000_000_000);
+    let liquidator = Address::generate(&s.env);
+
+    // Asking for more than the 50% close factor repays half the debt
+    s.client.liquidate(&liquidator, &loan_id, &100_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "loan_liquidated"), loan_id, liquidator.clone()),
+                LiquidationData {
+                    debt_repaid: 37_5000000,
+                    interest_paid: 0,
+                    principal_paid: 37_5000000,
+                    collateral_seized: 441_9191920,
+                    swap_proceeds: 39_6359457,
+                    liquidator_bonus: 1_8750000,
+                    protocol_fee: 0,
+                    borrower_refund: 2609457,
+                    collateral_returned: 0,
+                    remaining_debt: 37_5000000,
+                },
+            ),
+        ]
+    );
+}
+
+#[test]
+fn liquidation_sells_collateral_on_dex() {
+    let s = setup();
+    run_timelocked(&s, &s.admin, TimelockAction::SetCloseFactor(10000));
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    s.oracle.set_price(&9_000_000_000_000);
+    seed_amm(&s, 9_000_000_000_000);
+    let liquidator = Address::generate(&s.env);
+    let lender_before = s.usdc.balance(&s.lender);
+    let borrower_usdc_before = s.usdc.balance(&s.borrower);
+    let borrower_xlm_before = s.xlm.balance(&s.borrower);
+    let (usdc_reserve, xlm_reserve) = s.amm.get_reserves();
+
+    // Enforce real auth: only the liquidator signs, the market authorizes its own swap
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &liquidator,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "liquidate",
+                args: (&liquidator, loan_id, 75_0000000_i128).into_val(&s.env),
+                sub_invokes: &[],
+            },
+        }])
+        .liquidate(&liquidator, &loan_id, &75_0000000);
+
+    // Liquidator needs no USDC up front and keeps only the bonus
+    assert_eq!(s.usdc.balance(&liquidator), 3_7500000);
+    assert_eq!(s.xlm.balance(&liquidator), 0);
+    assert_eq!(s.usdc.balance(&s.lender) - lender_before, 75_0000000);
+    assert_eq!(s.usdc.balance(&s.borrower) - borrower_usdc_before, 4869955);
+    assert_eq!(s.xlm.balance(&s.borrower) - borrower_xlm_before, 116_1616161);
+    assert_eq!(
+        s.amm.get_reserves(),
+        (usdc_reserve - 79_2369955, xlm_reserve + 883_8383839)
+    );
+
+    // Nothing is left behind in the market
+    assert_eq!(s.usdc.balance(&s.client.address), 25_0000000);
+    assert_eq!(s.xlm.balance(&s.client.address), 0);
+    assert!(!s.client.get_loan(&loan_id).is_active);
+}
+
+#[test]
+fn partial_liquidation_keeps_loan_open() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    s.oracle.set_price(&9_000_000_000_000);
+    seed_amm(&s, 9_000_000_000_000);
+    let liquidator = Address::generate(&s.env);
+
+    // A liquidator may cover less than the close factor allows
+    s.client.liquidate(&liquidator, &loan_id, &10_0000000);
+    let loan = s.client.get_loan(&loan_id);
+    assert!(loan.is_active);
+    assert_eq!(loan.borrowed_amount, 65_0000000);
+    assert!(s.client.is_liquidatable(&loan_id));
+
+    // Covering the close factor restores the loan's health
+    s.client.liquidate(&liquidator, &loan_id, &i128::MAX);
+    let loan = s.client.get_loan(&loan_id);
+    assert!(loan.is_active);
+    assert_eq!(loan.borrowed_amount, 32_5000000);
+    assert_eq!(loan.accumulated_interest, 0);
+    assert_eq!(s.xlm.balance(&s.client.address), loan.collateral_amount);
+    assert!(s.client.get_loan_health(&loan_id).collateralization_ratio > 12500);
+    assert_eq!(s.client.get_offer_loans(&offer_id), vec![&s.env, loan_id]);
+
+    assert_eq!(
+        s.client.try_liquidate(&liquidator, &loan_id, &10_0000000),
+        Err(Ok(Error::NotLiquidatable))
+    );
+
+    // The borrower can still repay the rest and get the remaining collateral back
+    let collateral = loan.collateral_amount;
+    let borrower_xlm = s.xlm.balance(&s.borrower);
+    s.client.repay(&s.borrower, &loan_id, &32_5000000);
+    assert_eq!(s.xlm.balance(&s.borrower) - borrower_xlm, collateral);
+}
+
+#[test]
+fn liquidation_rejects_non_positive_amount() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    s.oracle.set_price(&9_000_000_000_000);
+    seed_amm(&s, 9_000_000_000_000);
+    let liquidator = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_liquidate(&liquidator, &loan_id, &0),
+        Err(Ok(Error::InvalidRepayAmount))
+    );
+}
+
+#[test]
+fn set_close_factor_validates_and_emits_event() {
+    let s = setup();
+    assert_eq!(s.client.get_close_factor(), 5000);
+
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetCloseFactor(7500));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "close_factor_updated"), s.admin.clone()), 7500_u32),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_close_factor(), 7500);
+
+    for close_factor in [0, 10001] {
+        assert_eq!(
+            s.client.try_queue_action(&s.admin, &TimelockAction::SetCloseFactor(close_factor)),
+            Err(Ok(Error::InvalidCloseFactor))
+        );
+    }
+}
+
+#[test]
+fn liquidation_fails_when_dex_price_exceeds_slippage() {
+    let s = setup();
+    // Queue a wider tolerance up front so the loan accrues nothing while it waits
+    let action_id = s.client.queue_action(&s.admin, &TimelockAction::SetMaxSlippage(600));
+    s.env
+        .ledger()
+        .set_timestamp(s.client.get_queued_action(&action_id).eta);
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    s.oracle.set_price(&9_000_000_000_000);
+
+    // The pool trades 5% below the oracle, beyond the default 1% tolerance
+    seed_amm(&s, 8_550_000_000_000);
+    let liquidator = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_liquidate(&liquidator, &loan_id, &75_0000000),
+        Err(Ok(Error::LiquidationSwapFailed))
+    );
+    assert!(s.client.get_loan(&loan_id).is_active);
+
+    // Widening the tolerance lets the liquidation through
+    s.client.execute_action(&s.admin, &action_id);
+    s.client.liquidate(&liquidator, &loan_id, &75_0000000);
+    assert_eq!(s.client.get_loan(&loan_id).borrowed_amount, 37_5000000);
+}
+
+#[test]
+fn set_max_slippage_validates_and_emits_event() {
+    let s = setup();
+    assert_eq!(s.client.get_max_slippage(), 100);
+    assert_eq!(s.client.get_dex_router(), s.amm.address);
+
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetMaxSlippage(250));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "slippage_updated"), s.admin.clone()), 250_u32),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_max_slippage(), 250);
+
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &TimelockAction::SetMaxSlippage(2001)),
+        Err(Ok(Error::InvalidSlippage))
+    );
+
+    let router = Address::generate(&s.env);
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetDexRouter(router.clone()));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "dex_router_updated"), s.admin.clone()), router.clone()),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_dex_router(), router);
+}
+
+#[test]
+fn admin_setters_emit_events() {
+    let s = setup();
+    let new_oracle = Address::generate(&s.env);
+
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetMaxInterestRate(2000));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "max_rate_updated"), s.admin.clone()), 2000_u32),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &TimelockAction::SetMaxInterestRate(0)),
+        Err(Ok(Error::InvalidInterestRate))
+    );
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &TimelockAction::SetMaxInterestRate(10001)),
+        Err(Ok(Error::InvalidInterestRate))
+    );
+
+    let action = TimelockAction::SetOracleAddress(new_oracle.clone());
+    let action_id = run_timelocked(&s, &s.admin, action);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "oracle_updated"), s.admin.clone()), new_oracle.clone()),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+
+    s.client.pause_contract(&s.admin);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "pause_updated"), s.admin.clone()),
+                PauseState {
+                    offers: true,
+                    borrows: true,
+                    withdrawals: true,
+                    liquidations: true,
+                    repayments: false,
+                },
+            ),
+        ]
+    );
+
+    s.client.unpause_contract(&s.admin);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "pause_updated"), s.admin.clone()), PauseState::default()),
+        ]
+    );
+}
+
+#[test]
+fn pause_flags_halt_only_their_operations() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &4);
+    let pool_loan_id = pool_loan(&s);
+    assert_eq!(s.client.get_pause_state(), PauseState::default());
+
+    // A full pause still lets borrowers repay and top up their collateral
+    s.client.pause_contract(&s.admin);
+    assert_eq!(
+        s.client.try_create_offer(
+            &s.lender,
+            &s.usdc.address,
+            &100_0000000,
+            &500,
+            &InterestMode::Simple,
+            &20000,
+            &12500,
+            &4,
+            &vec![&s.env, s.xlm.address.clone()],
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(s.client.try_deposit(&s.lender, &10_0000000), Err(Ok(Error::ContractPaused)));
+    assert_eq!(
+        s.client
+            .try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        s.client.try_withdraw_collateral(&s.borrower, &loan_id, &1_0000000),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(s.client.try_redeem(&s.lender, &1_0000000), Err(Ok(Error::ContractPaused)));
+    assert_eq!(
+        s.client.try_liquidate(&s.lender, &loan_id, &10_0000000),
+        Err(Ok(Error::ContractPaused))
+    );
+    s.client.add_collateral(&s.borrower, &loan_id, &100_0000000);
+    s.client.pool_add_collateral(&s.borrower, &pool_loan_id, &100_0000000);
+    s.client.repay(&s.borrower, &loan_id, &10_0000000);
+    s.client.pool_repay(&s.borrower, &pool_loan_id, &10_0000000);
+
+    // Flags can be set one by one, and a full pause keeps the repayment flag
+    let repayments_only = PauseState {
+        repayments: true,
+        ..PauseState::default()
+    };
+    s.client.set_pause_state(&s.admin, &repayments_only);
+    assert_eq!(s.client.get_pause_state(), repayments_only);
+    assert_eq!(
+        s.client.try_repay(&s.borrower, &loan_id, &10_0000000),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        s.client.try_pool_repay(&s.borrower, &pool_loan_id, &10_0000000),
+        Err(Ok(Error::ContractPaused))
+    );
+    s.client.deposit(&s.lender, &10_0000000);
+    s.client.pause_contract(&s.admin);
+    assert!(s.client.get_pause_state().repayments);
+    s.client.unpause_contract(&s.admin);
+    s.client.repay(&s.borrower, &loan_id, &10_0000000);
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_set_pause_state(&stranger, &repayments_only),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn borrow_rejects_invalid_duration() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+
+    assert_eq!(
+        s.client
+            .try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &5),
+        Err(Ok(Error::LoanDurationExceeded))
+    );
+    assert_eq!(
+        s.client
+            .try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn loan_health_reports_time_remaining() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &2);
+
+    let due_time = 1_000_000 + 2 * 604800;
+    assert_eq!(s.client.get_loan(&loan_id).due_time, due_time);
+
+    s.env.ledger().set_timestamp(1_000_000 + 604800);
+    let health = s.client.get_loan_health(&loan_id);
+    assert_eq!(health.due_time, due_time);
+    assert_eq!(health.time_remaining, 604800);
+    assert!(!health.is_overdue);
+
+    s.env.ledger().set_timestamp(due_time + 1);
+    let health = s.client.get_loan_health(&loan_id);
+    assert_eq!(health.time_remaining, 0);
+    assert!(health.is_overdue);
+}
+
+#[test]
+fn lender_claims_collateral_after_due_time() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &1);
+
+    // Not claimable before the due time
+    s.env.ledger().set_timestamp(1_000_000 + 604800);
+    assert_eq!(
+        s.client.try_claim_defaulted_loan(&s.lender, &loan_id),
+        Err(Ok(Error::LoanNotOverdue))
+    );
+
+    // Only the lender can claim
+    s.env.ledger().set_timestamp(1_000_000 + 604800 + 1);
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_claim_defaulted_loan(&stranger, &loan_id),
+        Err(Ok(Error::OnlyLender))
+    );
+
+    // Two weeks overdue: 5% weekly on 50 USDC accrues 2.5 USDC per week
+    s.env.ledger().set_timestamp(1_000_000 + 2 * 604800);
+    s.client.claim_defaulted_loan(&s.lender, &loan_id);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "loan_defaulted"), loan_id, s.lender.clone()),
+                DefaultClaimData {
+                    debt_outstanding: 55_0000000,
+                    collateral_seized: 1_000_0000000,
+                },
+            ),
+        ]
+    );
+
+    assert_eq!(s.xlm.balance(&s.lender), 1_000_0000000);
+    let loan = s.client.get_loan(&loan_id);
+    assert!(!loan.is_active);
+    assert_eq!(loan.collateral_amount, 0);
+    assert_eq!(s.client.get_active_loan_count(), 0);
+    assert_eq!(
+        s.client.try_repay(&s.borrower, &loan_id, &1_0000000),
+        Err(Ok(Error::LoanNotActive))
+    );
+}
+
+#[test]
+fn lender_claims_collateral_of_long_overdue_compounding_loan() {
+    let s = setup();
+    let offer_id = s.client.create_offer(
+        &s.lender,
+        &s.usdc.address,
+        &100_0000000,
+        &3000,
+        &InterestMode::CompoundPerWeek,
+        &20000,
+        &12500,
+        &4,
+        &vec![&s.env, s.xlm.address.clone()],
+    );
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &4);
+
+    // Four years at 30% weekly compounded weekly is far beyond what i128 can hold
+    advance_time(&s, 208 * 604800);
+    assert_eq!(
+        s.client.try_calculate_interest(&loan_id),
+        Err(Ok(Error::ArithmeticOverflow))
+    );
+
+    s.client.claim_defaulted_loan(&s.lender, &loan_id);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "loan_defaulted"), loan_id, s.lender.clone()),
+                DefaultClaimData {
+                    debt_outstanding: i128::MAX,
+                    collateral_seized: 1_000_0000000,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.xlm.balance(&s.lender), 1_000_0000000);
+    assert!(!s.client.get_loan(&loan_id).is_active);
+}
+
+#[test]
+fn position_nft_holder_is_paid_as_lender() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &2);
+    assert_eq!(s.nft.owner_of(&(loan_id as u32)), s.lender);
+    assert_eq!(s.client.get_user_loans_as_lender(&s.lender, &0, &10), vec![&s.env, loan_id]);
+
+    // The lender sells the performing loan
+    let buyer = Address::generate(&s.env);
+    s.nft.transfer(&s.lender, &buyer, &(loan_id as u32));
+    assert_eq!(s.client.get_user_loans_as_lender(&s.lender, &0, &10), vec![&s.env]);
+    assert_eq!(s.client.get_user_loans_as_lender(&buyer, &0, &10), vec![&s.env, loan_id]);
+
+    // Repayments go to the new holder
+    s.client.repay(&s.borrower, &loan_id, &20_0000000);
+    assert_eq!(s.usdc.balance(&buyer), 20_0000000);
+    assert_eq!(s.usdc.balance(&s.lender), 900_0000000);
+
+    // And so does the defaulted collateral, which only the holder can claim
+    s.env.ledger().set_timestamp(1_000_000 + 3 * 604800);
+    assert_eq!(
+        s.client.try_claim_defaulted_loan(&s.lender, &loan_id),
+        Err(Ok(Error::OnlyLender))
+    );
+    s.client.claim_defaulted_loan(&buyer, &loan_id);
+    assert_eq!(s.xlm.balance(&buyer), 1_000_0000000);
+}
+
+#[test]
+fn position_nft_is_set_once_and_must_mint_for_the_market() {
+    let s = setup();
+    assert_eq!(s.client.get_position_nft(), s.nft.address);
+
+    let other = s.env.register(LoanPositionNft, (&s.admin,));
+    assert_eq!(
+        s.client.try_set_position_nft(&s.admin, &other),
+        Err(Ok(Error::InvalidPositionNft))
+    );
+
+    // A fresh market refuses an NFT contract that names another minter
+    let market = LendingMarketClient::new(
+        &s.env,
+        &s.env.register(
+            LendingMarket,
+            (
+                &s.admin,
+                &s.usdc.address,
+                &s.xlm.address,
+                &s.oracle.address,
+                3000_u32,
+                &s.amm.address,
+            ),
+        ),
+    );
+    assert_eq!(
+        market.try_set_position_nft(&s.admin, &s.nft.address),
+        Err(Ok(Error::InvalidPositionNft))
+    );
+
+    // Without a position NFT loans open and pay the lender recorded on them
+    s.usdc.mint(&s.borrower, &10_0000000);
+    let offer_id = market.create_offer(
+        &s.lender,
+        &s.usdc.address,
+        &100_0000000,
+        &500,
+        &InterestMode::Simple,
+        &20000,
+        &12500,
+        &4,
+        &vec![&s.env, s.xlm.address.clone()],
+    );
+    let early_loan =
+        market.borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &25_0000000, &2);
+    assert_eq!(market.get_user_loans_as_lender(&s.lender, &0, &10), vec![&s.env, early_loan]);
+
+    market.set_position_nft(
+        &s.admin,
+        &s.env.register(LoanPositionNft, (&market.address,)),
+    );
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            (
+                market.address.clone(),
+                (name(&s, "position_nft_set"), s.admin.clone()).into_val(&s.env),
+                market.get_position_nft().into_val(&s.env),
+            ),
+        ]
+    );
+
+    // Later loans get a token; the earlier one still repays its recorded lender
+    let late_loan =
+        market.borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &25_0000000, &2);
+    assert_eq!(
+        market.get_user_loans_as_lender(&s.lender, &0, &10),
+        vec![&s.env, early_loan, late_loan]
+    );
+    // Pages run across both kinds of loan
+    assert_eq!(market.get_user_loans_as_lender(&s.lender, &0, &1), vec![&s.env, early_loan]);
+    assert_eq!(market.get_user_loans_as_lender(&s.lender, &1, &1), vec![&s.env, late_loan]);
+    assert_eq!(market.get_user_loans_as_lender(&s.lender, &2, &1), vec![&s.env]);
+    assert_eq!(
+        market.try_get_user_loans_as_lender(&s.lender, &0, &0),
+        Err(Ok(Error::InvalidPagination))
+    );
+    let lender_balance = s.usdc.balance(&s.lender);
+    market.repay(&s.borrower, &early_loan, &25_0000000);
+    assert_eq!(s.usdc.balance(&s.lender), lender_balance + 25_0000000);
+}
+
+#[test]
+fn offer_loans_track_outstanding_loans() {
+    let s = setup();
+    run_timelocked(&s, &s.admin, TimelockAction::SetCloseFactor(10000));
+    let offer_id = create_default_offer(&s);
+
+    let first = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &20_0000000, &4);
+    let second = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &30_0000000, &4);
+    assert_eq!(s.client.get_offer_loans(&offer_id), vec![&s.env, first, second]);
+
+    // Full repayment drops the loan from the index
+    s.client.repay(&s.borrower, &first, &20_0000000);
+    assert_eq!(s.client.get_offer_loans(&offer_id), vec![&s.env, second]);
+
+    // So does liquidation
+    s.oracle.set_price(&3_500_000_000_000);
+    seed_amm(&s, 3_500_000_000_000);
+    let liquidator = Address::generate(&s.env);
+    s.client.liquidate(&liquidator, &second, &30_0000000);
+    assert_eq!(s.client.get_offer_loans(&offer_id), Vec::new(&s.env));
+}
+
+#[test]
+fn cancel_offer_with_active_loans_only_withdraws_idle_funds() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &40_0000000, &4);
+
+    // Idle USDC is returned but the offer stays open for the outstanding loan
+    s.client.cancel_offer(&s.lender, &offer_id);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "offer_withdrawn"), offer_id, s.lender.clone()),
+                OfferFundsData {
+                    amount: 60_0000000,
+                    remaining: 0,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.usdc.balance(&s.lender), 960_0000000);
+    let offer = s.client.get_offer(&offer_id);
+    assert!(offer.is_active);
+    assert_eq!(offer.amount, 0);
+
+    // Nothing left to withdraw while the loan is outstanding
+    assert_eq!(
+        s.client.try_cancel_offer(&s.lender, &offer_id),
+        Err(Ok(Error::OfferHasActiveLoans))
+    );
+
+    // Once the loan is repaid the offer can be fully closed
+    s.client.repay(&s.borrower, &loan_id, &40_0000000);
+    s.client.cancel_offer(&s.lender, &offer_id);
+    assert!(!s.client.get_offer(&offer_id).is_active);
+    assert_eq!(s.client.get_active_offer_count(), 0);
+}
+
+fn no_filter() -> OfferFilter {
+    OfferFilter {
+        loan_token: None,
+        min_amount: None,
+        max_rate: None,
+        max_collateral_ratio: None,
+        collateral_asset: None,
+    }
+}
+
+fn offer_ids(offers: &Vec<LendingOffer>) -> std::vec::Vec<u64> {
+    offers.iter().map(|offer| offer.offer_id).collect()
+}
+
+#[test]
+fn list_offers_sorts_filters_and_paginates() {
+    let s = setup();
+    // (amount, rate, collateral ratio), created one hour apart
+    let terms = [
+        (100_0000000, 500, 20000),
+        (300_0000000, 200, 15000),
+        (200_0000000, 800, 30000),
+        (50_0000000, 200, 20000),
+    ];
+    for (i, (amount, rate, ratio)) in terms.iter().enumerate() {
+        s.env.ledger().set_timestamp(1_000_000 + i as u64 * 3600);
+        s.client
+            .create_offer(
+                &s.lender,
+                &s.usdc.address,
+                amount,
+                rate,
+                &InterestMode::Simple,
+                ratio,
+                &12500,
+                &4,
+                &vec![&s.env, s.xlm.address.clone()],
+            );
+    }
+
+    let list = |sort: SortOption, offset: u32, limit: u32, filter: OfferFilter| {
+        offer_ids(&s.client.list_offers(&sort, &offset, &limit, &filter))
+    };
+
+    assert_eq!(list(SortOption::BestRate, 0, 10, no_filter()), [2, 4, 1, 3]);
+    assert_eq!(list(SortOption::HighestAmount, 0, 10, no_filter()), [2, 3, 1, 4]);
+    assert_eq!(list(SortOption::Newest, 0, 10, no_filter()), [4, 3, 2, 1]);
+
+    // Pages are cut from the sorted list
+    assert_eq!(list(SortOption::BestRate, 0, 2, no_filter()), [2, 4]);
+    assert_eq!(list(SortOption::BestRate, 2, 2, no_filter()), [1, 3]);
+    assert_eq!(list(SortOption::BestRate, 3, 2, no_filter()), [3]);
+    assert_eq!(list(SortOption::Newest, 1, 2, no_filter()), [3, 2]);
+    assert!(list(SortOption::BestRate, 10, 2, no_filter()).is_empty());
+
+    // Filters
+    let filter = OfferFilter {
+        min_amount: Some(100_0000000),
+        ..no_filter()
+    };
+    assert_eq!(list(SortOption::BestRate, 0, 10, filter), [2, 1, 3]);
+    let filter = OfferFilter {
+        max_rate: Some(500),
+        ..no_filter()
+    };
+    assert_eq!(list(SortOption::BestRate, 0, 10, filter), [2, 4, 1]);
+    let filter = OfferFilter {
+        max_collateral_ratio: Some(20000),
+        max_rate: Some(400),
+        ..no_filter()
+    };
+    assert_eq!(list(SortOption::HighestAmount, 0, 10, filter), [2, 4]);
+
+    // Drained and cancelled offers are not listed
+    s.client.withdraw_from_offer(&s.lender, &2, &300_0000000);
+    s.client.cancel_offer(&s.lender, &4);
+    assert_eq!(list(SortOption::BestRate, 0, 10, no_filter()), [1, 3]);
+}
+
+/// Create `count` 10 USDC offers, ten per fresh lender, with `rate(i)` for the i-th
+fn create_offers(s: &Setup, count: u32, rate: fn(u32) -> u32) {
+    let mut lender = Address::generate(&s.env);
+    for i in 0..count {
+        if i % 10 == 0 {
+            lender = Address::generate(&s.env);
+            s.usdc.mint(&lender, &100_0000000);
+        }
+        s.client.create_offer(
+            &lender,
+            &s.usdc.address,
+            &10_0000000,
+            &rate(i),
+            &InterestMode::Simple,
+            &20000,
+            &12500,
+            &4,
+            &vec![&s.env, s.xlm.address.clone()],
+        );
+    }
+}
+
+#[test]
+fn list_offers_reads_a_bounded_walk_of_many_offers() {
+    let s = setup();
+    // Twelve offers at each of ten rates, more than one call could read
+    create_offers(&s, 120, |i| 500 + i % 10);
+
+    let list = |sort: SortOption, offset: u32, filter: OfferFilter| {
+        // Every call fits the default network limits however many offers are open
+        s.env.cost_estimate().budget().reset_default();
+        let page = offer_ids(&s.client.list_offers(&sort, &offset, &25, &filter));
+        let resources = s.env.cost_estimate().resources();
+        assert!(
+            resources.disk_read_entries + resources.memory_read_entries + resources.write_entries
+                <= 100
+        );
+        page
+    };
+
+    // Offers ahead of the page are counted from the rate index without reading them
+    let mut seen = std::vec::Vec::new();
+    for offset in (0..120).step_by(25) {
+        seen.extend(list(SortOption::BestRate, offset, no_filter()));
+    }
+    let mut cheapest_first: std::vec::Vec<u64> = (1..=120).collect();
+    cheapest_first.sort_by_key(|id| ((id - 1) % 10, *id));
+    assert_eq!(seen, cheapest_first);
+
+    assert_eq!(
+        list(SortOption::Newest, 0, no_filter()),
+        (96..=120).rev().collect::<std::vec::Vec<u64>>()
+    );
+    // Ranking by amount needs every active offer, more than one call can read
+    assert_eq!(
+        s.client
+            .try_list_offers(&SortOption::HighestAmount, &0, &25, &no_filter()),
+        Err(Ok(Error::InvalidSortOption))
+    );
+
+    // A filter on an offer's own terms reads every offer it passes over, so a deep
+    // page runs into the scan cap and comes back short
+    let filter = OfferFilter {
+        min_amount: Some(10_0000000),
+        ..no_filter()
+    };
+    assert_eq!(list(SortOption::BestRate, 0, filter.clone()).len(), 25);
+    assert!(list(SortOption::BestRate, 100, filter).is_empty());
+}
+
+#[test]
+fn list_offers_keeps_its_order_after_removals() {
+    let s = setup();
+    create_offers(&s, 90, |_| 500);
+    let cancel = |offer_id: u64| {
+        let lender = s.client.get_offer(&offer_id).lender;
+        s.client.cancel_offer(&lender, &offer_id);
+    };
+    let list = |sort: SortOption, offset: u32| {
+        offer_ids(&s.client.list_offers(&sort, &offset, &25, &no_filter()))
+    };
+
+    // Cancelling moves the last offer of the active index into the freed slot, and a
+    // drained offer stays in the index
+    cancel(10);
+    let drained = s.client.get_offer(&85);
+    s.client.withdraw_from_offer(&drained.lender, &85, &10_0000000);
+    let newest: std::vec::Vec<u64> = (1..=90)
+        .rev()
+        .filter(|id| *id != 10 && *id != 85)
+        .collect();
+    assert_eq!(list(SortOption::Newest, 0), newest[..25]);
+    assert_eq!(list(SortOption::Newest, 50), newest[50..75]);
+    // The walk past the 80th offer ID is left to a later call
+    assert_eq!(list(SortOption::Newest, 75), [14, 13, 12, 11]);
+
+    // Once the active index fits one call, amounts rank across every offer in it
+    assert_eq!(
+        s.client
+            .try_list_offers(&SortOption::HighestAmount, &0, &25, &no_filter()),
+        Err(Ok(Error::InvalidSortOption))
+    );
+    for offer_id in 1..=15 {
+        if offer_id != 10 {
+            cancel(offer_id);
+        }
+    }
+    let smaller = s.client.get_offer(&90);
+    s.client.withdraw_from_offer(&smaller.lender, &90, &5_0000000);
+    let mut by_amount: std::vec::Vec<u64> = (16..90).filter(|id| *id != 85).collect();
+    by_amount.push(90);
+    assert_eq!(list(SortOption::HighestAmount, 0), by_amount[..25]);
+    assert_eq!(list(SortOption::HighestAmount, 50), by_amount[50..]);
+}
+
+#[test]
+fn offers_at_one_rate_spread_over_index_pages() {
+    let s = setup();
+    // More offers at one rate than fit in a page of the rate's index
+    create_offers(&s, 120, |_| 500);
+
+    let list_all = || {
+        let mut seen = std::vec::Vec::new();
+        for offset in (0..120).step_by(25) {
+            let page = s.client.list_offers(&SortOption::BestRate, &offset, &25, &no_filter());
+            seen.extend(offer_ids(&page));
+        }
+        seen
+    };
+    assert_eq!(list_all(), (1..=120).collect::<std::vec::Vec<u64>>());
+
+    // Draining an offer on the first page moves the last offer into its slot
+    let drained = s.client.get_offer(&10);
+    s.client.withdraw_from_offer(&drained.lender, &10, &10_0000000);
+    let mut expected: std::vec::Vec<u64> = (1..120).filter(|id| *id != 10).collect();
+    expected.insert(9, 120);
+    assert_eq!(list_all(), expected);
+
+    // borrow_best takes the first offers of the first page
+    let loan_ids = s.client.borrow_best(
+        &s.borrower,
+        &s.usdc.address,
+        &s.xlm.address,
+        &1_000_0000000,
+        &20_0000000,
+        &500,
+        &4,
+    );
+    assert_eq!(s.client.get_loan(&loan_ids.get(0).unwrap()).offer_id, 1);
+    assert_eq!(s.client.get_loan(&loan_ids.get(1).unwrap()).offer_id, 2);
+}
+
+#[test]
+fn offer_rates_per_loan_token_are_capped() {
+    let s = setup();
+    create_offers(&s, 100, |i| 100 + i);
+    let lender = Address::generate(&s.env);
+    s.usdc.mint(&lender, &100_0000000);
+    let create = |rate: u32| {
+        s.client.try_create_offer(
+            &lender,
+            &s.usdc.address,
+            &10_0000000,
+            &rate,
+            &InterestMode::Simple,
+            &20000,
+            &12500,
+            &4,
+            &vec![&s.env, s.xlm.address.clone()],
+        )
+    };
+
+    // A new rate needs a free slot, a rate already in use does not
+    assert_eq!(create(300), Err(Ok(Error::TooManyOfferRates)));
+    assert!(create(150).is_ok());
+
+    // Draining the only offer at a rate frees its slot
+    let drained = s.client.get_offer(&1);
+    s.client.withdraw_from_offer(&drained.lender, &1, &10_0000000);
+    assert!(create(300).is_ok());
+}
+
+#[test]
+fn list_offers_rejects_bad_inputs() {
+    let s = setup();
+    create_default_offer(&s);
+
+    assert_eq!(
+        s.client
+            .try_list_offers(&SortOption::BestRate, &0, &0, &no_filter()),
+        Err(Ok(Error::InvalidPagination))
+    );
+    assert_eq!(
+        s.client
+            .try_list_offers(&SortOption::BestRate, &0, &51, &no_filter()),
+        Err(Ok(Error::InvalidPagination))
+    );
+    assert_eq!(
+        s.client
+            .try_list_offers(&SortOption::BestRate, &u32::MAX, &10, &no_filter()),
+        Err(Ok(Error::InvalidPagination))
+    );
+    let filter = OfferFilter {
+        min_amount: Some(-1),
+        ..no_filter()
+    };
+    assert_eq!(
+        s.client
+            .try_list_offers(&SortOption::BestRate, &0, &10, &filter),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // Amounts of two loan tokens cannot be ranked against each other
+    let eurc = s.env.register(TestToken, ());
+    let config = LoanAssetConfig {
+        oracle_asset: Asset::Other(symbol_short!("EURC")),
+        decimals: 6,
+    };
+    run_timelocked(&s, &s.admin, TimelockAction::SetLoanAsset(eurc, config));
+    assert_eq!(
+        s.client
+            .try_list_offers(&SortOption::HighestAmount, &0, &10, &no_filter()),
+        Err(Ok(Error::InvalidSortOption))
+    );
+    let filter = OfferFilter {
+        loan_token: Some(s.usdc.address.clone()),
+        ..no_filter()
+    };
+    assert_eq!(
+        s.client
+            .list_offers(&SortOption::HighestAmount, &0, &10, &filter)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn active_index_queries_follow_loan_lifecycle() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loans: std::vec::Vec<u64> = (0..3)
+        .map(|_| {
+            s.client
+                .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4)
+        })
+        .collect();
+
+    assert_eq!(s.client.get_active_offer_count(), 1);
+    assert_eq!(s.client.get_active_offers_page(&0), vec![&s.env, offer_id]);
+    assert_eq!(s.client.get_active_loan_count(), 3);
+    assert_eq!(
+        s.client.get_active_loans_page(&0),
+        vec![&s.env, loans[0], loans[1], loans[2]]
+    );
+
+    // Closing the first loan moves the last one into its slot
+    s.client.repay(&s.borrower, &loans[0], &10_0000000);
+    assert_eq!(s.client.get_active_loan_count(), 2);
+    assert_eq!(s.client.get_active_loans_page(&0), vec![&s.env, loans[2], loans[1]]);
+    assert!(s.client.get_active_loans_page(&1).is_empty());
+}
+
+fn persistent_ttl(s: &Setup, key: &DataKey) -> u32 {
+    s.env
+        .as_contract(&s.client.address, || s.env.storage().persistent().get_ttl(key))
+}
+
+fn instance_ttl(s: &Setup) -> u32 {
+    s.env
+        .as_contract(&s.client.address, || s.env.storage().instance().get_ttl())
+}
+
+fn advance_ledgers(s: &Setup, ledgers: u32) {
+    let sequence = s.env.ledger().sequence();
+    s.env.ledger().set_sequence_number(sequence + ledgers);
+}
+
+#[test]
+fn writes_extend_storage_ttl() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4);
+
+    assert_eq!(s.client.get_ttl_config().extend_to, DEFAULT_TTL_EXTEND_TO);
+    assert_eq!(instance_ttl(&s), DEFAULT_TTL_EXTEND_TO);
+    assert_eq!(persistent_ttl(&s, &DataKey::Offer(offer_id)), DEFAULT_TTL_EXTEND_TO);
+    assert_eq!(persistent_ttl(&s, &DataKey::Loan(loan_id)), DEFAULT_TTL_EXTEND_TO);
+    assert_eq!(
+        persistent_ttl(&s, &DataKey::ActiveLoanPosition(loan_id)),
+        DEFAULT_TTL_EXTEND_TO
+    );
+}
+
+#[test]
+fn bump_loan_extends_loan_and_index_entries() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4);
+
+    // Within the threshold nothing is extended
+    advance_ledgers(&s, DAY_IN_LEDGERS / 2);
+    s.client.bump_loan(&loan_id);
+    let expected = DEFAULT_TTL_EXTEND_TO - DAY_IN_LEDGERS / 2;
+    assert_eq!(persistent_ttl(&s, &DataKey::Loan(loan_id)), expected);
+
+    // Once below the threshold, the loan and its index entries are bumped
+    advance_ledgers(&s, 2 * DAY_IN_LEDGERS);
+    s.client.bump_loan(&loan_id);
+    for key in [
+        DataKey::Loan(loan_id),
+        DataKey::ActiveLoanPosition(loan_id),
+        DataKey::OfferLoans(offer_id),
+        DataKey::UserLoansAsBorrower(s.borrower.clone()),
+    ] {
+        assert_eq!(persistent_ttl(&s, &key), DEFAULT_TTL_EXTEND_TO);
+    }
+    assert_eq!(instance_ttl(&s), DEFAULT_TTL_EXTEND_TO);
+
+    assert_eq!(s.client.try_bump_loan(&99), Err(Ok(Error::LoanNotFound)));
+}
+
+#[test]
+fn bump_offer_extends_offer_and_index_entries() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+
+    advance_ledgers(&s, 2 * DAY_IN_LEDGERS);
+    s.client.bump_offer(&offer_id);
+    for key in [
+        DataKey::Offer(offer_id),
+        DataKey::ActiveOfferPosition(offer_id),
+        DataKey::UserOffers(s.lender.clone()),
+    ] {
+        assert_eq!(persistent_ttl(&s, &key), DEFAULT_TTL_EXTEND_TO);
+    }
+
+    assert_eq!(s.client.try_bump_offer(&99), Err(Ok(Error::OfferNotFound)));
+}
+
+#[test]
+fn set_ttl_config_applies_to_later_writes() {
+    let s = setup();
+    let config = TtlConfig {
+        threshold: 10 * DAY_IN_LEDGERS,
+        extend_to: 60 * DAY_IN_LEDGERS,
+    };
+
+    s.client.set_ttl_config(&s.admin, &config);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "ttl_config_updated"), s.admin.clone()),
+                config.clone()
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_ttl_config(), config);
+
+    let offer_id = create_default_offer(&s);
+    assert_eq!(persistent_ttl(&s, &DataKey::Offer(offer_id)), config.extend_to);
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_set_ttl_config(&stranger, &config),
+        Err(Ok(Error::OnlyAdmin))
+    );
+}
+
+#[test]
+fn set_ttl_config_rejects_invalid_values() {
+    let s = setup();
+    let max_ttl = s.env.as_contract(&s.client.address, || s.env.storage().max_ttl());
+
+    for (threshold, extend_to) in [
+        (0, DAY_IN_LEDGERS),
+        (DAY_IN_LEDGERS, DAY_IN_LEDGERS),
+        (2 * DAY_IN_LEDGERS, DAY_IN_LEDGERS),
+        (DAY_IN_LEDGERS, max_ttl + 1),
+    ] {
+        assert_eq!(
+            s.client
+                .try_set_ttl_config(&s.admin, &TtlConfig { threshold, extend_to }),
+            Err(Ok(Error::InvalidTtlConfig))
+        );
+    }
+}
+
+/// 75 USDC loan against 1000 XLM, made liquidatable by XLM dropping to $0.09
+fn liquidatable_loan(s: &Setup) -> u64 {
+    let offer_id = create_default_offer(s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    s.oracle.set_price(&9_000_000_000_000);
+    loan_id
+}
+
+fn advance_time(s: &Setup, seconds: u64) {
+    let timestamp = s.env.ledger().timestamp();
+    s.env.ledger().set_timestamp(timestamp + seconds);
+}
+
+#[test]
+fn start_auction_requires_liquidatable_loan() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    let keeper = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_start_auction(&keeper, &loan_id),
+        Err(Ok(Error::NotLiquidatable))
+    );
+
+    s.oracle.set_price(&9_000_000_000_000);
+    s.client.start_auction(&keeper, &loan_id);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "auction_started"), loan_id, keeper.clone()),
+                1_000_000_u64
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_auction(&loan_id).start_time, 1_000_000);
+
+    assert_eq!(
+        s.client.try_start_auction(&keeper, &loan_id),
+        Err(Ok(Error::AuctionAlreadyActive))
+    );
+}
+
+#[test]
+fn auction_discount_grows_linearly_to_cap() {
+    let s = setup();
+    let loan_id = liquidatable_loan(&s);
+    s.client.start_auction(&s.lender, &loan_id);
+
+    assert_eq!(s.client.get_auction_discount(&loan_id), 0);
+    advance_time(&s, 900);
+    assert_eq!(s.client.get_auction_discount(&loan_id), 500);
+    advance_time(&s, 900);
+    assert_eq!(s.client.get_auction_discount(&loan_id), 1000);
+    advance_time(&s, 1800);
+    assert_eq!(s.client.get_auction_discount(&loan_id), 2000);
+
+    // Past its duration the discount holds at the cap
+    advance_time(&s, 10_000);
+    assert_eq!(s.client.get_auction_discount(&loan_id), 2000);
+}
+
+#[test]
+fn auction_keeps_running_at_cap_after_duration() {
+    let s = setup();
+    let loan_id = liquidatable_loan(&s);
+    let bidder = Address::generate(&s.env);
+    s.usdc.mint(&bidder, &100_0000000);
+    s.client.start_auction(&bidder, &loan_id);
+
+    // Nobody bids until long after the duration; the auction is still the same one
+    advance_time(&s, 10_000);
+    assert_eq!(
+        s.client.try_start_auction(&bidder, &loan_id),
+        Err(Ok(Error::AuctionAlreadyActive))
+    );
+    assert_eq!(s.client.get_auction(&loan_id).start_time, 1_000_000);
+
+    s.client.bid(&bidder, &loan_id, &10_0000000);
+    assert_eq!(s.usdc.balance(&bidder), 90_0000000);
+    assert!(s.xlm.balance(&bidder) > 0);
+}
+
+#[test]
+fn bid_is_capped_at_close_factor() {
+    let s = setup();
+    let loan_id = liquidatable_loan(&s);
+    let bidder = Address::generate(&s.env);
+    s.usdc.mint(&bidder, &100_0000000);
+    s.client.start_auction(&bidder, &loan_id);
+    advance_time(&s, 1800);
+
+    // Half of the 75.0111607 USDC debt at the default 50% close factor
+    s.client.bid(&bidder, &loan_id, &i128::MAX);
+    assert_eq!(s.usdc.balance(&bidder), 100_0000000 - 37_5055803);
+
+    let loan = s.client.get_loan(&loan_id);
+    assert!(loan.is_active);
+    assert_eq!(loan.borrowed_amount + loan.accumulated_interest, 37_5055804);
+}
+
+#[test]
+fn bid_fills_at_current_discount() {
+    let s = setup();
+    let loan_id = liquidatable_loan(&s);
+    let bidder = Address::generate(&s.env);
+    s.usdc.mint(&bidder, &100_0000000);
+
+    assert_eq!(
+        s.client.try_bid(&bidder, &loan_id, &30_0000000),
+        Err(Ok(Error::AuctionNotFound))
+    );
+
+    s.client.start_auction(&bidder, &loan_id);
+    advance_time(&s, 1800);
+    let lender_before = s.usdc.balance(&s.lender);
+
+    // 30 USDC buys $30 of XLM at a 10% discount: 333.33 / 0.9 = 370.37 XLM
+    s.client.bid(&bidder, &loan_id, &30_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "auction_bid"), loan_id, bidder.clone()),
+                AuctionBidData {
+                    amount_paid: 30_0000000,
+                    interest_paid: 111607,
+                    principal_paid: 29_9888393,
+                    protocol_fee: 0,
+                    collateral_sold: 370_3703703,
+                    discount_bps: 1000,
+                    collateral_returned: 0,
+                    remaining_debt: 45_0111607,
+                },
+            ),
+            // The loan is back above its liquidation threshold, so the auction ends
+            event(&s, (name(&s, "auction_ended"), loan_id), 1_000_000_u64),
+        ]
+    );
+    assert_eq!(s.xlm.balance(&bidder), 370_3703703);
+    assert_eq!(s.usdc.balance(&s.lender) - lender_before, 30_0000000);
+
+    let loan = s.client.get_loan(&loan_id);
+    assert!(loan.is_active);
+    assert_eq!(loan.collateral_amount, 629_6296297);
+    assert_eq!(
+        s.client.try_get_auction(&loan_id),
+        Err(Ok(Error::AuctionNotFound))
+    );
+}
+
+#[test]
+fn bid_for_full_debt_closes_loan() {
+    let s = setup();
+    run_timelocked(&s, &s.admin, TimelockAction::SetCloseFactor(10000));
+    let loan_id = liquidatable_loan(&s);
+    let bidder = Address::generate(&s.env);
+    s.usdc.mint(&bidder, &100_0000000);
+    s.client.start_auction(&bidder, &loan_id);
+    advance_time(&s, 1800);
+    let borrower_xlm = s.xlm.balance(&s.borrower);
+
+    // With a 100% close factor, bids are capped at the outstanding debt
+    s.client.bid(&bidder, &loan_id, &i128::MAX);
+    assert_eq!(s.usdc.balance(&bidder), 100_0000000 - 75_0111607);
+    assert_eq!(s.xlm.balance(&bidder), 926_0637123);
+    assert_eq!(s.xlm.balance(&s.borrower) - borrower_xlm, 73_9362877);
+    assert_eq!(s.xlm.balance(&s.client.address), 0);
+
+    assert!(!s.client.get_loan(&loan_id).is_active);
+    assert_eq!(s.client.get_active_loan_count(), 0);
+    assert_eq!(
+        s.client.try_get_auction(&loan_id),
+        Err(Ok(Error::AuctionNotFound))
+    );
+}
+
+#[test]
+fn auction_ends_when_borrower_restores_health() {
+    let s = setup();
+    let loan_id = liquidatable_loan(&s);
+    let bidder = Address::generate(&s.env);
+    s.usdc.mint(&bidder, &100_0000000);
+    s.client.start_auction(&bidder, &loan_id);
+
+    s.client.add_collateral(&s.borrower, &loan_id, &500_0000000);
+    assert_eq!(
+        s.client.try_get_auction(&loan_id),
+        Err(Ok(Error::AuctionNotFound))
+    );
+    assert_eq!(
+        s.client.try_bid(&bidder, &loan_id, &10_0000000),
+        Err(Ok(Error::AuctionNotFound))
+    );
+}
+
+#[test]
+fn set_auction_config_validates_and_emits_event() {
+    let s = setup();
+    let config = AuctionConfig {
+        max_discount_bps: 1500,
+        duration: 7200,
+    };
+
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetAuctionConfig(config.clone()));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "auction_config_updated"), s.admin.clone()),
+                config.clone()
+            ),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_auction_config(), config);
+
+    for (max_discount_bps, duration) in [(0, 3600), (5001, 3600), (2000, 0)] {
+        assert_eq!(
+            s.client.try_queue_action(
+                &s.admin,
+                &TimelockAction::SetAuctionConfig(AuctionConfig {
+                    max_discount_bps,
+                    duration
+                })
+            ),
+            Err(Ok(Error::InvalidAuctionConfig))
+        );
+    }
+}
+
+#[test]
+fn set_fee_config_validates_and_emits_event() {
+    let s = setup();
+    assert_eq!(
+        s.client.get_fee_config(),
+        FeeConfig {
+            protocol_fee_bps: 0,
+            liquidation_fee_bps: 0,
+        }
+    );
+
+    let config = FeeConfig {
+        protocol_fee_bps: 1000,
+        liquidation_fee_bps: 100,
+    };
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetFeeConfig(config.clone()));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "fee_config_updated"), s.admin.clone()),
+                config.clone()
+            ),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_fee_config(), config);
+
+    for (protocol_fee_bps, liquidation_fee_bps) in [(5001, 0), (0, 1001)] {
+        assert_eq!(
+            s.client.try_queue_action(
+                &s.admin,
+                &TimelockAction::SetFeeConfig(FeeConfig {
+                    protocol_fee_bps,
+                    liquidation_fee_bps
+                })
+            ),
+            Err(Ok(Error::InvalidFeeConfig))
+        );
+    }
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_queue_action(&stranger, &TimelockAction::SetFeeConfig(config)),
+        Err(Ok(Error::OnlyAdmin))
+    );
+}
+
+#[test]
+fn repay_sends_interest_fee_to_treasury() {
+    let s = setup();
+    run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFeeConfig(FeeConfig {
+            protocol_fee_bps: 2000,
+            liquidation_fee_bps: 0,
+        }),
+    );
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    let lender_before = s.usdc.balance(&s.lender);
+
+    // One week of 5% interest on 75 USDC = 3.75 USDC, 20% of which is 0.75 USDC
+    advance_time(&s, 604800);
+    s.usdc.mint(&s.borrower, &3_7500000);
+    s.client.repay(&s.borrower, &loan_id, &78_7500000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "loan_repaid"), loan_id, s.borrower.clone()),
+                LoanRepaidData {
+                    amount: 78_7500000,
+                    interest_paid: 3_7500000,
+                    principal_paid: 75_0000000,
+                    protocol_fee: 7500000,
+                    remaining_principal: 0,
+                    collateral_returned: 1_000_0000000,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.usdc.balance(&s.lender) - lender_before, 78_0000000);
+    assert_eq!(s.client.get_protocol_fees(&s.usdc.address), 7500000);
+
+    // Only the admin or a fee collector can claim
+    let treasury = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_claim_protocol_fees(&stranger, &s.usdc.address, &treasury),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    assert_eq!(s.client.claim_protocol_fees(&s.admin, &s.usdc.address, &treasury), 7500000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (
+                    name(&s, "protocol_fees_claimed"),
+                    s.admin.clone(),
+                    s.usdc.address.clone(),
+                    treasury.clone()
+                ),
+                7500000_i128
+            ),
+        ]
+    );
+    assert_eq!(s.usdc.balance(&treasury), 7500000);
+    assert_eq!(s.client.get_protocol_fees(&s.usdc.address), 0);
+
+    // The idle offer funds are untouched
+    assert_eq!(s.usdc.balance(&s.client.address), 25_0000000);
+    assert_eq!(s.client.claim_protocol_fees(&s.admin, &s.usdc.address, &treasury), 0);
+}
+
+#[test]
+fn liquidation_fee_goes_to_treasury() {
+    let s = setup();
+    run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFeeConfig(FeeConfig {
+            protocol_fee_bps: 0,
+            liquidation_fee_bps: 100,
+        }),
+    );
+    let loan_id = liquidatable_loan(&s);
+    seed_amm(&s, 9_000_000_000_000);
+    let liquidator = Address::generate(&s.env);
+    let lender_before = s.usdc.balance(&s.lender);
+
+    // 1% of the 37.5 USDC repaid goes to the protocol on top of the 5% bonus
+    s.client.liquidate(&liquidator, &loan_id, &100_0000000);
+    assert_eq!(s.usdc.balance(&s.lender) - lender_before, 37_5000000);
+    assert_eq!(s.usdc.balance(&liquidator), 1_8750000);
+    assert_eq!(s.client.get_protocol_fees(&s.usdc.address), 3750000);
+    assert_eq!(s.usdc.balance(&s.client.address), 25_0000000 + 3750000);
+}
+
+#[test]
+fn auction_bid_pays_interest_fee() {
+    let s = setup();
+    run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFeeConfig(FeeConfig {
+            protocol_fee_bps: 5000,
+            liquidation_fee_bps: 0,
+        }),
+    );
+    let loan_id = liquidatable_loan(&s);
+    let bidder = Address::generate(&s.env);
+    s.usdc.mint(&bidder, &100_0000000);
+    s.client.start_auction(&bidder, &loan_id);
+    advance_time(&s, 1800);
+    let lender_before = s.usdc.balance(&s.lender);
+
+    // 0.0111607 USDC of interest accrued over the auction, half of it is kept
+    s.client.bid(&bidder, &loan_id, &30_0000000);
+    assert_eq!(s.client.get_protocol_fees(&s.usdc.address), 55803);
+    assert_eq!(s.usdc.balance(&s.lender) - lender_before, 30_0000000 - 55803);
+    assert_eq!(s.usdc.balance(&bidder), 70_0000000);
+}
+
+#[test]
+fn compounding_offer_accrues_interest_on_interest() {
+    let s = setup();
+    let offer_id = s.client.create_offer(
+        &s.lender,
+        &s.usdc.address,
+        &100_0000000,
+        &500,
+        &InterestMode::CompoundPerWeek,
+        &20000,
+        &12500,
+        &4,
+        &vec![&s.env, s.xlm.address.clone()],
+    );
+    assert_eq!(
+        s.client.get_offer(&offer_id).interest_mode,
+        InterestMode::CompoundPerWeek
+    );
+    // 1.05^52 - 1 = 1164.28%
+    assert_eq!(s.client.get_offer_apy(&offer_id), 116428);
+
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &4);
+    assert_eq!(
+        s.client.get_loan(&loan_id).interest_mode,
+        InterestMode::CompoundPerWeek
+    );
+
+    // Two weeks at 5% compounded weekly: 50 × 1.05² = 55.125 USDC
+    advance_time(&s, 2 * 604800);
+    assert_eq!(s.client.calculate_interest(&loan_id), 5_1250000);
+    assert_eq!(s.client.get_loan_health(&loan_id).debt_value, 55_1250000);
+
+    // Unpaid interest keeps compounding after a partial payment
+    s.usdc.mint(&s.borrower, &10_0000000);
+    s.client.repay(&s.borrower, &loan_id, &1_1250000);
+    let loan = s.client.get_loan(&loan_id);
+    assert_eq!(loan.accumulated_interest, 4_0000000);
+    assert_eq!(loan.borrowed_amount, 50_0000000);
+
+    // 54 × 1.05 = 56.7 USDC
+    advance_time(&s, 604800);
+    assert_eq!(s.client.get_loan_health(&loan_id).debt_value, 56_7000000);
+
+    s.client.repay(&s.borrower, &loan_id, &56_7000000);
+    assert!(!s.client.get_loan(&loan_id).is_active);
+}
+
+/// 100 USDC deposited into the pool and 75 USDC borrowed against 1000 XLM
+fn pool_loan(s: &Setup) -> u64 {
+    s.client.deposit(&s.lender, &100_0000000);
+    s.client
+        .pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &75_0000000)
+}
+
+#[test]
+fn pool_accrues_variable_interest_for_lenders() {
+    let s = setup();
+
+    let shares = s.client.deposit(&s.lender, &100_0000000);
+    assert_eq!(shares, 100_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (symbol_short!("mint"), s.lender.clone()),
+                100_0000000_i128,
+            ),
+            event(
+                &s,
+                (name(&s, "pool_deposited"), s.lender.clone()),
+                PoolSharesData {
+                    amount: 100_0000000,
+                    shares: 100_0000000,
+                },
+            ),
+        ]
+    );
+    assert_eq!(
+        s.client.get_pool_rates(),
+        PoolRates {
+            utilization: 0,
+            borrow_rate: 10,
+            supply_rate: 0,
+        }
+    );
+
+    // 50% utilization: 0.1% + 1% × 50 / 80 = 0.72% weekly
+    let loan_id = s
+        .client
+        .pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &50_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "pool_loan_opened"), loan_id, s.borrower.clone()),
+                PoolLoanOpenedData {
+                    collateral_token: s.xlm.address.clone(),
+                    collateral_amount: 1_000_0000000,
+                    borrowed_amount: 50_0000000,
+                    borrow_rate: 72,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.usdc.balance(&s.borrower), 50_0000000);
+
+    // One week later the debt and the lender's balance have grown by 0.36 USDC
+    advance_time(&s, 604800);
+    assert_eq!(s.client.get_pool_loan_debt(&loan_id), 50_3600000);
+    assert_eq!(s.client.get_pool_balance(&s.lender), 100_3600000);
+    assert_eq!(
+        s.client.get_pool_loan_health(&loan_id).debt_value,
+        50_3600000
+    );
+
+    s.usdc.mint(&s.borrower, &3600000);
+    s.client.pool_repay(&s.borrower, &loan_id, &50_3600000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "pool_loan_repaid"), loan_id, s.borrower.clone()),
+                PoolLoanRepaidData {
+                    amount: 50_3600000,
+                    remaining_debt: 0,
+                    collateral_returned: 1_000_0000000,
+                },
+            ),
+        ]
+    );
+    assert!(!s.client.get_pool_loan(&loan_id).is_active);
+    assert_eq!(s.xlm.balance(&s.borrower), 10_000_0000000);
+
+    assert_eq!(s.client.redeem(&s.lender, &shares), 100_3600000);
+    assert_eq!(s.usdc.balance(&s.lender), 1_000_3600000);
+    assert_eq!(s.client.balance(&s.lender), 0);
+    assert_eq!(s.usdc.balance(&s.client.address), 0);
+}
+
+#[test]
+fn pool_shares_are_a_transferable_token() {
+    let s = setup();
+    assert_eq!(s.client.name(), String::from_str(&s.env, "Lending Market Pool Share"));
+    assert_eq!(s.client.symbol(), String::from_str(&s.env, "lmUSDC"));
+    assert_eq!(s.client.decimals(), 7);
+    assert_eq!(s.client.get_share_price(), 1_0000000);
+
+    s.client.deposit(&s.lender, &100_0000000);
+    s.client
+        .pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &50_0000000);
+    assert_eq!(s.client.total_supply(), 100_0000000);
+
+    // 0.36 USDC of interest after a week raises the price of every share
+    advance_time(&s, 604800);
+    assert_eq!(s.client.get_share_price(), 1_0036000);
+
+    // Shares moved to another holder carry their claim on the pool with them
+    let holder = Address::generate(&s.env);
+    s.client.transfer(&s.lender, &holder, &40_0000000);
+    assert_eq!(s.client.balance(&s.lender), 60_0000000);
+    assert_eq!(s.client.get_pool_balance(&holder), 40_1440000);
+    assert_eq!(s.client.redeem(&holder, &40_0000000), 40_1440000);
+    assert_eq!(s.usdc.balance(&holder), 40_1440000);
+    assert_eq!(s.client.balance(&holder), 0);
+
+    // New deposits mint at the current price
+    s.usdc.mint(&holder, &10_0360000);
+    assert_eq!(s.client.deposit(&holder, &10_0360000), 10_0000000);
+    assert_eq!(s.client.total_supply(), 70_0000000);
+    assert_eq!(s.client.get_share_price(), 1_0036000);
+}
+
+#[test]
+fn pool_enforces_liquidity_collateral_and_shares() {
+    let s = setup();
+    s.client.deposit(&s.lender, &100_0000000);
+
+    // $150 of collateral at a 200% ratio allows 75 USDC
+    assert_eq!(
+        s.client
+            .try_pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &80_0000000),
+        Err(Ok(Error::InsufficientCollateral))
+    );
+    assert_eq!(
+        s.client
+            .try_pool_borrow(&s.borrower, &s.xlm.address, &2_000_0000000, &101_0000000),
+        Err(Ok(Error::InsufficientPoolLiquidity))
+    );
+    let loan_id = s
+        .client
+        .pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &75_0000000);
+
+    // Only the idle 25 USDC can be withdrawn
+    assert_eq!(
+        s.client.try_redeem(&s.lender, &100_0000000),
+        Err(Ok(Error::InsufficientPoolLiquidity))
+    );
+    assert_eq!(
+        s.client.try_redeem(&s.lender, &100_0000001),
+        Err(Ok(Error::InsufficientShares))
+    );
+    assert_eq!(s.client.redeem(&s.lender, &25_0000000), 25_0000000);
+
+    assert_eq!(
+        s.client
+            .try_pool_withdraw_collateral(&s.borrower, &loan_id, &300_0000000),
+        Err(Ok(Error::WithdrawalBreachesHealth))
+    );
+    s.client
+        .pool_add_collateral(&s.borrower, &loan_id, &1_000_0000000);
+    s.client
+        .pool_withdraw_collateral(&s.borrower, &loan_id, &500_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (
+                    name(&s, "pool_collateral_withdrawn"),
+                    loan_id,
+                    s.borrower.clone()
+                ),
+                CollateralData {
+                    amount: 500_0000000,
+                    collateral_amount: 1_500_0000000,
+                },
+            ),
+        ]
+    );
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_pool_repay(&stranger, &loan_id, &1_0000000),
+        Err(Ok(Error::OnlyBorrower))
+    );
+    assert_eq!(
+        s.client.try_get_pool_loan(&99),
+        Err(Ok(Error::PoolLoanNotFound))
+    );
+}
+
+#[test]
+fn pool_rate_model_kinks_and_funds_reserves() {
+    let s = setup();
+    let model = RateModel {
+        base_rate: 0,
+        slope1: 800,
+        optimal_utilization: 5000,
+        slope2: 2000,
+    };
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetRateModel(model.clone()));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "rate_model_updated"), s.admin.clone()),
+                model.clone()
+            ),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_rate_model(), model);
+
+    for (optimal_utilization, slope2) in [(0, 2000), (10000, 2000), (5000, 2201)] {
+        assert_eq!(
+            s.client.try_queue_action(
+                &s.admin,
+                &TimelockAction::SetRateModel(RateModel {
+                    optimal_utilization,
+                    slope2,
+                    ..model.clone()
+                })
+            ),
+            Err(Ok(Error::InvalidRateModel))
+        );
+    }
+
+    run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFeeConfig(FeeConfig {
+            protocol_fee_bps: 1000,
+            liquidation_fee_bps: 0,
+        }),
+    );
+    pool_loan(&s);
+
+    // 75% utilization is past the kink: 8% + 20% × 25 / 50 = 18% weekly
+    assert_eq!(
+        s.client.get_pool_rates(),
+        PoolRates {
+            utilization: 7500,
+            borrow_rate: 1800,
+            supply_rate: 1215,
+        }
+    );
+
+    // 13.5 USDC of interest over a week, 10% of it reserved for the protocol
+    advance_time(&s, 604800);
+    let state = s.client.get_pool_state();
+    assert_eq!(state.reserves, 1_3500000);
+    assert_eq!(s.client.get_pool_balance(&s.lender), 112_1500000);
+
+    let treasury = Address::generate(&s.env);
+    assert_eq!(s.client.claim_protocol_fees(&s.admin, &s.usdc.address, &treasury), 1_3500000);
+    assert_eq!(s.usdc.balance(&treasury), 1_3500000);
+    assert_eq!(s.client.get_pool_state().reserves, 0);
+    assert_eq!(s.client.get_pool_state().cash, 25_0000000 - 1_3500000);
+    assert_eq!(s.client.get_pool_balance(&s.lender), 112_1500000);
+}
+
+#[test]
+fn rate_limits_are_checked_again_when_they_run() {
+    let s = setup();
+    // Both fit under the current 30% maximum when queued
+    let steep = RateModel {
+        base_rate: 0,
+        slope1: 800,
+        optimal_utilization: 5000,
+        slope2: 2200,
+    };
+    let model_id = s.client.queue_action(&s.admin, &TimelockAction::SetRateModel(steep));
+    let max_id = s.client.queue_action(&s.admin, &TimelockAction::SetMaxInterestRate(2500));
+    s.env
+        .ledger()
+        .set_timestamp(s.client.get_queued_action(&max_id).eta);
+
+    // Once the maximum drops, the steeper curve no longer fits under it
+    s.client.execute_action(&s.admin, &max_id);
+    assert_eq!(
+        s.client.try_execute_action(&s.admin, &model_id),
+        Err(Ok(Error::InvalidRateModel))
+    );
+    assert_eq!(s.client.get_rate_model().slope2, 1000);
+
+    // Nor may the maximum drop below the curve in force
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &TimelockAction::SetMaxInterestRate(1000)),
+        Err(Ok(Error::InvalidRateModel))
+    );
+}
+
+#[test]
+fn pool_loan_liquidation_sells_collateral() {
+    let s = setup();
+    let loan_id = pool_loan(&s);
+    s.oracle.set_price(&9_000_000_000_000);
+    seed_amm(&s, 9_000_000_000_000);
+    let liquidator = Address::generate(&s.env);
+
+    s.client.pool_liquidate(&liquidator, &loan_id, &100_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "pool_loan_liquidated"), loan_id, liquidator.clone()),
+                PoolLiquidationData {
+                    debt_repaid: 37_5000000,
+                    collateral_seized: 441_9191920,
+                    swap_proceeds: 39_6359457,
+                    liquidator_bonus: 1_8750000,
+                    protocol_fee: 0,
+                    borrower_refund: 2609457,
+                    collateral_returned: 0,
+                    remaining_debt: 37_5000000,
+                    bad_debt: 0,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_pool_state().cash, 62_5000000);
+    assert_eq!(s.client.get_pool_balance(&s.lender), 100_0000000);
+    assert!(s.client.get_pool_loan(&loan_id).is_active);
+
+    assert_eq!(
+        s.client.try_pool_liquidate(&liquidator, &loan_id, &10_0000000),
+        Err(Ok(Error::NotLiquidatable))
+    );
+}
+
+#[test]
+fn pool_liquidation_writes_off_bad_debt() {
+    let s = setup();
+    let loan_id = pool_loan(&s);
+
+    // $38.50 of collateral against 75 USDC: selling all of it only covers half
+    s.oracle.set_price(&3_850_000_000_000);
+    seed_amm(&s, 3_850_000_000_000);
+    let liquidator = Address::generate(&s.env);
+    s.client.pool_liquidate(&liquidator, &loan_id, &100_0000000);
+
+    let loan = s.client.get_pool_loan(&loan_id);
+    assert!(!loan.is_active);
+    assert_eq!(loan.collateral_amount, 0);
+    assert_eq!(loan.scaled_debt, 0);
+
+    // Lenders absorb the 37.5 USDC that could not be recovered
+    let state = s.client.get_pool_state();
+    assert_eq!(state.total_scaled_debt, 0);
+    assert_eq!(state.cash, 62_5000000);
+    assert_eq!(s.client.get_pool_balance(&s.lender), 62_5000000);
+
+    assert_eq!(s.client.get_active_pool_loan_count(), 0);
+    assert!(s.client.get_user_pool_loans(&s.borrower).is_empty());
+}
+
+#[test]
+fn deposit_into_insolvent_pool_is_rejected() {
+    let s = setup();
+    s.client.deposit(&s.lender, &100_0000000);
+
+    // Bad debt has left the outstanding shares backed by nothing
+    let mut state = s.client.get_pool_state();
+    state.cash = 0;
+    s.env.as_contract(&s.client.address, || {
+        s.env.storage().instance().set(&DataKey::PoolState, &state)
+    });
+    assert_eq!(s.client.get_share_price(), 0);
+
+    let depositor = Address::generate(&s.env);
+    s.usdc.mint(&depositor, &50_0000000);
+    assert_eq!(
+        s.client.try_deposit(&depositor, &50_0000000),
+        Err(Ok(Error::PoolInsolvent))
+    );
+}
+
+#[test]
+fn pool_loans_are_indexed_until_closed() {
+    let s = setup();
+    let first = pool_loan(&s);
+    let second = s
+        .client
+        .pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &10_0000000);
+
+    assert_eq!(s.client.get_active_pool_loan_count(), 2);
+    assert_eq!(
+        s.client.get_active_pool_loans_page(&0),
+        vec![&s.env, first, second]
+    );
+    assert_eq!(
+        s.client.get_user_pool_loans(&s.borrower),
+        vec![&s.env, first, second]
+    );
+
+    // A partial repayment keeps the loan listed, a full one removes it
+    s.client.pool_repay(&s.borrower, &first, &10_0000000);
+    assert_eq!(s.client.get_active_pool_loan_count(), 2);
+    let debt = s.client.get_pool_loan_debt(&first);
+    s.client.pool_repay(&s.borrower, &first, &debt);
+    assert_eq!(s.client.get_active_pool_loan_count(), 1);
+    assert_eq!(s.client.get_active_pool_loans_page(&0), vec![&s.env, second]);
+    assert_eq!(s.client.get_user_pool_loans(&s.borrower), vec![&s.env, second]);
+}
+
+#[test]
+fn pool_loans_count_against_the_loan_limit() {
+    let s = setup();
+    let config = RiskConfig {
+        max_loans_per_user: 1,
+        ..s.client.get_risk_config()
+    };
+    run_timelocked(&s, &s.admin, TimelockAction::UpdateRiskConfig(config));
+    s.client.deposit(&s.lender, &100_0000000);
+
+    s.client
+        .pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &10_0000000);
+    assert_eq!(
+        s.client
+            .try_pool_borrow(&s.borrower, &s.xlm.address, &1_000_0000000, &10_0000000),
+        Err(Ok(Error::TooManyLoans))
+    );
+}
+
+#[test]
+fn closed_loans_free_their_slot_in_the_loan_limit() {
+    let s = setup();
+    let config = RiskConfig {
+        max_loans_per_user: 1,
+        ..s.client.get_risk_config()
+    };
+    run_timelocked(&s, &s.admin, TimelockAction::UpdateRiskConfig(config));
+    let offer_id = create_default_offer(&s);
+
+    let first = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4);
+    assert_eq!(
+        s.client
+            .try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4),
+        Err(Ok(Error::TooManyLoans))
+    );
+
+    // Repaying the only open loan lets the borrower open another
+    s.client.repay(&s.borrower, &first, &10_0000000);
+    assert!(s.client.get_user_loans_as_borrower(&s.borrower).is_empty());
+    let second = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4);
+    assert_eq!(s.client.get_user_loans_as_borrower(&s.borrower), vec![&s.env, second]);
+    assert_eq!(
+        s.client
+            .try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4),
+        Err(Ok(Error::TooManyLoans))
+    );
+}
+
+#[test]
+fn bump_pool_loan_extends_loan_and_index_entries() {
+    let s = setup();
+    let loan_id = pool_loan(&s);
+
+    advance_ledgers(&s, 2 * DAY_IN_LEDGERS);
+    s.client.bump_pool_loan(&loan_id);
+    for key in [
+        DataKey::PoolLoan(loan_id),
+        DataKey::ActivePoolLoanPosition(loan_id),
+        DataKey::UserPoolLoans(s.borrower.clone()),
+    ] {
+        assert_eq!(persistent_ttl(&s, &key), DEFAULT_TTL_EXTEND_TO);
+    }
+
+    assert_eq!(
+        s.client.try_bump_pool_loan(&99),
+        Err(Ok(Error::PoolLoanNotFound))
+    );
+}
+
+/// 18-decimal collateral priced at $2000, borrowable up to 70% LTV and
+/// liquidatable at 80% LTV; returns the token, its config and the listing action
+fn list_eth<'a>(s: &Setup<'a>) -> (TestTokenClient<'a>, CollateralConfig, u64) {
+    let eth = TestTokenClient::new(&s.env, &s.env.register(TestToken, ()));
+    let config = CollateralConfig {
+        oracle_asset: Asset::Other(symbol_short!("ETH")),
+        decimals: 18,
+        max_ltv_bps: 7000,
+        liquidation_threshold_cap: 8000,
+        liquidation_bonus_bps: 1000,
+    };
+    eth.mint(&s.borrower, &1_000_000_000_000_000_000);
+    s.oracle
+        .set_asset_price(&config.oracle_asset, &200_000_000_000_000_000);
+    let action = TimelockAction::SetCollateralConfig(eth.address.clone(), config.clone());
+    let action_id = run_timelocked(s, &s.admin, action);
+    (eth, config, action_id)
+}
+
+#[test]
+fn collateral_registry_lists_assets_with_their_own_risk_parameters() {
+    let s = setup();
+    let (eth, config, action_id) = list_eth(&s);
+
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "collateral_config_updated"), s.admin.clone(), eth.address.clone()),
+                config.clone(),
+            ),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_collateral_config(&eth.address), config);
+    assert_eq!(
+        s.client.get_collateral_assets(),
+        vec![&s.env, s.xlm.address.clone(), eth.address.clone()]
+    );
+    assert_eq!(s.client.get_collateral_price(&eth.address), 200_000_000_000_000_000);
+    assert_eq!(s.client.get_collateral_price(&s.xlm.address), XLM_PRICE);
+    assert_eq!(s.client.get_xlm_price(), XLM_PRICE);
+
+    // LTV must sit below the liquidation cap
+    let invalid = CollateralConfig {
+        max_ltv_bps: 8000,
+        ..config.clone()
+    };
+    assert_eq!(
+        s.client.try_queue_action(
+            &s.admin,
+            &TimelockAction::SetCollateralConfig(eth.address.clone(), invalid)
+        ),
+        Err(Ok(Error::InvalidCollateralConfig))
+    );
+    let unknown = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_get_collateral_config(&unknown),
+        Err(Ok(Error::UnsupportedCollateral))
+    );
+
+    // Offers may only accept listed, distinct assets
+    assert_eq!(
+        s.client.try_create_offer(
+            &s.lender,
+            &s.usdc.address,
+            &100_0000000,
+            &500,
+            &InterestMode::Simple,
+            &12000,
+            &11000,
+            &4,
+            &vec![&s.env, unknown],
+        ),
+        Err(Ok(Error::UnsupportedCollateral))
+    );
+    assert_eq!(
+        s.client.try_create_offer(
+            &s.lender,
+            &s.usdc.address,
+            &100_0000000,
+            &500,
+            &InterestMode::Simple,
+            &12000,
+            &11000,
+            &4,
+            &vec![&s.env, eth.address.clone(), eth.address.clone()],
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // An XLM-only offer does not take ETH
+    let xlm_offer = create_default_offer(&s);
+    assert_eq!(
+        s.client.try_borrow(
+            &s.borrower,
+            &xlm_offer,
+            &eth.address,
+            &100_000_000_000_000_000,
+            &50_0000000,
+            &4
+        ),
+        Err(Ok(Error::CollateralNotAccepted))
+    );
+
+    let offer_id = s.client.create_offer(
+        &s.lender,
+        &s.usdc.address,
+        &200_0000000,
+        &500,
+        &InterestMode::Simple,
+        &12000,
+        &11000,
+        &4,
+        &vec![&s.env, s.xlm.address.clone(), eth.address.clone()],
+    );
+    let filter = OfferFilter {
+        collateral_asset: Some(eth.address.clone()),
+        ..no_filter()
+    };
+    assert_eq!(
+        offer_ids(&s.client.list_offers(&SortOption::Newest, &0, &10, &filter)),
+        [offer_id]
+    );
+
+    // 0.1 ETH is worth $200; at 70% LTV (143% collateral) at most ~139.99 USDC
+    assert_eq!(
+        s.client.try_borrow(
+            &s.borrower,
+            &offer_id,
+            &eth.address,
+            &100_000_000_000_000_000,
+            &140_0000000,
+            &4
+        ),
+        Err(Ok(Error::InsufficientCollateral))
+    );
+    let loan_id = s.client.borrow(
+        &s.borrower,
+        &offer_id,
+        &eth.address,
+        &100_000_000_000_000_000,
+        &130_0000000,
+        &4,
+    );
+
+    // The asset's 80% liquidation LTV (125%) is stricter than the offer's 110%
+    let loan = s.client.get_loan(&loan_id);
+    assert_eq!(loan.collateral_token, eth.address);
+    assert_eq!(loan.liquidation_threshold, 12500);
+    assert_eq!(eth.balance(&s.client.address), 100_000_000_000_000_000);
+
+    let health = s.client.get_loan_health(&loan_id);
+    assert_eq!(health.collateral_value, 200_0000000);
+    assert!(!health.is_liquidatable);
+
+    // ETH at $1600: $160 against 130 USDC is 123%, while XLM is unaffected
+    s.oracle
+        .set_asset_price(&config.oracle_asset, &160_000_000_000_000_000);
+    assert!(s.client.is_liquidatable(&loan_id));
+
+    // Collateral comes back in ETH on repayment
+    s.usdc.mint(&s.borrower, &10_0000000);
+    s.client.repay(&s.borrower, &loan_id, &130_0000000);
+    assert_eq!(eth.balance(&s.borrower), 1_000_000_000_000_000_000);
+}
+
+#[test]
+fn offers_lend_listed_assets_priced_against_their_collateral() {
+    let s = setup();
+    run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFeeConfig(FeeConfig {
+            protocol_fee_bps: 2000,
+            liquidation_fee_bps: 0,
+        }),
+    );
+
+    // Unlisted tokens cannot be lent
+    let eurc = TestTokenClient::new(&s.env, &s.env.register(TestToken, ()));
+    eurc.mint(&s.lender, &1_000_000000);
+    let eurc_offer = |collateral: Address| {
+        s.client.try_create_offer(
+            &s.lender,
+            &eurc.address,
+            &100_000000,
+            &500,
+            &InterestMode::Simple,
+            &20000,
+            &12500,
+            &4,
+            &vec![&s.env, collateral],
+        )
+    };
+    assert_eq!(
+        eurc_offer(s.xlm.address.clone()),
+        Err(Ok(Error::UnsupportedLoanAsset))
+    );
+
+    // EURC has 6 decimals and trades at $1.10
+    let config = LoanAssetConfig {
+        oracle_asset: Asset::Other(symbol_short!("EURC")),
+        decimals: 6,
+    };
+    s.oracle
+        .set_asset_price(&config.oracle_asset, &110_000_000_000_000);
+    let action = TimelockAction::SetLoanAsset(eurc.address.clone(), config.clone());
+    let action_id = run_timelocked(&s, &s.admin, action);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "loan_asset_updated"), s.admin.clone(), eurc.address.clone()),
+                config.clone(),
+            ),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_loan_asset(&eurc.address), config);
+    assert_eq!(
+        s.client.get_loan_assets(),
+        vec![&s.env, s.usdc.address.clone(), eurc.address.clone()]
+    );
+    let invalid = LoanAssetConfig {
+        decimals: 19,
+        ..config.clone()
+    };
+    assert_eq!(
+        s.client.try_queue_action(
+            &s.admin,
+            &TimelockAction::SetLoanAsset(eurc.address.clone(), invalid)
+        ),
+        Err(Ok(Error::InvalidLoanAsset))
+    );
+
+    // An offer cannot take its own loan token as collateral
+    assert_eq!(eurc_offer(eurc.address.clone()), Err(Ok(Error::InvalidInput)));
+    let offer_id = eurc_offer(s.xlm.address.clone()).unwrap().unwrap();
+    assert_eq!(s.client.get_offer(&offer_id).loan_token, eurc.address);
+    let filter = OfferFilter {
+        loan_token: Some(eurc.address.clone()),
+        ..no_filter()
+    };
+    assert_eq!(
+        offer_ids(&s.client.list_offers(&SortOption::Newest, &0, &10, &filter)),
+        [offer_id]
+    );
+
+    // 1000 XLM at 0.15 / 1.10 is worth 136.363636 EURC, so 200% allows ~68.18 EURC
+    assert_eq!(
+        s.client.try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &69_000000, &4),
+        Err(Ok(Error::InsufficientCollateral))
+    );
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &60_000000, &4);
+    assert_eq!(s.client.get_loan(&loan_id).loan_token, eurc.address);
+    assert_eq!(eurc.balance(&s.borrower), 60_000000);
+    let health = s.client.get_loan_health(&loan_id);
+    assert_eq!(health.collateral_value, 136_363636);
+    assert_eq!(health.debt_value, 60_000000);
+
+    // One week of 5% interest is repaid in EURC and its fee is kept per token
+    advance_time(&s, 604800);
+    eurc.mint(&s.borrower, &3_000000);
+    s.client.repay(&s.borrower, &loan_id, &63_000000);
+    assert_eq!(s.client.get_protocol_fees(&eurc.address), 600000);
+    assert_eq!(s.client.get_protocol_fees(&s.usdc.address), 0);
+
+    let treasury = Address::generate(&s.env);
+    assert_eq!(s.client.claim_protocol_fees(&s.admin, &eurc.address, &treasury), 600000);
+    assert_eq!(eurc.balance(&treasury), 600000);
+    assert_eq!(s.usdc.balance(&treasury), 0);
+}
+
+#[test]
+fn single_price_spike_does_not_liquidate() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    let liquidator = Address::generate(&s.env);
+    let xlm = Asset::Other(symbol_short!("XLM"));
+
+    // One $0.05 tick: the 5-record TWAP is (0.05 + 4 * 0.15) / 5 = $0.13, or 173%
+    s.oracle.push_price(&xlm, &5_000_000_000_000);
+    assert_eq!(s.client.get_twap_records(), 5);
+    assert!(!s.client.is_liquidatable(&loan_id));
+    assert_eq!(s.client.get_loan_health(&loan_id).collateral_value, 130_0000000);
+    assert_eq!(
+        s.client.try_liquidate(&liquidator, &loan_id, &37_5000000),
+        Err(Ok(Error::NotLiquidatable))
+    );
+
+    // Releasing collateral prices it at the lower spot price
+    assert_eq!(
+        s.client.try_withdraw_collateral(&s.borrower, &loan_id, &1_0000000),
+        Err(Ok(Error::WithdrawalBreachesHealth))
+    );
+
+    // A drop that holds for the whole window does liquidate
+    for _ in 0..4 {
+        s.oracle.push_price(&xlm, &5_000_000_000_000);
+    }
+    assert!(s.client.is_liquidatable(&loan_id));
+}
+
+#[test]
+fn admin_sets_twap_window() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    s.oracle
+        .push_price(&Asset::Other(symbol_short!("XLM")), &5_000_000_000_000);
+    assert!(!s.client.is_liquidatable(&loan_id));
+
+    // A one-record window is the spot price
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetTwapRecords(1));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "twap_records_updated"), s.admin.clone()), 1_u32),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_twap_records(), 1);
+    assert!(s.client.is_liquidatable(&loan_id));
+
+    for records in [0, 21] {
+        assert_eq!(
+            s.client.try_queue_action(&s.admin, &TimelockAction::SetTwapRecords(records)),
+            Err(Ok(Error::InvalidTwapRecords))
+        );
+    }
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_queue_action(&stranger, &TimelockAction::SetTwapRecords(10)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn fallback_oracle_serves_prices_while_primary_is_stale() {
+    let s = setup();
+    let fallback = TestOracleClient::new(&s.env, &s.env.register(TestOracle, ()));
+    fallback.set_asset_price(&Asset::Other(symbol_short!("USDC")), &100_000_000_000_000);
+
+    let action_id = run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFallbackOracle(Some(fallback.address.clone())),
+    );
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(
+                &s,
+                (name(&s, "fallback_oracle_updated"), s.admin.clone()),
+                Some(fallback.address.clone()),
+            ),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_fallback_oracle(), Some(fallback.address.clone()));
+
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &4);
+    assert_eq!(s.client.get_oracle_source(), OracleSource::Primary);
+
+    // The primary stops updating and goes stale; the fallback keeps prices flowing
+    s.oracle.set_updated_at(&s.env.ledger().timestamp());
+    advance_time(&s, 600);
+    // Queries price from the fallback without recording the switch
+    assert!(!s.client.get_loan_health(&loan_id).is_liquidatable);
+    assert!(s.env.events().all().is_empty());
+    assert_eq!(s.client.get_oracle_source(), OracleSource::Primary);
+    s.client
+        .withdraw_collateral(&s.borrower, &loan_id, &100_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "oracle_switched"), fallback.address.clone()), OracleSource::Fallback),
+            event(
+                &s,
+                (name(&s, "collateral_withdrawn"), loan_id, s.borrower.clone()),
+                CollateralData {
+                    amount: 100_0000000,
+                    collateral_amount: 900_0000000,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_oracle_source(), OracleSource::Fallback);
+
+    // The primary recovers 20% away from the fallback: new debt and withdrawals halt
+    s.oracle.set_updated_at(&s.env.ledger().timestamp());
+    fallback.set_price(&12_000_000_000_000);
+    assert_eq!(
+        s.client.try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4),
+        Err(Ok(Error::OraclePriceDeviation))
+    );
+    assert_eq!(
+        s.client.try_withdraw_collateral(&s.borrower, &loan_id, &100_0000000),
+        Err(Ok(Error::OraclePriceDeviation))
+    );
+    // Liquidations still run, on the primary price under which the loan is healthy
+    assert_eq!(
+        s.client.try_liquidate(&Address::generate(&s.env), &loan_id, &10_0000000),
+        Err(Ok(Error::NotLiquidatable))
+    );
+
+    // Repayments and collateral top-ups never depend on a price
+    s.client.add_collateral(&s.borrower, &loan_id, &100_0000000);
+    s.client.repay(&s.borrower, &loan_id, &10_0000000);
+
+    // A wider allowed deviation lets the primary serve prices again
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetMaxOracleDeviation(2500));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "oracle_deviation_updated"), s.admin.clone()), 2500_u32),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    s.oracle.set_updated_at(&s.env.ledger().timestamp());
+    s.client
+        .withdraw_collateral(&s.borrower, &loan_id, &100_0000000);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "oracle_switched"), s.oracle.address.clone()), OracleSource::Primary),
+            event(
+                &s,
+                (name(&s, "collateral_withdrawn"), loan_id, s.borrower.clone()),
+                CollateralData {
+                    amount: 100_0000000,
+                    collateral_amount: 900_0000000,
+                },
+            ),
+        ]
+    );
+    assert_eq!(s.client.get_oracle_source(), OracleSource::Primary);
+
+    for deviation_bps in [0, 10001] {
+        assert_eq!(
+            s.client
+                .try_queue_action(&s.admin, &TimelockAction::SetMaxOracleDeviation(deviation_bps)),
+            Err(Ok(Error::InvalidOracleDeviation))
+        );
+    }
+}
+
+
+#[test]
+fn liquidations_run_on_the_primary_price_while_feeds_disagree() {
+    let s = setup();
+    let fallback = TestOracleClient::new(&s.env, &s.env.register(TestOracle, ()));
+    fallback.set_asset_price(&Asset::Other(symbol_short!("USDC")), &100_000_000_000_000);
+    run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFallbackOracle(Some(fallback.address.clone())),
+    );
+
+    // The primary drops 40% below the fallback
+    let loan_id = liquidatable_loan(&s);
+    seed_amm(&s, 9_000_000_000_000);
+    let offer_id = create_default_offer(&s);
+    assert_eq!(
+        s.client.try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &10_0000000, &4),
+        Err(Ok(Error::OraclePriceDeviation))
+    );
+
+    // The liquidation goes ahead at the primary price instead of waiting for the feeds to agree
+    assert!(s.client.is_liquidatable(&loan_id));
+    let liquidator = Address::generate(&s.env);
+    s.client.liquidate(&liquidator, &loan_id, &10_0000000);
+    assert_eq!(s.client.get_loan(&loan_id).borrowed_amount, 65_0000000);
+    assert_eq!(s.client.get_oracle_source(), OracleSource::Primary);
+
+    let bidder = Address::generate(&s.env);
+    s.usdc.mint(&bidder, &100_0000000);
+    s.client.start_auction(&bidder, &loan_id);
+    s.client.bid(&bidder, &loan_id, &10_0000000);
+    assert_eq!(s.client.get_loan(&loan_id).borrowed_amount, 55_0000000);
+}
+
+#[test]
+fn fallback_oracle_serves_prices_while_primary_traps() {
+    let s = setup();
+    let offer_id = create_default_offer(&s);
+
+    // Without a fallback a trapping primary halts borrowing
+    s.oracle.set_down(&true);
+    assert!(s
+        .client
+        .try_borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &4)
+        .is_err());
+
+    let fallback = TestOracleClient::new(&s.env, &s.env.register(TestOracle, ()));
+    fallback.set_asset_price(&Asset::Other(symbol_short!("USDC")), &100_000_000_000_000);
+    run_timelocked(
+        &s,
+        &s.admin,
+        TimelockAction::SetFallbackOracle(Some(fallback.address.clone())),
+    );
+
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &50_0000000, &4);
+    assert_eq!(s.client.get_oracle_source(), OracleSource::Fallback);
+    assert_eq!(s.client.get_loan(&loan_id).borrowed_amount, 50_0000000);
+    s.client
+        .withdraw_collateral(&s.borrower, &loan_id, &100_0000000);
+
+    // Once the primary answers again it takes over
+    s.oracle.set_down(&false);
+    s.client
+        .withdraw_collateral(&s.borrower, &loan_id, &100_0000000);
+    assert_eq!(s.client.get_oracle_source(), OracleSource::Primary);
+}
+#[test]
+fn risk_config_replaces_hardcoded_limits() {
+    let s = setup();
+    let config = s.client.get_risk_config();
+    assert_eq!(
+        config,
+        RiskConfig {
+            price_staleness_threshold: 300,
+            max_offers_per_user: 10,
+            max_requests_per_user: 10,
+            max_loans_per_user: 20,
+            max_collateral_ratio: 50000,
+            withdrawal_margin_bps: 2500,
+            max_liquidation_bonus_bps: 2000,
+            pool_min_collateral_ratio: 20000,
+            pool_liquidation_threshold: 12500,
+        }
+    );
+
+    let offer_id = create_default_offer(&s);
+    let loan_id = s
+        .client
+        .borrow(&s.borrower, &offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4);
+    // 700 XLM is 140% of the debt, short of the 125% threshold plus a 25% margin
+    assert_eq!(
+        s.client.try_withdraw_collateral(&s.borrower, &loan_id, &300_0000000),
+        Err(Ok(Error::WithdrawalBreachesHealth))
+    );
+
+    let config = RiskConfig {
+        price_staleness_threshold: 60,
+        max_offers_per_user: 1,
+        max_requests_per_user: 1,
+        max_collateral_ratio: 30000,
+        withdrawal_margin_bps: 500,
+        ..config
+    };
+    let action = TimelockAction::UpdateRiskConfig(config.clone());
+    let action_id = run_timelocked(&s, &s.admin, action);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "risk_config_updated"), s.admin.clone()), config.clone()),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_risk_config(), config);
+
+    // Every limit is read from the new config
+    s.client.withdraw_collateral(&s.borrower, &loan_id, &300_0000000);
+    assert_eq!(
+        s.client.try_create_offer(
+            &s.lender,
+            &s.usdc.address,
+            &100_0000000,
+            &500,
+            &InterestMode::Simple,
+            &20000,
+            &12500,
+            &4,
+            &vec![&s.env, s.xlm.address.clone()],
+        ),
+        Err(Ok(Error::TooManyOffers))
+    );
+    s.client.cancel_offer(&s.lender, &offer_id);
+    assert_eq!(
+        s.client.try_create_offer(
+            &s.lender,
+            &s.usdc.address,
+            &100_0000000,
+            &500,
+            &InterestMode::Simple,
+            &35000,
+            &12500,
+            &4,
+            &vec![&s.env, s.xlm.address.clone()],
+        ),
+        Err(Ok(Error::InvalidCollateralRatio))
+    );
+    let request = |borrower: &Address| {
+        s.client.try_create_borrow_request(
+            borrower,
+            &s.usdc.address,
+            &s.xlm.address,
+            &1_000_0000000,
+            &10_0000000,
+            &500,
+            &4,
+        )
+    };
+    assert!(request(&s.borrower).is_ok());
+    assert_eq!(request(&s.borrower), Err(Ok(Error::TooManyBorrowRequests)));
+    s.oracle.set_updated_at(&s.env.ledger().timestamp());
+    advance_time(&s, 120);
+    assert_eq!(
+        s.client.try_get_loan_health(&loan_id),
+        Err(Ok(Error::StalePriceData))
+    );
+
+    // The pool must stay liquidatable below its borrowing ratio, and withdrawals
+    // must stay possible under the collateral cap
+    let invalid = [
+        RiskConfig {
+            pool_liquidation_threshold: 20000,
+            ..config.clone()
+        },
+        RiskConfig {
+            withdrawal_margin_bps: 30000,
+            ..config.clone()
+        },
+        RiskConfig {
+            price_staleness_threshold: 0,
+            ..config.clone()
+        },
+        RiskConfig {
+            max_requests_per_user: 0,
+            ..config.clone()
+        },
+    ];
+    for invalid in invalid {
+        assert_eq!(
+            s.client.try_queue_action(&s.admin, &TimelockAction::UpdateRiskConfig(invalid)),
+            Err(Ok(Error::InvalidRiskConfig))
+        );
+    }
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_queue_action(&stranger, &TimelockAction::UpdateRiskConfig(config)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn risk_config_cannot_cap_bonuses_below_listed_collateral() {
+    let s = setup();
+    let config = s.client.get_risk_config();
+    let capped_at = |max_liquidation_bonus_bps: u32| {
+        TimelockAction::UpdateRiskConfig(RiskConfig {
+            max_liquidation_bonus_bps,
+            ..config.clone()
+        })
+    };
+
+    // XLM is listed with a 5% bonus
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &capped_at(400)),
+        Err(Ok(Error::InvalidRiskConfig))
+    );
+
+    // ETH is listed with a 10% bonus while an 8% cap waits in the queue
+    let action_id = s.client.queue_action(&s.admin, &capped_at(800));
+    list_eth(&s);
+    assert_eq!(
+        s.client.try_execute_action(&s.admin, &action_id),
+        Err(Ok(Error::InvalidRiskConfig))
+    );
+    assert_eq!(s.client.get_risk_config(), config);
+}
+
+#[test]
+fn migrate_is_admin_only_and_rejects_newer_schemas() {
+    let s = setup();
+    assert_eq!(s.client.get_schema_version(), SCHEMA_VERSION);
+
+    // Up to date: nothing to do
+    assert_eq!(s.client.migrate(&s.admin, &10), SCHEMA_VERSION);
+    assert_eq!(s.env.events().all(), vec![&s.env]);
+    assert_eq!(
+        s.client.try_migrate(&s.admin, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_migrate(&stranger, &10),
+        Err(Ok(Error::OnlyAdmin))
+    );
+
+    // Storage written by a newer build cannot be walked back
+    s.env.as_contract(&s.client.address, || {
+        s.env
+            .storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &(SCHEMA_VERSION + 1));
+    });
+    assert_eq!(
+        s.client.try_migrate(&s.admin, &10),
+        Err(Ok(Error::UnsupportedSchemaVersion))
+    );
+}
+
+#[test]
+fn upgrade_migrates_live_loans_and_offers_in_batches() {
+    let s = setup_with(release_build::WASM);
+    let offer_ids: std::vec::Vec<u64> = (0..3).map(|_| create_default_offer(&s)).collect();
+    let loan_ids: std::vec::Vec<u64> = offer_ids[..2]
+        .iter()
+        .map(|offer_id| {
+            s.client
+                .borrow(&s.borrower, offer_id, &s.xlm.address, &1_000_0000000, &75_0000000, &4)
+        })
+        .collect();
+    let loans: std::vec::Vec<Loan> = loan_ids.iter().map(|id| s.client.get_loan(id)).collect();
+    let offers: std::vec::Vec<LendingOffer> =
+        offer_ids.iter().map(|id| s.client.get_offer(id)).collect();
+
+    let wasm_hash = s.env.deployer().upload_contract_wasm(next_build::WASM);
+    run_timelocked(&s, &s.admin, TimelockAction::Upgrade(wasm_hash));
+    assert_eq!(s.client.get_schema_version(), SCHEMA_VERSION);
+
+    // Two loans and three offers to rewrite, two records per batch
+    assert_eq!(s.client.migrate(&s.admin, &2), SCHEMA_VERSION);
+    assert_eq!(s.env.events().all(), vec![&s.env]);
+    s.env.as_contract(&s.client.address, || {
+        assert!(s.env.storage().instance().has(&DataKey::MigrationCursor));
+    });
+    assert_eq!(s.client.migrate(&s.admin, &2), SCHEMA_VERSION);
+    assert_eq!(s.client.migrate(&s.admin, &2), SCHEMA_VERSION + 1);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "schema_migrated"), s.admin.clone()), SCHEMA_VERSION + 1),
+        ]
+    );
+    assert_eq!(s.client.get_schema_version(), SCHEMA_VERSION + 1);
+    assert_eq!(s.client.migrate(&s.admin, &2), SCHEMA_VERSION + 1);
+    for (loan_id, loan) in loan_ids.iter().zip(loans.iter()) {
+        assert_eq!(&s.client.get_loan(loan_id), loan);
+    }
+    for (offer_id, offer) in offer_ids.iter().zip(offers.iter()) {
+        assert_eq!(&s.client.get_offer(offer_id), offer);
+    }
+    s.env.as_contract(&s.client.address, || {
+        assert!(!s.env.storage().instance().has(&DataKey::MigrationCursor));
+    });
+
+    // The upgraded market repays and liquidates the loans it carried over
+    s.client.repay(&s.borrower, &loan_ids[0], &10_0000000);
+    assert_eq!(s.usdc.balance(&s.borrower), 140_0000000);
+    s.oracle.set_price(&9_000_000_000_000);
+    seed_amm(&s, 9_000_000_000_000);
+    let liquidator = Address::generate(&s.env);
+    s.client.liquidate(&liquidator, &loan_ids[1], &i128::MAX);
+    assert!(s.client.get_loan(&loan_ids[1]).borrowed_amount < 75_0000000);
+    assert!(s.usdc.balance(&liquidator) > 0);
+}
+
+#[test]
+fn upgrade_is_admin_only() {
+    let s = setup();
+    let stranger = Address::generate(&s.env);
+    let hash = BytesN::from_array(&s.env, &[0; 32]);
+    assert_eq!(
+        s.client.try_queue_action(&stranger, &TimelockAction::Upgrade(hash)),
+        Err(Ok(Error::OnlyAdmin))
+    );
+}
+
+#[test]
+fn roles_gate_operations_and_admin_moves_in_two_steps() {
+    let s = setup();
+    let pauser = Address::generate(&s.env);
+    let oracle_manager = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+
+    assert_eq!(
+        s.client.try_pause_contract(&pauser),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_grant_role(&stranger, &pauser, &Role::Pauser),
+        Err(Ok(Error::OnlyAdmin))
+    );
+    s.client.grant_role(&s.admin, &pauser, &Role::Pauser);
+    s.client.grant_role(&s.admin, &oracle_manager, &Role::OracleManager);
+    assert!(s.client.has_role(&pauser, &Role::Pauser));
+    assert!(!s.client.has_role(&pauser, &Role::OracleManager));
+
+    // Each role reaches only its own functions
+    s.client.pause_contract(&pauser);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "pause_updated"), pauser.clone()), s.client.get_pause_state()),
+        ]
+    );
+    assert_eq!(
+        s.client
+            .try_queue_action(&pauser, &TimelockAction::SetOracleAddress(stranger.clone())),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_pause_contract(&oracle_manager),
+        Err(Ok(Error::Unauthorized))
+    );
+    run_timelocked(
+        &s,
+        &oracle_manager,
+        TimelockAction::SetOracleAddress(s.oracle.address.clone()),
+    );
+    assert_eq!(
+        s.client
+            .try_queue_action(&oracle_manager, &TimelockAction::SetMaxInterestRate(2000)),
+        Err(Ok(Error::Unauthorized))
+    );
+    // The admin holds every role
+    s.client.unpause_contract(&s.admin);
+
+    s.client.revoke_role(&s.admin, &pauser, &Role::Pauser);
+    assert!(!s.client.has_role(&pauser, &Role::Pauser));
+    assert_eq!(
+        s.client.try_pause_contract(&pauser),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.client.try_revoke_role(&s.admin, &pauser, &Role::Pauser),
+        Err(Ok(Error::RoleNotHeld))
+    );
+
+    // The admin only changes once the proposed admin accepts
+    let new_admin = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_propose_admin(&stranger, &new_admin, &1000),
+        Err(Ok(Error::OnlyAdmin))
+    );
+    s.client.propose_admin(&s.admin, &new_admin, &1000);
+    assert_eq!(s.client.admin(), s.admin);
+    s.client.accept_admin();
+    assert_eq!(s.client.admin(), new_admin);
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &TimelockAction::SetMaxInterestRate(2000)),
+        Err(Ok(Error::Unauthorized))
+    );
+    run_timelocked(&s, &new_admin, TimelockAction::SetMaxInterestRate(2000));
+    // Granted roles outlive the admin that granted them
+    assert!(s.client.has_role(&oracle_manager, &Role::OracleManager));
+}
+
+#[test]
+fn timelock_queues_cancels_and_expires_actions() {
+    let s = setup();
+    let new_oracle = Address::generate(&s.env);
+    let action = TimelockAction::SetOracleAddress(new_oracle.clone());
+    assert_eq!(s.client.get_timelock_delay(), 172800);
+
+    let action_id = s.client.queue_action(&s.admin, &action);
+    let queued = QueuedAction {
+        action_id,
+        action: action.clone(),
+        proposer: s.admin.clone(),
+        eta: 1_000_000 + 172800,
+    };
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "action_queued"), action_id), queued.clone()),
+        ]
+    );
+    // Users see the pending change before it can run
+    assert_eq!(s.client.get_queued_actions(), vec![&s.env, queued.clone()]);
+    advance_time(&s, 172799);
+    assert_eq!(
+        s.client.try_execute_action(&s.admin, &action_id),
+        Err(Ok(Error::ActionNotReady))
+    );
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.client.try_cancel_action(&stranger, &action_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.client.cancel_action(&s.admin, &action_id);
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "action_cancelled"), action_id), s.admin.clone()),
+        ]
+    );
+    assert!(s.client.get_queued_actions().is_empty());
+    advance_time(&s, 1);
+    assert_eq!(
+        s.client.try_execute_action(&s.admin, &action_id),
+        Err(Ok(Error::ActionNotFound))
+    );
+
+    // An action left unrun past the grace period expires
+    let action_id = s.client.queue_action(&s.admin, &action);
+    advance_time(&s, 172800 + 1209600 + 1);
+    assert_eq!(
+        s.client.try_execute_action(&s.admin, &action_id),
+        Err(Ok(Error::ActionExpired))
+    );
+    s.client.cancel_action(&s.admin, &action_id);
+
+    // The delay itself only changes through the queue, and within bounds
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &TimelockAction::SetTimelockDelay(3600)),
+        Err(Ok(Error::InvalidTimelockDelay))
+    );
+    let action_id = run_timelocked(&s, &s.admin, TimelockAction::SetTimelockDelay(86400));
+    assert_eq!(
+        s.env.events().all(),
+        vec![
+            &s.env,
+            event(&s, (name(&s, "timelock_delay_updated"), s.admin.clone()), 86400_u64),
+            event(&s, (name(&s, "action_executed"), action_id), s.admin.clone()),
+        ]
+    );
+    assert_eq!(s.client.get_timelock_delay(), 86400);
+
+    // The queue is bounded so it can always be listed
+    for _ in 0..20 {
+        s.client.queue_action(&s.admin, &action);
+    }
+    assert_eq!(
+        s.client.try_queue_action(&s.admin, &action),
+        Err(Ok(Error::TooManyQueuedActions))
+    );
+}
+
+#[test]
+fn market_parameters_only_change_after_the_timelock_delay() {
+    let s = setup();
+    let eurc = Address::generate(&s.env);
+    let router = Address::generate(&s.env);
+    let xlm_config = CollateralConfig {
+        max_ltv_bps: 4000,
+        ..s.client.get_collateral_config(&s.xlm.address)
+    };
+    let eurc_config = LoanAssetConfig {
+        oracle_asset: Asset::Other(symbol_short!("EURC")),
+        decimals: 6,
+    };
+    let actions = [
+        TimelockAction::SetCollateralConfig(s.xlm.address.clone(), xlm_config.clone()),
+        TimelockAction::SetLoanAsset(eurc.clone(), eurc_config.clone()),
+        TimelockAction::SetDexRouter(router.clone()),
+        TimelockAction::SetMaxOracleDeviation(2500),
+        TimelockAction::SetTwapRecords(10),
+        TimelockAction::SetMaxSlippage(250),
+        TimelockAction::SetCloseFactor(7500),
+    ];
+    let mut action_ids = Vec::new(&s.env);
+    for action in actions.iter() {
+        action_ids.push_back(s.client.queue_action(&s.admin, action));
+    }
+
+    // Nothing takes effect before the ETA
+    advance_time(&s, 172799);
+    for action_id in action_ids.iter() {
+        assert_eq!(
+            s.client.try_execute_action(&s.admin, &action_id),
+            Err(Ok(Error::ActionNotReady))
+        );
+    }
+    assert_eq!(s.client.get_collateral_config(&s.xlm.address).max_ltv_bps, 5000);
+    assert_eq!(s.client.get_loan_assets(), vec![&s.env, s.usdc.address.clone()]);
+    assert_eq!(s.client.get_dex_router(), s.amm.address);
+    assert_eq!(s.client.get_max_oracle_deviation(), 500);
+    assert_eq!(s.client.get_twap_records(), 5);
+    assert_eq!(s.client.get_max_slippage(), 100);
+    assert_eq!(s.client.get_close_factor(), 5000);
+
+    advance_time(&s, 1);
+    for action_id in action_ids.iter() {
+        s.client.execute_action(&s.admin, &action_id);
+    }
+    assert_eq!(s.client.get_collateral_config(&s.xlm.address), xlm_config);
+    assert_eq!(s.client.get_loan_asset(&eurc), eurc_config);
+    assert_eq!(s.client.get_dex_router(), router);
+    assert_eq!(s.client.get_max_oracle_deviation(), 2500);
+    assert_eq!(s.client.get_twap_records(), 10);
+    assert_eq!(s.client.get_max_slippage(), 250);
+    assert_eq!(s.client.get_close_factor(), 7500);
+
+    // Risk managers queue liquidation parameters; loan tokens and routers stay with the admin
+    let risk_manager = Address::generate(&s.env);
+    s.client.grant_role(&s.admin, &risk_manager, &Role::RiskManager);
+    s.client
+        .queue_action(&risk_manager, &TimelockAction::SetCloseFactor(5000));
+    assert_eq!(
+        s.client
+            .try_queue_action(&risk_manager, &TimelockAction::SetDexRouter(s.amm.address.clone())),
+        Err(Ok(Error::OnlyAdmin))
+    );
+}
@@ -0,0 +1,187 @@
+//! Timelocked governance queue
+//!
+//! Actions that can hurt every open position at once (swapping the price source,
+//! changing the risk limits, rates, fees or liquidation parameters, replacing the
+//! code) are queued with an ETA instead of
+//! taking effect immediately. Users can watch the queue and exit before an action
+//! runs; a queued action can be cancelled until then, and expires if it is not run
+//! within the grace period.
+
+use crate::error::Error;
+use crate::events;
+use crate::pool;
+use crate::storage;
+use crate::types::{QueuedAction, Role, TimelockAction, MAX_QUEUED_ACTIONS, TIMELOCK_GRACE_PERIOD};
+use crate::validation;
+use soroban_sdk::{Address, Env};
+
+/// Role that may queue, cancel and run `action`; `None` leaves it to the admin
+fn required_role(action: &TimelockAction) -> Option<Role> {
+    match action {
+        TimelockAction::SetOracleAddress(_)
+        | TimelockAction::SetFallbackOracle(_)
+        | TimelockAction::SetMaxOracleDeviation(_)
+        | TimelockAction::SetTwapRecords(_) => Some(Role::OracleManager),
+        TimelockAction::UpdateRiskConfig(_)
+        | TimelockAction::SetCollateralConfig(_, _)
+        | TimelockAction::SetMaxSlippage(_)
+        | TimelockAction::SetCloseFactor(_)
+        | TimelockAction::SetMaxInterestRate(_)
+        | TimelockAction::SetAuctionConfig(_)
+        | TimelockAction::SetRateModel(_) => Some(Role::RiskManager),
+        TimelockAction::Upgrade(_)
+        | TimelockAction::SetTimelockDelay(_)
+        | TimelockAction::SetLoanAsset(_, _)
+        | TimelockAction::SetDexRouter(_)
+        | TimelockAction::SetFeeConfig(_) => None,
+    }
+}
+
+pub fn require_authorized(
+    env: &Env,
+    caller: &Address,
+    action: &TimelockAction,
+) -> Result<(), Error> {
+    match required_role(action) {
+        Some(role) => storage::require_role(env, caller, role),
+        None => storage::require_admin(env, caller),
+    }
+}
+
+/// Queue `action` to run once the current delay has passed
+pub fn queue(env: &Env, proposer: &Address, action: TimelockAction) -> Result<QueuedAction, Error> {
+    match &action {
+        TimelockAction::UpdateRiskConfig(config) => {
+            validation::validate_risk_config(config)?;
+            validation::validate_listed_collateral_bonuses(env, config)?
+        }
+        TimelockAction::SetTimelockDelay(delay) => validation::validate_timelock_delay(*delay)?,
+        TimelockAction::SetCollateralConfig(_, config) => {
+            validation::validate_collateral_config(env, config)?
+        }
+        TimelockAction::SetLoanAsset(_, config) => validation::validate_loan_asset(config)?,
+        TimelockAction::SetMaxOracleDeviation(deviation_bps) => {
+            validation::validate_oracle_deviation(*deviation_bps)?
+        }
+        TimelockAction::SetTwapRecords(records) => validation::validate_twap_records(*records)?,
+        TimelockAction::SetMaxSlippage(slippage_bps) => {
+            validation::validate_slippage(*slippage_bps)?
+        }
+        TimelockAction::SetCloseFactor(close_factor_bps) => {
+            validation::validate_close_factor(*close_factor_bps)?
+        }
+        TimelockAction::SetMaxInterestRate(max_rate) => {
+            validation::validate_max_interest_rate(*max_rate)?;
+            validation::validate_rate_model(&storage::get_rate_model(env), *max_rate)?
+        }
+        TimelockAction::SetAuctionConfig(config) => validation::validate_auction_config(config)?,
+        TimelockAction::SetFeeConfig(config) => validation::validate_fee_config(config)?,
+        TimelockAction::SetRateModel(model) => {
+            validation::validate_rate_model(model, storage::get_max_interest_rate(env))?
+        }
+        _ => {}
+    }
+    if storage::get_queued_action_ids(env).len() >= MAX_QUEUED_ACTIONS {
+        return Err(Error::TooManyQueuedActions);
+    }
+
+    let queued = QueuedAction {
+        action_id: storage::get_next_action_id(env),
+        action,
+        proposer: proposer.clone(),
+        eta: env.ledger().timestamp() + storage::get_timelock_delay(env),
+    };
+    storage::add_queued_action(env, &queued);
+    Ok(queued)
+}
+
+/// Run a queued action whose delay has passed, removing it from the queue
+pub fn execute(env: &Env, caller: &Address, queued: &QueuedAction) -> Result<(), Error> {
+    let now = env.ledger().timestamp();
+    if now < queued.eta {
+        return Err(Error::ActionNotReady);
+    }
+    if now > queued.eta + TIMELOCK_GRACE_PERIOD {
+        return Err(Error::ActionExpired);
+    }
+
+    storage::remove_queued_action(env, queued.action_id);
+    match &queued.action {
+        TimelockAction::SetOracleAddress(oracle) => {
+            storage::set_oracle_address(env, oracle);
+            events::oracle_updated(env, caller, oracle);
+        }
+        TimelockAction::SetFallbackOracle(oracle) => {
+            storage::set_fallback_oracle(env, oracle);
+            events::fallback_oracle_updated(env, caller, oracle.clone());
+        }
+        TimelockAction::UpdateRiskConfig(config) => {
+            // Collateral may have been listed with a higher bonus since the action was queued
+            validation::validate_listed_collateral_bonuses(env, config)?;
+            storage::set_risk_config(env, config);
+            events::risk_config_updated(env, caller, config.clone());
+        }
+        TimelockAction::Upgrade(wasm_hash) => {
+            env.deployer()
+                .update_current_contract_wasm(wasm_hash.clone());
+            events::contract_upgraded(env, caller, wasm_hash);
+        }
+        TimelockAction::SetTimelockDelay(delay) => {
+            storage::set_timelock_delay(env, *delay);
+            events::timelock_delay_updated(env, caller, *delay);
+        }
+        TimelockAction::SetCollateralConfig(token, config) => {
+            // The risk config's bonus cap may have changed since the action was queued
+            validation::validate_collateral_config(env, config)?;
+            storage::set_collateral_config(env, token, config);
+            events::collateral_config_updated(env, caller, token, config.clone());
+        }
+        TimelockAction::SetLoanAsset(token, config) => {
+            storage::set_loan_asset(env, token, config);
+            events::loan_asset_updated(env, caller, token, config.clone());
+        }
+        TimelockAction::SetDexRouter(router) => {
+            storage::set_dex_router(env, router);
+            events::dex_router_updated(env, caller, router);
+        }
+        TimelockAction::SetMaxOracleDeviation(deviation_bps) => {
+            storage::set_max_oracle_deviation(env, *deviation_bps);
+            events::oracle_deviation_updated(env, caller, *deviation_bps);
+        }
+        TimelockAction::SetTwapRecords(records) => {
+            storage::set_twap_records(env, *records);
+            events::twap_records_updated(env, caller, *records);
+        }
+        TimelockAction::SetMaxSlippage(slippage_bps) => {
+            storage::set_max_slippage(env, *slippage_bps);
+            events::max_slippage_updated(env, caller, *slippage_bps);
+        }
+        TimelockAction::SetCloseFactor(close_factor_bps) => {
+            storage::set_close_factor(env, *close_factor_bps);
+            events::close_factor_updated(env, caller, *close_factor_bps);
+        }
+        TimelockAction::SetMaxInterestRate(max_rate) => {
+            // The rate model may have changed since the action was queued
+            validation::validate_rate_model(&storage::get_rate_model(env), *max_rate)?;
+            storage::set_max_interest_rate(env, *max_rate);
+            events::max_interest_rate_updated(env, caller, *max_rate);
+        }
+        TimelockAction::SetAuctionConfig(config) => {
+            storage::set_auction_config(env, config);
+            events::auction_config_updated(env, caller, config.clone());
+        }
+        TimelockAction::SetFeeConfig(config) => {
+            storage::set_fee_config(env, config);
+            events::fee_config_updated(env, caller, config.clone());
+        }
+        TimelockAction::SetRateModel(model) => {
+            // The maximum interest rate may have changed since the action was queued
+            validation::validate_rate_model(model, storage::get_max_interest_rate(env))?;
+            // Interest up to now accrues at the old rates
+            pool::accrue(env)?;
+            storage::set_rate_model(env, model);
+            events::rate_model_updated(env, caller, model.clone());
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,19 @@
 //! Data types and structures for the Lending Market contract
 
-use soroban_sdk::{contracttype, Address, String, Vec};
+use crate::reflector::Asset;
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+
+/// How interest accrues on a loan
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InterestMode {
+    /// Simple per-second interest on the outstanding principal
+    Simple,
+    /// Principal and unpaid interest compound every second
+    CompoundPerSecond,
+    /// Principal and unpaid interest compound every full week
+    CompoundPerWeek,
+}
 
 /// Lending offer created by a lender
 #[contracttype]
@@ -10,37 +23,87 @@ pub struct LendingOffer {
     pub offer_id: u64,
     /// Address of the lender
     pub lender: Address,
-    /// Amount of USDC available to lend (with 7 decimals)
-    pub usdc_amount: i128,
+    /// Token being lent
+    pub loan_token: Address,
+    /// Amount of the loan token available to lend (in its decimals)
+    pub amount: i128,
     /// Weekly interest rate in basis points (e.g., 500 = 5%)
     pub weekly_interest_rate: u32,
+    /// How interest accrues on loans taken against this offer
+    pub interest_mode: InterestMode,
     /// Minimum collateral ratio in basis points (e.g., 20000 = 200% = max 50% LTV)
     pub min_collateral_ratio: u32,
     /// Liquidation threshold in basis points (e.g., 12500 = 125%)
     pub liquidation_threshold: u32,
     /// Maximum loan duration in weeks
     pub max_duration_weeks: u32,
+    /// Collateral tokens borrowers may post against this offer
+    pub collateral_assets: Vec<Address>,
     /// Whether this offer is active
     pub is_active: bool,
     /// Timestamp when offer was created
     pub created_at: u64,
 }
 
+/// One loan of a `borrow_best` call, as planned by `quote_borrow`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BorrowFill {
+    /// Offer the loan is taken against
+    pub offer_id: u64,
+    /// Amount of the loan token borrowed from the offer (in its decimals)
+    pub amount: i128,
+    /// Share of the collateral posted for this loan (in the collateral token's decimals)
+    pub collateral_amount: i128,
+    /// Weekly interest rate of the offer in basis points
+    pub interest_rate: u32,
+}
+
+/// Loan terms posted by a borrower, with the collateral escrowed up front
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BorrowRequest {
+    /// Unique identifier for this request
+    pub request_id: u64,
+    /// Address of the borrower
+    pub borrower: Address,
+    /// Token the borrower wants to borrow
+    pub loan_token: Address,
+    /// Token escrowed as collateral
+    pub collateral_token: Address,
+    /// Amount of collateral escrowed (in the collateral token's decimals)
+    pub collateral_amount: i128,
+    /// Amount of the loan token requested (in its decimals)
+    pub desired_amount: i128,
+    /// Highest weekly interest rate the borrower accepts, in basis points
+    pub max_rate: u32,
+    /// Loan duration in weeks, counted from when the request is filled
+    pub duration_weeks: u32,
+    /// Whether the request can still be filled
+    pub is_active: bool,
+    /// Timestamp when the request was created
+    pub created_at: u64,
+}
+
 /// Active loan position
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Loan {
     /// Unique identifier for this loan
     pub loan_id: u64,
-    /// Reference to the original offer
+    /// Reference to the original offer (0 for loans filled from a borrow request)
     pub offer_id: u64,
     /// Address of the borrower
     pub borrower: Address,
-    /// Address of the lender
+    /// Lender who funded the loan (payments go to the holder of its position NFT)
     pub lender: Address,
-    /// Amount of XLM deposited as collateral (with 7 decimals)
+    /// Token borrowed and repaid
+    pub loan_token: Address,
+    /// Token posted as collateral
+    pub collateral_token: Address,
+    /// Amount of collateral deposited (in the collateral token's decimals)
     pub collateral_amount: i128,
-    /// Amount of USDC borrowed (with 7 decimals)
+    /// Amount of the loan token borrowed (in its decimals)
     pub borrowed_amount: i128,
     /// Weekly interest rate in basis points
     pub interest_rate: u32,
@@ -48,10 +111,14 @@ pub struct Loan {
     pub start_time: u64,
     /// Last time interest was updated
     pub last_interest_update: u64,
-    /// Accumulated interest so far (in USDC with 7 decimals)
+    /// Accumulated interest so far (in the loan token's decimals)
     pub accumulated_interest: i128,
+    /// How interest accrues (copied from the offer)
+    pub interest_mode: InterestMode,
     /// Liquidation threshold in basis points
     pub liquidation_threshold: u32,
+    /// Timestamp after which the loan is in default
+    pub due_time: u64,
     /// Whether this loan is active
     pub is_active: bool,
 }
@@ -62,18 +129,49 @@ pub struct Loan {
 pub struct LoanHealth {
     /// Loan identifier
     pub loan_id: u64,
-    /// Current collateral value in USDC (with 7 decimals)
-    pub collateral_value_usd: i128,
-    /// Total debt including principal and interest (with 7 decimals)
-    pub debt_value_usd: i128,
+    /// Current collateral value in the loan token (in its decimals)
+    pub collateral_value: i128,
+    /// Total debt including principal and interest (in the loan token's decimals)
+    pub debt_value: i128,
     /// Collateralization ratio in basis points
     pub collateralization_ratio: u32,
-    /// XLM price that would trigger liquidation (with oracle decimals)
+    /// Collateral price in the loan token that would trigger liquidation (with oracle decimals)
     pub liquidation_price: i128,
     /// Health factor in basis points (10000 = 100%)
     pub health_factor: u32,
     /// Whether this loan can be liquidated
     pub is_liquidatable: bool,
+    /// Timestamp after which the loan is in default
+    pub due_time: u64,
+    /// Seconds left until the loan is due (0 once past due)
+    pub time_remaining: u64,
+    /// Whether the loan is past due and its collateral can be claimed by the lender
+    pub is_overdue: bool,
+}
+
+/// Oracle currently serving prices
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OracleSource {
+    /// The primary Reflector feed
+    Primary,
+    /// The fallback feed, used while the primary cannot serve a valid price
+    Fallback,
+}
+
+/// Roles the admin can grant for day-to-day operations
+/// The admin passes every role check
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// May pause and unpause the market
+    Pauser,
+    /// May change interest, collateral, liquidation and pool risk parameters
+    RiskManager,
+    /// May change the primary and fallback oracles and how prices are read
+    OracleManager,
+    /// May claim accrued protocol fees
+    FeeCollector,
 }
 
 /// Sort options for querying offers
@@ -82,48 +180,392 @@ pub struct LoanHealth {
 pub enum SortOption {
     /// Sort by best (lowest) interest rate
     BestRate,
-    /// Sort by highest amount available
+    /// Sort by highest amount available (in raw token units, so best combined with a loan token filter)
     HighestAmount,
     /// Sort by newest first
     Newest,
 }
 
+/// Optional filters applied when listing offers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferFilter {
+    /// Only include offers lending this token
+    pub loan_token: Option<Address>,
+    /// Only include offers with at least this much of their loan token available
+    pub min_amount: Option<i128>,
+    /// Only include offers with a weekly rate at or below this (basis points)
+    pub max_rate: Option<u32>,
+    /// Only include offers requiring at most this collateral ratio (basis points)
+    pub max_collateral_ratio: Option<u32>,
+    /// Only include offers accepting this collateral token
+    pub collateral_asset: Option<Address>,
+}
+
+/// Time-to-live policy applied to instance and persistent storage (in ledgers)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    /// Entries are extended once their remaining TTL drops below this
+    pub threshold: u32,
+    /// TTL entries are extended to
+    pub extend_to: u32,
+}
+
+/// Dutch auction selling a liquidatable loan's collateral at a growing discount
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Auction {
+    /// Loan whose collateral is being auctioned
+    pub loan_id: u64,
+    /// Address that started the auction
+    pub initiator: Address,
+    /// Timestamp the auction started (discount is 0 at this point)
+    pub start_time: u64,
+}
+
+/// Dutch auction discount curve
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionConfig {
+    /// Maximum collateral discount in basis points
+    pub max_discount_bps: u32,
+    /// Seconds for the discount to grow linearly from 0 to the maximum
+    pub duration: u64,
+}
+
+/// Protocol fees charged by the market
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Share of all interest paid kept by the protocol, in basis points
+    pub protocol_fee_bps: u32,
+    /// Fee on debt repaid through DEX liquidations, in basis points
+    pub liquidation_fee_bps: u32,
+}
+
+/// Market-wide risk limits, adjustable by the admin without redeploying
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RiskConfig {
+    /// Maximum age of an oracle price in seconds
+    pub price_staleness_threshold: u64,
+    /// Maximum number of offers a lender can hold
+    pub max_offers_per_user: u32,
+    /// Maximum number of open borrow requests a borrower can hold
+    pub max_requests_per_user: u32,
+    /// Maximum number of loans a borrower can hold
+    pub max_loans_per_user: u32,
+    /// Highest collateral ratio an offer may require, in basis points
+    pub max_collateral_ratio: u32,
+    /// Headroom above the liquidation threshold kept after a collateral withdrawal, in basis points
+    pub withdrawal_margin_bps: u32,
+    /// Upper bound for a collateral asset's liquidator bonus, in basis points
+    pub max_liquidation_bonus_bps: u32,
+    /// Minimum collateral ratio for pool loans, in basis points
+    pub pool_min_collateral_ratio: u32,
+    /// Liquidation threshold for pool loans, in basis points
+    pub pool_liquidation_threshold: u32,
+}
+
+/// Operations halted by the pause flags
+/// Collateral top-ups are never paused, so borrowers can always defend a position
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PauseState {
+    /// New P2P offers and pool deposits
+    pub offers: bool,
+    /// New P2P and pool loans
+    pub borrows: bool,
+    /// Withdrawals of idle offer funds, pool shares and loan collateral
+    pub withdrawals: bool,
+    /// Liquidations, auctions and lender claims on defaulted loans
+    pub liquidations: bool,
+    /// Loan repayments, left as they are by `pause_contract`
+    pub repayments: bool,
+}
+
+/// Operation guarded by one of the pause flags
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    Offers,
+    Borrows,
+    Withdrawals,
+    Liquidations,
+    Repayments,
+}
+
+/// Admin action that only takes effect once the timelock delay has passed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockAction {
+    /// Replace the primary oracle
+    SetOracleAddress(Address),
+    /// Set or clear the fallback oracle
+    SetFallbackOracle(Option<Address>),
+    /// Replace the market-wide risk limits; open P2P loans keep the liquidation
+    /// threshold they were taken with, while pool loans follow the new one
+    UpdateRiskConfig(RiskConfig),
+    /// Replace the contract code with an uploaded wasm build, whose storage is
+    /// brought up to date with `migrate`
+    Upgrade(BytesN<32>),
+    /// Change the delay of actions queued afterwards, in seconds
+    SetTimelockDelay(u64),
+    /// List a collateral token or update its risk parameters; open loans keep
+    /// the liquidation threshold they were taken with
+    SetCollateralConfig(Address, CollateralConfig),
+    /// List a token that offers may lend, or update how it is priced
+    SetLoanAsset(Address, LoanAssetConfig),
+    /// Replace the DEX router used to sell collateral on liquidation
+    /// The constructor sets the first router; every later change is timelocked
+    SetDexRouter(Address),
+    /// Change how far the primary and fallback prices may drift apart, in basis points
+    SetMaxOracleDeviation(u32),
+    /// Change how many oracle records are averaged into the TWAP
+    SetTwapRecords(u32),
+    /// Change the maximum slippage accepted on liquidation swaps, in basis points
+    SetMaxSlippage(u32),
+    /// Change the maximum share of a loan's debt one liquidation repays, in basis points
+    SetCloseFactor(u32),
+    /// Change the highest weekly interest rate offers and the pool may charge, in basis points
+    SetMaxInterestRate(u32),
+    /// Replace the Dutch auction discount curve
+    SetAuctionConfig(AuctionConfig),
+    /// Replace the protocol fees on interest and liquidations
+    SetFeeConfig(FeeConfig),
+    /// Replace the pool's utilization curve; interest up to the change accrues at the old rates
+    SetRateModel(RateModel),
+}
+
+/// Action waiting in the timelock queue
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    pub action_id: u64,
+    pub action: TimelockAction,
+    /// Account that queued the action
+    pub proposer: Address,
+    /// Earliest timestamp the action may run
+    pub eta: u64,
+}
+
+/// Risk parameters for a token accepted as collateral
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralConfig {
+    /// Reflector asset the token is priced as
+    pub oracle_asset: Asset,
+    /// Decimals of the token's amounts
+    pub decimals: u32,
+    /// Maximum loan-to-value when borrowing, in basis points (5000 = 200% collateral)
+    pub max_ltv_bps: u32,
+    /// Highest loan-to-value a position may reach before it is liquidatable, in
+    /// basis points (8000 = liquidatable at 125% collateral)
+    pub liquidation_threshold_cap: u32,
+    /// Bonus paid to liquidators on the debt they repay, in basis points
+    pub liquidation_bonus_bps: u32,
+}
+
+/// Token that offers may lend
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanAssetConfig {
+    /// Reflector asset the token is priced as
+    pub oracle_asset: Asset,
+    /// Decimals of the token's amounts
+    pub decimals: u32,
+}
+
+/// Kinked utilization curve for the variable-rate pool (weekly rates in basis points)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateModel {
+    /// Borrow rate at 0% utilization
+    pub base_rate: u32,
+    /// Rate added between 0% and optimal utilization
+    pub slope1: u32,
+    /// Utilization in basis points where the curve kinks
+    pub optimal_utilization: u32,
+    /// Rate added between optimal and 100% utilization
+    pub slope2: u32,
+}
+
+/// Accounting for the pooled, variable-rate USDC market
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolState {
+    /// USDC held by the pool and available to borrow or withdraw
+    pub cash: i128,
+    /// Sum of borrowers' scaled debt (debt = scaled debt × borrow index)
+    pub total_scaled_debt: i128,
+    /// Cumulative borrow interest factor with 18 decimals
+    pub borrow_index: i128,
+    /// Protocol share of pool interest not yet moved to the treasury
+    pub reserves: i128,
+    /// Last time pool interest was accrued
+    pub last_update: u64,
+}
+
+/// Variable-rate loan drawn from the pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolLoan {
+    /// Unique identifier for this pool loan
+    pub loan_id: u64,
+    /// Address of the borrower
+    pub borrower: Address,
+    /// Token posted as collateral
+    pub collateral_token: Address,
+    /// Amount of collateral deposited (in the collateral token's decimals)
+    pub collateral_amount: i128,
+    /// Debt divided by the borrow index when it was taken or last repaid
+    pub scaled_debt: i128,
+    /// Timestamp when loan was created
+    pub start_time: u64,
+    /// Whether this loan is active
+    pub is_active: bool,
+}
+
+/// Current rates of the variable-rate pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolRates {
+    /// Share of pool assets lent out in basis points
+    pub utilization: u32,
+    /// Weekly borrow rate in basis points
+    pub borrow_rate: u32,
+    /// Weekly rate earned by lenders in basis points
+    pub supply_rate: u32,
+}
+
 /// Storage keys for the contract
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    /// Contract administrator
-    Admin,
-    /// USDC token address
+    /// USDC token address (the pool's asset)
     UsdcToken,
-    /// XLM token address (native)
-    XlmToken,
+    /// Tokens offers may lend
+    LoanAssets,
+    /// Pricing details of a lendable token
+    LoanAsset(Address),
+    /// Tokens registered as collateral
+    CollateralAssets,
+    /// Risk parameters of a collateral token
+    CollateralConfig(Address),
     /// Reflector oracle contract address
     OracleAddress,
+    /// Secondary oracle consulted when the primary fails
+    FallbackOracle,
+    /// Oracle that served the most recent price
+    OracleSource,
+    /// Maximum disagreement between the two oracles in basis points
+    MaxOracleDeviation,
+    /// Number of oracle records averaged into the TWAP
+    TwapRecords,
+    /// DEX router used to sell collateral on liquidation
+    DexRouter,
+    /// Loan position NFT contract whose holders are paid as lenders
+    PositionNft,
+    /// First loan ID minted a position token; earlier loans pay their original lender
+    PositionNftFirstLoan,
+    /// Maximum slippage accepted on liquidation swaps in basis points
+    MaxSlippage,
+    /// Maximum share of a loan's debt repaid by one liquidation in basis points
+    CloseFactor,
+    /// Dutch auction discount curve
+    AuctionConfig,
+    /// Protocol fee configuration
+    FeeConfig,
+    /// Market-wide risk limits
+    RiskConfig,
+    /// Unclaimed protocol fees held by the contract in a token
+    ProtocolFees(Address),
+    /// Variable-rate pool utilization curve
+    RateModel,
+    /// Variable-rate pool accounting
+    PoolState,
+    /// Next pool loan ID counter
+    NextPoolLoanId,
     /// Next offer ID counter
     NextOfferId,
     /// Next loan ID counter
     NextLoanId,
+    /// Next borrow request ID counter
+    NextRequestId,
+    /// Next timelock action ID counter
+    NextActionId,
+    /// Seconds between queueing a timelocked action and running it
+    TimelockDelay,
+    /// IDs of the actions waiting in the timelock queue
+    QueuedActions,
+    /// Timelocked action by ID
+    QueuedAction(u64),
     /// Maximum allowed interest rate in basis points (e.g., 3000 = 30%)
     MaxInterestRate,
-    /// Contract paused state
-    IsPaused,
+    /// Per-operation pause flags
+    PauseState,
     /// Reentrancy lock
     Locked,
+    /// Storage TTL policy
+    TtlConfig,
+    /// Layout version of the stored loans and offers
+    SchemaVersion,
+    /// Last loan and offer IDs rewritten by an unfinished migration step
+    MigrationCursor,
     /// Individual offer by ID
     Offer(u64),
     /// Individual loan by ID
     Loan(u64),
     /// List of offer IDs for a user
     UserOffers(Address),
-    /// List of loan IDs where user is borrower
+    /// List of open loan IDs where user is borrower
     UserLoansAsBorrower(Address),
-    /// List of loan IDs where user is lender
+    /// List of loan IDs a user funded that have no position token
     UserLoansAsLender(Address),
-    /// List of all active offer IDs
-    ActiveOffers,
-    /// List of all active loan IDs
-    ActiveLoans,
+    /// List of outstanding loan IDs taken against an offer
+    OfferLoans(u64),
+    /// Number of active offer IDs
+    ActiveOffersLen,
+    /// Page of active offer IDs
+    ActiveOffersPage(u32),
+    /// Position of an offer ID in the active offer index
+    ActiveOfferPosition(u64),
+    /// Number of active loan IDs
+    ActiveLoansLen,
+    /// Page of active loan IDs
+    ActiveLoansPage(u32),
+    /// Position of a loan ID in the active loan index
+    ActiveLoanPosition(u64),
+    /// Dutch auction running for a loan
+    Auction(u64),
+    /// Individual pool loan by ID
+    PoolLoan(u64),
+    /// List of open pool loan IDs for a user
+    UserPoolLoans(Address),
+    /// Number of active pool loan IDs
+    ActivePoolLoansLen,
+    /// Page of active pool loan IDs
+    ActivePoolLoansPage(u32),
+    /// Position of a pool loan ID in the active pool loan index
+    ActivePoolLoanPosition(u64),
+    /// Individual borrow request by ID
+    BorrowRequest(u64),
+    /// List of open borrow request IDs for a user
+    UserBorrowRequests(Address),
+    /// Number of active borrow request IDs
+    ActiveRequestsLen,
+    /// Page of active borrow request IDs
+    ActiveRequestsPage(u32),
+    /// Position of a borrow request ID in the active request index
+    ActiveRequestPosition(u64),
+    /// Weekly rates at which a loan token has offers with funds left, lowest first
+    OfferRates(Address),
+    /// Number of a loan token's offers with funds left at a weekly rate
+    OffersAtRateLen(Address, u32),
+    /// Page of the IDs of a loan token's offers with funds left at a weekly rate
+    OffersAtRatePage(Address, u32, u32),
+    /// Position of an offer ID in the index of its rate
+    OfferRatePosition(u64),
 }
 
 /// Price data from oracle
@@ -139,7 +581,52 @@ pub struct PriceData {
 /// Constants for the contract
 pub const BASIS_POINTS: u32 = 10000;
 pub const SECONDS_PER_WEEK: u64 = 604800;
-pub const MAX_OFFERS_PER_USER: u32 = 10;
-pub const MAX_LOANS_PER_USER: u32 = 20;
-pub const PRICE_STALENESS_THRESHOLD: u64 = 300; // 5 minutes
-pub const LIQUIDATION_BONUS_BPS: u32 = 500; // 5% bonus to liquidator
+pub const WEEKS_PER_YEAR: u32 = 52;
+pub const DEFAULT_MAX_OFFERS_PER_USER: u32 = 10;
+pub const DEFAULT_MAX_REQUESTS_PER_USER: u32 = 10;
+pub const DEFAULT_MAX_LOANS_PER_USER: u32 = 20;
+pub const DEFAULT_PRICE_STALENESS_THRESHOLD: u64 = 300; // 5 minutes
+pub const DEFAULT_MAX_COLLATERAL_RATIO: u32 = 50000; // 500%, anything higher is likely a typo
+pub const DEFAULT_WITHDRAWAL_MARGIN_BPS: u32 = 2500; // 25% above the liquidation threshold
+pub const DEFAULT_TWAP_RECORDS: u32 = 5; // 25 minutes of 5-minute records
+pub const MAX_TWAP_RECORDS: u32 = 20; // Reflector keeps at most 20 records per query
+pub const DEFAULT_MAX_ORACLE_DEVIATION_BPS: u32 = 500; // 5% between primary and fallback
+pub const XLM_LIQUIDATION_BONUS_BPS: u32 = 500; // 5% bonus to liquidators of XLM
+pub const DEFAULT_MAX_LIQUIDATION_BONUS_BPS: u32 = 2000; // 20% upper bound for a collateral asset
+pub const XLM_MAX_LTV_BPS: u32 = 5000; // 200% collateral to borrow against XLM
+pub const XLM_LIQUIDATION_THRESHOLD_CAP: u32 = 8000; // XLM loans liquidatable by 125%
+pub const MAX_COLLATERAL_ASSETS: u32 = 10; // per offer
+pub const MAX_PAGE_SIZE: u32 = 50; // offers one `list_offers` page returns
+pub const MAX_OFFER_SCAN: u32 = 80; // index entries and offers `list_offers` reads, inside the 100-entry footprint
+pub const MAX_BEST_OFFER_SCAN: u32 = 20; // offers `borrow_best` reads, cheapest first, in one call
+pub const MAX_BEST_FILLS: u32 = 5; // loans `borrow_best` opens in one call
+pub const MAX_OFFER_RATES: u32 = 100; // distinct weekly rates a loan token's offers may use at once
+pub const MAX_TOKEN_DECIMALS: u32 = 18;
+pub const MAX_INTEREST_RATE: u32 = 10000; // 100% weekly upper bound for the admin setting
+pub const USDC_DECIMALS: u32 = 7;
+pub const DEFAULT_CLOSE_FACTOR_BPS: u32 = 5000; // 50% of the debt per liquidation
+pub const DEFAULT_AUCTION_MAX_DISCOUNT_BPS: u32 = 2000; // 20% below the oracle price
+pub const DEFAULT_AUCTION_DURATION: u64 = 3600; // 1 hour to reach the maximum discount
+pub const MAX_AUCTION_DISCOUNT_BPS: u32 = 5000; // 50% upper bound for the admin setting
+pub const MAX_PROTOCOL_FEE_BPS: u32 = 5000; // at most half of the interest
+pub const MAX_LIQUIDATION_FEE_BPS: u32 = 1000; // at most 10% of the debt repaid
+pub const DEFAULT_POOL_BASE_RATE: u32 = 10; // 0.1% weekly at 0% utilization
+pub const DEFAULT_POOL_SLOPE1: u32 = 100; // +1% weekly up to the kink
+pub const DEFAULT_POOL_OPTIMAL_UTILIZATION: u32 = 8000; // kink at 80% utilization
+pub const DEFAULT_POOL_SLOPE2: u32 = 1000; // +10% weekly from the kink to 100%
+pub const DEFAULT_POOL_MIN_COLLATERAL_RATIO: u32 = 20000; // 200% to borrow from the pool
+pub const DEFAULT_POOL_LIQUIDATION_THRESHOLD: u32 = 12500; // pool loans liquidatable at 125%
+pub const SHARE_TOKEN_DECIMALS: u32 = 7; // pool shares use USDC's decimals
+pub const SHARE_TOKEN_NAME: &str = "Lending Market Pool Share";
+pub const SHARE_TOKEN_SYMBOL: &str = "lmUSDC";
+pub const SHARE_UNIT: i128 = 1_0000000; // one whole pool share
+pub const DEFAULT_MAX_SLIPPAGE_BPS: u32 = 100; // 1% below the oracle price
+pub const MAX_SLIPPAGE_BPS: u32 = 2000; // 20% upper bound for the admin setting
+pub const DAY_IN_LEDGERS: u32 = 17280; // ~5 seconds per ledger
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 30 * DAY_IN_LEDGERS;
+pub const DEFAULT_TTL_THRESHOLD: u32 = DEFAULT_TTL_EXTEND_TO - DAY_IN_LEDGERS;
+pub const DEFAULT_TIMELOCK_DELAY: u64 = 172800; // 2 days' notice before an action runs
+pub const MIN_TIMELOCK_DELAY: u64 = 86400; // 1 day
+pub const MAX_TIMELOCK_DELAY: u64 = 2592000; // 30 days
+pub const TIMELOCK_GRACE_PERIOD: u64 = 1209600; // 14 days to run an action once ready
+pub const MAX_QUEUED_ACTIONS: u32 = 20;
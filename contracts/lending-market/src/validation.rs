@@ -1,10 +1,16 @@
 //! Input validation and guard functions
 
 use crate::error::Error;
-use crate::oracle;
+use crate::oracle::{self, Valuation};
 use crate::storage;
-use crate::types::{BASIS_POINTS, MAX_LOANS_PER_USER, MAX_OFFERS_PER_USER};
-use soroban_sdk::{Address, Env};
+use crate::types::{
+    AuctionConfig, CollateralConfig, FeeConfig, LoanAssetConfig, OfferFilter, RateModel,
+    RiskConfig, SortOption, TtlConfig, BASIS_POINTS, MAX_AUCTION_DISCOUNT_BPS, MAX_COLLATERAL_ASSETS,
+    MAX_INTEREST_RATE, MAX_LIQUIDATION_FEE_BPS, MAX_OFFER_RATES, MAX_PAGE_SIZE,
+    MAX_PROTOCOL_FEE_BPS, MAX_SLIPPAGE_BPS, MAX_TIMELOCK_DELAY, MAX_TOKEN_DECIMALS,
+    MAX_TWAP_RECORDS, MIN_TIMELOCK_DELAY,
+};
+use soroban_sdk::{Address, Env, Vec};
 
 /// Validate interest rate is within allowed range
 pub fn validate_interest_rate(env: &Env, rate: u32) -> Result<(), Error> {
@@ -20,16 +26,26 @@ pub fn validate_interest_rate(env: &Env, rate: u32) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validate the maximum interest rate setting
+/// Must be positive and at most 100% weekly
+pub fn validate_max_interest_rate(max_rate: u32) -> Result<(), Error> {
+    if max_rate == 0 || max_rate > MAX_INTEREST_RATE {
+        return Err(Error::InvalidInterestRate);
+    }
+
+    Ok(())
+}
+
 /// Validate collateral ratio is reasonable
 /// Should be at least 100% (10000 basis points) and typically 150-300%
-pub fn validate_collateral_ratio(ratio: u32) -> Result<(), Error> {
+pub fn validate_collateral_ratio(ratio: u32, max_ratio: u32) -> Result<(), Error> {
     if ratio < BASIS_POINTS {
         // Minimum 100% collateral
         return Err(Error::InvalidCollateralRatio);
     }
 
-    if ratio > 50000 {
-        // Maximum 500% (probably misconfigured)
+    if ratio > max_ratio {
+        // Above the risk config's cap (probably misconfigured)
         return Err(Error::InvalidCollateralRatio);
     }
 
@@ -85,26 +101,115 @@ pub fn validate_collateral_amount(amount: i128) -> Result<(), Error> {
 /// Validate user doesn't have too many offers
 pub fn validate_offer_limit(env: &Env, user: &Address) -> Result<(), Error> {
     let user_offers = storage::get_user_offers(env, user);
-    if user_offers.len() >= MAX_OFFERS_PER_USER {
+    if user_offers.len() >= storage::get_risk_config(env).max_offers_per_user {
         return Err(Error::TooManyOffers);
     }
 
     Ok(())
 }
 
-/// Validate user doesn't have too many loans
+/// Validate an offer at `rate` fits in the loan token's rate index
+/// A rate already in use always fits; a new one needs a free slot
+pub fn validate_offer_rate_limit(env: &Env, loan_token: &Address, rate: u32) -> Result<(), Error> {
+    let rates = storage::get_offer_rates(env, loan_token);
+    if rates.binary_search(rate).is_err() && rates.len() >= MAX_OFFER_RATES {
+        return Err(Error::TooManyOfferRates);
+    }
+
+    Ok(())
+}
+
+/// Validate user doesn't have too many open borrow requests
+pub fn validate_request_limit(env: &Env, user: &Address) -> Result<(), Error> {
+    let user_requests = storage::get_user_borrow_requests(env, user);
+    if user_requests.len() >= storage::get_risk_config(env).max_requests_per_user {
+        return Err(Error::TooManyBorrowRequests);
+    }
+
+    Ok(())
+}
+
+/// Validate user doesn't have too many open loans
 pub fn validate_loan_limit(env: &Env, user: &Address) -> Result<(), Error> {
     let user_loans = storage::get_user_loans_as_borrower(env, user);
-    if user_loans.len() >= MAX_LOANS_PER_USER {
+    if user_loans.len() >= storage::get_risk_config(env).max_loans_per_user {
+        return Err(Error::TooManyLoans);
+    }
+
+    Ok(())
+}
+
+/// Validate user doesn't have too many open pool loans
+pub fn validate_pool_loan_limit(env: &Env, user: &Address) -> Result<(), Error> {
+    let user_loans = storage::get_user_pool_loans(env, user);
+    if user_loans.len() >= storage::get_risk_config(env).max_loans_per_user {
         return Err(Error::TooManyLoans);
     }
 
     Ok(())
 }
 
+/// Validate a collateral asset's risk parameters
+/// LTV must be positive and below the liquidation threshold cap, which is at most 100%
+pub fn validate_collateral_config(env: &Env, config: &CollateralConfig) -> Result<(), Error> {
+    if config.max_ltv_bps == 0
+        || config.max_ltv_bps >= config.liquidation_threshold_cap
+        || config.liquidation_threshold_cap > BASIS_POINTS
+    {
+        return Err(Error::InvalidCollateralConfig);
+    }
+
+    if config.liquidation_bonus_bps > storage::get_risk_config(env).max_liquidation_bonus_bps {
+        return Err(Error::InvalidCollateralConfig);
+    }
+
+    if config.decimals > MAX_TOKEN_DECIMALS {
+        return Err(Error::InvalidCollateralConfig);
+    }
+
+    Ok(())
+}
+
+/// Validate a lendable token's pricing details
+pub fn validate_loan_asset(config: &LoanAssetConfig) -> Result<(), Error> {
+    if config.decimals > MAX_TOKEN_DECIMALS {
+        return Err(Error::InvalidLoanAsset);
+    }
+
+    Ok(())
+}
+
+/// Validate the collateral assets an offer accepts are listed, distinct and
+/// not the token being lent
+pub fn validate_offer_collateral(
+    env: &Env,
+    loan_token: &Address,
+    assets: &Vec<Address>,
+) -> Result<(), Error> {
+    if assets.is_empty() || assets.len() > MAX_COLLATERAL_ASSETS {
+        return Err(Error::InvalidInput);
+    }
+
+    if assets.contains(loan_token) {
+        return Err(Error::InvalidInput);
+    }
+
+    for (i, asset) in assets.iter().enumerate() {
+        storage::get_collateral_config(env, &asset)?;
+
+        if assets.first_index_of(&asset) != Some(i as u32) {
+            return Err(Error::InvalidInput);
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate borrower has sufficient collateral for requested amount
 pub fn validate_sufficient_collateral(
     env: &Env,
+    config: &CollateralConfig,
+    loan_asset: &LoanAssetConfig,
     collateral_amount: i128,
     borrow_amount: i128,
     min_collateral_ratio: u32,
@@ -112,9 +217,25 @@ pub fn validate_sufficient_collateral(
     // Get oracle address
     let oracle_address = storage::get_oracle_address(env)?;
 
-    // Calculate collateral value in USDC
-    let collateral_value = oracle::xlm_to_usdc_value(env, &oracle_address, collateral_amount)?;
+    // Value collateral in the loan token at the lower of spot and TWAP
+    let collateral_value = oracle::collateral_value(
+        env,
+        &oracle_address,
+        config,
+        loan_asset,
+        collateral_amount,
+        Valuation::Conservative,
+    )?;
+
+    validate_collateral_value(collateral_value, borrow_amount, min_collateral_ratio)
+}
 
+/// Validate that collateral already valued in the loan token covers a borrow amount
+pub fn validate_collateral_value(
+    collateral_value: i128,
+    borrow_amount: i128,
+    min_collateral_ratio: u32,
+) -> Result<(), Error> {
     // Calculate maximum borrowable amount
     // max_borrow = (collateral_value * BASIS_POINTS) / min_collateral_ratio
     let max_borrow = collateral_value
@@ -159,6 +280,8 @@ pub fn validate_loan_duration(duration_weeks: u32, max_duration: u32) -> Result<
 /// Validate collateral withdrawal won't breach health threshold
 pub fn validate_collateral_withdrawal(
     env: &Env,
+    config: &CollateralConfig,
+    loan_asset: &LoanAssetConfig,
     current_collateral: i128,
     withdrawal_amount: i128,
     total_debt: i128,
@@ -179,8 +302,15 @@ pub fn validate_collateral_withdrawal(
     // Get oracle address
     let oracle_address = storage::get_oracle_address(env)?;
 
-    // Calculate new collateral value
-    let new_collateral_value = oracle::xlm_to_usdc_value(env, &oracle_address, new_collateral)?;
+    // Value the remaining collateral at the lower of spot and TWAP
+    let new_collateral_value = oracle::collateral_value(
+        env,
+        &oracle_address,
+        config,
+        loan_asset,
+        new_collateral,
+        Valuation::Conservative,
+    )?;
 
     // Calculate new collateralization ratio
     let new_ratio = new_collateral_value
@@ -190,9 +320,8 @@ pub fn validate_collateral_withdrawal(
         .ok_or(Error::DivisionByZero)?;
 
     // Ensure new ratio is above liquidation threshold with a safety margin
-    // We require at least 150% health to allow withdrawal
     let min_safe_ratio = liquidation_threshold
-        .checked_add(2500) // Add 25% safety margin
+        .checked_add(storage::get_risk_config(env).withdrawal_margin_bps)
         .ok_or(Error::ArithmeticOverflow)?;
 
     if (new_ratio as u32) < min_safe_ratio {
@@ -202,15 +331,201 @@ pub fn validate_collateral_withdrawal(
     Ok(())
 }
 
+/// Validate liquidation swap slippage tolerance (in basis points)
+pub fn validate_slippage(slippage_bps: u32) -> Result<(), Error> {
+    if slippage_bps > MAX_SLIPPAGE_BPS {
+        return Err(Error::InvalidSlippage);
+    }
+
+    Ok(())
+}
+
+/// Validate market-wide risk limits and their consistency with each other
+pub fn validate_risk_config(config: &RiskConfig) -> Result<(), Error> {
+    if config.price_staleness_threshold == 0
+        || config.max_offers_per_user == 0
+        || config.max_requests_per_user == 0
+        || config.max_loans_per_user == 0
+    {
+        return Err(Error::InvalidRiskConfig);
+    }
+
+    // Offers must be able to ask for more than 100%, and still pass withdrawals
+    if config.max_collateral_ratio <= BASIS_POINTS
+        || config.withdrawal_margin_bps >= config.max_collateral_ratio
+    {
+        return Err(Error::InvalidRiskConfig);
+    }
+
+    if config.max_liquidation_bonus_bps >= BASIS_POINTS {
+        return Err(Error::InvalidRiskConfig);
+    }
+
+    // Pool loans follow the same rules as offers
+    if validate_collateral_ratio(config.pool_min_collateral_ratio, config.max_collateral_ratio)
+        .is_err()
+        || validate_liquidation_threshold(
+            config.pool_liquidation_threshold,
+            config.pool_min_collateral_ratio,
+        )
+        .is_err()
+    {
+        return Err(Error::InvalidRiskConfig);
+    }
+
+    Ok(())
+}
+
+/// Validate that every listed collateral asset's liquidation bonus is within the
+/// risk config's cap
+pub fn validate_listed_collateral_bonuses(env: &Env, config: &RiskConfig) -> Result<(), Error> {
+    for token in storage::get_collateral_assets(env).iter() {
+        let listed = storage::get_collateral_config(env, &token)?;
+        if listed.liquidation_bonus_bps > config.max_liquidation_bonus_bps {
+            return Err(Error::InvalidRiskConfig);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the maximum deviation between primary and fallback oracles (in basis points)
+pub fn validate_oracle_deviation(deviation_bps: u32) -> Result<(), Error> {
+    if deviation_bps == 0 || deviation_bps > BASIS_POINTS {
+        return Err(Error::InvalidOracleDeviation);
+    }
+
+    Ok(())
+}
+
+/// Validate the timelock delay (in seconds)
+pub fn validate_timelock_delay(delay: u64) -> Result<(), Error> {
+    if !(MIN_TIMELOCK_DELAY..=MAX_TIMELOCK_DELAY).contains(&delay) {
+        return Err(Error::InvalidTimelockDelay);
+    }
+
+    Ok(())
+}
+
+/// Validate the number of oracle records averaged into the TWAP
+pub fn validate_twap_records(records: u32) -> Result<(), Error> {
+    if records == 0 || records > MAX_TWAP_RECORDS {
+        return Err(Error::InvalidTwapRecords);
+    }
+
+    Ok(())
+}
+
+/// Validate liquidation close factor (in basis points)
+pub fn validate_close_factor(close_factor_bps: u32) -> Result<(), Error> {
+    if close_factor_bps == 0 || close_factor_bps > BASIS_POINTS {
+        return Err(Error::InvalidCloseFactor);
+    }
+
+    Ok(())
+}
+
+/// Validate Dutch auction discount curve
+pub fn validate_auction_config(config: &AuctionConfig) -> Result<(), Error> {
+    if config.max_discount_bps == 0 || config.max_discount_bps > MAX_AUCTION_DISCOUNT_BPS {
+        return Err(Error::InvalidAuctionConfig);
+    }
+
+    if config.duration == 0 {
+        return Err(Error::InvalidAuctionConfig);
+    }
+
+    Ok(())
+}
+
+/// Validate protocol fee configuration
+pub fn validate_fee_config(config: &FeeConfig) -> Result<(), Error> {
+    if config.protocol_fee_bps > MAX_PROTOCOL_FEE_BPS
+        || config.liquidation_fee_bps > MAX_LIQUIDATION_FEE_BPS
+    {
+        return Err(Error::InvalidFeeConfig);
+    }
+
+    Ok(())
+}
+
+/// Validate the pool utilization curve
+/// The kink must sit strictly inside (0%, 100%) and the rate at full
+/// utilization may not exceed `max_interest_rate`
+pub fn validate_rate_model(model: &RateModel, max_interest_rate: u32) -> Result<(), Error> {
+    if model.optimal_utilization == 0 || model.optimal_utilization >= BASIS_POINTS {
+        return Err(Error::InvalidRateModel);
+    }
+
+    let max_rate = (model.base_rate as u64) + (model.slope1 as u64) + (model.slope2 as u64);
+    if max_rate > max_interest_rate as u64 {
+        return Err(Error::InvalidRateModel);
+    }
+
+    Ok(())
+}
+
+/// Validate storage TTL policy
+/// The threshold must be below the extension, which cannot exceed the network maximum
+pub fn validate_ttl_config(env: &Env, config: &TtlConfig) -> Result<(), Error> {
+    if config.threshold == 0 || config.threshold >= config.extend_to {
+        return Err(Error::InvalidTtlConfig);
+    }
+
+    if config.extend_to > env.storage().max_ttl() {
+        return Err(Error::InvalidTtlConfig);
+    }
+
+    Ok(())
+}
+
 /// Validate pagination parameters
 pub fn validate_pagination(limit: u32, offset: u32) -> Result<(), Error> {
-    if limit == 0 || limit > 100 {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        return Err(Error::InvalidPagination);
+    }
+
+    if offset.checked_add(limit).is_none() {
         return Err(Error::InvalidPagination);
     }
 
     Ok(())
 }
 
+/// Validate that the sort order can rank the offers the filter lets through
+/// Amounts of different tokens cannot be compared, so once several loan assets are
+/// listed `HighestAmount` needs a loan token filter
+pub fn validate_sort_option(
+    env: &Env,
+    sort: &SortOption,
+    filter: &OfferFilter,
+) -> Result<(), Error> {
+    if *sort == SortOption::HighestAmount
+        && filter.loan_token.is_none()
+        && storage::get_loan_assets(env).len() > 1
+    {
+        return Err(Error::InvalidSortOption);
+    }
+
+    Ok(())
+}
+
+/// Validate offer filter bounds
+pub fn validate_offer_filter(env: &Env, filter: &OfferFilter) -> Result<(), Error> {
+    if let Some(min_amount) = filter.min_amount {
+        if min_amount < 0 {
+            return Err(Error::InvalidInput);
+        }
+    }
+
+    if let Some(max_collateral_ratio) = filter.max_collateral_ratio {
+        let max_ratio = storage::get_risk_config(env).max_collateral_ratio;
+        validate_collateral_ratio(max_collateral_ratio, max_ratio)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,14 +550,25 @@ mod tests {
     #[test]
     fn test_validate_collateral_ratio() {
         // Valid ratios
-        assert!(validate_collateral_ratio(10000).is_ok()); // 100%
-        assert!(validate_collateral_ratio(15000).is_ok()); // 150%
-        assert!(validate_collateral_ratio(20000).is_ok()); // 200%
-        assert!(validate_collateral_ratio(30000).is_ok()); // 300%
+        assert!(validate_collateral_ratio(10000, 50000).is_ok()); // 100%
+        assert!(validate_collateral_ratio(15000, 50000).is_ok()); // 150%
+        assert!(validate_collateral_ratio(20000, 50000).is_ok()); // 200%
+        assert!(validate_collateral_ratio(30000, 50000).is_ok()); // 300%
 
         // Invalid ratios
-        assert!(validate_collateral_ratio(9999).is_err()); // < 100%
-        assert!(validate_collateral_ratio(50001).is_err()); // > 500%
+        assert!(validate_collateral_ratio(9999, 50000).is_err()); // < 100%
+        assert!(validate_collateral_ratio(50001, 50000).is_err()); // > 500%
+        assert!(validate_collateral_ratio(30001, 30000).is_err()); // > a lowered cap
+    }
+
+    #[test]
+    fn test_validate_pagination() {
+        assert!(validate_pagination(1, 0).is_ok());
+        assert!(validate_pagination(50, 500).is_ok());
+
+        assert_eq!(validate_pagination(0, 0), Err(Error::InvalidPagination));
+        assert_eq!(validate_pagination(51, 0), Err(Error::InvalidPagination));
+        assert_eq!(validate_pagination(10, u32::MAX), Err(Error::InvalidPagination));
     }
 
     #[test]
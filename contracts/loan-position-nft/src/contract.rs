@@ -0,0 +1,60 @@
+//! Loan Position NFT Contract.
+//!
+//! One token per lending-market loan, using the Enumerable extension so the
+//! lending market can list every position an account holds. The token ID is
+//! the loan ID, and whoever holds the token is paid as the loan's lender. Only
+//! the minter (the lending market) can mint; positions move with ordinary NFT
+//! transfers.
+
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String};
+use stellar_macros::default_impl;
+use stellar_tokens::non_fungible::{
+    enumerable::{Enumerable, NonFungibleEnumerable},
+    Base, NonFungibleToken,
+};
+
+#[contracttype]
+pub enum DataKey {
+    Minter,
+}
+
+#[contract]
+pub struct LoanPositionNft;
+
+#[contractimpl]
+impl LoanPositionNft {
+    pub fn __constructor(e: &Env, minter: Address) {
+        e.storage().instance().set(&DataKey::Minter, &minter);
+        Base::set_metadata(
+            e,
+            String::from_str(e, ""),
+            String::from_str(e, "Lending Market Loan Position"),
+            String::from_str(e, "LMLP"),
+        );
+    }
+
+    /// Mint the position token for loan `token_id` to `to`
+    pub fn mint(e: &Env, to: Address, token_id: u32) {
+        let minter = Self::minter(e);
+        minter.require_auth();
+        Enumerable::non_sequential_mint(e, &to, token_id);
+    }
+
+    /// The contract allowed to mint positions
+    pub fn minter(e: &Env) -> Address {
+        e.storage()
+            .instance()
+            .get(&DataKey::Minter)
+            .expect("minter should be set")
+    }
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleToken for LoanPositionNft {
+    type ContractType = Enumerable;
+}
+
+#[default_impl]
+#[contractimpl]
+impl NonFungibleEnumerable for LoanPositionNft {}
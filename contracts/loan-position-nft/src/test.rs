@@ -0,0 +1,53 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal,
+};
+
+use crate::contract::{LoanPositionNft, LoanPositionNftClient};
+
+fn create_client<'a>(e: &Env, minter: &Address) -> LoanPositionNftClient<'a> {
+    let address = e.register(LoanPositionNft, (minter,));
+    LoanPositionNftClient::new(e, &address)
+}
+
+#[test]
+fn minted_positions_are_enumerable_and_transferable() {
+    let e = Env::default();
+    let minter = Address::generate(&e);
+    let lender = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_client(&e, &minter);
+
+    e.mock_all_auths();
+    client.mint(&lender, &3);
+    client.mint(&lender, &7);
+    assert_eq!(client.balance(&lender), 2);
+    assert_eq!(client.get_owner_token_id(&lender, &1), 7);
+
+    client.transfer(&lender, &buyer, &7);
+    assert_eq!(client.owner_of(&7), buyer);
+    assert_eq!(client.balance(&lender), 1);
+    assert_eq!(client.get_owner_token_id(&buyer, &0), 7);
+}
+
+#[test]
+fn only_the_minter_can_mint() {
+    let e = Env::default();
+    let minter = Address::generate(&e);
+    let lender = Address::generate(&e);
+    let client = create_client(&e, &minter);
+
+    e.mock_auths(&[MockAuth {
+        address: &lender,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "mint",
+            args: (&lender, 0_u32).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_mint(&lender, &0).is_err());
+    assert_eq!(client.minter(), minter);
+}
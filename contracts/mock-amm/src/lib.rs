@@ -0,0 +1,172 @@
+#![no_std]
+
+//! Mock AMM Contract for Local Development
+//! Single constant-product (x * y = k) pool with a 0.3% fee, exposing the
+//! Soroswap router entry points used by the lending market's liquidations
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, token, vec, Address,
+    Env, Vec,
+};
+
+/// Swap fee in basis points (0.3%)
+const FEE_BPS: i128 = 30;
+const BASIS_POINTS: i128 = 10_000;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AmmError {
+    /// Path is not a single hop between the two pool tokens
+    InvalidPath = 1,
+    /// Amount must be positive
+    InvalidAmount = 2,
+    /// Deadline has passed
+    Expired = 3,
+    /// Output is below the caller's minimum
+    InsufficientOutputAmount = 4,
+    /// Pool has no liquidity for the swap
+    InsufficientLiquidity = 5,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    TokenA,
+    TokenB,
+    ReserveA,
+    ReserveB,
+}
+
+#[contract]
+pub struct MockAmm;
+
+#[contractimpl]
+impl MockAmm {
+    /// Create the pool for a token pair (constructor)
+    pub fn __constructor(env: Env, token_a: Address, token_b: Address) {
+        env.storage().instance().set(&DataKey::TokenA, &token_a);
+        env.storage().instance().set(&DataKey::TokenB, &token_b);
+        env.storage().instance().set(&DataKey::ReserveA, &0i128);
+        env.storage().instance().set(&DataKey::ReserveB, &0i128);
+    }
+
+    /// Deposit liquidity into the pool (no LP shares are issued)
+    pub fn add_liquidity(env: Env, from: Address, amount_a: i128, amount_b: i128) {
+        from.require_auth();
+        if amount_a <= 0 || amount_b <= 0 {
+            panic_with_error!(&env, AmmError::InvalidAmount);
+        }
+
+        let (token_a, token_b) = Self::tokens(&env);
+        let pool = env.current_contract_address();
+        token::TokenClient::new(&env, &token_a).transfer(&from, &pool, &amount_a);
+        token::TokenClient::new(&env, &token_b).transfer(&from, &pool, &amount_b);
+
+        let (reserve_a, reserve_b) = Self::get_reserves(env.clone());
+        Self::set_reserves(&env, reserve_a + amount_a, reserve_b + amount_b);
+    }
+
+    /// Current pool reserves as (token_a, token_b)
+    pub fn get_reserves(env: Env) -> (i128, i128) {
+        (
+            env.storage().instance().get(&DataKey::ReserveA).unwrap(),
+            env.storage().instance().get(&DataKey::ReserveB).unwrap(),
+        )
+    }
+
+    /// Pair contract holding the pool for two tokens (this contract)
+    pub fn router_pair_for(env: Env, token_a: Address, token_b: Address) -> Address {
+        Self::oriented_reserves(&env, &token_a, &token_b);
+        env.current_contract_address()
+    }
+
+    /// Output amounts for each step of `path` when selling `amount_in`
+    pub fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128> {
+        let (reserve_in, reserve_out) = Self::path_reserves(&env, &path);
+        let amount_out = Self::get_amount_out(&env, amount_in, reserve_in, reserve_out);
+        vec![&env, amount_in, amount_out]
+    }
+
+    /// Sell exactly `amount_in` of `path[0]` for at least `amount_out_min` of `path[1]`
+    pub fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Vec<i128> {
+        to.require_auth();
+        if env.ledger().timestamp() > deadline {
+            panic_with_error!(&env, AmmError::Expired);
+        }
+
+        let (reserve_in, reserve_out) = Self::path_reserves(&env, &path);
+        let amount_out = Self::get_amount_out(&env, amount_in, reserve_in, reserve_out);
+        if amount_out < amount_out_min {
+            panic_with_error!(&env, AmmError::InsufficientOutputAmount);
+        }
+
+        let token_in = path.get(0).unwrap();
+        let token_out = path.get(1).unwrap();
+        let pool = env.current_contract_address();
+        token::TokenClient::new(&env, &token_in).transfer(&to, &pool, &amount_in);
+        token::TokenClient::new(&env, &token_out).transfer(&pool, &to, &amount_out);
+
+        let (token_a, _) = Self::tokens(&env);
+        if token_in == token_a {
+            Self::set_reserves(&env, reserve_in + amount_in, reserve_out - amount_out);
+        } else {
+            Self::set_reserves(&env, reserve_out - amount_out, reserve_in + amount_in);
+        }
+
+        vec![&env, amount_in, amount_out]
+    }
+
+    fn tokens(env: &Env) -> (Address, Address) {
+        (
+            env.storage().instance().get(&DataKey::TokenA).unwrap(),
+            env.storage().instance().get(&DataKey::TokenB).unwrap(),
+        )
+    }
+
+    fn set_reserves(env: &Env, reserve_a: i128, reserve_b: i128) {
+        env.storage().instance().set(&DataKey::ReserveA, &reserve_a);
+        env.storage().instance().set(&DataKey::ReserveB, &reserve_b);
+    }
+
+    /// Reserves ordered as (token_in, token_out)
+    fn oriented_reserves(env: &Env, token_in: &Address, token_out: &Address) -> (i128, i128) {
+        let (token_a, token_b) = Self::tokens(env);
+        let (reserve_a, reserve_b) = Self::get_reserves(env.clone());
+
+        if *token_in == token_a && *token_out == token_b {
+            (reserve_a, reserve_b)
+        } else if *token_in == token_b && *token_out == token_a {
+            (reserve_b, reserve_a)
+        } else {
+            panic_with_error!(env, AmmError::InvalidPath)
+        }
+    }
+
+    fn path_reserves(env: &Env, path: &Vec<Address>) -> (i128, i128) {
+        if path.len() != 2 {
+            panic_with_error!(env, AmmError::InvalidPath);
+        }
+        Self::oriented_reserves(env, &path.get(0).unwrap(), &path.get(1).unwrap())
+    }
+
+    /// Constant-product output after the swap fee
+    fn get_amount_out(env: &Env, amount_in: i128, reserve_in: i128, reserve_out: i128) -> i128 {
+        if amount_in <= 0 {
+            panic_with_error!(env, AmmError::InvalidAmount);
+        }
+        if reserve_in <= 0 || reserve_out <= 0 {
+            panic_with_error!(env, AmmError::InsufficientLiquidity);
+        }
+
+        let amount_in_with_fee = amount_in * (BASIS_POINTS - FEE_BPS);
+        amount_in_with_fee * reserve_out / (reserve_in * BASIS_POINTS + amount_in_with_fee)
+    }
+}
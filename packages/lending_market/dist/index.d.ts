@@ -6,7 +6,7 @@ import {
   MethodOptions,
   Result,
 } from "@stellar/stellar-sdk/contract";
-import type { u32, u64, i128 } from "@stellar/stellar-sdk/contract";
+import type { u32, u64, i128, Option } from "@stellar/stellar-sdk/contract";
 export * from "@stellar/stellar-sdk";
 export * as contract from "@stellar/stellar-sdk/contract";
 export * as rpc from "@stellar/stellar-sdk/rpc";
@@ -53,6 +53,12 @@ export declare const Errors: {
   13: {
     message: string;
   };
+  /**
+   * Account does not hold the role
+   */
+  14: {
+    message: string;
+  };
   /**
    * Offer not found
    */
@@ -107,6 +113,12 @@ export declare const Errors: {
   28: {
     message: string;
   };
+  /**
+   * Loan token already has offers at the maximum number of distinct rates
+   */
+  29: {
+    message: string;
+  };
   /**
    * Loan not found
    */
@@ -167,6 +179,12 @@ export declare const Errors: {
   49: {
     message: string;
   };
+  /**
+   * Loan is not past its due time yet
+   */
+  50: {
+    message: string;
+  };
   /**
    * Loan is not liquidatable (health is above threshold)
    */
@@ -191,6 +209,42 @@ export declare const Errors: {
   63: {
     message: string;
   };
+  /**
+   * DEX router address not set
+   */
+  64: {
+    message: string;
+  };
+  /**
+   * Invalid slippage tolerance
+   */
+  65: {
+    message: string;
+  };
+  /**
+   * Invalid close factor (must be between 0 and 100%)
+   */
+  66: {
+    message: string;
+  };
+  /**
+   * No auction is running for the loan
+   */
+  67: {
+    message: string;
+  };
+  /**
+   * An auction is already running for the loan
+   */
+  68: {
+    message: string;
+  };
+  /**
+   * Invalid auction configuration
+   */
+  69: {
+    message: string;
+  };
   /**
    * Oracle address not set
    */
@@ -216,15 +270,27 @@ export declare const Errors: {
     message: string;
   };
   /**
-   * USDC token not set
+   * TWAP window outside 1..=MAX_TWAP_RECORDS records
    */
-  100: {
+  84: {
+    message: string;
+  };
+  /**
+   * Primary and fallback oracles disagree by more than the allowed deviation
+   */
+  85: {
+    message: string;
+  };
+  /**
+   * Oracle deviation limit out of range
+   */
+  86: {
     message: string;
   };
   /**
-   * XLM token not set
+   * USDC token not set
    */
-  101: {
+  100: {
     message: string;
   };
   /**
@@ -240,7 +306,49 @@ export declare const Errors: {
     message: string;
   };
   /**
-   * Contract is paused
+   * Loan position NFT contract not set
+   */
+  104: {
+    message: string;
+  };
+  /**
+   * Loan position NFT contract is already set or does not let this contract mint
+   */
+  105: {
+    message: string;
+  };
+  /**
+   * Offer does not accept the collateral token
+   */
+  106: {
+    message: string;
+  };
+  /**
+   * Invalid collateral risk parameters
+   */
+  107: {
+    message: string;
+  };
+  /**
+   * Token is not registered as a loan asset
+   */
+  108: {
+    message: string;
+  };
+  /**
+   * Invalid loan asset parameters
+   */
+  109: {
+    message: string;
+  };
+  /**
+   * Collateral token is not in the registry
+   */
+  110: {
+    message: string;
+  };
+  /**
+   * The requested operation is paused
    */
   120: {
     message: string;
@@ -275,6 +383,30 @@ export declare const Errors: {
   125: {
     message: string;
   };
+  /**
+   * Invalid storage TTL configuration
+   */
+  126: {
+    message: string;
+  };
+  /**
+   * Invalid protocol fee configuration
+   */
+  127: {
+    message: string;
+  };
+  /**
+   * Invalid risk configuration
+   */
+  128: {
+    message: string;
+  };
+  /**
+   * Stored schema is newer than this build can migrate
+   */
+  129: {
+    message: string;
+  };
   /**
    * Invalid sort option
    */
@@ -299,15 +431,121 @@ export declare const Errors: {
   143: {
     message: string;
   };
+  /**
+   * Pool loan not found
+   */
+  160: {
+    message: string;
+  };
+  /**
+   * Not enough idle USDC in the pool
+   */
+  161: {
+    message: string;
+  };
+  /**
+   * Invalid pool utilization curve
+   */
+  162: {
+    message: string;
+  };
+  /**
+   * Lender holds fewer pool shares than requested
+   */
+  163: {
+    message: string;
+  };
+  /**
+   * Bad debt has wiped out the pool's assets while shares are outstanding
+   */
+  164: {
+    message: string;
+  };
+  /**
+   * Timelocked action not found
+   */
+  180: {
+    message: string;
+  };
+  /**
+   * Timelock delay has not passed yet
+   */
+  181: {
+    message: string;
+  };
+  /**
+   * Grace period to run the action has passed
+   */
+  182: {
+    message: string;
+  };
+  /**
+   * Invalid timelock delay
+   */
+  183: {
+    message: string;
+  };
+  /**
+   * Timelock queue is full
+   */
+  184: {
+    message: string;
+  };
+  /**
+   * Borrow request not found
+   */
+  200: {
+    message: string;
+  };
+  /**
+   * Borrow request is no longer open
+   */
+  201: {
+    message: string;
+  };
+  /**
+   * User has too many open borrow requests
+   */
+  202: {
+    message: string;
+  };
 };
+/**
+ * How interest accrues on a loan
+ */
+export type InterestMode =
+  | {
+      tag: "Simple";
+      values: void;
+    }
+  | {
+      tag: "CompoundPerSecond";
+      values: void;
+    }
+  | {
+      tag: "CompoundPerWeek";
+      values: void;
+    };
 /**
  * Lending offer created by a lender
  */
 export interface LendingOffer {
+  /**
+   * Amount of the loan token available to lend (in its decimals)
+   */
+  amount: i128;
+  /**
+   * Collateral tokens borrowers may post against this offer
+   */
+  collateral_assets: Array<string>;
   /**
    * Timestamp when offer was created
    */
   created_at: u64;
+  /**
+   * How interest accrues on loans taken against this offer
+   */
+  interest_mode: InterestMode;
   /**
    * Whether this offer is active
    */
@@ -320,6 +558,10 @@ export interface LendingOffer {
    * Liquidation threshold in basis points (e.g., 12500 = 125%)
    */
   liquidation_threshold: u32;
+  /**
+   * Token being lent
+   */
+  loan_token: string;
   /**
    * Maximum loan duration in weeks
    */
@@ -332,25 +574,87 @@ export interface LendingOffer {
    * Unique identifier for this offer
    */
   offer_id: u64;
-  /**
-   * Amount of USDC available to lend (with 7 decimals)
-   */
-  usdc_amount: i128;
   /**
    * Weekly interest rate in basis points (e.g., 500 = 5%)
    */
   weekly_interest_rate: u32;
 }
+/**
+ * One loan of a `borrow_best` call, as planned by `quote_borrow`
+ */
+export interface BorrowFill {
+  /**
+   * Amount of the loan token borrowed from the offer (in its decimals)
+   */
+  amount: i128;
+  /**
+   * Share of the collateral posted for this loan (in the collateral token's decimals)
+   */
+  collateral_amount: i128;
+  /**
+   * Weekly interest rate of the offer in basis points
+   */
+  interest_rate: u32;
+  /**
+   * Offer the loan is taken against
+   */
+  offer_id: u64;
+}
+/**
+ * Loan terms posted by a borrower, with the collateral escrowed up front
+ */
+export interface BorrowRequest {
+  /**
+   * Address of the borrower
+   */
+  borrower: string;
+  /**
+   * Amount of collateral escrowed (in the collateral token's decimals)
+   */
+  collateral_amount: i128;
+  /**
+   * Token escrowed as collateral
+   */
+  collateral_token: string;
+  /**
+   * Timestamp when the request was created
+   */
+  created_at: u64;
+  /**
+   * Amount of the loan token requested (in its decimals)
+   */
+  desired_amount: i128;
+  /**
+   * Loan duration in weeks, counted from when the request is filled
+   */
+  duration_weeks: u32;
+  /**
+   * Whether the request can still be filled
+   */
+  is_active: boolean;
+  /**
+   * Token the borrower wants to borrow
+   */
+  loan_token: string;
+  /**
+   * Highest weekly interest rate the borrower accepts, in basis points
+   */
+  max_rate: u32;
+  /**
+   * Unique identifier for this request
+   */
+  request_id: u64;
+}
 /**
  * Active loan position
  */
 export interface Loan {
   /**
-   * Accumulated interest so far (in USDC with 7 decimals)
+   * Accumulated interest so far (in the loan token's decimals)
    */
   accumulated_interest: i128;
   /**
-   * Amount of USDC borrowed (with 7 decimals)
+   * Amount of the loan token borrowed (in its decimals)
    */
   borrowed_amount: i128;
   /**
@@ -358,9 +662,21 @@ export interface Loan {
    */
   borrower: string;
   /**
-   * Amount of XLM deposited as collateral (with 7 decimals)
+   * Amount of collateral deposited (in the collateral token's decimals)
    */
   collateral_amount: i128;
+  /**
+   * Token posted as collateral
+   */
+  collateral_token: string;
+  /**
+   * Timestamp after which the loan is in default
+   */
+  due_time: u64;
+  /**
+   * How interest accrues (copied from the offer)
+   */
+  interest_mode: InterestMode;
   /**
    * Weekly interest rate in basis points
    */
@@ -374,7 +690,7 @@ export interface Loan {
    */
   last_interest_update: u64;
   /**
-   * Address of the lender
+   * Lender who funded the loan (payments go to the holder of its position NFT)
    */
   lender: string;
   /**
@@ -386,7 +702,11 @@ export interface Loan {
    */
   loan_id: u64;
   /**
-   * Reference to the original offer
+   * Token borrowed and repaid
+   */
+  loan_token: string;
+  /**
+   * Reference to the original offer (0 for loans filled from a borrow request)
    */
   offer_id: u64;
   /**
@@ -399,17 +719,21 @@ export interface Loan {
  */
 export interface LoanHealth {
   /**
-   * Current collateral value in USDC (with 7 decimals)
+   * Current collateral value in the loan token (in its decimals)
    */
-  collateral_value_usd: i128;
+  collateral_value: i128;
   /**
    * Collateralization ratio in basis points
    */
   collateralization_ratio: u32;
   /**
-   * Total debt including principal and interest (with 7 decimals)
+   * Total debt including principal and interest (in the loan token's decimals)
    */
-  debt_value_usd: i128;
+  debt_value: i128;
+  /**
+   * Timestamp after which the loan is in default
+   */
+  due_time: u64;
   /**
    * Health factor in basis points (10000 = 100%)
    */
@@ -419,131 +743,2830 @@ export interface LoanHealth {
    */
   is_liquidatable: boolean;
   /**
-   * XLM price that would trigger liquidation (with oracle decimals)
+   * Whether the loan is past due and its collateral can be claimed by the lender
+   */
+  is_overdue: boolean;
+  /**
+   * Collateral price in the loan token that would trigger liquidation (with oracle decimals)
    */
   liquidation_price: i128;
   /**
    * Loan identifier
    */
   loan_id: u64;
+  /**
+   * Seconds left until the loan is due (0 once past due)
+   */
+  time_remaining: u64;
 }
 /**
- * Sort options for querying offers
+ * Oracle currently serving prices
  */
-export type SortOption =
+export type OracleSource =
   | {
-      tag: "BestRate";
-      values: void;
-    }
-  | {
-      tag: "HighestAmount";
+      tag: "Primary";
       values: void;
     }
   | {
-      tag: "Newest";
+      tag: "Fallback";
       values: void;
     };
 /**
- * Storage keys for the contract
+ * Roles the admin can grant for day-to-day operations
+ * The admin passes every role check
  */
-export type DataKey =
+export type Role =
   | {
-      tag: "Admin";
+      tag: "Pauser";
       values: void;
     }
   | {
-      tag: "UsdcToken";
-      values: void;
-    }
-  | {
-      tag: "XlmToken";
-      values: void;
-    }
-  | {
-      tag: "OracleAddress";
+      tag: "RiskManager";
       values: void;
     }
   | {
-      tag: "NextOfferId";
+      tag: "OracleManager";
       values: void;
     }
   | {
-      tag: "NextLoanId";
+      tag: "FeeCollector";
       values: void;
-    }
+    };
+/**
+ * Sort options for querying offers
+ */
+export type SortOption =
   | {
-      tag: "MaxInterestRate";
+      tag: "BestRate";
       values: void;
     }
   | {
-      tag: "IsPaused";
+      tag: "HighestAmount";
       values: void;
     }
   | {
-      tag: "Locked";
+      tag: "Newest";
       values: void;
+    };
+/**
+ * Optional filters applied when listing offers
+ */
+export interface OfferFilter {
+  /**
+   * Only include offers accepting this collateral token
+   */
+  collateral_asset: Option<string>;
+  /**
+   * Only include offers lending this token
+   */
+  loan_token: Option<string>;
+  /**
+   * Only include offers requiring at most this collateral ratio (basis points)
+   */
+  max_collateral_ratio: Option<u32>;
+  /**
+   * Only include offers with a weekly rate at or below this (basis points)
+   */
+  max_rate: Option<u32>;
+  /**
+   * Only include offers with at least this much of their loan token available
+   */
+  min_amount: Option<i128>;
+}
+/**
+ * Time-to-live policy applied to instance and persistent storage (in ledgers)
+ */
+export interface TtlConfig {
+  /**
+   * TTL entries are extended to
+   */
+  extend_to: u32;
+  /**
+   * Entries are extended once their remaining TTL drops below this
+   */
+  threshold: u32;
+}
+/**
+ * Dutch auction selling a liquidatable loan's collateral at a growing discount
+ */
+export interface Auction {
+  /**
+   * Address that started the auction
+   */
+  initiator: string;
+  /**
+   * Loan whose collateral is being auctioned
+   */
+  loan_id: u64;
+  /**
+   * Timestamp the auction started (discount is 0 at this point)
+   */
+  start_time: u64;
+}
+/**
+ * Dutch auction discount curve
+ */
+export interface AuctionConfig {
+  /**
+   * Seconds for the discount to grow linearly from 0 to the maximum
+   */
+  duration: u64;
+  /**
+   * Maximum collateral discount in basis points
+   */
+  max_discount_bps: u32;
+}
+/**
+ * Protocol fees charged by the market
+ */
+export interface FeeConfig {
+  /**
+   * Fee on debt repaid through DEX liquidations, in basis points
+   */
+  liquidation_fee_bps: u32;
+  /**
+   * Share of all interest paid kept by the protocol, in basis points
+   */
+  protocol_fee_bps: u32;
+}
+/**
+ * Market-wide risk limits, adjustable by the admin without redeploying
+ */
+export interface RiskConfig {
+  /**
+   * Highest collateral ratio an offer may require, in basis points
+   */
+  max_collateral_ratio: u32;
+  /**
+   * Upper bound for a collateral asset's liquidator bonus, in basis points
+   */
+  max_liquidation_bonus_bps: u32;
+  /**
+   * Maximum number of loans a borrower can hold
+   */
+  max_loans_per_user: u32;
+  /**
+   * Maximum number of offers a lender can hold
+   */
+  max_offers_per_user: u32;
+  /**
+   * Maximum number of open borrow requests a borrower can hold
+   */
+  max_requests_per_user: u32;
+  /**
+   * Liquidation threshold for pool loans, in basis points
+   */
+  pool_liquidation_threshold: u32;
+  /**
+   * Minimum collateral ratio for pool loans, in basis points
+   */
+  pool_min_collateral_ratio: u32;
+  /**
+   * Maximum age of an oracle price in seconds
+   */
+  price_staleness_threshold: u64;
+  /**
+   * Headroom above the liquidation threshold kept after a collateral withdrawal, in basis points
+   */
+  withdrawal_margin_bps: u32;
+}
+/**
+ * Operations halted by the pause flags
+ * Collateral top-ups are never paused, so borrowers can always defend a position
+ */
+export interface PauseState {
+  /**
+   * New P2P and pool loans
+   */
+  borrows: boolean;
+  /**
+   * Liquidations, auctions and lender claims on defaulted loans
+   */
+  liquidations: boolean;
+  /**
+   * New P2P offers and pool deposits
+   */
+  offers: boolean;
+  /**
+   * Loan repayments, left as they are by `pause_contract`
+   */
+  repayments: boolean;
+  /**
+   * Withdrawals of idle offer funds, pool shares and loan collateral
+   */
+  withdrawals: boolean;
+}
+/**
+ * Admin action that only takes effect once the timelock delay has passed
+ */
+export type TimelockAction =
+  | {
+      tag: "SetOracleAddress";
+      values: readonly [string];
     }
   | {
-      tag: "Offer";
-      values: readonly [u64];
-    }
-  | {
-      tag: "Loan";
+      tag: "SetFallbackOracle";
+      values: readonly [Option<string>];
+    }
+  | {
+      tag: "UpdateRiskConfig";
+      values: readonly [RiskConfig];
+    }
+  | {
+      tag: "Upgrade";
+      values: readonly [Buffer];
+    }
+  | {
+      tag: "SetTimelockDelay";
       values: readonly [u64];
     }
   | {
-      tag: "UserOffers";
+      tag: "SetCollateralConfig";
+      values: readonly [string, CollateralConfig];
+    }
+  | {
+      tag: "SetLoanAsset";
+      values: readonly [string, LoanAssetConfig];
+    }
+  | {
+      tag: "SetDexRouter";
       values: readonly [string];
     }
   | {
-      tag: "UserLoansAsBorrower";
+      tag: "SetMaxOracleDeviation";
+      values: readonly [u32];
+    }
+  | {
+      tag: "SetTwapRecords";
+      values: readonly [u32];
+    }
+  | {
+      tag: "SetMaxSlippage";
+      values: readonly [u32];
+    }
+  | {
+      tag: "SetCloseFactor";
+      values: readonly [u32];
+    }
+  | {
+      tag: "SetMaxInterestRate";
+      values: readonly [u32];
+    }
+  | {
+      tag: "SetAuctionConfig";
+      values: readonly [AuctionConfig];
+    }
+  | {
+      tag: "SetFeeConfig";
+      values: readonly [FeeConfig];
+    }
+  | {
+      tag: "SetRateModel";
+      values: readonly [RateModel];
+    };
+/**
+ * Action waiting in the timelock queue
+ */
+export interface QueuedAction {
+  action: TimelockAction;
+  action_id: u64;
+  /**
+   * Earliest timestamp the action may run
+   */
+  eta: u64;
+  /**
+   * Account that queued the action
+   */
+  proposer: string;
+}
+/**
+ * Risk parameters for a token accepted as collateral
+ */
+export interface CollateralConfig {
+  /**
+   * Decimals of the token's amounts
+   */
+  decimals: u32;
+  /**
+   * Bonus paid to liquidators on the debt they repay, in basis points
+   */
+  liquidation_bonus_bps: u32;
+  /**
+   * Highest loan-to-value a position may reach before it is liquidatable, in
+   * basis points (8000 = liquidatable at 125% collateral)
+   */
+  liquidation_threshold_cap: u32;
+  /**
+   * Maximum loan-to-value when borrowing, in basis points (5000 = 200% collateral)
+   */
+  max_ltv_bps: u32;
+  /**
+   * Reflector asset the token is priced as
+   */
+  oracle_asset: Asset;
+}
+/**
+ * Token that offers may lend
+ */
+export interface LoanAssetConfig {
+  /**
+   * Decimals of the token's amounts
+   */
+  decimals: u32;
+  /**
+   * Reflector asset the token is priced as
+   */
+  oracle_asset: Asset;
+}
+/**
+ * Kinked utilization curve for the variable-rate pool (weekly rates in basis points)
+ */
+export interface RateModel {
+  /**
+   * Borrow rate at 0% utilization
+   */
+  base_rate: u32;
+  /**
+   * Utilization in basis points where the curve kinks
+   */
+  optimal_utilization: u32;
+  /**
+   * Rate added between 0% and optimal utilization
+   */
+  slope1: u32;
+  /**
+   * Rate added between optimal and 100% utilization
+   */
+  slope2: u32;
+}
+/**
+ * Accounting for the pooled, variable-rate USDC market
+ */
+export interface PoolState {
+  /**
+   * Cumulative borrow interest factor with 18 decimals
+   */
+  borrow_index: i128;
+  /**
+   * USDC held by the pool and available to borrow or withdraw
+   */
+  cash: i128;
+  /**
+   * Last time pool interest was accrued
+   */
+  last_update: u64;
+  /**
+   * Protocol share of pool interest not yet moved to the treasury
+   */
+  reserves: i128;
+  /**
+   * Sum of borrowers' scaled debt (debt = scaled debt × borrow index)
+   */
+  total_scaled_debt: i128;
+}
+/**
+ * Variable-rate loan drawn from the pool
+ */
+export interface PoolLoan {
+  /**
+   * Address of the borrower
+   */
+  borrower: string;
+  /**
+   * Amount of collateral deposited (in the collateral token's decimals)
+   */
+  collateral_amount: i128;
+  /**
+   * Token posted as collateral
+   */
+  collateral_token: string;
+  /**
+   * Whether this loan is active
+   */
+  is_active: boolean;
+  /**
+   * Unique identifier for this pool loan
+   */
+  loan_id: u64;
+  /**
+   * Debt divided by the borrow index when it was taken or last repaid
+   */
+  scaled_debt: i128;
+  /**
+   * Timestamp when loan was created
+   */
+  start_time: u64;
+}
+/**
+ * Current rates of the variable-rate pool
+ */
+export interface PoolRates {
+  /**
+   * Weekly borrow rate in basis points
+   */
+  borrow_rate: u32;
+  /**
+   * Weekly rate earned by lenders in basis points
+   */
+  supply_rate: u32;
+  /**
+   * Share of pool assets lent out in basis points
+   */
+  utilization: u32;
+}
+/**
+ * Storage keys for the contract
+ */
+export type DataKey =
+  | {
+      tag: "UsdcToken";
+      values: void;
+    }
+  | {
+      tag: "LoanAssets";
+      values: void;
+    }
+  | {
+      tag: "LoanAsset";
       values: readonly [string];
     }
   | {
-      tag: "UserLoansAsLender";
+      tag: "CollateralAssets";
+      values: void;
+    }
+  | {
+      tag: "CollateralConfig";
       values: readonly [string];
     }
   | {
-      tag: "ActiveOffers";
+      tag: "OracleAddress";
       values: void;
     }
   | {
-      tag: "ActiveLoans";
+      tag: "FallbackOracle";
       values: void;
-    };
-/**
- * Price data from oracle
- */
-export interface PriceData {
+    }
+  | {
+      tag: "OracleSource";
+      values: void;
+    }
+  | {
+      tag: "MaxOracleDeviation";
+      values: void;
+    }
+  | {
+      tag: "TwapRecords";
+      values: void;
+    }
+  | {
+      tag: "DexRouter";
+      values: void;
+    }
+  | {
+      tag: "PositionNft";
+      values: void;
+    }
+  | {
+      tag: "PositionNftFirstLoan";
+      values: void;
+    }
+  | {
+      tag: "MaxSlippage";
+      values: void;
+    }
+  | {
+      tag: "CloseFactor";
+      values: void;
+    }
+  | {
+      tag: "AuctionConfig";
+      values: void;
+    }
+  | {
+      tag: "FeeConfig";
+      values: void;
+    }
+  | {
+      tag: "RiskConfig";
+      values: void;
+    }
+  | {
+      tag: "ProtocolFees";
+      values: readonly [string];
+    }
+  | {
+      tag: "RateModel";
+      values: void;
+    }
+  | {
+      tag: "PoolState";
+      values: void;
+    }
+  | {
+      tag: "NextPoolLoanId";
+      values: void;
+    }
+  | {
+      tag: "NextOfferId";
+      values: void;
+    }
+  | {
+      tag: "NextLoanId";
+      values: void;
+    }
+  | {
+      tag: "NextRequestId";
+      values: void;
+    }
+  | {
+      tag: "NextActionId";
+      values: void;
+    }
+  | {
+      tag: "TimelockDelay";
+      values: void;
+    }
+  | {
+      tag: "QueuedActions";
+      values: void;
+    }
+  | {
+      tag: "QueuedAction";
+      values: readonly [u64];
+    }
+  | {
+      tag: "MaxInterestRate";
+      values: void;
+    }
+  | {
+      tag: "PauseState";
+      values: void;
+    }
+  | {
+      tag: "Locked";
+      values: void;
+    }
+  | {
+      tag: "TtlConfig";
+      values: void;
+    }
+  | {
+      tag: "SchemaVersion";
+      values: void;
+    }
+  | {
+      tag: "MigrationCursor";
+      values: void;
+    }
+  | {
+      tag: "Offer";
+      values: readonly [u64];
+    }
+  | {
+      tag: "Loan";
+      values: readonly [u64];
+    }
+  | {
+      tag: "UserOffers";
+      values: readonly [string];
+    }
+  | {
+      tag: "UserLoansAsBorrower";
+      values: readonly [string];
+    }
+  | {
+      tag: "UserLoansAsLender";
+      values: readonly [string];
+    }
+  | {
+      tag: "OfferLoans";
+      values: readonly [u64];
+    }
+  | {
+      tag: "ActiveOffersLen";
+      values: void;
+    }
+  | {
+      tag: "ActiveOffersPage";
+      values: readonly [u32];
+    }
+  | {
+      tag: "ActiveOfferPosition";
+      values: readonly [u64];
+    }
+  | {
+      tag: "ActiveLoansLen";
+      values: void;
+    }
+  | {
+      tag: "ActiveLoansPage";
+      values: readonly [u32];
+    }
+  | {
+      tag: "ActiveLoanPosition";
+      values: readonly [u64];
+    }
+  | {
+      tag: "Auction";
+      values: readonly [u64];
+    }
+  | {
+      tag: "PoolLoan";
+      values: readonly [u64];
+    }
+  | {
+      tag: "UserPoolLoans";
+      values: readonly [string];
+    }
+  | {
+      tag: "ActivePoolLoansLen";
+      values: void;
+    }
+  | {
+      tag: "ActivePoolLoansPage";
+      values: readonly [u32];
+    }
+  | {
+      tag: "ActivePoolLoanPosition";
+      values: readonly [u64];
+    }
+  | {
+      tag: "BorrowRequest";
+      values: readonly [u64];
+    }
+  | {
+      tag: "UserBorrowRequests";
+      values: readonly [string];
+    }
+  | {
+      tag: "ActiveRequestsLen";
+      values: void;
+    }
+  | {
+      tag: "ActiveRequestsPage";
+      values: readonly [u32];
+    }
+  | {
+      tag: "ActiveRequestPosition";
+      values: readonly [u64];
+    }
+  | {
+      tag: "OfferRates";
+      values: readonly [string];
+    }
+  | {
+      tag: "OffersAtRateLen";
+      values: readonly [string, u32];
+    }
+  | {
+      tag: "OffersAtRatePage";
+      values: readonly [string, u32, u32];
+    }
+  | {
+      tag: "OfferRatePosition";
+      values: readonly [u64];
+    };
+/**
+ * Price data from oracle
+ */
+export interface PriceData {
+  /**
+   * Price value
+   */
+  price: i128;
+  /**
+   * Timestamp of the price
+   */
+  timestamp: u64;
+}
+/**
+ * Terms of a newly created offer
+ */
+export interface OfferCreatedData {
+  /**
+   * Amount of the loan token escrowed into the offer
+   */
+  amount: i128;
+  /**
+   * Collateral tokens accepted by the offer
+   */
+  collateral_assets: Array<string>;
+  /**
+   * How interest accrues on loans taken against the offer
+   */
+  interest_mode: InterestMode;
+  /**
+   * Liquidation threshold in basis points
+   */
+  liquidation_threshold: u32;
+  /**
+   * Token being lent
+   */
+  loan_token: string;
+  /**
+   * Maximum loan duration in weeks
+   */
+  max_duration_weeks: u32;
+  /**
+   * Minimum collateral ratio in basis points
+   */
+  min_collateral_ratio: u32;
+  /**
+   * Weekly interest rate in basis points
+   */
+  weekly_interest_rate: u32;
+}
+/**
+ * Loan tokens moved out of an offer back to its lender
+ */
+export interface OfferFundsData {
+  /**
+   * Amount returned to the lender
+   */
+  amount: i128;
+  /**
+   * Amount still available in the offer afterwards
+   */
+  remaining: i128;
+}
+/**
+ * Terms of a newly opened loan
+ */
+export interface LoanOpenedData {
+  /**
+   * Amount of the loan token borrowed
+   */
+  borrowed_amount: i128;
+  /**
+   * Amount of collateral deposited
+   */
+  collateral_amount: i128;
+  /**
+   * Token posted as collateral
+   */
+  collateral_token: string;
+  /**
+   * Timestamp after which the loan is in default
+   */
+  due_time: u64;
+  /**
+   * Weekly interest rate in basis points
+   */
+  interest_rate: u32;
+  /**
+   * Address of the lender
+   */
+  lender: string;
+  /**
+   * Token borrowed
+   */
+  loan_token: string;
+}
+/**
+ * Breakdown of a repayment
+ */
+export interface LoanRepaidData {
+  /**
+   * Total amount paid by the borrower
+   */
+  amount: i128;
+  /**
+   * Collateral released back to the borrower (non-zero only on full repayment)
+   */
+  collateral_returned: i128;
+  /**
+   * Portion of the payment applied to interest
+   */
+  interest_paid: i128;
+  /**
+   * Portion of the payment applied to principal
+   */
+  principal_paid: i128;
+  /**
+   * Share of the interest kept by the protocol treasury
+   */
+  protocol_fee: i128;
+  /**
+   * Principal still outstanding
+   */
+  remaining_principal: i128;
+}
+/**
+ * Collateral moved into or out of a loan
+ */
+export interface CollateralData {
+  /**
+   * Amount of collateral moved
+   */
+  amount: i128;
+  /**
+   * Collateral held by the loan afterwards
+   */
+  collateral_amount: i128;
+}
+/**
+ * Outcome of a (possibly partial) liquidation
+ */
+export interface LiquidationData {
+  /**
+   * Excess loan tokens returned to the borrower
+   */
+  borrower_refund: i128;
+  /**
+   * Unsold collateral returned to the borrower (non-zero only when the loan closes)
+   */
+  collateral_returned: i128;
+  /**
+   * Collateral sold on the DEX
+   */
+  collateral_seized: i128;
+  /**
+   * Debt repaid to the lender (principal + interest, before the protocol fee)
+   */
+  debt_repaid: i128;
+  /**
+   * Portion of the repaid debt that was interest
+   */
+  interest_paid: i128;
+  /**
+   * Bonus paid to the liquidator (in the loan token)
+   */
+  liquidator_bonus: i128;
+  /**
+   * Portion of the repaid debt that was principal
+   */
+  principal_paid: i128;
+  /**
+   * Interest and liquidation fees kept by the protocol treasury
+   */
+  protocol_fee: i128;
+  /**
+   * Debt left on the loan (written off if the loan closed without collateral)
+   */
+  remaining_debt: i128;
+  /**
+   * Loan tokens received from the swap
+   */
+  swap_proceeds: i128;
+}
+/**
+ * Collateral claimed by a lender from a loan past its due time
+ */
+export interface DefaultClaimData {
+  /**
+   * Collateral transferred to the lender
+   */
+  collateral_seized: i128;
+  /**
+   * Debt outstanding at the time of the claim (principal + interest)
+   */
+  debt_outstanding: i128;
+}
+/**
+ * Fill of a Dutch auction bid
+ */
+export interface AuctionBidData {
+  /**
+   * Loan tokens paid by the bidder (to the lender, less the protocol fee)
+   */
+  amount_paid: i128;
+  /**
+   * Unsold collateral returned to the borrower (non-zero only when the loan closes)
+   */
+  collateral_returned: i128;
+  /**
+   * Collateral transferred to the bidder
+   */
+  collateral_sold: i128;
+  /**
+   * Discount below the oracle price the bid filled at, in basis points
+   */
+  discount_bps: u32;
+  /**
+   * Portion of the payment applied to interest
+   */
+  interest_paid: i128;
+  /**
+   * Portion of the payment applied to principal
+   */
+  principal_paid: i128;
+  /**
+   * Share of the interest kept by the protocol treasury
+   */
+  protocol_fee: i128;
+  /**
+   * Debt left on the loan (written off if the loan closed without collateral)
+   */
+  remaining_debt: i128;
+}
+/**
+ * USDC moved into or out of the pool by a lender
+ */
+export interface PoolSharesData {
+  /**
+   * USDC deposited or redeemed
+   */
+  amount: i128;
+  /**
+   * Pool shares minted or burned
+   */
+  shares: i128;
+}
+/**
+ * Terms of a newly opened pool loan
+ */
+export interface PoolLoanOpenedData {
+  /**
+   * Weekly borrow rate in basis points after the borrow
+   */
+  borrow_rate: u32;
+  /**
+   * USDC borrowed from the pool
+   */
+  borrowed_amount: i128;
+  /**
+   * Collateral locked
+   */
+  collateral_amount: i128;
+  /**
+   * Token posted as collateral
+   */
+  collateral_token: string;
+}
+/**
+ * Repayment applied to a pool loan
+ */
+export interface PoolLoanRepaidData {
+  /**
+   * USDC paid by the borrower
+   */
+  amount: i128;
+  /**
+   * Collateral released back to the borrower (non-zero only on full repayment)
+   */
+  collateral_returned: i128;
+  /**
+   * Debt still outstanding
+   */
+  remaining_debt: i128;
+}
+/**
+ * Outcome of a (possibly partial) pool loan liquidation
+ */
+export interface PoolLiquidationData {
+  /**
+   * Debt written off against the pool when the collateral ran out
+   */
+  bad_debt: i128;
+  /**
+   * Excess USDC returned to the borrower
+   */
+  borrower_refund: i128;
+  /**
+   * Unsold collateral returned to the borrower (non-zero only when the loan closes)
+   */
+  collateral_returned: i128;
+  /**
+   * Collateral sold on the DEX
+   */
+  collateral_seized: i128;
+  /**
+   * Debt repaid to the pool
+   */
+  debt_repaid: i128;
+  /**
+   * Bonus paid to the liquidator (in USDC)
+   */
+  liquidator_bonus: i128;
+  /**
+   * Liquidation fee kept by the protocol treasury
+   */
+  protocol_fee: i128;
+  /**
+   * Debt left on the loan
+   */
+  remaining_debt: i128;
+  /**
+   * USDC received from the swap
+   */
+  swap_proceeds: i128;
+}
+/**
+ * Quoted asset definition
+ */
+export type Asset =
+  | {
+      tag: "Stellar";
+      values: readonly [string];
+    }
+  | {
+      tag: "Other";
+      values: readonly [string];
+    };
+export interface Client {
+  /**
+   * Construct and simulate a create_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a new lending offer of `amount` of `loan_token`
+   */
+  create_offer: (
+    {
+      lender,
+      loan_token,
+      amount,
+      weekly_interest_rate,
+      interest_mode,
+      min_collateral_ratio,
+      liquidation_threshold,
+      max_duration_weeks,
+      collateral_assets,
+    }: {
+      lender: string;
+      loan_token: string;
+      amount: i128;
+      weekly_interest_rate: u32;
+      interest_mode: InterestMode;
+      min_collateral_ratio: u32;
+      liquidation_threshold: u32;
+      max_duration_weeks: u32;
+      collateral_assets: Array<string>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+  /**
+   * Construct and simulate a cancel_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel an offer
+   * If loans against the offer are still outstanding, only its idle funds are returned
+   * and the offer stays open until they are closed
+   */
+  cancel_offer: (
+    {
+      lender,
+      offer_id,
+    }: {
+      lender: string;
+      offer_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a withdraw_from_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw unused funds from an offer
+   */
+  withdraw_from_offer: (
+    {
+      lender,
+      offer_id,
+      amount,
+    }: {
+      lender: string;
+      offer_id: u64;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a borrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Borrow the offer's loan token against `collateral_token` for `duration_weeks`
+   * (at most the offer's `max_duration_weeks`)
+   *
+   * The loan uses the stricter of the offer's and the collateral asset's
+   * collateral ratio and liquidation threshold.
+   */
+  borrow: (
+    {
+      borrower,
+      offer_id,
+      collateral_token,
+      collateral_amount,
+      borrow_amount,
+      duration_weeks,
+    }: {
+      borrower: string;
+      offer_id: u64;
+      collateral_token: string;
+      collateral_amount: i128;
+      borrow_amount: i128;
+      duration_weeks: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+  /**
+   * Construct and simulate a borrow_best transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Borrow `amount` of `loan_token` from the cheapest offers at or below `max_rate`,
+   * opening one loan per offer, and return the loan IDs
+   *
+   * The collateral is split across the loans in proportion to what each borrows;
+   * `quote_borrow` shows the split without borrowing. Offers are read cheapest first,
+   * at most 20 of them, and at most 5 loans are opened per call.
+   */
+  borrow_best: (
+    {
+      borrower,
+      loan_token,
+      collateral_token,
+      collateral_amount,
+      amount,
+      max_rate,
+      duration_weeks,
+    }: {
+      borrower: string;
+      loan_token: string;
+      collateral_token: string;
+      collateral_amount: i128;
+      amount: i128;
+      max_rate: u32;
+      duration_weeks: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<u64>>>>;
+  /**
+   * Construct and simulate a repay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Repay a loan (partial or full)
+   */
+  repay: (
+    {
+      borrower,
+      loan_id,
+      repay_amount,
+    }: {
+      borrower: string;
+      loan_id: u64;
+      repay_amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a add_collateral transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add more collateral to improve loan health
+   */
+  add_collateral: (
+    {
+      borrower,
+      loan_id,
+      additional_collateral,
+    }: {
+      borrower: string;
+      loan_id: u64;
+      additional_collateral: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a withdraw_collateral transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw excess collateral (if health allows)
+   */
+  withdraw_collateral: (
+    {
+      borrower,
+      loan_id,
+      amount,
+    }: {
+      borrower: string;
+      loan_id: u64;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a create_borrow_request transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ask to borrow `desired_amount` of `loan_token` for `duration_weeks` at a weekly
+   * rate of at most `max_rate`, escrowing the collateral until the request is
+   * filled or cancelled
+   *
+   * The loan uses the collateral asset's collateral ratio and liquidation threshold.
+   */
+  create_borrow_request: (
+    {
+      borrower,
+      loan_token,
+      collateral_token,
+      collateral_amount,
+      desired_amount,
+      max_rate,
+      duration_weeks,
+    }: {
+      borrower: string;
+      loan_token: string;
+      collateral_token: string;
+      collateral_amount: i128;
+      desired_amount: i128;
+      max_rate: u32;
+      duration_weeks: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+  /**
+   * Construct and simulate a cancel_borrow_request transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel an open borrow request and return its collateral
+   */
+  cancel_borrow_request: (
+    {
+      borrower,
+      request_id,
+    }: {
+      borrower: string;
+      request_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a fill_borrow_request transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Fund a borrow request at `interest_rate` (at most its `max_rate`), opening a loan
+   * The loan tokens go straight from the lender to the borrower
+   */
+  fill_borrow_request: (
+    {
+      lender,
+      request_id,
+      interest_rate,
+    }: {
+      lender: string;
+      request_id: u64;
+      interest_rate: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+  /**
+   * Construct and simulate a is_liquidatable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check if a loan is liquidatable
+   */
+  is_liquidatable: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<boolean>>>;
+  /**
+   * Construct and simulate a liquidate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Liquidate an undercollateralized loan
+   * Repays up to `debt_to_cover` of debt, capped by the close factor
+   * Anyone can call this function
+   */
+  liquidate: (
+    {
+      liquidator,
+      loan_id,
+      debt_to_cover,
+    }: {
+      liquidator: string;
+      loan_id: u64;
+      debt_to_cover: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a start_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a Dutch auction for a liquidatable loan's collateral
+   * Anyone can call this function
+   */
+  start_auction: (
+    {
+      initiator,
+      loan_id,
+    }: {
+      initiator: string;
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a bid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Bid on a loan's auction: repay up to `amount` of its debt, capped at the
+   * close factor, and receive collateral at the auction's current discount
+   */
+  bid: (
+    {
+      bidder,
+      loan_id,
+      amount,
+    }: {
+      bidder: string;
+      loan_id: u64;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a claim_defaulted_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim the collateral of a loan that is past its due time
+   * Only the holder of the loan's position NFT can call this function
+   */
+  claim_defaulted_loan: (
+    {
+      lender,
+      loan_id,
+    }: {
+      lender: string;
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a batch_check_liquidations transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Batch check which loans are liquidatable
+   */
+  batch_check_liquidations: (
+    {
+      loan_ids,
+    }: {
+      loan_ids: Array<u64>;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<u64>>>>;
+  /**
+   * Construct and simulate a deposit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deposit USDC into the variable-rate pool
+   *
+   * Mints pool shares (this contract's SEP-41 token) at the current share
+   * price and returns the shares minted
+   */
+  deposit: (
+    {
+      lender,
+      amount,
+    }: {
+      lender: string;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a redeem transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Burn pool shares for their USDC value, limited by the pool's idle cash
+   *
+   * Returns the USDC redeemed
+   */
+  redeem: (
+    {
+      lender,
+      shares,
+    }: {
+      lender: string;
+      shares: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a pool_borrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Borrow USDC from the pool against `collateral_token` at the variable rate
+   *
+   * Returns the pool loan ID
+   */
+  pool_borrow: (
+    {
+      borrower,
+      collateral_token,
+      collateral_amount,
+      borrow_amount,
+    }: {
+      borrower: string;
+      collateral_token: string;
+      collateral_amount: i128;
+      borrow_amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u64>>>;
+  /**
+   * Construct and simulate a pool_repay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Repay part or all of a pool loan
+   * Collateral is returned once the debt is fully repaid
+   */
+  pool_repay: (
+    {
+      borrower,
+      loan_id,
+      repay_amount,
+    }: {
+      borrower: string;
+      loan_id: u64;
+      repay_amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a pool_add_collateral transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add more collateral to a pool loan
+   */
+  pool_add_collateral: (
+    {
+      borrower,
+      loan_id,
+      additional_collateral,
+    }: {
+      borrower: string;
+      loan_id: u64;
+      additional_collateral: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a pool_withdraw_collateral transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw excess collateral from a pool loan
+   */
+  pool_withdraw_collateral: (
+    {
+      borrower,
+      loan_id,
+      amount,
+    }: {
+      borrower: string;
+      loan_id: u64;
+      amount: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a pool_liquidate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Liquidate an undercollateralized pool loan, repaying up to the close
+   * factor of its debt by selling collateral on the DEX
+   * Anyone can call this function
+   */
+  pool_liquidate: (
+    {
+      liquidator,
+      loan_id,
+      debt_to_cover,
+    }: {
+      liquidator: string;
+      loan_id: u64;
+      debt_to_cover: i128;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a get_pool_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the pool accounting with interest accrued up to now
+   */
+  get_pool_state: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<PoolState>>>;
+  /**
+   * Construct and simulate a get_pool_rates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the pool's utilization and weekly borrow and supply rates
+   */
+  get_pool_rates: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<PoolRates>>>;
+  /**
+   * Construct and simulate a get_share_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the USDC value of one whole pool share, with interest accrued up to now
+   */
+  get_share_price: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a get_pool_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the USDC value of a lender's pool shares
+   */
+  get_pool_balance: (
+    {
+      lender,
+    }: {
+      lender: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a get_pool_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get pool loan details by ID
+   */
+  get_pool_loan: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<PoolLoan>>>;
+  /**
+   * Construct and simulate a get_pool_loan_debt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current debt of a pool loan
+   */
+  get_pool_loan_debt: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a get_user_pool_loans transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a user's open pool loans
+   */
+  get_user_pool_loans: (
+    {
+      user,
+    }: {
+      user: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_active_pool_loans_page transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get one page of active pool loan IDs (up to 50 per page, pages start at 0)
+   */
+  get_active_pool_loans_page: (
+    {
+      page,
+    }: {
+      page: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_active_pool_loan_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of active pool loans
+   */
+  get_active_pool_loan_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a get_pool_loan_health transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get pool loan health information
+   * Pool loans have no due time, so `due_time` is `u64::MAX`
+   */
+  get_pool_loan_health: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<LoanHealth>>>;
+  /**
+   * Construct and simulate a bump_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of a loan and its index entries
+   * Anyone can call this function (e.g. keepers for long-lived loans)
+   */
+  bump_loan: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a bump_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of an offer and its index entries
+   * Anyone can call this function
+   */
+  bump_offer: (
+    {
+      offer_id,
+    }: {
+      offer_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a bump_pool_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of a pool loan and its index entries
+   * Anyone can call this function (e.g. keepers for long-lived loans)
+   */
+  bump_pool_loan: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a bump_borrow_request transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Extend the TTL of a borrow request and its index entries
+   * Anyone can call this function
+   */
+  bump_borrow_request: (
+    {
+      request_id,
+    }: {
+      request_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a get_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get offer details
+   */
+  get_offer: (
+    {
+      offer_id,
+    }: {
+      offer_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<LendingOffer>>>;
+  /**
+   * Construct and simulate a get_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get loan details
+   */
+  get_loan: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Loan>>>;
+  /**
+   * Construct and simulate a get_borrow_request transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get borrow request details
+   */
+  get_borrow_request: (
+    {
+      request_id,
+    }: {
+      request_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<BorrowRequest>>>;
+  /**
+   * Construct and simulate a get_auction transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the auction running for a loan
+   */
+  get_auction: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Auction>>>;
+  /**
+   * Construct and simulate a get_auction_discount transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current collateral discount of a loan's auction in basis points
+   */
+  get_auction_discount: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+  /**
+   * Construct and simulate a get_offer_apy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the annual percentage yield of an offer in basis points
+   */
+  get_offer_apy: (
+    {
+      offer_id,
+    }: {
+      offer_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<u32>>>;
+  /**
+   * Construct and simulate a quote_borrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Preview how `borrow_best` would split a loan across offers
+   */
+  quote_borrow: (
+    {
+      borrower,
+      loan_token,
+      collateral_token,
+      collateral_amount,
+      amount,
+      max_rate,
+      duration_weeks,
+    }: {
+      borrower: string;
+      loan_token: string;
+      collateral_token: string;
+      collateral_amount: i128;
+      amount: i128;
+      max_rate: u32;
+      duration_weeks: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<BorrowFill>>>>;
+  /**
+   * Construct and simulate a get_loan_health transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get loan health information
+   */
+  get_loan_health: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<LoanHealth>>>;
+  /**
+   * Construct and simulate a calculate_interest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Calculate accumulated interest for a loan
+   */
+  calculate_interest: (
+    {
+      loan_id,
+    }: {
+      loan_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a get_collateral_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current USD price of a collateral token (with oracle decimals)
+   * from the oracle that served the latest price
+   */
+  get_collateral_price: (
+    {
+      token,
+    }: {
+      token: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a get_xlm_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current XLM price, kept for clients written against the XLM-only market
+   */
+  get_xlm_price: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<i128>>>;
+  /**
+   * Construct and simulate a get_user_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get user's offers
+   */
+  get_user_offers: (
+    {
+      user,
+    }: {
+      user: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_user_borrow_requests transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get user's open borrow requests
+   */
+  get_user_borrow_requests: (
+    {
+      user,
+    }: {
+      user: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_user_loans_as_borrower transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get user's open loans as borrower
+   */
+  get_user_loans_as_borrower: (
+    {
+      user,
+    }: {
+      user: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_user_loans_as_lender transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get user's loans as lender: loans the user funded before the position NFT
+   * was set, then every loan whose position NFT the user holds
+   * `limit` must be between 1 and 50
+   */
+  get_user_loans_as_lender: (
+    {
+      user,
+      offset,
+      limit,
+    }: {
+      user: string;
+      offset: u32;
+      limit: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<u64>>>>;
+  /**
+   * Construct and simulate a get_offer_loans transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the outstanding loans taken against an offer
+   */
+  get_offer_loans: (
+    {
+      offer_id,
+    }: {
+      offer_id: u64;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a list_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * List active offers with available funds, filtered, sorted and paginated
+   * The call walks the offers in listing order and reads at most 80 index entries and
+   * offers, so deep pages or narrow filters can come back short. `HighestAmount`
+   * ranks every active offer and fails with `InvalidSortOption` once there are more
+   * than it can read. `limit` must be between 1 and 50
+   */
+  list_offers: (
+    {
+      sort,
+      offset,
+      limit,
+      filter,
+    }: {
+      sort: SortOption;
+      offset: u32;
+      limit: u32;
+      filter: OfferFilter;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<Array<LendingOffer>>>>;
+  /**
+   * Construct and simulate a get_active_borrow_requests_page transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get one page of open borrow request IDs (up to 50 per page, pages start at 0)
+   */
+  get_active_borrow_requests_page: (
+    {
+      page,
+    }: {
+      page: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_active_offers_page transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get one page of active offer IDs (up to 50 per page, pages start at 0)
+   */
+  get_active_offers_page: (
+    {
+      page,
+    }: {
+      page: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_active_loans_page transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get one page of active loan IDs (up to 50 per page, pages start at 0)
+   */
+  get_active_loans_page: (
+    {
+      page,
+    }: {
+      page: u32;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Array<u64>>>;
+  /**
+   * Construct and simulate a get_active_offer_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of active offers
+   */
+  get_active_offer_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a get_active_loan_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of active loans
+   */
+  get_active_loan_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a set_position_nft transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the loan position NFT contract (once)
+   * The NFT contract must name this contract as its minter
+   */
+  set_position_nft: (
+    {
+      admin,
+      nft,
+    }: {
+      admin: string;
+      nft: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
+  /**
+   * Construct and simulate a get_collateral_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the risk parameters of a collateral token
+   */
+  get_collateral_config: (
+    {
+      token,
+    }: {
+      token: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<CollateralConfig>>>;
+  /**
+   * Construct and simulate a get_collateral_assets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get every token listed as collateral
+   */
+  get_collateral_assets: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>;
+  /**
+   * Construct and simulate a get_loan_asset transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the oracle asset and decimals of a loan token
+   */
+  get_loan_asset: (
+    {
+      token,
+    }: {
+      token: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<LoanAssetConfig>>>;
+  /**
+   * Construct and simulate a get_loan_assets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get every token offers may lend
+   */
+  get_loan_assets: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>;
   /**
-   * Price value
+   * Construct and simulate a pause_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause every operation except repayments, which keep their current flag
    */
-  price: i128;
+  pause_contract: (
+    {
+      caller,
+    }: {
+      caller: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Timestamp of the price
+   * Construct and simulate a unpause_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Clear every pause flag
    */
-  timestamp: u64;
-}
-export interface Client {
+  unpause_contract: (
+    {
+      caller,
+    }: {
+      caller: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a create_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Create a new lending offer
+   * Construct and simulate a set_pause_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause or resume individual operations
    */
-  create_offer: (
+  set_pause_state: (
     {
-      lender,
-      usdc_amount,
-      weekly_interest_rate,
-      min_collateral_ratio,
-      liquidation_threshold,
-      max_duration_weeks,
+      caller,
+      state,
     }: {
-      lender: string;
-      usdc_amount: i128;
-      weekly_interest_rate: u32;
-      min_collateral_ratio: u32;
-      liquidation_threshold: u32;
-      max_duration_weeks: u32;
+      caller: string;
+      state: PauseState;
     },
     options?: {
       /**
@@ -559,18 +3582,36 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<u64>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a cancel_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Cancel an offer (only if no active loans against it)
+   * Construct and simulate a get_pause_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get which operations are paused
    */
-  cancel_offer: (
+  get_pause_state: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PauseState>>;
+  /**
+   * Construct and simulate a set_ttl_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the storage TTL policy (in ledgers)
+   */
+  set_ttl_config: (
     {
-      lender,
-      offer_id,
+      admin,
+      config,
     }: {
-      lender: string;
-      offer_id: u64;
+      admin: string;
+      config: TtlConfig;
     },
     options?: {
       /**
@@ -588,49 +3629,255 @@ export interface Client {
     },
   ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a withdraw_from_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Withdraw unused funds from an offer
+   * Construct and simulate a get_ttl_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the storage TTL policy
+   */
+  get_ttl_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<TtlConfig>>;
+  /**
+   * Construct and simulate a get_dex_router transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the DEX router used on liquidation
+   */
+  get_dex_router: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+  /**
+   * Construct and simulate a get_position_nft transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the loan position NFT contract
+   */
+  get_position_nft: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
+  /**
+   * Construct and simulate a get_max_slippage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maximum liquidation swap slippage in basis points
+   */
+  get_max_slippage: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a get_fallback_oracle transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the secondary oracle, if any
+   */
+  get_fallback_oracle: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>;
+  /**
+   * Construct and simulate a get_oracle_source transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the oracle that served the latest price
+   */
+  get_oracle_source: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<OracleSource>>;
+  /**
+   * Construct and simulate a get_max_oracle_deviation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maximum primary/fallback price deviation in basis points
+   */
+  get_max_oracle_deviation: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a get_twap_records transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of oracle records averaged into the TWAP
+   */
+  get_twap_records: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a get_close_factor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the liquidation close factor in basis points
+   */
+  get_close_factor: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a get_auction_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the Dutch auction discount curve
+   */
+  get_auction_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<AuctionConfig>>;
+  /**
+   * Construct and simulate a get_rate_model transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the pool's utilization curve
+   */
+  get_rate_model: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<RateModel>>;
+  /**
+   * Construct and simulate a get_fee_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the protocol fee configuration
+   */
+  get_fee_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<FeeConfig>>;
+  /**
+   * Construct and simulate a get_risk_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the market-wide risk limits
    */
-  withdraw_from_offer: (
-    {
-      lender,
-      offer_id,
-      amount,
-    }: {
-      lender: string;
-      offer_id: u64;
-      amount: i128;
-    },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    },
-  ) => Promise<AssembledTransaction<Result<void>>>;
+  get_risk_config: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<RiskConfig>>;
   /**
-   * Construct and simulate a borrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Borrow USDC against XLM collateral
+   * Construct and simulate a claim_protocol_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Transfer all protocol fees accrued in `token` to `to`. Claiming USDC
+   * also releases the pool's interest reserves that are backed by idle pool cash
+   *
+   * Returns the amount claimed
    */
-  borrow: (
+  claim_protocol_fees: (
     {
-      borrower,
-      offer_id,
-      collateral_amount,
-      borrow_amount,
+      caller,
+      token,
+      to,
     }: {
-      borrower: string;
-      offer_id: u64;
-      collateral_amount: i128;
-      borrow_amount: i128;
+      caller: string;
+      token: string;
+      to: string;
     },
     options?: {
       /**
@@ -646,20 +3893,16 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<u64>>>;
+  ) => Promise<AssembledTransaction<Result<i128>>>;
   /**
-   * Construct and simulate a repay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Repay a loan (partial or full)
+   * Construct and simulate a get_protocol_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the unclaimed protocol fees held by the contract in `token`
    */
-  repay: (
+  get_protocol_fees: (
     {
-      borrower,
-      loan_id,
-      repay_amount,
+      token,
     }: {
-      borrower: string;
-      loan_id: u64;
-      repay_amount: i128;
+      token: string;
     },
     options?: {
       /**
@@ -675,49 +3918,39 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<void>>>;
+  ) => Promise<AssembledTransaction<i128>>;
   /**
-   * Construct and simulate a add_collateral transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Add more collateral to improve loan health
+   * Construct and simulate a admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get admin address
    */
-  add_collateral: (
-    {
-      borrower,
-      loan_id,
-      additional_collateral,
-    }: {
-      borrower: string;
-      loan_id: u64;
-      additional_collateral: i128;
-    },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    },
-  ) => Promise<AssembledTransaction<Result<void>>>;
+  admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<string>>>;
   /**
-   * Construct and simulate a withdraw_collateral transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Withdraw excess collateral (if health allows)
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose `new_admin` as the next admin, who must accept before `live_until_ledger`
+   * A `live_until_ledger` of 0 withdraws a pending proposal
    */
-  withdraw_collateral: (
+  propose_admin: (
     {
-      borrower,
-      loan_id,
-      amount,
+      admin,
+      new_admin,
+      live_until_ledger,
     }: {
-      borrower: string;
-      loan_id: u64;
-      amount: i128;
+      admin: string;
+      new_admin: string;
+      live_until_ledger: u32;
     },
     options?: {
       /**
@@ -735,14 +3968,36 @@ export interface Client {
     },
   ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a is_liquidatable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Check if a loan is liquidatable
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin proposal, authorized by the proposed admin
    */
-  is_liquidatable: (
+  accept_admin: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<void>>;
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant `role` to `account`
+   */
+  grant_role: (
     {
-      loan_id,
+      admin,
+      account,
+      role,
     }: {
-      loan_id: u64;
+      admin: string;
+      account: string;
+      role: Role;
     },
     options?: {
       /**
@@ -758,19 +4013,20 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<boolean>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a liquidate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Liquidate an undercollateralized loan
-   * Anyone can call this function
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke `role` from `account`
    */
-  liquidate: (
+  revoke_role: (
     {
-      liquidator,
-      loan_id,
+      admin,
+      account,
+      role,
     }: {
-      liquidator: string;
-      loan_id: u64;
+      admin: string;
+      account: string;
+      role: Role;
     },
     options?: {
       /**
@@ -788,14 +4044,16 @@ export interface Client {
     },
   ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a batch_check_liquidations transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Batch check which loans are liquidatable
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether `account` holds `role`
    */
-  batch_check_liquidations: (
+  has_role: (
     {
-      loan_ids,
+      account,
+      role,
     }: {
-      loan_ids: Array<u64>;
+      account: string;
+      role: Role;
     },
     options?: {
       /**
@@ -811,16 +4069,22 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<Array<u64>>>>;
+  ) => Promise<AssembledTransaction<boolean>>;
   /**
-   * Construct and simulate a get_offer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get offer details
+   * Construct and simulate a queue_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Queue a sensitive admin action to run once the timelock delay has passed
+   * Oracle actions need the oracle manager role, risk config the risk manager
+   * role, and upgrades and delay changes the admin
+   *
+   * Returns the action ID
    */
-  get_offer: (
+  queue_action: (
     {
-      offer_id,
+      caller,
+      action,
     }: {
-      offer_id: u64;
+      caller: string;
+      action: TimelockAction;
     },
     options?: {
       /**
@@ -836,16 +4100,18 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<LendingOffer>>>;
+  ) => Promise<AssembledTransaction<Result<u64>>>;
   /**
-   * Construct and simulate a get_loan transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get loan details
+   * Construct and simulate a cancel_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Drop a queued action before it runs
    */
-  get_loan: (
+  cancel_action: (
     {
-      loan_id,
+      caller,
+      action_id,
     }: {
-      loan_id: u64;
+      caller: string;
+      action_id: u64;
     },
     options?: {
       /**
@@ -861,16 +4127,18 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<Loan>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a get_loan_health transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get loan health information
+   * Construct and simulate a execute_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Run a queued action between its ETA and the end of the grace period
    */
-  get_loan_health: (
+  execute_action: (
     {
-      loan_id,
+      caller,
+      action_id,
     }: {
-      loan_id: u64;
+      caller: string;
+      action_id: u64;
     },
     options?: {
       /**
@@ -886,16 +4154,16 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<LoanHealth>>>;
+  ) => Promise<AssembledTransaction<Result<void>>>;
   /**
-   * Construct and simulate a calculate_interest transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Calculate accumulated interest for a loan
+   * Construct and simulate a get_queued_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a queued action by ID
    */
-  calculate_interest: (
+  get_queued_action: (
     {
-      loan_id,
+      action_id,
     }: {
-      loan_id: u64;
+      action_id: u64;
     },
     options?: {
       /**
@@ -911,12 +4179,12 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<i128>>>;
+  ) => Promise<AssembledTransaction<Result<QueuedAction>>>;
   /**
-   * Construct and simulate a get_xlm_price transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current XLM price
+   * Construct and simulate a get_queued_actions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get every action waiting in the timelock queue, oldest first
    */
-  get_xlm_price: (options?: {
+  get_queued_actions: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -929,66 +4197,39 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<i128>>>;
-  /**
-   * Construct and simulate a get_user_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get user's offers
-   */
-  get_user_offers: (
-    {
-      user,
-    }: {
-      user: string;
-    },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    },
-  ) => Promise<AssembledTransaction<Array<u64>>>;
+  }) => Promise<AssembledTransaction<Array<QueuedAction>>>;
   /**
-   * Construct and simulate a get_user_loans_as_borrower transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get user's loans as borrower
+   * Construct and simulate a get_timelock_delay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the delay between queueing an action and running it (in seconds)
    */
-  get_user_loans_as_borrower: (
-    {
-      user,
-    }: {
-      user: string;
-    },
-    options?: {
-      /**
-       * The fee to pay for the transaction. Default: BASE_FEE
-       */
-      fee?: number;
-      /**
-       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
-       */
-      timeoutInSeconds?: number;
-      /**
-       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
-       */
-      simulate?: boolean;
-    },
-  ) => Promise<AssembledTransaction<Array<u64>>>;
+  get_timelock_delay: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>;
   /**
-   * Construct and simulate a get_user_loans_as_lender transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get user's loans as lender
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rewrite up to `limit` stored loans and offers towards the schema of this build
+   * Call repeatedly after a timelocked upgrade until it returns `SCHEMA_VERSION`
+   *
+   * Returns the stored schema version after the batch
    */
-  get_user_loans_as_lender: (
+  migrate: (
     {
-      user,
+      admin,
+      limit,
     }: {
-      user: string;
+      admin: string;
+      limit: u32;
     },
     options?: {
       /**
@@ -1004,12 +4245,12 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Array<u64>>>;
+  ) => Promise<AssembledTransaction<Result<u32>>>;
   /**
-   * Construct and simulate a get_active_offers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all active offers
+   * Construct and simulate a get_schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the schema version of the stored loans and offers
    */
-  get_active_offers: (options?: {
+  get_schema_version: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1022,12 +4263,11 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<u64>>>;
+  }) => Promise<AssembledTransaction<u32>>;
   /**
-   * Construct and simulate a get_active_loans transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all active loans
+   * Construct and simulate a total_supply transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_active_loans: (options?: {
+  total_supply: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1040,18 +4280,41 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<u64>>>;
+  }) => Promise<AssembledTransaction<i128>>;
+  /**
+   * Construct and simulate a balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  balance: (
+    {
+      account,
+    }: {
+      account: string;
+    },
+    options?: {
+      /**
+       * The fee to pay for the transaction. Default: BASE_FEE
+       */
+      fee?: number;
+      /**
+       * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+       */
+      timeoutInSeconds?: number;
+      /**
+       * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+       */
+      simulate?: boolean;
+    },
+  ) => Promise<AssembledTransaction<i128>>;
   /**
-   * Construct and simulate a set_max_interest_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update maximum interest rate
+   * Construct and simulate a allowance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_max_interest_rate: (
+  allowance: (
     {
-      admin,
-      max_rate,
+      owner,
+      spender,
     }: {
-      admin: string;
-      max_rate: u32;
+      owner: string;
+      spender: string;
     },
     options?: {
       /**
@@ -1067,18 +4330,19 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<void>>>;
+  ) => Promise<AssembledTransaction<i128>>;
   /**
-   * Construct and simulate a set_oracle_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update oracle address
+   * Construct and simulate a transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_oracle_address: (
+  transfer: (
     {
-      admin,
-      oracle,
+      from,
+      to,
+      amount,
     }: {
-      admin: string;
-      oracle: string;
+      from: string;
+      to: string;
+      amount: i128;
     },
     options?: {
       /**
@@ -1094,16 +4358,21 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<void>>>;
+  ) => Promise<AssembledTransaction<void>>;
   /**
-   * Construct and simulate a pause_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Pause contract
+   * Construct and simulate a transfer_from transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  pause_contract: (
+  transfer_from: (
     {
-      admin,
+      spender,
+      from,
+      to,
+      amount,
     }: {
-      admin: string;
+      spender: string;
+      from: string;
+      to: string;
+      amount: i128;
     },
     options?: {
       /**
@@ -1119,16 +4388,21 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<void>>>;
+  ) => Promise<AssembledTransaction<void>>;
   /**
-   * Construct and simulate a unpause_contract transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Unpause contract
+   * Construct and simulate a approve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  unpause_contract: (
+  approve: (
     {
-      admin,
+      owner,
+      spender,
+      amount,
+      live_until_ledger,
     }: {
-      admin: string;
+      owner: string;
+      spender: string;
+      amount: i128;
+      live_until_ledger: u32;
     },
     options?: {
       /**
@@ -1144,12 +4418,11 @@ export interface Client {
        */
       simulate?: boolean;
     },
-  ) => Promise<AssembledTransaction<Result<void>>>;
+  ) => Promise<AssembledTransaction<void>>;
   /**
-   * Construct and simulate a admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get admin address
+   * Construct and simulate a decimals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  admin: (options?: {
+  decimals: (options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -1162,7 +4435,41 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Result<string>>>;
+  }) => Promise<AssembledTransaction<u32>>;
+  /**
+   * Construct and simulate a name transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  name: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>;
+  /**
+   * Construct and simulate a symbol transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  symbol: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>;
 }
 export declare class Client extends ContractClient {
   readonly options: ContractClientOptions;
@@ -1174,12 +4481,14 @@ export declare class Client extends ContractClient {
       xlm_token,
       oracle_address,
       max_interest_rate,
+      dex_router,
     }: {
       admin: string;
       usdc_token: string;
       xlm_token: string;
       oracle_address: string;
       max_interest_rate: u32;
+      dex_router: string;
     },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
@@ -1214,6 +4523,11 @@ export declare class Client extends ContractClient {
     ) => AssembledTransaction<
       Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    borrow_best: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint[], import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
     repay: (
       json: string,
     ) => AssembledTransaction<
@@ -1229,6 +4543,21 @@ export declare class Client extends ContractClient {
     ) => AssembledTransaction<
       Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    create_borrow_request: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    cancel_borrow_request: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    fill_borrow_request: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
     is_liquidatable: (
       json: string,
     ) => AssembledTransaction<
@@ -1239,11 +4568,121 @@ export declare class Client extends ContractClient {
     ) => AssembledTransaction<
       Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    start_auction: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    bid: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    claim_defaulted_loan: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
     batch_check_liquidations: (
       json: string,
     ) => AssembledTransaction<
       Result<bigint[], import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    deposit: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    redeem: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    pool_borrow: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    pool_repay: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    pool_add_collateral: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    pool_withdraw_collateral: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    pool_liquidate: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_pool_state: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<PoolState, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_pool_rates: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<PoolRates, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_share_price: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_pool_balance: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_pool_loan: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<PoolLoan, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_pool_loan_debt: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_user_pool_loans: (json: string) => AssembledTransaction<bigint[]>;
+    get_active_pool_loans_page: (
+      json: string,
+    ) => AssembledTransaction<bigint[]>;
+    get_active_pool_loan_count: (json: string) => AssembledTransaction<number>;
+    get_pool_loan_health: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<LoanHealth, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    bump_loan: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    bump_offer: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    bump_pool_loan: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    bump_borrow_request: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
     get_offer: (
       json: string,
     ) => AssembledTransaction<
@@ -1254,6 +4693,34 @@ export declare class Client extends ContractClient {
     ) => AssembledTransaction<
       Result<Loan, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    get_borrow_request: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<
+        BorrowRequest,
+        import("@stellar/stellar-sdk/contract").ErrorMessage
+      >
+    >;
+    get_auction: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<Auction, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_auction_discount: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_offer_apy: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    quote_borrow: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<BorrowFill[], import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
     get_loan_health: (
       json: string,
     ) => AssembledTransaction<
@@ -1264,28 +4731,65 @@ export declare class Client extends ContractClient {
     ) => AssembledTransaction<
       Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    get_collateral_price: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
     get_xlm_price: (
       json: string,
     ) => AssembledTransaction<
       Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
     get_user_offers: (json: string) => AssembledTransaction<bigint[]>;
+    get_user_borrow_requests: (json: string) => AssembledTransaction<bigint[]>;
     get_user_loans_as_borrower: (
       json: string,
     ) => AssembledTransaction<bigint[]>;
-    get_user_loans_as_lender: (json: string) => AssembledTransaction<bigint[]>;
-    get_active_offers: (json: string) => AssembledTransaction<bigint[]>;
-    get_active_loans: (json: string) => AssembledTransaction<bigint[]>;
-    set_max_interest_rate: (
+    get_user_loans_as_lender: (
       json: string,
     ) => AssembledTransaction<
-      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+      Result<bigint[], import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_offer_loans: (json: string) => AssembledTransaction<bigint[]>;
+    list_offers: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<
+        LendingOffer[],
+        import("@stellar/stellar-sdk/contract").ErrorMessage
+      >
     >;
-    set_oracle_address: (
+    get_active_borrow_requests_page: (
+      json: string,
+    ) => AssembledTransaction<bigint[]>;
+    get_active_offers_page: (json: string) => AssembledTransaction<bigint[]>;
+    get_active_loans_page: (json: string) => AssembledTransaction<bigint[]>;
+    get_active_offer_count: (json: string) => AssembledTransaction<number>;
+    get_active_loan_count: (json: string) => AssembledTransaction<number>;
+    set_position_nft: (
       json: string,
     ) => AssembledTransaction<
       Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    get_collateral_config: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<
+        CollateralConfig,
+        import("@stellar/stellar-sdk/contract").ErrorMessage
+      >
+    >;
+    get_collateral_assets: (json: string) => AssembledTransaction<string[]>;
+    get_loan_asset: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<
+        LoanAssetConfig,
+        import("@stellar/stellar-sdk/contract").ErrorMessage
+      >
+    >;
+    get_loan_assets: (json: string) => AssembledTransaction<string[]>;
     pause_contract: (
       json: string,
     ) => AssembledTransaction<
@@ -1296,10 +4800,104 @@ export declare class Client extends ContractClient {
     ) => AssembledTransaction<
       Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    set_pause_state: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_pause_state: (json: string) => AssembledTransaction<PauseState>;
+    set_ttl_config: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_ttl_config: (json: string) => AssembledTransaction<TtlConfig>;
+    get_dex_router: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_position_nft: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_max_slippage: (json: string) => AssembledTransaction<number>;
+    get_fallback_oracle: (
+      json: string,
+    ) => AssembledTransaction<string | undefined>;
+    get_oracle_source: (json: string) => AssembledTransaction<OracleSource>;
+    get_max_oracle_deviation: (json: string) => AssembledTransaction<number>;
+    get_twap_records: (json: string) => AssembledTransaction<number>;
+    get_close_factor: (json: string) => AssembledTransaction<number>;
+    get_auction_config: (json: string) => AssembledTransaction<AuctionConfig>;
+    get_rate_model: (json: string) => AssembledTransaction<RateModel>;
+    get_fee_config: (json: string) => AssembledTransaction<FeeConfig>;
+    get_risk_config: (json: string) => AssembledTransaction<RiskConfig>;
+    claim_protocol_fees: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_protocol_fees: (json: string) => AssembledTransaction<bigint>;
     admin: (
       json: string,
     ) => AssembledTransaction<
       Result<string, import("@stellar/stellar-sdk/contract").ErrorMessage>
     >;
+    propose_admin: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    accept_admin: (json: string) => AssembledTransaction<void>;
+    grant_role: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    revoke_role: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    has_role: (json: string) => AssembledTransaction<boolean>;
+    queue_action: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<bigint, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    cancel_action: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    execute_action: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<void, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_queued_action: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<QueuedAction, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_queued_actions: (json: string) => AssembledTransaction<QueuedAction[]>;
+    get_timelock_delay: (json: string) => AssembledTransaction<bigint>;
+    migrate: (
+      json: string,
+    ) => AssembledTransaction<
+      Result<number, import("@stellar/stellar-sdk/contract").ErrorMessage>
+    >;
+    get_schema_version: (json: string) => AssembledTransaction<number>;
+    total_supply: (json: string) => AssembledTransaction<bigint>;
+    balance: (json: string) => AssembledTransaction<bigint>;
+    allowance: (json: string) => AssembledTransaction<bigint>;
+    transfer: (json: string) => AssembledTransaction<void>;
+    transfer_from: (json: string) => AssembledTransaction<void>;
+    approve: (json: string) => AssembledTransaction<void>;
+    decimals: (json: string) => AssembledTransaction<number>;
+    name: (json: string) => AssembledTransaction<string>;
+    symbol: (json: string) => AssembledTransaction<string>;
   };
 }
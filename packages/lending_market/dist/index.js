@@ -41,6 +41,10 @@ export const Errors = {
    * Only borrower can perform this operation
    */
   13: { message: "OnlyBorrower" },
+  /**
+   * Account does not hold the role
+   */
+  14: { message: "RoleNotHeld" },
   /**
    * Offer not found
    */
@@ -77,6 +81,10 @@ export const Errors = {
    * Cannot cancel offer with active loans
    */
   28: { message: "OfferHasActiveLoans" },
+  /**
+   * Loan token already has offers at the maximum number of distinct rates
+   */
+  29: { message: "TooManyOfferRates" },
   /**
    * Loan not found
    */
@@ -117,6 +125,10 @@ export const Errors = {
    * Loan duration exceeds maximum allowed
    */
   49: { message: "LoanDurationExceeded" },
+  /**
+   * Loan is not past its due time yet
+   */
+  50: { message: "LoanNotOverdue" },
   /**
    * Loan is not liquidatable (health is above threshold)
    */
@@ -133,6 +145,30 @@ export const Errors = {
    * Insufficient collateral value to cover debt
    */
   63: { message: "InsufficientCollateralValue" },
+  /**
+   * DEX router address not set
+   */
+  64: { message: "DexRouterNotSet" },
+  /**
+   * Invalid slippage tolerance
+   */
+  65: { message: "InvalidSlippage" },
+  /**
+   * Invalid close factor (must be between 0 and 100%)
+   */
+  66: { message: "InvalidCloseFactor" },
+  /**
+   * No auction is running for the loan
+   */
+  67: { message: "AuctionNotFound" },
+  /**
+   * An auction is already running for the loan
+   */
+  68: { message: "AuctionAlreadyActive" },
+  /**
+   * Invalid auction configuration
+   */
+  69: { message: "InvalidAuctionConfig" },
   /**
    * Oracle address not set
    */
@@ -150,13 +186,21 @@ export const Errors = {
    */
   83: { message: "InvalidPriceData" },
   /**
-   * USDC token not set
+   * TWAP window outside 1..=MAX_TWAP_RECORDS records
    */
-  100: { message: "UsdcTokenNotSet" },
+  84: { message: "InvalidTwapRecords" },
+  /**
+   * Primary and fallback oracles disagree by more than the allowed deviation
+   */
+  85: { message: "OraclePriceDeviation" },
   /**
-   * XLM token not set
+   * Oracle deviation limit out of range
    */
-  101: { message: "XlmTokenNotSet" },
+  86: { message: "InvalidOracleDeviation" },
+  /**
+   * USDC token not set
+   */
+  100: { message: "UsdcTokenNotSet" },
   /**
    * Token transfer failed
    */
@@ -166,7 +210,35 @@ export const Errors = {
    */
   103: { message: "InsufficientBalance" },
   /**
-   * Contract is paused
+   * Loan position NFT contract not set
+   */
+  104: { message: "PositionNftNotSet" },
+  /**
+   * Loan position NFT contract is already set or does not let this contract mint
+   */
+  105: { message: "InvalidPositionNft" },
+  /**
+   * Offer does not accept the collateral token
+   */
+  106: { message: "CollateralNotAccepted" },
+  /**
+   * Invalid collateral risk parameters
+   */
+  107: { message: "InvalidCollateralConfig" },
+  /**
+   * Token is not registered as a loan asset
+   */
+  108: { message: "UnsupportedLoanAsset" },
+  /**
+   * Invalid loan asset parameters
+   */
+  109: { message: "InvalidLoanAsset" },
+  /**
+   * Collateral token is not in the registry
+   */
+  110: { message: "UnsupportedCollateral" },
+  /**
+   * The requested operation is paused
    */
   120: { message: "ContractPaused" },
   /**
@@ -189,6 +261,22 @@ export const Errors = {
    * Division by zero
    */
   125: { message: "DivisionByZero" },
+  /**
+   * Invalid storage TTL configuration
+   */
+  126: { message: "InvalidTtlConfig" },
+  /**
+   * Invalid protocol fee configuration
+   */
+  127: { message: "InvalidFeeConfig" },
+  /**
+   * Invalid risk configuration
+   */
+  128: { message: "InvalidRiskConfig" },
+  /**
+   * Stored schema is newer than this build can migrate
+   */
+  129: { message: "UnsupportedSchemaVersion" },
   /**
    * Invalid sort option
    */
@@ -205,56 +293,285 @@ export const Errors = {
    * No loans found
    */
   143: { message: "NoLoansFound" },
+  /**
+   * Pool loan not found
+   */
+  160: { message: "PoolLoanNotFound" },
+  /**
+   * Not enough idle USDC in the pool
+   */
+  161: { message: "InsufficientPoolLiquidity" },
+  /**
+   * Invalid pool utilization curve
+   */
+  162: { message: "InvalidRateModel" },
+  /**
+   * Lender holds fewer pool shares than requested
+   */
+  163: { message: "InsufficientShares" },
+  /**
+   * Bad debt has wiped out the pool's assets while shares are outstanding
+   */
+  164: { message: "PoolInsolvent" },
+  /**
+   * Timelocked action not found
+   */
+  180: { message: "ActionNotFound" },
+  /**
+   * Timelock delay has not passed yet
+   */
+  181: { message: "ActionNotReady" },
+  /**
+   * Grace period to run the action has passed
+   */
+  182: { message: "ActionExpired" },
+  /**
+   * Invalid timelock delay
+   */
+  183: { message: "InvalidTimelockDelay" },
+  /**
+   * Timelock queue is full
+   */
+  184: { message: "TooManyQueuedActions" },
+  /**
+   * Borrow request not found
+   */
+  200: { message: "BorrowRequestNotFound" },
+  /**
+   * Borrow request is no longer open
+   */
+  201: { message: "BorrowRequestNotActive" },
+  /**
+   * User has too many open borrow requests
+   */
+  202: { message: "TooManyBorrowRequests" },
 };
 export class Client extends ContractClient {
   options;
   static async deploy(
     /** Constructor/Initialization Args for the contract's `__constructor` method */
-    { admin, usdc_token, xlm_token, oracle_address, max_interest_rate },
+    {
+      admin,
+      usdc_token,
+      xlm_token,
+      oracle_address,
+      max_interest_rate,
+      dex_router,
+    },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options,
   ) {
     return ContractClient.deploy(
-      { admin, usdc_token, xlm_token, oracle_address, max_interest_rate },
+      {
+        admin,
+        usdc_token,
+        xlm_token,
+        oracle_address,
+        max_interest_rate,
+        dex_router,
+      },
       options,
     );
   }
   constructor(options) {
     super(
       new ContractSpec([
-        "AAAAAAAAADdJbml0aWFsaXplIHRoZSBjb250cmFjdApUaGlzIHNob3VsZCBvbmx5IGJlIGNhbGxlZCBvbmNlAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAABQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAp1c2RjX3Rva2VuAAAAAAATAAAAAAAAAAl4bG1fdG9rZW4AAAAAAAATAAAAAAAAAA5vcmFjbGVfYWRkcmVzcwAAAAAAEwAAAAAAAAARbWF4X2ludGVyZXN0X3JhdGUAAAAAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAABpDcmVhdGUgYSBuZXcgbGVuZGluZyBvZmZlcgAAAAAADGNyZWF0ZV9vZmZlcgAAAAYAAAAAAAAABmxlbmRlcgAAAAAAEwAAAAAAAAALdXNkY19hbW91bnQAAAAACwAAAAAAAAAUd2Vla2x5X2ludGVyZXN0X3JhdGUAAAAEAAAAAAAAABRtaW5fY29sbGF0ZXJhbF9yYXRpbwAAAAQAAAAAAAAAFWxpcXVpZGF0aW9uX3RocmVzaG9sZAAAAAAAAAQAAAAAAAAAEm1heF9kdXJhdGlvbl93ZWVrcwAAAAAABAAAAAEAAAPpAAAABgAAAAM=",
-        "AAAAAAAAADRDYW5jZWwgYW4gb2ZmZXIgKG9ubHkgaWYgbm8gYWN0aXZlIGxvYW5zIGFnYWluc3QgaXQpAAAADGNhbmNlbF9vZmZlcgAAAAIAAAAAAAAABmxlbmRlcgAAAAAAEwAAAAAAAAAIb2ZmZXJfaWQAAAAGAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAACNXaXRoZHJhdyB1bnVzZWQgZnVuZHMgZnJvbSBhbiBvZmZlcgAAAAATd2l0aGRyYXdfZnJvbV9vZmZlcgAAAAADAAAAAAAAAAZsZW5kZXIAAAAAABMAAAAAAAAACG9mZmVyX2lkAAAABgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAACJCb3Jyb3cgVVNEQyBhZ2FpbnN0IFhMTSBjb2xsYXRlcmFsAAAAAAAGYm9ycm93AAAAAAAEAAAAAAAAAAhib3Jyb3dlcgAAABMAAAAAAAAACG9mZmVyX2lkAAAABgAAAAAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAAAAAAA1ib3Jyb3dfYW1vdW50AAAAAAAACwAAAAEAAAPpAAAABgAAAAM=",
-        "AAAAAAAAAB5SZXBheSBhIGxvYW4gKHBhcnRpYWwgb3IgZnVsbCkAAAAAAAVyZXBheQAAAAAAAAMAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAAAAAAxyZXBheV9hbW91bnQAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAACpBZGQgbW9yZSBjb2xsYXRlcmFsIHRvIGltcHJvdmUgbG9hbiBoZWFsdGgAAAAAAA5hZGRfY29sbGF0ZXJhbAAAAAAAAwAAAAAAAAAIYm9ycm93ZXIAAAATAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAAAAAAAFWFkZGl0aW9uYWxfY29sbGF0ZXJhbAAAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAC1XaXRoZHJhdyBleGNlc3MgY29sbGF0ZXJhbCAoaWYgaGVhbHRoIGFsbG93cykAAAAAAAATd2l0aGRyYXdfY29sbGF0ZXJhbAAAAAADAAAAAAAAAAhib3Jyb3dlcgAAABMAAAAAAAAAB2xvYW5faWQAAAAABgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+        "AAAAAAAAADdJbml0aWFsaXplIHRoZSBjb250cmFjdApUaGlzIHNob3VsZCBvbmx5IGJlIGNhbGxlZCBvbmNlAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAABgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAp1c2RjX3Rva2VuAAAAAAATAAAAAAAAAAl4bG1fdG9rZW4AAAAAAAATAAAAAAAAAA5vcmFjbGVfYWRkcmVzcwAAAAAAEwAAAAAAAAARbWF4X2ludGVyZXN0X3JhdGUAAAAAAAAEAAAAAAAAAApkZXhfcm91dGVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADZDcmVhdGUgYSBuZXcgbGVuZGluZyBvZmZlciBvZiBgYW1vdW50YCBvZiBgbG9hbl90b2tlbmAAAAAAAAxjcmVhdGVfb2ZmZXIAAAAJAAAAAAAAAAZsZW5kZXIAAAAAABMAAAAAAAAACmxvYW5fdG9rZW4AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAUd2Vla2x5X2ludGVyZXN0X3JhdGUAAAAEAAAAAAAAAA1pbnRlcmVzdF9tb2RlAAAAAAAH0AAAAAxJbnRlcmVzdE1vZGUAAAAAAAAAFG1pbl9jb2xsYXRlcmFsX3JhdGlvAAAABAAAAAAAAAAVbGlxdWlkYXRpb25fdGhyZXNob2xkAAAAAAAABAAAAAAAAAASbWF4X2R1cmF0aW9uX3dlZWtzAAAAAAAEAAAAAAAAABFjb2xsYXRlcmFsX2Fzc2V0cwAAAAAAA+oAAAATAAAAAQAAA+kAAAAGAAAAAw==",
+        "AAAAAAAAAJFDYW5jZWwgYW4gb2ZmZXIKSWYgbG9hbnMgYWdhaW5zdCB0aGUgb2ZmZXIgYXJlIHN0aWxsIG91dHN0YW5kaW5nLCBvbmx5IGl0cyBpZGxlIGZ1bmRzIGFyZSByZXR1cm5lZAphbmQgdGhlIG9mZmVyIHN0YXlzIG9wZW4gdW50aWwgdGhleSBhcmUgY2xvc2VkAAAAAAAADGNhbmNlbF9vZmZlcgAAAAIAAAAAAAAABmxlbmRlcgAAAAAAEwAAAAAAAAAIb2ZmZXJfaWQAAAAGAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAACNXaXRoZHJhdyB1bnVzZWQgZnVuZHMgZnJvbSBhbiBvZmZlcgAAAAATd2l0aGRyYXdfZnJvbV9vZmZlcgAAAAADAAAAAAAAAAZsZW5kZXIAAAAAABMAAAAAAAAACG9mZmVyX2lkAAAABgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAOpCb3Jyb3cgdGhlIG9mZmVyJ3MgbG9hbiB0b2tlbiBhZ2FpbnN0IGBjb2xsYXRlcmFsX3Rva2VuYCBmb3IgYGR1cmF0aW9uX3dlZWtzYAooYXQgbW9zdCB0aGUgb2ZmZXIncyBgbWF4X2R1cmF0aW9uX3dlZWtzYCkKClRoZSBsb2FuIHVzZXMgdGhlIHN0cmljdGVyIG9mIHRoZSBvZmZlcidzIGFuZCB0aGUgY29sbGF0ZXJhbCBhc3NldCdzCmNvbGxhdGVyYWwgcmF0aW8gYW5kIGxpcXVpZGF0aW9uIHRocmVzaG9sZC4AAAAAAAZib3Jyb3cAAAAAAAYAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAAAAAAIb2ZmZXJfaWQAAAAGAAAAAAAAABBjb2xsYXRlcmFsX3Rva2VuAAAAEwAAAAAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAAAAAAA1ib3Jyb3dfYW1vdW50AAAAAAAACwAAAAAAAAAOZHVyYXRpb25fd2Vla3MAAAAAAAQAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAWFCb3Jyb3cgYGFtb3VudGAgb2YgYGxvYW5fdG9rZW5gIGZyb20gdGhlIGNoZWFwZXN0IG9mZmVycyBhdCBvciBiZWxvdyBgbWF4X3JhdGVgLApvcGVuaW5nIG9uZSBsb2FuIHBlciBvZmZlciwgYW5kIHJldHVybiB0aGUgbG9hbiBJRHMKClRoZSBjb2xsYXRlcmFsIGlzIHNwbGl0IGFjcm9zcyB0aGUgbG9hbnMgaW4gcHJvcG9ydGlvbiB0byB3aGF0IGVhY2ggYm9ycm93czsKYHF1b3RlX2JvcnJvd2Agc2hvd3MgdGhlIHNwbGl0IHdpdGhvdXQgYm9ycm93aW5nLiBPZmZlcnMgYXJlIHJlYWQgY2hlYXBlc3QgZmlyc3QsCmF0IG1vc3QgMjAgb2YgdGhlbSwgYW5kIGF0IG1vc3QgNSBsb2FucyBhcmUgb3BlbmVkIHBlciBjYWxsLgAAAAAAAAtib3Jyb3dfYmVzdAAAAAAHAAAAAAAAAAhib3Jyb3dlcgAAABMAAAAAAAAACmxvYW5fdG9rZW4AAAAAABMAAAAAAAAAEGNvbGxhdGVyYWxfdG9rZW4AAAATAAAAAAAAABFjb2xsYXRlcmFsX2Ftb3VudAAAAAAAAAsAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAIbWF4X3JhdGUAAAAEAAAAAAAAAA5kdXJhdGlvbl93ZWVrcwAAAAAABAAAAAEAAAPpAAAD6gAAAAYAAAAD",
+        "AAAAAAAAAB5SZXBheSBhIGxvYW4gKHBhcnRpYWwgb3IgZnVsbCkAAAAAAAVyZXBheQAAAAAAAAMAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAAAAAAxyZXBheV9hbW91bnQAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAACpBZGQgbW9yZSBjb2xsYXRlcmFsIHRvIGltcHJvdmUgbG9hbiBoZWFsdGgAAAAAAA5hZGRfY29sbGF0ZXJhbAAAAAAAAwAAAAAAAAAIYm9ycm93ZXIAAAATAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAAAAAAAFWFkZGl0aW9uYWxfY29sbGF0ZXJhbAAAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAC1XaXRoZHJhdyBleGNlc3MgY29sbGF0ZXJhbCAoaWYgaGVhbHRoIGFsbG93cykAAAAAAAATd2l0aGRyYXdfY29sbGF0ZXJhbAAAAAADAAAAAAAAAAhib3Jyb3dlcgAAABMAAAAAAAAAB2xvYW5faWQAAAAABgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAP9Bc2sgdG8gYm9ycm93IGBkZXNpcmVkX2Ftb3VudGAgb2YgYGxvYW5fdG9rZW5gIGZvciBgZHVyYXRpb25fd2Vla3NgIGF0IGEgd2Vla2x5CnJhdGUgb2YgYXQgbW9zdCBgbWF4X3JhdGVgLCBlc2Nyb3dpbmcgdGhlIGNvbGxhdGVyYWwgdW50aWwgdGhlIHJlcXVlc3QgaXMKZmlsbGVkIG9yIGNhbmNlbGxlZAoKVGhlIGxvYW4gdXNlcyB0aGUgY29sbGF0ZXJhbCBhc3NldCdzIGNvbGxhdGVyYWwgcmF0aW8gYW5kIGxpcXVpZGF0aW9uIHRocmVzaG9sZC4AAAAAFWNyZWF0ZV9ib3Jyb3dfcmVxdWVzdAAAAAAAAAcAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAAAAAAKbG9hbl90b2tlbgAAAAAAEwAAAAAAAAAQY29sbGF0ZXJhbF90b2tlbgAAABMAAAAAAAAAEWNvbGxhdGVyYWxfYW1vdW50AAAAAAAACwAAAAAAAAAOZGVzaXJlZF9hbW91bnQAAAAAAAsAAAAAAAAACG1heF9yYXRlAAAABAAAAAAAAAAOZHVyYXRpb25fd2Vla3MAAAAAAAQAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAADdDYW5jZWwgYW4gb3BlbiBib3Jyb3cgcmVxdWVzdCBhbmQgcmV0dXJuIGl0cyBjb2xsYXRlcmFsAAAAABVjYW5jZWxfYm9ycm93X3JlcXVlc3QAAAAAAAACAAAAAAAAAAhib3Jyb3dlcgAAABMAAAAAAAAACnJlcXVlc3RfaWQAAAAAAAYAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAI1GdW5kIGEgYm9ycm93IHJlcXVlc3QgYXQgYGludGVyZXN0X3JhdGVgIChhdCBtb3N0IGl0cyBgbWF4X3JhdGVgKSwgb3BlbmluZyBhIGxvYW4KVGhlIGxvYW4gdG9rZW5zIGdvIHN0cmFpZ2h0IGZyb20gdGhlIGxlbmRlciB0byB0aGUgYm9ycm93ZXIAAAAAAAATZmlsbF9ib3Jyb3dfcmVxdWVzdAAAAAADAAAAAAAAAAZsZW5kZXIAAAAAABMAAAAAAAAACnJlcXVlc3RfaWQAAAAAAAYAAAAAAAAADWludGVyZXN0X3JhdGUAAAAAAAAEAAAAAQAAA+kAAAAGAAAAAw==",
         "AAAAAAAAAB9DaGVjayBpZiBhIGxvYW4gaXMgbGlxdWlkYXRhYmxlAAAAAA9pc19saXF1aWRhdGFibGUAAAAAAQAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAQAAA+kAAAABAAAAAw==",
-        "AAAAAAAAAENMaXF1aWRhdGUgYW4gdW5kZXJjb2xsYXRlcmFsaXplZCBsb2FuCkFueW9uZSBjYW4gY2FsbCB0aGlzIGZ1bmN0aW9uAAAAAAlsaXF1aWRhdGUAAAAAAAACAAAAAAAAAApsaXF1aWRhdG9yAAAAAAATAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAIRMaXF1aWRhdGUgYW4gdW5kZXJjb2xsYXRlcmFsaXplZCBsb2FuClJlcGF5cyB1cCB0byBgZGVidF90b19jb3ZlcmAgb2YgZGVidCwgY2FwcGVkIGJ5IHRoZSBjbG9zZSBmYWN0b3IKQW55b25lIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24AAAAJbGlxdWlkYXRlAAAAAAAAAwAAAAAAAAAKbGlxdWlkYXRvcgAAAAAAEwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAAAAAA1kZWJ0X3RvX2NvdmVyAAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAFhTdGFydCBhIER1dGNoIGF1Y3Rpb24gZm9yIGEgbGlxdWlkYXRhYmxlIGxvYW4ncyBjb2xsYXRlcmFsCkFueW9uZSBjYW4gY2FsbCB0aGlzIGZ1bmN0aW9uAAAADXN0YXJ0X2F1Y3Rpb24AAAAAAAACAAAAAAAAAAlpbml0aWF0b3IAAAAAAAATAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAI9CaWQgb24gYSBsb2FuJ3MgYXVjdGlvbjogcmVwYXkgdXAgdG8gYGFtb3VudGAgb2YgaXRzIGRlYnQsIGNhcHBlZCBhdCB0aGUKY2xvc2UgZmFjdG9yLCBhbmQgcmVjZWl2ZSBjb2xsYXRlcmFsIGF0IHRoZSBhdWN0aW9uJ3MgY3VycmVudCBkaXNjb3VudAAAAAADYmlkAAAAAAMAAAAAAAAABmJpZGRlcgAAAAAAEwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAHpDbGFpbSB0aGUgY29sbGF0ZXJhbCBvZiBhIGxvYW4gdGhhdCBpcyBwYXN0IGl0cyBkdWUgdGltZQpPbmx5IHRoZSBob2xkZXIgb2YgdGhlIGxvYW4ncyBwb3NpdGlvbiBORlQgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbgAAAAAAFGNsYWltX2RlZmF1bHRlZF9sb2FuAAAAAgAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAChCYXRjaCBjaGVjayB3aGljaCBsb2FucyBhcmUgbGlxdWlkYXRhYmxlAAAAGGJhdGNoX2NoZWNrX2xpcXVpZGF0aW9ucwAAAAEAAAAAAAAACGxvYW5faWRzAAAD6gAAAAYAAAABAAAD6QAAA+oAAAAGAAAAAw==",
+        "AAAAAAAAAJNEZXBvc2l0IFVTREMgaW50byB0aGUgdmFyaWFibGUtcmF0ZSBwb29sCgpNaW50cyBwb29sIHNoYXJlcyAodGhpcyBjb250cmFjdCdzIFNFUC00MSB0b2tlbikgYXQgdGhlIGN1cnJlbnQgc2hhcmUKcHJpY2UgYW5kIHJldHVybnMgdGhlIHNoYXJlcyBtaW50ZWQAAAAAB2RlcG9zaXQAAAAAAgAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAGFCdXJuIHBvb2wgc2hhcmVzIGZvciB0aGVpciBVU0RDIHZhbHVlLCBsaW1pdGVkIGJ5IHRoZSBwb29sJ3MgaWRsZSBjYXNoCgpSZXR1cm5zIHRoZSBVU0RDIHJlZGVlbWVkAAAAAAAABnJlZGVlbQAAAAAAAgAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAAAAAAZzaGFyZXMAAAAAAAsAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAGNCb3Jyb3cgVVNEQyBmcm9tIHRoZSBwb29sIGFnYWluc3QgYGNvbGxhdGVyYWxfdG9rZW5gIGF0IHRoZSB2YXJpYWJsZSByYXRlCgpSZXR1cm5zIHRoZSBwb29sIGxvYW4gSUQAAAAAC3Bvb2xfYm9ycm93AAAAAAQAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAAAAAAQY29sbGF0ZXJhbF90b2tlbgAAABMAAAAAAAAAEWNvbGxhdGVyYWxfYW1vdW50AAAAAAAACwAAAAAAAAANYm9ycm93X2Ftb3VudAAAAAAAAAsAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAFVSZXBheSBwYXJ0IG9yIGFsbCBvZiBhIHBvb2wgbG9hbgpDb2xsYXRlcmFsIGlzIHJldHVybmVkIG9uY2UgdGhlIGRlYnQgaXMgZnVsbHkgcmVwYWlkAAAAAAAACnBvb2xfcmVwYXkAAAAAAAMAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAAAAAAxyZXBheV9hbW91bnQAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAACJBZGQgbW9yZSBjb2xsYXRlcmFsIHRvIGEgcG9vbCBsb2FuAAAAAAATcG9vbF9hZGRfY29sbGF0ZXJhbAAAAAADAAAAAAAAAAhib3Jyb3dlcgAAABMAAAAAAAAAB2xvYW5faWQAAAAABgAAAAAAAAAVYWRkaXRpb25hbF9jb2xsYXRlcmFsAAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAACtXaXRoZHJhdyBleGNlc3MgY29sbGF0ZXJhbCBmcm9tIGEgcG9vbCBsb2FuAAAAABhwb29sX3dpdGhkcmF3X2NvbGxhdGVyYWwAAAADAAAAAAAAAAhib3Jyb3dlcgAAABMAAAAAAAAAB2xvYW5faWQAAAAABgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAJZMaXF1aWRhdGUgYW4gdW5kZXJjb2xsYXRlcmFsaXplZCBwb29sIGxvYW4sIHJlcGF5aW5nIHVwIHRvIHRoZSBjbG9zZQpmYWN0b3Igb2YgaXRzIGRlYnQgYnkgc2VsbGluZyBjb2xsYXRlcmFsIG9uIHRoZSBERVgKQW55b25lIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24AAAAAAA5wb29sX2xpcXVpZGF0ZQAAAAAAAwAAAAAAAAAKbGlxdWlkYXRvcgAAAAAAEwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAAAAAA1kZWJ0X3RvX2NvdmVyAAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAADdHZXQgdGhlIHBvb2wgYWNjb3VudGluZyB3aXRoIGludGVyZXN0IGFjY3J1ZWQgdXAgdG8gbm93AAAAAA5nZXRfcG9vbF9zdGF0ZQAAAAAAAAAAAAEAAAPpAAAH0AAAAAlQb29sU3RhdGUAAAAAAAAD",
+        "AAAAAAAAAD1HZXQgdGhlIHBvb2wncyB1dGlsaXphdGlvbiBhbmQgd2Vla2x5IGJvcnJvdyBhbmQgc3VwcGx5IHJhdGVzAAAAAAAADmdldF9wb29sX3JhdGVzAAAAAAAAAAAAAQAAA+kAAAfQAAAACVBvb2xSYXRlcwAAAAAAAAM=",
+        "AAAAAAAAAEtHZXQgdGhlIFVTREMgdmFsdWUgb2Ygb25lIHdob2xlIHBvb2wgc2hhcmUsIHdpdGggaW50ZXJlc3QgYWNjcnVlZCB1cCB0byBub3cAAAAAD2dldF9zaGFyZV9wcmljZQAAAAAAAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAACxHZXQgdGhlIFVTREMgdmFsdWUgb2YgYSBsZW5kZXIncyBwb29sIHNoYXJlcwAAABBnZXRfcG9vbF9iYWxhbmNlAAAAAQAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAABtHZXQgcG9vbCBsb2FuIGRldGFpbHMgYnkgSUQAAAAADWdldF9wb29sX2xvYW4AAAAAAAABAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAD6QAAB9AAAAAIUG9vbExvYW4AAAAD",
+        "AAAAAAAAACNHZXQgdGhlIGN1cnJlbnQgZGVidCBvZiBhIHBvb2wgbG9hbgAAAAASZ2V0X3Bvb2xfbG9hbl9kZWJ0AAAAAAABAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAABxHZXQgYSB1c2VyJ3Mgb3BlbiBwb29sIGxvYW5zAAAAE2dldF91c2VyX3Bvb2xfbG9hbnMAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAEpHZXQgb25lIHBhZ2Ugb2YgYWN0aXZlIHBvb2wgbG9hbiBJRHMgKHVwIHRvIDUwIHBlciBwYWdlLCBwYWdlcyBzdGFydCBhdCAwKQAAAAAAGmdldF9hY3RpdmVfcG9vbF9sb2Fuc19wYWdlAAAAAAABAAAAAAAAAARwYWdlAAAABAAAAAEAAAPqAAAABg==",
+        "AAAAAAAAACNHZXQgdGhlIG51bWJlciBvZiBhY3RpdmUgcG9vbCBsb2FucwAAAAAaZ2V0X2FjdGl2ZV9wb29sX2xvYW5fY291bnQAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAFlHZXQgcG9vbCBsb2FuIGhlYWx0aCBpbmZvcm1hdGlvbgpQb29sIGxvYW5zIGhhdmUgbm8gZHVlIHRpbWUsIHNvIGBkdWVfdGltZWAgaXMgYHU2NDo6TUFYYAAAAAAAABRnZXRfcG9vbF9sb2FuX2hlYWx0aAAAAAEAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAPpAAAH0AAAAApMb2FuSGVhbHRoAAAAAAAD",
+        "AAAAAAAAAHBFeHRlbmQgdGhlIFRUTCBvZiBhIGxvYW4gYW5kIGl0cyBpbmRleCBlbnRyaWVzCkFueW9uZSBjYW4gY2FsbCB0aGlzIGZ1bmN0aW9uIChlLmcuIGtlZXBlcnMgZm9yIGxvbmctbGl2ZWQgbG9hbnMpAAAACWJ1bXBfbG9hbgAAAAAAAAEAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAE5FeHRlbmQgdGhlIFRUTCBvZiBhbiBvZmZlciBhbmQgaXRzIGluZGV4IGVudHJpZXMKQW55b25lIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24AAAAAAApidW1wX29mZmVyAAAAAAABAAAAAAAAAAhvZmZlcl9pZAAAAAYAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAHVFeHRlbmQgdGhlIFRUTCBvZiBhIHBvb2wgbG9hbiBhbmQgaXRzIGluZGV4IGVudHJpZXMKQW55b25lIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24gKGUuZy4ga2VlcGVycyBmb3IgbG9uZy1saXZlZCBsb2FucykAAAAAAAAOYnVtcF9wb29sX2xvYW4AAAAAAAEAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAFZFeHRlbmQgdGhlIFRUTCBvZiBhIGJvcnJvdyByZXF1ZXN0IGFuZCBpdHMgaW5kZXggZW50cmllcwpBbnlvbmUgY2FuIGNhbGwgdGhpcyBmdW5jdGlvbgAAAAAAE2J1bXBfYm9ycm93X3JlcXVlc3QAAAAAAQAAAAAAAAAKcmVxdWVzdF9pZAAAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAABFHZXQgb2ZmZXIgZGV0YWlscwAAAAAAAAlnZXRfb2ZmZXIAAAAAAAABAAAAAAAAAAhvZmZlcl9pZAAAAAYAAAABAAAD6QAAB9AAAAAMTGVuZGluZ09mZmVyAAAAAw==",
         "AAAAAAAAABBHZXQgbG9hbiBkZXRhaWxzAAAACGdldF9sb2FuAAAAAQAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAQAAA+kAAAfQAAAABExvYW4AAAAD",
+        "AAAAAAAAABpHZXQgYm9ycm93IHJlcXVlc3QgZGV0YWlscwAAAAAAEmdldF9ib3Jyb3dfcmVxdWVzdAAAAAAAAQAAAAAAAAAKcmVxdWVzdF9pZAAAAAAABgAAAAEAAAPpAAAH0AAAAA1Cb3Jyb3dSZXF1ZXN0AAAAAAAAAw==",
+        "AAAAAAAAACJHZXQgdGhlIGF1Y3Rpb24gcnVubmluZyBmb3IgYSBsb2FuAAAAAAALZ2V0X2F1Y3Rpb24AAAAAAQAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAQAAA+kAAAfQAAAAB0F1Y3Rpb24AAAAAAw==",
+        "AAAAAAAAAEdHZXQgdGhlIGN1cnJlbnQgY29sbGF0ZXJhbCBkaXNjb3VudCBvZiBhIGxvYW4ncyBhdWN0aW9uIGluIGJhc2lzIHBvaW50cwAAAAAUZ2V0X2F1Y3Rpb25fZGlzY291bnQAAAABAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAADtHZXQgdGhlIGFubnVhbCBwZXJjZW50YWdlIHlpZWxkIG9mIGFuIG9mZmVyIGluIGJhc2lzIHBvaW50cwAAAAANZ2V0X29mZmVyX2FweQAAAAAAAAEAAAAAAAAACG9mZmVyX2lkAAAABgAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAADpQcmV2aWV3IGhvdyBgYm9ycm93X2Jlc3RgIHdvdWxkIHNwbGl0IGEgbG9hbiBhY3Jvc3Mgb2ZmZXJzAAAAAAAMcXVvdGVfYm9ycm93AAAABwAAAAAAAAAIYm9ycm93ZXIAAAATAAAAAAAAAApsb2FuX3Rva2VuAAAAAAATAAAAAAAAABBjb2xsYXRlcmFsX3Rva2VuAAAAEwAAAAAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACG1heF9yYXRlAAAABAAAAAAAAAAOZHVyYXRpb25fd2Vla3MAAAAAAAQAAAABAAAD6QAAA+oAAAfQAAAACkJvcnJvd0ZpbGwAAAAAAAM=",
         "AAAAAAAAABtHZXQgbG9hbiBoZWFsdGggaW5mb3JtYXRpb24AAAAAD2dldF9sb2FuX2hlYWx0aAAAAAABAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAD6QAAB9AAAAAKTG9hbkhlYWx0aAAAAAAAAw==",
         "AAAAAAAAAClDYWxjdWxhdGUgYWNjdW11bGF0ZWQgaW50ZXJlc3QgZm9yIGEgbG9hbgAAAAAAABJjYWxjdWxhdGVfaW50ZXJlc3QAAAAAAAEAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAPpAAAACwAAAAM=",
-        "AAAAAAAAABVHZXQgY3VycmVudCBYTE0gcHJpY2UAAAAAAAANZ2V0X3hsbV9wcmljZQAAAAAAAAAAAAABAAAD6QAAAAsAAAAD",
+        "AAAAAAAAAHNHZXQgdGhlIGN1cnJlbnQgVVNEIHByaWNlIG9mIGEgY29sbGF0ZXJhbCB0b2tlbiAod2l0aCBvcmFjbGUgZGVjaW1hbHMpCmZyb20gdGhlIG9yYWNsZSB0aGF0IHNlcnZlZCB0aGUgbGF0ZXN0IHByaWNlAAAAABRnZXRfY29sbGF0ZXJhbF9wcmljZQAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAEtHZXQgY3VycmVudCBYTE0gcHJpY2UsIGtlcHQgZm9yIGNsaWVudHMgd3JpdHRlbiBhZ2FpbnN0IHRoZSBYTE0tb25seSBtYXJrZXQAAAAADWdldF94bG1fcHJpY2UAAAAAAAAAAAAAAQAAA+kAAAALAAAAAw==",
         "AAAAAAAAABFHZXQgdXNlcidzIG9mZmVycwAAAAAAAA9nZXRfdXNlcl9vZmZlcnMAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAD6gAAAAY=",
-        "AAAAAAAAABxHZXQgdXNlcidzIGxvYW5zIGFzIGJvcnJvd2VyAAAAGmdldF91c2VyX2xvYW5zX2FzX2JvcnJvd2VyAAAAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPqAAAABg==",
-        "AAAAAAAAABpHZXQgdXNlcidzIGxvYW5zIGFzIGxlbmRlcgAAAAAAGGdldF91c2VyX2xvYW5zX2FzX2xlbmRlcgAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAG",
-        "AAAAAAAAABVHZXQgYWxsIGFjdGl2ZSBvZmZlcnMAAAAAAAARZ2V0X2FjdGl2ZV9vZmZlcnMAAAAAAAAAAAAAAQAAA+oAAAAG",
-        "AAAAAAAAABRHZXQgYWxsIGFjdGl2ZSBsb2FucwAAABBnZXRfYWN0aXZlX2xvYW5zAAAAAAAAAAEAAAPqAAAABg==",
-        "AAAAAAAAABxVcGRhdGUgbWF4aW11bSBpbnRlcmVzdCByYXRlAAAAFXNldF9tYXhfaW50ZXJlc3RfcmF0ZQAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIbWF4X3JhdGUAAAAEAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAABVVcGRhdGUgb3JhY2xlIGFkZHJlc3MAAAAAAAASc2V0X29yYWNsZV9hZGRyZXNzAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAABm9yYWNsZQAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAAA5QYXVzZSBjb250cmFjdAAAAAAADnBhdXNlX2NvbnRyYWN0AAAAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAABBVbnBhdXNlIGNvbnRyYWN0AAAAEHVucGF1c2VfY29udHJhY3QAAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+        "AAAAAAAAAB9HZXQgdXNlcidzIG9wZW4gYm9ycm93IHJlcXVlc3RzAAAAABhnZXRfdXNlcl9ib3Jyb3dfcmVxdWVzdHMAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPqAAAABg==",
+        "AAAAAAAAACFHZXQgdXNlcidzIG9wZW4gbG9hbnMgYXMgYm9ycm93ZXIAAAAAAAAaZ2V0X3VzZXJfbG9hbnNfYXNfYm9ycm93ZXIAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAG",
+        "AAAAAAAAAKVHZXQgdXNlcidzIGxvYW5zIGFzIGxlbmRlcjogbG9hbnMgdGhlIHVzZXIgZnVuZGVkIGJlZm9yZSB0aGUgcG9zaXRpb24gTkZUCndhcyBzZXQsIHRoZW4gZXZlcnkgbG9hbiB3aG9zZSBwb3NpdGlvbiBORlQgdGhlIHVzZXIgaG9sZHMKYGxpbWl0YCBtdXN0IGJlIGJldHdlZW4gMSBhbmQgNTAAAAAAAAAYZ2V0X3VzZXJfbG9hbnNfYXNfbGVuZGVyAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAABm9mZnNldAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+kAAAPqAAAABgAAAAM=",
+        "AAAAAAAAADBHZXQgdGhlIG91dHN0YW5kaW5nIGxvYW5zIHRha2VuIGFnYWluc3QgYW4gb2ZmZXIAAAAPZ2V0X29mZmVyX2xvYW5zAAAAAAEAAAAAAAAACG9mZmVyX2lkAAAABgAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAWlMaXN0IGFjdGl2ZSBvZmZlcnMgd2l0aCBhdmFpbGFibGUgZnVuZHMsIGZpbHRlcmVkLCBzb3J0ZWQgYW5kIHBhZ2luYXRlZApUaGUgY2FsbCB3YWxrcyB0aGUgb2ZmZXJzIGluIGxpc3Rpbmcgb3JkZXIgYW5kIHJlYWRzIGF0IG1vc3QgODAgaW5kZXggZW50cmllcyBhbmQKb2ZmZXJzLCBzbyBkZWVwIHBhZ2VzIG9yIG5hcnJvdyBmaWx0ZXJzIGNhbiBjb21lIGJhY2sgc2hvcnQuIGBIaWdoZXN0QW1vdW50YApyYW5rcyBldmVyeSBhY3RpdmUgb2ZmZXIgYW5kIGZhaWxzIHdpdGggYEludmFsaWRTb3J0T3B0aW9uYCBvbmNlIHRoZXJlIGFyZSBtb3JlCnRoYW4gaXQgY2FuIHJlYWQuIGBsaW1pdGAgbXVzdCBiZSBiZXR3ZWVuIDEgYW5kIDUwAAAAAAAAC2xpc3Rfb2ZmZXJzAAAAAAQAAAAAAAAABHNvcnQAAAfQAAAAClNvcnRPcHRpb24AAAAAAAAAAAAGb2Zmc2V0AAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAAAAAAABmZpbHRlcgAAAAAH0AAAAAtPZmZlckZpbHRlcgAAAAABAAAD6QAAA+oAAAfQAAAADExlbmRpbmdPZmZlcgAAAAM=",
+        "AAAAAAAAAE1HZXQgb25lIHBhZ2Ugb2Ygb3BlbiBib3Jyb3cgcmVxdWVzdCBJRHMgKHVwIHRvIDUwIHBlciBwYWdlLCBwYWdlcyBzdGFydCBhdCAwKQAAAAAAAB9nZXRfYWN0aXZlX2JvcnJvd19yZXF1ZXN0c19wYWdlAAAAAAEAAAAAAAAABHBhZ2UAAAAEAAAAAQAAA+oAAAAG",
+        "AAAAAAAAAEZHZXQgb25lIHBhZ2Ugb2YgYWN0aXZlIG9mZmVyIElEcyAodXAgdG8gNTAgcGVyIHBhZ2UsIHBhZ2VzIHN0YXJ0IGF0IDApAAAAAAAWZ2V0X2FjdGl2ZV9vZmZlcnNfcGFnZQAAAAAAAQAAAAAAAAAEcGFnZQAAAAQAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAEVHZXQgb25lIHBhZ2Ugb2YgYWN0aXZlIGxvYW4gSURzICh1cCB0byA1MCBwZXIgcGFnZSwgcGFnZXMgc3RhcnQgYXQgMCkAAAAAAAAVZ2V0X2FjdGl2ZV9sb2Fuc19wYWdlAAAAAAAAAQAAAAAAAAAEcGFnZQAAAAQAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAB9HZXQgdGhlIG51bWJlciBvZiBhY3RpdmUgb2ZmZXJzAAAAABZnZXRfYWN0aXZlX29mZmVyX2NvdW50AAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAB5HZXQgdGhlIG51bWJlciBvZiBhY3RpdmUgbG9hbnMAAAAAABVnZXRfYWN0aXZlX2xvYW5fY291bnQAAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAGBTZXQgdGhlIGxvYW4gcG9zaXRpb24gTkZUIGNvbnRyYWN0IChvbmNlKQpUaGUgTkZUIGNvbnRyYWN0IG11c3QgbmFtZSB0aGlzIGNvbnRyYWN0IGFzIGl0cyBtaW50ZXIAAAAQc2V0X3Bvc2l0aW9uX25mdAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAADbmZ0AAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAC1HZXQgdGhlIHJpc2sgcGFyYW1ldGVycyBvZiBhIGNvbGxhdGVyYWwgdG9rZW4AAAAAAAAVZ2V0X2NvbGxhdGVyYWxfY29uZmlnAAAAAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAA+kAAAfQAAAAEENvbGxhdGVyYWxDb25maWcAAAAD",
+        "AAAAAAAAACRHZXQgZXZlcnkgdG9rZW4gbGlzdGVkIGFzIGNvbGxhdGVyYWwAAAAVZ2V0X2NvbGxhdGVyYWxfYXNzZXRzAAAAAAAAAAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAADFHZXQgdGhlIG9yYWNsZSBhc3NldCBhbmQgZGVjaW1hbHMgb2YgYSBsb2FuIHRva2VuAAAAAAAADmdldF9sb2FuX2Fzc2V0AAAAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAD6QAAB9AAAAAPTG9hbkFzc2V0Q29uZmlnAAAAAAM=",
+        "AAAAAAAAAB9HZXQgZXZlcnkgdG9rZW4gb2ZmZXJzIG1heSBsZW5kAAAAAA9nZXRfbG9hbl9hc3NldHMAAAAAAAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAAEZQYXVzZSBldmVyeSBvcGVyYXRpb24gZXhjZXB0IHJlcGF5bWVudHMsIHdoaWNoIGtlZXAgdGhlaXIgY3VycmVudCBmbGFnAAAAAAAOcGF1c2VfY29udHJhY3QAAAAAAAEAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAABZDbGVhciBldmVyeSBwYXVzZSBmbGFnAAAAAAAQdW5wYXVzZV9jb250cmFjdAAAAAEAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAACVQYXVzZSBvciByZXN1bWUgaW5kaXZpZHVhbCBvcGVyYXRpb25zAAAAAAAAD3NldF9wYXVzZV9zdGF0ZQAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAABXN0YXRlAAAAAAAH0AAAAApQYXVzZVN0YXRlAAAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAB9HZXQgd2hpY2ggb3BlcmF0aW9ucyBhcmUgcGF1c2VkAAAAAA9nZXRfcGF1c2Vfc3RhdGUAAAAAAAAAAAEAAAfQAAAAClBhdXNlU3RhdGUAAA==",
+        "AAAAAAAAACpVcGRhdGUgdGhlIHN0b3JhZ2UgVFRMIHBvbGljeSAoaW4gbGVkZ2VycykAAAAAAA5zZXRfdHRsX2NvbmZpZwAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAZjb25maWcAAAAAB9AAAAAJVHRsQ29uZmlnAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAABpHZXQgdGhlIHN0b3JhZ2UgVFRMIHBvbGljeQAAAAAADmdldF90dGxfY29uZmlnAAAAAAAAAAAAAQAAB9AAAAAJVHRsQ29uZmlnAAAA",
+        "AAAAAAAAACZHZXQgdGhlIERFWCByb3V0ZXIgdXNlZCBvbiBsaXF1aWRhdGlvbgAAAAAADmdldF9kZXhfcm91dGVyAAAAAAAAAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAACJHZXQgdGhlIGxvYW4gcG9zaXRpb24gTkZUIGNvbnRyYWN0AAAAAAAQZ2V0X3Bvc2l0aW9uX25mdAAAAAAAAAABAAAD6QAAABMAAAAD",
+        "AAAAAAAAADlHZXQgdGhlIG1heGltdW0gbGlxdWlkYXRpb24gc3dhcCBzbGlwcGFnZSBpbiBiYXNpcyBwb2ludHMAAAAAAAAQZ2V0X21heF9zbGlwcGFnZQAAAAAAAAABAAAABA==",
+        "AAAAAAAAACBHZXQgdGhlIHNlY29uZGFyeSBvcmFjbGUsIGlmIGFueQAAABNnZXRfZmFsbGJhY2tfb3JhY2xlAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAACtHZXQgdGhlIG9yYWNsZSB0aGF0IHNlcnZlZCB0aGUgbGF0ZXN0IHByaWNlAAAAABFnZXRfb3JhY2xlX3NvdXJjZQAAAAAAAAAAAAABAAAH0AAAAAxPcmFjbGVTb3VyY2U=",
+        "AAAAAAAAAEBHZXQgdGhlIG1heGltdW0gcHJpbWFyeS9mYWxsYmFjayBwcmljZSBkZXZpYXRpb24gaW4gYmFzaXMgcG9pbnRzAAAAGGdldF9tYXhfb3JhY2xlX2RldmlhdGlvbgAAAAAAAAABAAAABA==",
+        "AAAAAAAAADdHZXQgdGhlIG51bWJlciBvZiBvcmFjbGUgcmVjb3JkcyBhdmVyYWdlZCBpbnRvIHRoZSBUV0FQAAAAABBnZXRfdHdhcF9yZWNvcmRzAAAAAAAAAAEAAAAE",
+        "AAAAAAAAADBHZXQgdGhlIGxpcXVpZGF0aW9uIGNsb3NlIGZhY3RvciBpbiBiYXNpcyBwb2ludHMAAAAQZ2V0X2Nsb3NlX2ZhY3RvcgAAAAAAAAABAAAABA==",
+        "AAAAAAAAACRHZXQgdGhlIER1dGNoIGF1Y3Rpb24gZGlzY291bnQgY3VydmUAAAASZ2V0X2F1Y3Rpb25fY29uZmlnAAAAAAAAAAAAAQAAB9AAAAANQXVjdGlvbkNvbmZpZwAAAA==",
+        "AAAAAAAAACBHZXQgdGhlIHBvb2wncyB1dGlsaXphdGlvbiBjdXJ2ZQAAAA5nZXRfcmF0ZV9tb2RlbAAAAAAAAAAAAAEAAAfQAAAACVJhdGVNb2RlbAAAAA==",
+        "AAAAAAAAACJHZXQgdGhlIHByb3RvY29sIGZlZSBjb25maWd1cmF0aW9uAAAAAAAOZ2V0X2ZlZV9jb25maWcAAAAAAAAAAAABAAAH0AAAAAlGZWVDb25maWcAAAA=",
+        "AAAAAAAAAB9HZXQgdGhlIG1hcmtldC13aWRlIHJpc2sgbGltaXRzAAAAAA9nZXRfcmlza19jb25maWcAAAAAAAAAAAEAAAfQAAAAClJpc2tDb25maWcAAA==",
+        "AAAAAAAAAK1UcmFuc2ZlciBhbGwgcHJvdG9jb2wgZmVlcyBhY2NydWVkIGluIGB0b2tlbmAgdG8gYHRvYC4gQ2xhaW1pbmcgVVNEQwphbHNvIHJlbGVhc2VzIHRoZSBwb29sJ3MgaW50ZXJlc3QgcmVzZXJ2ZXMgdGhhdCBhcmUgYmFja2VkIGJ5IGlkbGUgcG9vbCBjYXNoCgpSZXR1cm5zIHRoZSBhbW91bnQgY2xhaW1lZAAAAAAAABNjbGFpbV9wcm90b2NvbF9mZWVzAAAAAAMAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAPpAAAACwAAAAM=",
+        "AAAAAAAAAD9HZXQgdGhlIHVuY2xhaW1lZCBwcm90b2NvbCBmZWVzIGhlbGQgYnkgdGhlIGNvbnRyYWN0IGluIGB0b2tlbmAAAAAAEWdldF9wcm90b2NvbF9mZWVzAAAAAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
         "AAAAAAAAABFHZXQgYWRtaW4gYWRkcmVzcwAAAAAAAAVhZG1pbgAAAAAAAAAAAAABAAAD6QAAABMAAAAD",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAALwAAABxDb250cmFjdCBhbHJlYWR5IGluaXRpYWxpemVkAAAAEkFscmVhZHlJbml0aWFsaXplZAAAAAAAAQAAABhDb250cmFjdCBub3QgaW5pdGlhbGl6ZWQAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAIAAAArQ2FsbGVyIGlzIG5vdCBhdXRob3JpemVkIGZvciB0aGlzIG9wZXJhdGlvbgAAAAAMVW5hdXRob3JpemVkAAAACgAAACVPbmx5IGFkbWluIGNhbiBwZXJmb3JtIHRoaXMgb3BlcmF0aW9uAAAAAAAACU9ubHlBZG1pbgAAAAAAAAsAAAAmT25seSBsZW5kZXIgY2FuIHBlcmZvcm0gdGhpcyBvcGVyYXRpb24AAAAAAApPbmx5TGVuZGVyAAAAAAAMAAAAKE9ubHkgYm9ycm93ZXIgY2FuIHBlcmZvcm0gdGhpcyBvcGVyYXRpb24AAAAMT25seUJvcnJvd2VyAAAADQAAAA9PZmZlciBub3QgZm91bmQAAAAADU9mZmVyTm90Rm91bmQAAAAAAAAUAAAAE09mZmVyIGlzIG5vdCBhY3RpdmUAAAAADk9mZmVyTm90QWN0aXZlAAAAAAAVAAAAJ0ludmFsaWQgaW50ZXJlc3QgcmF0ZSAoZXhjZWVkcyBtYXhpbXVtKQAAAAATSW52YWxpZEludGVyZXN0UmF0ZQAAAAAWAAAAGEludmFsaWQgY29sbGF0ZXJhbCByYXRpbwAAABZJbnZhbGlkQ29sbGF0ZXJhbFJhdGlvAAAAAAAXAAAAHUludmFsaWQgbGlxdWlkYXRpb24gdGhyZXNob2xkAAAAAAAAG0ludmFsaWRMaXF1aWRhdGlvblRocmVzaG9sZAAAAAAYAAAAJ0ludmFsaWQgb2ZmZXIgYW1vdW50IChtdXN0IGJlIHBvc2l0aXZlKQAAAAASSW52YWxpZE9mZmVyQW1vdW50AAAAAAAZAAAAGFVzZXIgaGFzIHRvbyBtYW55IG9mZmVycwAAAA1Ub29NYW55T2ZmZXJzAAAAAAAAGgAAABtJbnN1ZmZpY2llbnQgZnVuZHMgaW4gb2ZmZXIAAAAAFkluc3VmZmljaWVudE9mZmVyRnVuZHMAAAAAABsAAAAlQ2Fubm90IGNhbmNlbCBvZmZlciB3aXRoIGFjdGl2ZSBsb2FucwAAAAAAABNPZmZlckhhc0FjdGl2ZUxvYW5zAAAAABwAAAAOTG9hbiBub3QgZm91bmQAAAAAAAxMb2FuTm90Rm91bmQAAAAoAAAAEkxvYW4gaXMgbm90IGFjdGl2ZQAAAAAADUxvYW5Ob3RBY3RpdmUAAAAAAAApAAAAKEludmFsaWQgYm9ycm93IGFtb3VudCAobXVzdCBiZSBwb3NpdGl2ZSkAAAATSW52YWxpZEJvcnJvd0Ftb3VudAAAAAAqAAAALEludmFsaWQgY29sbGF0ZXJhbCBhbW91bnQgKG11c3QgYmUgcG9zaXRpdmUpAAAAF0ludmFsaWRDb2xsYXRlcmFsQW1vdW50AAAAACsAAAAzSW5zdWZmaWNpZW50IGNvbGxhdGVyYWwgZm9yIHJlcXVlc3RlZCBib3Jyb3cgYW1vdW50AAAAABZJbnN1ZmZpY2llbnRDb2xsYXRlcmFsAAAAAAAsAAAAF1VzZXIgaGFzIHRvbyBtYW55IGxvYW5zAAAAAAxUb29NYW55TG9hbnMAAAAtAAAAGEludmFsaWQgcmVwYXltZW50IGFtb3VudAAAABJJbnZhbGlkUmVwYXlBbW91bnQAAAAAAC4AAAAWUmVwYXltZW50IGV4Y2VlZHMgZGVidAAAAAAAEFJlcGF5RXhjZWVkc0RlYnQAAAAvAAAAOkNhbm5vdCB3aXRoZHJhdyBjb2xsYXRlcmFsIC0gd291bGQgYnJlYWNoIGhlYWx0aCB0aHJlc2hvbGQAAAAAABhXaXRoZHJhd2FsQnJlYWNoZXNIZWFsdGgAAAAwAAAAJUxvYW4gZHVyYXRpb24gZXhjZWVkcyBtYXhpbXVtIGFsbG93ZWQAAAAAAAAUTG9hbkR1cmF0aW9uRXhjZWVkZWQAAAAxAAAANExvYW4gaXMgbm90IGxpcXVpZGF0YWJsZSAoaGVhbHRoIGlzIGFib3ZlIHRocmVzaG9sZCkAAAAPTm90TGlxdWlkYXRhYmxlAAAAADwAAAAaTG9hbiBpcyBhbHJlYWR5IGxpcXVpZGF0ZWQAAAAAABFBbHJlYWR5TGlxdWlkYXRlZAAAAAAAAD0AAAAuTGlxdWlkYXRpb24gZmFpbGVkIC0gY291bGQgbm90IHN3YXAgY29sbGF0ZXJhbAAAAAAAFUxpcXVpZGF0aW9uU3dhcEZhaWxlZAAAAAAAAD4AAAArSW5zdWZmaWNpZW50IGNvbGxhdGVyYWwgdmFsdWUgdG8gY292ZXIgZGVidAAAAAAbSW5zdWZmaWNpZW50Q29sbGF0ZXJhbFZhbHVlAAAAAD8AAAAWT3JhY2xlIGFkZHJlc3Mgbm90IHNldAAAAAAADE9yYWNsZU5vdFNldAAAAFAAAAAkUHJpY2UgZGF0YSBub3QgYXZhaWxhYmxlIGZyb20gb3JhY2xlAAAAEVByaWNlTm90QXZhaWxhYmxlAAAAAAAAUQAAAB1QcmljZSBkYXRhIGlzIHN0YWxlICh0b28gb2xkKQAAAAAAAA5TdGFsZVByaWNlRGF0YQAAAAAAUgAAAB5JbnZhbGlkIHByaWNlIGRhdGEgZnJvbSBvcmFjbGUAAAAAABBJbnZhbGlkUHJpY2VEYXRhAAAAUwAAABJVU0RDIHRva2VuIG5vdCBzZXQAAAAAAA9Vc2RjVG9rZW5Ob3RTZXQAAAAAZAAAABFYTE0gdG9rZW4gbm90IHNldAAAAAAAAA5YbG1Ub2tlbk5vdFNldAAAAAAAZQAAABVUb2tlbiB0cmFuc2ZlciBmYWlsZWQAAAAAAAATVG9rZW5UcmFuc2ZlckZhaWxlZAAAAABmAAAAGkluc3VmZmljaWVudCB0b2tlbiBiYWxhbmNlAAAAAAATSW5zdWZmaWNpZW50QmFsYW5jZQAAAABnAAAAEkNvbnRyYWN0IGlzIHBhdXNlZAAAAAAADkNvbnRyYWN0UGF1c2VkAAAAAAB4AAAAE1JlZW50cmFuY3kgZGV0ZWN0ZWQAAAAACVJlZW50cmFudAAAAAAAAHkAAAAXSW52YWxpZCBpbnB1dCBwYXJhbWV0ZXIAAAAADEludmFsaWRJbnB1dAAAAHoAAAATQXJpdGhtZXRpYyBvdmVyZmxvdwAAAAASQXJpdGhtZXRpY092ZXJmbG93AAAAAAB7AAAAFEFyaXRobWV0aWMgdW5kZXJmbG93AAAAE0FyaXRobWV0aWNVbmRlcmZsb3cAAAAAfAAAABBEaXZpc2lvbiBieSB6ZXJvAAAADkRpdmlzaW9uQnlaZXJvAAAAAAB9AAAAE0ludmFsaWQgc29ydCBvcHRpb24AAAAAEUludmFsaWRTb3J0T3B0aW9uAAAAAAAAjAAAAB1JbnZhbGlkIHBhZ2luYXRpb24gcGFyYW1ldGVycwAAAAAAABFJbnZhbGlkUGFnaW5hdGlvbgAAAAAAAI0AAAATTm8gb2ZmZXJzIGF2YWlsYWJsZQAAAAARTm9PZmZlcnNBdmFpbGFibGUAAAAAAACOAAAADk5vIGxvYW5zIGZvdW5kAAAAAAAMTm9Mb2Fuc0ZvdW5kAAAAjw==",
-        "AAAAAQAAACFMZW5kaW5nIG9mZmVyIGNyZWF0ZWQgYnkgYSBsZW5kZXIAAAAAAAAAAAAADExlbmRpbmdPZmZlcgAAAAkAAAAgVGltZXN0YW1wIHdoZW4gb2ZmZXIgd2FzIGNyZWF0ZWQAAAAKY3JlYXRlZF9hdAAAAAAABgAAABxXaGV0aGVyIHRoaXMgb2ZmZXIgaXMgYWN0aXZlAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAVQWRkcmVzcyBvZiB0aGUgbGVuZGVyAAAAAAAABmxlbmRlcgAAAAAAEwAAADpMaXF1aWRhdGlvbiB0aHJlc2hvbGQgaW4gYmFzaXMgcG9pbnRzIChlLmcuLCAxMjUwMCA9IDEyNSUpAAAAAAAVbGlxdWlkYXRpb25fdGhyZXNob2xkAAAAAAAABAAAAB5NYXhpbXVtIGxvYW4gZHVyYXRpb24gaW4gd2Vla3MAAAAAABJtYXhfZHVyYXRpb25fd2Vla3MAAAAAAAQAAABLTWluaW11bSBjb2xsYXRlcmFsIHJhdGlvIGluIGJhc2lzIHBvaW50cyAoZS5nLiwgMjAwMDAgPSAyMDAlID0gbWF4IDUwJSBMVFYpAAAAABRtaW5fY29sbGF0ZXJhbF9yYXRpbwAAAAQAAAAgVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoaXMgb2ZmZXIAAAAIb2ZmZXJfaWQAAAAGAAAAMkFtb3VudCBvZiBVU0RDIGF2YWlsYWJsZSB0byBsZW5kICh3aXRoIDcgZGVjaW1hbHMpAAAAAAALdXNkY19hbW91bnQAAAAACwAAADVXZWVrbHkgaW50ZXJlc3QgcmF0ZSBpbiBiYXNpcyBwb2ludHMgKGUuZy4sIDUwMCA9IDUlKQAAAAAAABR3ZWVrbHlfaW50ZXJlc3RfcmF0ZQAAAAQ=",
-        "AAAAAQAAABRBY3RpdmUgbG9hbiBwb3NpdGlvbgAAAAAAAAAETG9hbgAAAAwAAAA1QWNjdW11bGF0ZWQgaW50ZXJlc3Qgc28gZmFyIChpbiBVU0RDIHdpdGggNyBkZWNpbWFscykAAAAAAAAUYWNjdW11bGF0ZWRfaW50ZXJlc3QAAAALAAAAKUFtb3VudCBvZiBVU0RDIGJvcnJvd2VkICh3aXRoIDcgZGVjaW1hbHMpAAAAAAAAD2JvcnJvd2VkX2Ftb3VudAAAAAALAAAAF0FkZHJlc3Mgb2YgdGhlIGJvcnJvd2VyAAAAAAhib3Jyb3dlcgAAABMAAAA3QW1vdW50IG9mIFhMTSBkZXBvc2l0ZWQgYXMgY29sbGF0ZXJhbCAod2l0aCA3IGRlY2ltYWxzKQAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAJFdlZWtseSBpbnRlcmVzdCByYXRlIGluIGJhc2lzIHBvaW50cwAAAA1pbnRlcmVzdF9yYXRlAAAAAAAABAAAABtXaGV0aGVyIHRoaXMgbG9hbiBpcyBhY3RpdmUAAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAeTGFzdCB0aW1lIGludGVyZXN0IHdhcyB1cGRhdGVkAAAAAAAUbGFzdF9pbnRlcmVzdF91cGRhdGUAAAAGAAAAFUFkZHJlc3Mgb2YgdGhlIGxlbmRlcgAAAAAAAAZsZW5kZXIAAAAAABMAAAAlTGlxdWlkYXRpb24gdGhyZXNob2xkIGluIGJhc2lzIHBvaW50cwAAAAAAABVsaXF1aWRhdGlvbl90aHJlc2hvbGQAAAAAAAAEAAAAH1VuaXF1ZSBpZGVudGlmaWVyIGZvciB0aGlzIGxvYW4AAAAAB2xvYW5faWQAAAAABgAAAB9SZWZlcmVuY2UgdG8gdGhlIG9yaWdpbmFsIG9mZmVyAAAAAAhvZmZlcl9pZAAAAAYAAAAfVGltZXN0YW1wIHdoZW4gbG9hbiB3YXMgY3JlYXRlZAAAAAAKc3RhcnRfdGltZQAAAAAABg==",
-        "AAAAAQAAAB1IZWFsdGggaW5mb3JtYXRpb24gZm9yIGEgbG9hbgAAAAAAAAAAAAAKTG9hbkhlYWx0aAAAAAAABwAAADJDdXJyZW50IGNvbGxhdGVyYWwgdmFsdWUgaW4gVVNEQyAod2l0aCA3IGRlY2ltYWxzKQAAAAAAFGNvbGxhdGVyYWxfdmFsdWVfdXNkAAAACwAAACdDb2xsYXRlcmFsaXphdGlvbiByYXRpbyBpbiBiYXNpcyBwb2ludHMAAAAAF2NvbGxhdGVyYWxpemF0aW9uX3JhdGlvAAAAAAQAAAA9VG90YWwgZGVidCBpbmNsdWRpbmcgcHJpbmNpcGFsIGFuZCBpbnRlcmVzdCAod2l0aCA3IGRlY2ltYWxzKQAAAAAAAA5kZWJ0X3ZhbHVlX3VzZAAAAAAACwAAACxIZWFsdGggZmFjdG9yIGluIGJhc2lzIHBvaW50cyAoMTAwMDAgPSAxMDAlKQAAAA1oZWFsdGhfZmFjdG9yAAAAAAAABAAAACNXaGV0aGVyIHRoaXMgbG9hbiBjYW4gYmUgbGlxdWlkYXRlZAAAAAAPaXNfbGlxdWlkYXRhYmxlAAAAAAEAAAA/WExNIHByaWNlIHRoYXQgd291bGQgdHJpZ2dlciBsaXF1aWRhdGlvbiAod2l0aCBvcmFjbGUgZGVjaW1hbHMpAAAAABFsaXF1aWRhdGlvbl9wcmljZQAAAAAAAAsAAAAPTG9hbiBpZGVudGlmaWVyAAAAAAdsb2FuX2lkAAAAAAY=",
-        "AAAAAgAAACBTb3J0IG9wdGlvbnMgZm9yIHF1ZXJ5aW5nIG9mZmVycwAAAAAAAAAKU29ydE9wdGlvbgAAAAAAAwAAAAAAAAAjU29ydCBieSBiZXN0IChsb3dlc3QpIGludGVyZXN0IHJhdGUAAAAACEJlc3RSYXRlAAAAAAAAACBTb3J0IGJ5IGhpZ2hlc3QgYW1vdW50IGF2YWlsYWJsZQAAAA1IaWdoZXN0QW1vdW50AAAAAAAAAAAAABRTb3J0IGJ5IG5ld2VzdCBmaXJzdAAAAAZOZXdlc3QAAA==",
-        "AAAAAgAAAB1TdG9yYWdlIGtleXMgZm9yIHRoZSBjb250cmFjdAAAAAAAAAAAAAAHRGF0YUtleQAAAAAQAAAAAAAAABZDb250cmFjdCBhZG1pbmlzdHJhdG9yAAAAAAAFQWRtaW4AAAAAAAAAAAAAElVTREMgdG9rZW4gYWRkcmVzcwAAAAAACVVzZGNUb2tlbgAAAAAAAAAAAAAaWExNIHRva2VuIGFkZHJlc3MgKG5hdGl2ZSkAAAAAAAhYbG1Ub2tlbgAAAAAAAAAhUmVmbGVjdG9yIG9yYWNsZSBjb250cmFjdCBhZGRyZXNzAAAAAAAADU9yYWNsZUFkZHJlc3MAAAAAAAAAAAAAFU5leHQgb2ZmZXIgSUQgY291bnRlcgAAAAAAAAtOZXh0T2ZmZXJJZAAAAAAAAAAAFE5leHQgbG9hbiBJRCBjb3VudGVyAAAACk5leHRMb2FuSWQAAAAAAAAAAABATWF4aW11bSBhbGxvd2VkIGludGVyZXN0IHJhdGUgaW4gYmFzaXMgcG9pbnRzIChlLmcuLCAzMDAwID0gMzAlKQAAAA9NYXhJbnRlcmVzdFJhdGUAAAAAAAAAABVDb250cmFjdCBwYXVzZWQgc3RhdGUAAAAAAAAISXNQYXVzZWQAAAAAAAAAD1JlZW50cmFuY3kgbG9jawAAAAAGTG9ja2VkAAAAAAABAAAAFkluZGl2aWR1YWwgb2ZmZXIgYnkgSUQAAAAAAAVPZmZlcgAAAAAAAAEAAAAGAAAAAQAAABVJbmRpdmlkdWFsIGxvYW4gYnkgSUQAAAAAAAAETG9hbgAAAAEAAAAGAAAAAQAAABxMaXN0IG9mIG9mZmVyIElEcyBmb3IgYSB1c2VyAAAAClVzZXJPZmZlcnMAAAAAAAEAAAATAAAAAQAAACdMaXN0IG9mIGxvYW4gSURzIHdoZXJlIHVzZXIgaXMgYm9ycm93ZXIAAAAAE1VzZXJMb2Fuc0FzQm9ycm93ZXIAAAAAAQAAABMAAAABAAAAJUxpc3Qgb2YgbG9hbiBJRHMgd2hlcmUgdXNlciBpcyBsZW5kZXIAAAAAAAARVXNlckxvYW5zQXNMZW5kZXIAAAAAAAABAAAAEwAAAAAAAAAcTGlzdCBvZiBhbGwgYWN0aXZlIG9mZmVyIElEcwAAAAxBY3RpdmVPZmZlcnMAAAAAAAAAG0xpc3Qgb2YgYWxsIGFjdGl2ZSBsb2FuIElEcwAAAAALQWN0aXZlTG9hbnMA",
+        "AAAAAAAAAIlQcm9wb3NlIGBuZXdfYWRtaW5gIGFzIHRoZSBuZXh0IGFkbWluLCB3aG8gbXVzdCBhY2NlcHQgYmVmb3JlIGBsaXZlX3VudGlsX2xlZGdlcmAKQSBgbGl2ZV91bnRpbF9sZWRnZXJgIG9mIDAgd2l0aGRyYXdzIGEgcGVuZGluZyBwcm9wb3NhbAAAAAAAAA1wcm9wb3NlX2FkbWluAAAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAEFBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHByb3Bvc2FsLCBhdXRob3JpemVkIGJ5IHRoZSBwcm9wb3NlZCBhZG1pbgAAAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAA==",
+        "AAAAAAAAABlHcmFudCBgcm9sZWAgdG8gYGFjY291bnRgAAAAAAAACmdyYW50X3JvbGUAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAABxSZXZva2UgYHJvbGVgIGZyb20gYGFjY291bnRgAAAAC3Jldm9rZV9yb2xlAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAACRDaGVjayB3aGV0aGVyIGBhY2NvdW50YCBob2xkcyBgcm9sZWAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAABAAAAAQ==",
+        "AAAAAAAAANhRdWV1ZSBhIHNlbnNpdGl2ZSBhZG1pbiBhY3Rpb24gdG8gcnVuIG9uY2UgdGhlIHRpbWVsb2NrIGRlbGF5IGhhcyBwYXNzZWQKT3JhY2xlIGFjdGlvbnMgbmVlZCB0aGUgb3JhY2xlIG1hbmFnZXIgcm9sZSwgcmlzayBjb25maWcgdGhlIHJpc2sgbWFuYWdlcgpyb2xlLCBhbmQgdXBncmFkZXMgYW5kIGRlbGF5IGNoYW5nZXMgdGhlIGFkbWluCgpSZXR1cm5zIHRoZSBhY3Rpb24gSUQAAAAMcXVldWVfYWN0aW9uAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAZhY3Rpb24AAAAAB9AAAAAOVGltZWxvY2tBY3Rpb24AAAAAAAEAAAPpAAAABgAAAAM=",
+        "AAAAAAAAACNEcm9wIGEgcXVldWVkIGFjdGlvbiBiZWZvcmUgaXQgcnVucwAAAAANY2FuY2VsX2FjdGlvbgAAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAJYWN0aW9uX2lkAAAAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAENSdW4gYSBxdWV1ZWQgYWN0aW9uIGJldHdlZW4gaXRzIEVUQSBhbmQgdGhlIGVuZCBvZiB0aGUgZ3JhY2UgcGVyaW9kAAAAAA5leGVjdXRlX2FjdGlvbgAAAAAAAgAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAlhY3Rpb25faWQAAAAAAAAGAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAABlHZXQgYSBxdWV1ZWQgYWN0aW9uIGJ5IElEAAAAAAAAEWdldF9xdWV1ZWRfYWN0aW9uAAAAAAAAAQAAAAAAAAAJYWN0aW9uX2lkAAAAAAAABgAAAAEAAAPpAAAH0AAAAAxRdWV1ZWRBY3Rpb24AAAAD",
+        "AAAAAAAAADxHZXQgZXZlcnkgYWN0aW9uIHdhaXRpbmcgaW4gdGhlIHRpbWVsb2NrIHF1ZXVlLCBvbGRlc3QgZmlyc3QAAAASZ2V0X3F1ZXVlZF9hY3Rpb25zAAAAAAAAAAAAAQAAA+oAAAfQAAAADFF1ZXVlZEFjdGlvbg==",
+        "AAAAAAAAAERHZXQgdGhlIGRlbGF5IGJldHdlZW4gcXVldWVpbmcgYW4gYWN0aW9uIGFuZCBydW5uaW5nIGl0IChpbiBzZWNvbmRzKQAAABJnZXRfdGltZWxvY2tfZGVsYXkAAAAAAAAAAAABAAAABg==",
+        "AAAAAAAAAM5SZXdyaXRlIHVwIHRvIGBsaW1pdGAgc3RvcmVkIGxvYW5zIGFuZCBvZmZlcnMgdG93YXJkcyB0aGUgc2NoZW1hIG9mIHRoaXMgYnVpbGQKQ2FsbCByZXBlYXRlZGx5IGFmdGVyIGEgdGltZWxvY2tlZCB1cGdyYWRlIHVudGlsIGl0IHJldHVybnMgYFNDSEVNQV9WRVJTSU9OYAoKUmV0dXJucyB0aGUgc3RvcmVkIHNjaGVtYSB2ZXJzaW9uIGFmdGVyIHRoZSBiYXRjaAAAAAAAB21pZ3JhdGUAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAADVHZXQgdGhlIHNjaGVtYSB2ZXJzaW9uIG9mIHRoZSBzdG9yZWQgbG9hbnMgYW5kIG9mZmVycwAAAAAAABJnZXRfc2NoZW1hX3ZlcnNpb24AAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAMdG90YWxfc3VwcGx5AAAAAAAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAHYmFsYW5jZQAAAAABAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAJYWxsb3dhbmNlAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzcGVuZGVyAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAIdHJhbnNmZXIAAAADAAAAAAAAAARmcm9tAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAANdHJhbnNmZXJfZnJvbQAAAAAAAAQAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAEZnJvbQAAABMAAAAAAAAAAnRvAAAAAAATAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAHYXBwcm92ZQAAAAAEAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAAB3NwZW5kZXIAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAABFsaXZlX3VudGlsX2xlZGdlcgAAAAAAAAQAAAAA",
+        "AAAAAAAAAAAAAAAIZGVjaW1hbHMAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAAEbmFtZQAAAAAAAAABAAAAEA==",
+        "AAAAAAAAAAAAAAAGc3ltYm9sAAAAAAAAAAAAAQAAABA=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAUgAAABxDb250cmFjdCBhbHJlYWR5IGluaXRpYWxpemVkAAAAEkFscmVhZHlJbml0aWFsaXplZAAAAAAAAQAAABhDb250cmFjdCBub3QgaW5pdGlhbGl6ZWQAAAAOTm90SW5pdGlhbGl6ZWQAAAAAAAIAAAArQ2FsbGVyIGlzIG5vdCBhdXRob3JpemVkIGZvciB0aGlzIG9wZXJhdGlvbgAAAAAMVW5hdXRob3JpemVkAAAACgAAACVPbmx5IGFkbWluIGNhbiBwZXJmb3JtIHRoaXMgb3BlcmF0aW9uAAAAAAAACU9ubHlBZG1pbgAAAAAAAAsAAAAmT25seSBsZW5kZXIgY2FuIHBlcmZvcm0gdGhpcyBvcGVyYXRpb24AAAAAAApPbmx5TGVuZGVyAAAAAAAMAAAAKE9ubHkgYm9ycm93ZXIgY2FuIHBlcmZvcm0gdGhpcyBvcGVyYXRpb24AAAAMT25seUJvcnJvd2VyAAAADQAAAB5BY2NvdW50IGRvZXMgbm90IGhvbGQgdGhlIHJvbGUAAAAAAAtSb2xlTm90SGVsZAAAAAAOAAAAD09mZmVyIG5vdCBmb3VuZAAAAAANT2ZmZXJOb3RGb3VuZAAAAAAAABQAAAATT2ZmZXIgaXMgbm90IGFjdGl2ZQAAAAAOT2ZmZXJOb3RBY3RpdmUAAAAAABUAAAAnSW52YWxpZCBpbnRlcmVzdCByYXRlIChleGNlZWRzIG1heGltdW0pAAAAABNJbnZhbGlkSW50ZXJlc3RSYXRlAAAAABYAAAAYSW52YWxpZCBjb2xsYXRlcmFsIHJhdGlvAAAAFkludmFsaWRDb2xsYXRlcmFsUmF0aW8AAAAAABcAAAAdSW52YWxpZCBsaXF1aWRhdGlvbiB0aHJlc2hvbGQAAAAAAAAbSW52YWxpZExpcXVpZGF0aW9uVGhyZXNob2xkAAAAABgAAAAnSW52YWxpZCBvZmZlciBhbW91bnQgKG11c3QgYmUgcG9zaXRpdmUpAAAAABJJbnZhbGlkT2ZmZXJBbW91bnQAAAAAABkAAAAYVXNlciBoYXMgdG9vIG1hbnkgb2ZmZXJzAAAADVRvb01hbnlPZmZlcnMAAAAAAAAaAAAAG0luc3VmZmljaWVudCBmdW5kcyBpbiBvZmZlcgAAAAAWSW5zdWZmaWNpZW50T2ZmZXJGdW5kcwAAAAAAGwAAACVDYW5ub3QgY2FuY2VsIG9mZmVyIHdpdGggYWN0aXZlIGxvYW5zAAAAAAAAE09mZmVySGFzQWN0aXZlTG9hbnMAAAAAHAAAAEVMb2FuIHRva2VuIGFscmVhZHkgaGFzIG9mZmVycyBhdCB0aGUgbWF4aW11bSBudW1iZXIgb2YgZGlzdGluY3QgcmF0ZXMAAAAAAAARVG9vTWFueU9mZmVyUmF0ZXMAAAAAAAAdAAAADkxvYW4gbm90IGZvdW5kAAAAAAAMTG9hbk5vdEZvdW5kAAAAKAAAABJMb2FuIGlzIG5vdCBhY3RpdmUAAAAAAA1Mb2FuTm90QWN0aXZlAAAAAAAAKQAAAChJbnZhbGlkIGJvcnJvdyBhbW91bnQgKG11c3QgYmUgcG9zaXRpdmUpAAAAE0ludmFsaWRCb3Jyb3dBbW91bnQAAAAAKgAAACxJbnZhbGlkIGNvbGxhdGVyYWwgYW1vdW50IChtdXN0IGJlIHBvc2l0aXZlKQAAABdJbnZhbGlkQ29sbGF0ZXJhbEFtb3VudAAAAAArAAAAM0luc3VmZmljaWVudCBjb2xsYXRlcmFsIGZvciByZXF1ZXN0ZWQgYm9ycm93IGFtb3VudAAAAAAWSW5zdWZmaWNpZW50Q29sbGF0ZXJhbAAAAAAALAAAABdVc2VyIGhhcyB0b28gbWFueSBsb2FucwAAAAAMVG9vTWFueUxvYW5zAAAALQAAABhJbnZhbGlkIHJlcGF5bWVudCBhbW91bnQAAAASSW52YWxpZFJlcGF5QW1vdW50AAAAAAAuAAAAFlJlcGF5bWVudCBleGNlZWRzIGRlYnQAAAAAABBSZXBheUV4Y2VlZHNEZWJ0AAAALwAAADpDYW5ub3Qgd2l0aGRyYXcgY29sbGF0ZXJhbCAtIHdvdWxkIGJyZWFjaCBoZWFsdGggdGhyZXNob2xkAAAAAAAYV2l0aGRyYXdhbEJyZWFjaGVzSGVhbHRoAAAAMAAAACVMb2FuIGR1cmF0aW9uIGV4Y2VlZHMgbWF4aW11bSBhbGxvd2VkAAAAAAAAFExvYW5EdXJhdGlvbkV4Y2VlZGVkAAAAMQAAACFMb2FuIGlzIG5vdCBwYXN0IGl0cyBkdWUgdGltZSB5ZXQAAAAAAAAOTG9hbk5vdE92ZXJkdWUAAAAAADIAAAA0TG9hbiBpcyBub3QgbGlxdWlkYXRhYmxlIChoZWFsdGggaXMgYWJvdmUgdGhyZXNob2xkKQAAAA9Ob3RMaXF1aWRhdGFibGUAAAAAPAAAABpMb2FuIGlzIGFscmVhZHkgbGlxdWlkYXRlZAAAAAAAEUFscmVhZHlMaXF1aWRhdGVkAAAAAAAAPQAAAC5MaXF1aWRhdGlvbiBmYWlsZWQgLSBjb3VsZCBub3Qgc3dhcCBjb2xsYXRlcmFsAAAAAAAVTGlxdWlkYXRpb25Td2FwRmFpbGVkAAAAAAAAPgAAACtJbnN1ZmZpY2llbnQgY29sbGF0ZXJhbCB2YWx1ZSB0byBjb3ZlciBkZWJ0AAAAABtJbnN1ZmZpY2llbnRDb2xsYXRlcmFsVmFsdWUAAAAAPwAAABpERVggcm91dGVyIGFkZHJlc3Mgbm90IHNldAAAAAAAD0RleFJvdXRlck5vdFNldAAAAABAAAAAGkludmFsaWQgc2xpcHBhZ2UgdG9sZXJhbmNlAAAAAAAPSW52YWxpZFNsaXBwYWdlAAAAAEEAAAAxSW52YWxpZCBjbG9zZSBmYWN0b3IgKG11c3QgYmUgYmV0d2VlbiAwIGFuZCAxMDAlKQAAAAAAABJJbnZhbGlkQ2xvc2VGYWN0b3IAAAAAAEIAAAAiTm8gYXVjdGlvbiBpcyBydW5uaW5nIGZvciB0aGUgbG9hbgAAAAAAD0F1Y3Rpb25Ob3RGb3VuZAAAAABDAAAAKkFuIGF1Y3Rpb24gaXMgYWxyZWFkeSBydW5uaW5nIGZvciB0aGUgbG9hbgAAAAAAFEF1Y3Rpb25BbHJlYWR5QWN0aXZlAAAARAAAAB1JbnZhbGlkIGF1Y3Rpb24gY29uZmlndXJhdGlvbgAAAAAAABRJbnZhbGlkQXVjdGlvbkNvbmZpZwAAAEUAAAAWT3JhY2xlIGFkZHJlc3Mgbm90IHNldAAAAAAADE9yYWNsZU5vdFNldAAAAFAAAAAkUHJpY2UgZGF0YSBub3QgYXZhaWxhYmxlIGZyb20gb3JhY2xlAAAAEVByaWNlTm90QXZhaWxhYmxlAAAAAAAAUQAAAB1QcmljZSBkYXRhIGlzIHN0YWxlICh0b28gb2xkKQAAAAAAAA5TdGFsZVByaWNlRGF0YQAAAAAAUgAAAB5JbnZhbGlkIHByaWNlIGRhdGEgZnJvbSBvcmFjbGUAAAAAABBJbnZhbGlkUHJpY2VEYXRhAAAAUwAAADBUV0FQIHdpbmRvdyBvdXRzaWRlIDEuLj1NQVhfVFdBUF9SRUNPUkRTIHJlY29yZHMAAAASSW52YWxpZFR3YXBSZWNvcmRzAAAAAABUAAAASFByaW1hcnkgYW5kIGZhbGxiYWNrIG9yYWNsZXMgZGlzYWdyZWUgYnkgbW9yZSB0aGFuIHRoZSBhbGxvd2VkIGRldmlhdGlvbgAAABRPcmFjbGVQcmljZURldmlhdGlvbgAAAFUAAAAjT3JhY2xlIGRldmlhdGlvbiBsaW1pdCBvdXQgb2YgcmFuZ2UAAAAAFkludmFsaWRPcmFjbGVEZXZpYXRpb24AAAAAAFYAAAASVVNEQyB0b2tlbiBub3Qgc2V0AAAAAAAPVXNkY1Rva2VuTm90U2V0AAAAAGQAAAAVVG9rZW4gdHJhbnNmZXIgZmFpbGVkAAAAAAAAE1Rva2VuVHJhbnNmZXJGYWlsZWQAAAAAZgAAABpJbnN1ZmZpY2llbnQgdG9rZW4gYmFsYW5jZQAAAAAAE0luc3VmZmljaWVudEJhbGFuY2UAAAAAZwAAACJMb2FuIHBvc2l0aW9uIE5GVCBjb250cmFjdCBub3Qgc2V0AAAAAAARUG9zaXRpb25OZnROb3RTZXQAAAAAAABoAAAATExvYW4gcG9zaXRpb24gTkZUIGNvbnRyYWN0IGlzIGFscmVhZHkgc2V0IG9yIGRvZXMgbm90IGxldCB0aGlzIGNvbnRyYWN0IG1pbnQAAAASSW52YWxpZFBvc2l0aW9uTmZ0AAAAAABpAAAAKk9mZmVyIGRvZXMgbm90IGFjY2VwdCB0aGUgY29sbGF0ZXJhbCB0b2tlbgAAAAAAFUNvbGxhdGVyYWxOb3RBY2NlcHRlZAAAAAAAAGoAAAAiSW52YWxpZCBjb2xsYXRlcmFsIHJpc2sgcGFyYW1ldGVycwAAAAAAF0ludmFsaWRDb2xsYXRlcmFsQ29uZmlnAAAAAGsAAAAnVG9rZW4gaXMgbm90IHJlZ2lzdGVyZWQgYXMgYSBsb2FuIGFzc2V0AAAAABRVbnN1cHBvcnRlZExvYW5Bc3NldAAAAGwAAAAdSW52YWxpZCBsb2FuIGFzc2V0IHBhcmFtZXRlcnMAAAAAAAAQSW52YWxpZExvYW5Bc3NldAAAAG0AAAAnQ29sbGF0ZXJhbCB0b2tlbiBpcyBub3QgaW4gdGhlIHJlZ2lzdHJ5AAAAABVVbnN1cHBvcnRlZENvbGxhdGVyYWwAAAAAAABuAAAAIVRoZSByZXF1ZXN0ZWQgb3BlcmF0aW9uIGlzIHBhdXNlZAAAAAAAAA5Db250cmFjdFBhdXNlZAAAAAAAeAAAABNSZWVudHJhbmN5IGRldGVjdGVkAAAAAAlSZWVudHJhbnQAAAAAAAB5AAAAF0ludmFsaWQgaW5wdXQgcGFyYW1ldGVyAAAAAAxJbnZhbGlkSW5wdXQAAAB6AAAAE0FyaXRobWV0aWMgb3ZlcmZsb3cAAAAAEkFyaXRobWV0aWNPdmVyZmxvdwAAAAAAewAAABRBcml0aG1ldGljIHVuZGVyZmxvdwAAABNBcml0aG1ldGljVW5kZXJmbG93AAAAAHwAAAAQRGl2aXNpb24gYnkgemVybwAAAA5EaXZpc2lvbkJ5WmVybwAAAAAAfQAAACFJbnZhbGlkIHN0b3JhZ2UgVFRMIGNvbmZpZ3VyYXRpb24AAAAAAAAQSW52YWxpZFR0bENvbmZpZwAAAH4AAAAiSW52YWxpZCBwcm90b2NvbCBmZWUgY29uZmlndXJhdGlvbgAAAAAAEEludmFsaWRGZWVDb25maWcAAAB/AAAAGkludmFsaWQgcmlzayBjb25maWd1cmF0aW9uAAAAAAARSW52YWxpZFJpc2tDb25maWcAAAAAAACAAAAAMlN0b3JlZCBzY2hlbWEgaXMgbmV3ZXIgdGhhbiB0aGlzIGJ1aWxkIGNhbiBtaWdyYXRlAAAAAAAYVW5zdXBwb3J0ZWRTY2hlbWFWZXJzaW9uAAAAgQAAABNJbnZhbGlkIHNvcnQgb3B0aW9uAAAAABFJbnZhbGlkU29ydE9wdGlvbgAAAAAAAIwAAAAdSW52YWxpZCBwYWdpbmF0aW9uIHBhcmFtZXRlcnMAAAAAAAARSW52YWxpZFBhZ2luYXRpb24AAAAAAACNAAAAE05vIG9mZmVycyBhdmFpbGFibGUAAAAAEU5vT2ZmZXJzQXZhaWxhYmxlAAAAAAAAjgAAAA5ObyBsb2FucyBmb3VuZAAAAAAADE5vTG9hbnNGb3VuZAAAAI8AAAATUG9vbCBsb2FuIG5vdCBmb3VuZAAAAAAQUG9vbExvYW5Ob3RGb3VuZAAAAKAAAAAgTm90IGVub3VnaCBpZGxlIFVTREMgaW4gdGhlIHBvb2wAAAAZSW5zdWZmaWNpZW50UG9vbExpcXVpZGl0eQAAAAAAAKEAAAAeSW52YWxpZCBwb29sIHV0aWxpemF0aW9uIGN1cnZlAAAAAAAQSW52YWxpZFJhdGVNb2RlbAAAAKIAAAAtTGVuZGVyIGhvbGRzIGZld2VyIHBvb2wgc2hhcmVzIHRoYW4gcmVxdWVzdGVkAAAAAAAAEkluc3VmZmljaWVudFNoYXJlcwAAAAAAowAAAEVCYWQgZGVidCBoYXMgd2lwZWQgb3V0IHRoZSBwb29sJ3MgYXNzZXRzIHdoaWxlIHNoYXJlcyBhcmUgb3V0c3RhbmRpbmcAAAAAAAANUG9vbEluc29sdmVudAAAAAAAAKQAAAAbVGltZWxvY2tlZCBhY3Rpb24gbm90IGZvdW5kAAAAAA5BY3Rpb25Ob3RGb3VuZAAAAAAAtAAAACFUaW1lbG9jayBkZWxheSBoYXMgbm90IHBhc3NlZCB5ZXQAAAAAAAAOQWN0aW9uTm90UmVhZHkAAAAAALUAAAApR3JhY2UgcGVyaW9kIHRvIHJ1biB0aGUgYWN0aW9uIGhhcyBwYXNzZWQAAAAAAAANQWN0aW9uRXhwaXJlZAAAAAAAALYAAAAWSW52YWxpZCB0aW1lbG9jayBkZWxheQAAAAAAFEludmFsaWRUaW1lbG9ja0RlbGF5AAAAtwAAABZUaW1lbG9jayBxdWV1ZSBpcyBmdWxsAAAAAAAUVG9vTWFueVF1ZXVlZEFjdGlvbnMAAAC4AAAAGEJvcnJvdyByZXF1ZXN0IG5vdCBmb3VuZAAAABVCb3Jyb3dSZXF1ZXN0Tm90Rm91bmQAAAAAAADIAAAAIEJvcnJvdyByZXF1ZXN0IGlzIG5vIGxvbmdlciBvcGVuAAAAFkJvcnJvd1JlcXVlc3ROb3RBY3RpdmUAAAAAAMkAAAAmVXNlciBoYXMgdG9vIG1hbnkgb3BlbiBib3Jyb3cgcmVxdWVzdHMAAAAAABVUb29NYW55Qm9ycm93UmVxdWVzdHMAAAAAAADK",
+        "AAAAAgAAAB5Ib3cgaW50ZXJlc3QgYWNjcnVlcyBvbiBhIGxvYW4AAAAAAAAAAAAMSW50ZXJlc3RNb2RlAAAAAwAAAAAAAAA3U2ltcGxlIHBlci1zZWNvbmQgaW50ZXJlc3Qgb24gdGhlIG91dHN0YW5kaW5nIHByaW5jaXBhbAAAAAAGU2ltcGxlAAAAAAAAAAAAM1ByaW5jaXBhbCBhbmQgdW5wYWlkIGludGVyZXN0IGNvbXBvdW5kIGV2ZXJ5IHNlY29uZAAAAAARQ29tcG91bmRQZXJTZWNvbmQAAAAAAAAAAAAANlByaW5jaXBhbCBhbmQgdW5wYWlkIGludGVyZXN0IGNvbXBvdW5kIGV2ZXJ5IGZ1bGwgd2VlawAAAAAAD0NvbXBvdW5kUGVyV2VlawA=",
+        "AAAAAQAAACFMZW5kaW5nIG9mZmVyIGNyZWF0ZWQgYnkgYSBsZW5kZXIAAAAAAAAAAAAADExlbmRpbmdPZmZlcgAAAAwAAAA8QW1vdW50IG9mIHRoZSBsb2FuIHRva2VuIGF2YWlsYWJsZSB0byBsZW5kIChpbiBpdHMgZGVjaW1hbHMpAAAABmFtb3VudAAAAAAACwAAADdDb2xsYXRlcmFsIHRva2VucyBib3Jyb3dlcnMgbWF5IHBvc3QgYWdhaW5zdCB0aGlzIG9mZmVyAAAAABFjb2xsYXRlcmFsX2Fzc2V0cwAAAAAAA+oAAAATAAAAIFRpbWVzdGFtcCB3aGVuIG9mZmVyIHdhcyBjcmVhdGVkAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAA2SG93IGludGVyZXN0IGFjY3J1ZXMgb24gbG9hbnMgdGFrZW4gYWdhaW5zdCB0aGlzIG9mZmVyAAAAAAANaW50ZXJlc3RfbW9kZQAAAAAAB9AAAAAMSW50ZXJlc3RNb2RlAAAAHFdoZXRoZXIgdGhpcyBvZmZlciBpcyBhY3RpdmUAAAAJaXNfYWN0aXZlAAAAAAAAAQAAABVBZGRyZXNzIG9mIHRoZSBsZW5kZXIAAAAAAAAGbGVuZGVyAAAAAAATAAAAOkxpcXVpZGF0aW9uIHRocmVzaG9sZCBpbiBiYXNpcyBwb2ludHMgKGUuZy4sIDEyNTAwID0gMTI1JSkAAAAAABVsaXF1aWRhdGlvbl90aHJlc2hvbGQAAAAAAAAEAAAAEFRva2VuIGJlaW5nIGxlbnQAAAAKbG9hbl90b2tlbgAAAAAAEwAAAB5NYXhpbXVtIGxvYW4gZHVyYXRpb24gaW4gd2Vla3MAAAAAABJtYXhfZHVyYXRpb25fd2Vla3MAAAAAAAQAAABLTWluaW11bSBjb2xsYXRlcmFsIHJhdGlvIGluIGJhc2lzIHBvaW50cyAoZS5nLiwgMjAwMDAgPSAyMDAlID0gbWF4IDUwJSBMVFYpAAAAABRtaW5fY29sbGF0ZXJhbF9yYXRpbwAAAAQAAAAgVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoaXMgb2ZmZXIAAAAIb2ZmZXJfaWQAAAAGAAAANVdlZWtseSBpbnRlcmVzdCByYXRlIGluIGJhc2lzIHBvaW50cyAoZS5nLiwgNTAwID0gNSUpAAAAAAAAFHdlZWtseV9pbnRlcmVzdF9yYXRlAAAABA==",
+        "AAAAAQAAAD5PbmUgbG9hbiBvZiBhIGBib3Jyb3dfYmVzdGAgY2FsbCwgYXMgcGxhbm5lZCBieSBgcXVvdGVfYm9ycm93YAAAAAAAAAAAAApCb3Jyb3dGaWxsAAAAAAAEAAAAQkFtb3VudCBvZiB0aGUgbG9hbiB0b2tlbiBib3Jyb3dlZCBmcm9tIHRoZSBvZmZlciAoaW4gaXRzIGRlY2ltYWxzKQAAAAAABmFtb3VudAAAAAAACwAAAFFTaGFyZSBvZiB0aGUgY29sbGF0ZXJhbCBwb3N0ZWQgZm9yIHRoaXMgbG9hbiAoaW4gdGhlIGNvbGxhdGVyYWwgdG9rZW4ncyBkZWNpbWFscykAAAAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAMVdlZWtseSBpbnRlcmVzdCByYXRlIG9mIHRoZSBvZmZlciBpbiBiYXNpcyBwb2ludHMAAAAAAAANaW50ZXJlc3RfcmF0ZQAAAAAAAAQAAAAfT2ZmZXIgdGhlIGxvYW4gaXMgdGFrZW4gYWdhaW5zdAAAAAAIb2ZmZXJfaWQAAAAG",
+        "AAAAAQAAAEZMb2FuIHRlcm1zIHBvc3RlZCBieSBhIGJvcnJvd2VyLCB3aXRoIHRoZSBjb2xsYXRlcmFsIGVzY3Jvd2VkIHVwIGZyb250AAAAAAAAAAAADUJvcnJvd1JlcXVlc3QAAAAAAAAKAAAAF0FkZHJlc3Mgb2YgdGhlIGJvcnJvd2VyAAAAAAhib3Jyb3dlcgAAABMAAABCQW1vdW50IG9mIGNvbGxhdGVyYWwgZXNjcm93ZWQgKGluIHRoZSBjb2xsYXRlcmFsIHRva2VuJ3MgZGVjaW1hbHMpAAAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAHFRva2VuIGVzY3Jvd2VkIGFzIGNvbGxhdGVyYWwAAAAQY29sbGF0ZXJhbF90b2tlbgAAABMAAAAmVGltZXN0YW1wIHdoZW4gdGhlIHJlcXVlc3Qgd2FzIGNyZWF0ZWQAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAANEFtb3VudCBvZiB0aGUgbG9hbiB0b2tlbiByZXF1ZXN0ZWQgKGluIGl0cyBkZWNpbWFscykAAAAOZGVzaXJlZF9hbW91bnQAAAAAAAsAAAA/TG9hbiBkdXJhdGlvbiBpbiB3ZWVrcywgY291bnRlZCBmcm9tIHdoZW4gdGhlIHJlcXVlc3QgaXMgZmlsbGVkAAAAAA5kdXJhdGlvbl93ZWVrcwAAAAAABAAAACdXaGV0aGVyIHRoZSByZXF1ZXN0IGNhbiBzdGlsbCBiZSBmaWxsZWQAAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAiVG9rZW4gdGhlIGJvcnJvd2VyIHdhbnRzIHRvIGJvcnJvdwAAAAAACmxvYW5fdG9rZW4AAAAAABMAAABCSGlnaGVzdCB3ZWVrbHkgaW50ZXJlc3QgcmF0ZSB0aGUgYm9ycm93ZXIgYWNjZXB0cywgaW4gYmFzaXMgcG9pbnRzAAAAAAAIbWF4X3JhdGUAAAAEAAAAIlVuaXF1ZSBpZGVudGlmaWVyIGZvciB0aGlzIHJlcXVlc3QAAAAAAApyZXF1ZXN0X2lkAAAAAAAG",
+        "AAAAAQAAABRBY3RpdmUgbG9hbiBwb3NpdGlvbgAAAAAAAAAETG9hbgAAABAAAAA6QWNjdW11bGF0ZWQgaW50ZXJlc3Qgc28gZmFyIChpbiB0aGUgbG9hbiB0b2tlbidzIGRlY2ltYWxzKQAAAAAAFGFjY3VtdWxhdGVkX2ludGVyZXN0AAAACwAAADNBbW91bnQgb2YgdGhlIGxvYW4gdG9rZW4gYm9ycm93ZWQgKGluIGl0cyBkZWNpbWFscykAAAAAD2JvcnJvd2VkX2Ftb3VudAAAAAALAAAAF0FkZHJlc3Mgb2YgdGhlIGJvcnJvd2VyAAAAAAhib3Jyb3dlcgAAABMAAABDQW1vdW50IG9mIGNvbGxhdGVyYWwgZGVwb3NpdGVkIChpbiB0aGUgY29sbGF0ZXJhbCB0b2tlbidzIGRlY2ltYWxzKQAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAGlRva2VuIHBvc3RlZCBhcyBjb2xsYXRlcmFsAAAAAAAQY29sbGF0ZXJhbF90b2tlbgAAABMAAAAsVGltZXN0YW1wIGFmdGVyIHdoaWNoIHRoZSBsb2FuIGlzIGluIGRlZmF1bHQAAAAIZHVlX3RpbWUAAAAGAAAALEhvdyBpbnRlcmVzdCBhY2NydWVzIChjb3BpZWQgZnJvbSB0aGUgb2ZmZXIpAAAADWludGVyZXN0X21vZGUAAAAAAAfQAAAADEludGVyZXN0TW9kZQAAACRXZWVrbHkgaW50ZXJlc3QgcmF0ZSBpbiBiYXNpcyBwb2ludHMAAAANaW50ZXJlc3RfcmF0ZQAAAAAAAAQAAAAbV2hldGhlciB0aGlzIGxvYW4gaXMgYWN0aXZlAAAAAAlpc19hY3RpdmUAAAAAAAABAAAAHkxhc3QgdGltZSBpbnRlcmVzdCB3YXMgdXBkYXRlZAAAAAAAFGxhc3RfaW50ZXJlc3RfdXBkYXRlAAAABgAAAEpMZW5kZXIgd2hvIGZ1bmRlZCB0aGUgbG9hbiAocGF5bWVudHMgZ28gdG8gdGhlIGhvbGRlciBvZiBpdHMgcG9zaXRpb24gTkZUKQAAAAAABmxlbmRlcgAAAAAAEwAAACVMaXF1aWRhdGlvbiB0aHJlc2hvbGQgaW4gYmFzaXMgcG9pbnRzAAAAAAAAFWxpcXVpZGF0aW9uX3RocmVzaG9sZAAAAAAAAAQAAAAfVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoaXMgbG9hbgAAAAAHbG9hbl9pZAAAAAAGAAAAGVRva2VuIGJvcnJvd2VkIGFuZCByZXBhaWQAAAAAAAAKbG9hbl90b2tlbgAAAAAAEwAAAEpSZWZlcmVuY2UgdG8gdGhlIG9yaWdpbmFsIG9mZmVyICgwIGZvciBsb2FucyBmaWxsZWQgZnJvbSBhIGJvcnJvdyByZXF1ZXN0KQAAAAAACG9mZmVyX2lkAAAABgAAAB9UaW1lc3RhbXAgd2hlbiBsb2FuIHdhcyBjcmVhdGVkAAAAAApzdGFydF90aW1lAAAAAAAG",
+        "AAAAAQAAAB1IZWFsdGggaW5mb3JtYXRpb24gZm9yIGEgbG9hbgAAAAAAAAAAAAAKTG9hbkhlYWx0aAAAAAAACgAAADxDdXJyZW50IGNvbGxhdGVyYWwgdmFsdWUgaW4gdGhlIGxvYW4gdG9rZW4gKGluIGl0cyBkZWNpbWFscykAAAAQY29sbGF0ZXJhbF92YWx1ZQAAAAsAAAAnQ29sbGF0ZXJhbGl6YXRpb24gcmF0aW8gaW4gYmFzaXMgcG9pbnRzAAAAABdjb2xsYXRlcmFsaXphdGlvbl9yYXRpbwAAAAAEAAAASlRvdGFsIGRlYnQgaW5jbHVkaW5nIHByaW5jaXBhbCBhbmQgaW50ZXJlc3QgKGluIHRoZSBsb2FuIHRva2VuJ3MgZGVjaW1hbHMpAAAAAAAKZGVidF92YWx1ZQAAAAAACwAAACxUaW1lc3RhbXAgYWZ0ZXIgd2hpY2ggdGhlIGxvYW4gaXMgaW4gZGVmYXVsdAAAAAhkdWVfdGltZQAAAAYAAAAsSGVhbHRoIGZhY3RvciBpbiBiYXNpcyBwb2ludHMgKDEwMDAwID0gMTAwJSkAAAANaGVhbHRoX2ZhY3RvcgAAAAAAAAQAAAAjV2hldGhlciB0aGlzIGxvYW4gY2FuIGJlIGxpcXVpZGF0ZWQAAAAAD2lzX2xpcXVpZGF0YWJsZQAAAAABAAAATFdoZXRoZXIgdGhlIGxvYW4gaXMgcGFzdCBkdWUgYW5kIGl0cyBjb2xsYXRlcmFsIGNhbiBiZSBjbGFpbWVkIGJ5IHRoZSBsZW5kZXIAAAAKaXNfb3ZlcmR1ZQAAAAAAAQAAAFhDb2xsYXRlcmFsIHByaWNlIGluIHRoZSBsb2FuIHRva2VuIHRoYXQgd291bGQgdHJpZ2dlciBsaXF1aWRhdGlvbiAod2l0aCBvcmFjbGUgZGVjaW1hbHMpAAAAEWxpcXVpZGF0aW9uX3ByaWNlAAAAAAAACwAAAA9Mb2FuIGlkZW50aWZpZXIAAAAAB2xvYW5faWQAAAAABgAAADRTZWNvbmRzIGxlZnQgdW50aWwgdGhlIGxvYW4gaXMgZHVlICgwIG9uY2UgcGFzdCBkdWUpAAAADnRpbWVfcmVtYWluaW5nAAAAAAAG",
+        "AAAAAgAAAB9PcmFjbGUgY3VycmVudGx5IHNlcnZpbmcgcHJpY2VzAAAAAAAAAAAMT3JhY2xlU291cmNlAAAAAgAAAAAAAAAaVGhlIHByaW1hcnkgUmVmbGVjdG9yIGZlZWQAAAAAAAdQcmltYXJ5AAAAAAAAAABEVGhlIGZhbGxiYWNrIGZlZWQsIHVzZWQgd2hpbGUgdGhlIHByaW1hcnkgY2Fubm90IHNlcnZlIGEgdmFsaWQgcHJpY2UAAAAIRmFsbGJhY2s=",
+        "AAAAAgAAAFVSb2xlcyB0aGUgYWRtaW4gY2FuIGdyYW50IGZvciBkYXktdG8tZGF5IG9wZXJhdGlvbnMKVGhlIGFkbWluIHBhc3NlcyBldmVyeSByb2xlIGNoZWNrAAAAAAAAAAAAAARSb2xlAAAABAAAAAAAAAAgTWF5IHBhdXNlIGFuZCB1bnBhdXNlIHRoZSBtYXJrZXQAAAAGUGF1c2VyAAAAAAAAAAAARU1heSBjaGFuZ2UgaW50ZXJlc3QsIGNvbGxhdGVyYWwsIGxpcXVpZGF0aW9uIGFuZCBwb29sIHJpc2sgcGFyYW1ldGVycwAAAAAAAAtSaXNrTWFuYWdlcgAAAAAAAAAAQ01heSBjaGFuZ2UgdGhlIHByaW1hcnkgYW5kIGZhbGxiYWNrIG9yYWNsZXMgYW5kIGhvdyBwcmljZXMgYXJlIHJlYWQAAAAADU9yYWNsZU1hbmFnZXIAAAAAAAAAAAAAH01heSBjbGFpbSBhY2NydWVkIHByb3RvY29sIGZlZXMAAAAADEZlZUNvbGxlY3Rvcg==",
+        "AAAAAgAAACBTb3J0IG9wdGlvbnMgZm9yIHF1ZXJ5aW5nIG9mZmVycwAAAAAAAAAKU29ydE9wdGlvbgAAAAAAAwAAAAAAAAAjU29ydCBieSBiZXN0IChsb3dlc3QpIGludGVyZXN0IHJhdGUAAAAACEJlc3RSYXRlAAAAAAAAAGBTb3J0IGJ5IGhpZ2hlc3QgYW1vdW50IGF2YWlsYWJsZSAoaW4gcmF3IHRva2VuIHVuaXRzLCBzbyBiZXN0IGNvbWJpbmVkIHdpdGggYSBsb2FuIHRva2VuIGZpbHRlcikAAAANSGlnaGVzdEFtb3VudAAAAAAAAAAAAAAUU29ydCBieSBuZXdlc3QgZmlyc3QAAAAGTmV3ZXN0AAA=",
+        "AAAAAQAAACxPcHRpb25hbCBmaWx0ZXJzIGFwcGxpZWQgd2hlbiBsaXN0aW5nIG9mZmVycwAAAAAAAAALT2ZmZXJGaWx0ZXIAAAAABQAAADNPbmx5IGluY2x1ZGUgb2ZmZXJzIGFjY2VwdGluZyB0aGlzIGNvbGxhdGVyYWwgdG9rZW4AAAAAEGNvbGxhdGVyYWxfYXNzZXQAAAPoAAAAEwAAACZPbmx5IGluY2x1ZGUgb2ZmZXJzIGxlbmRpbmcgdGhpcyB0b2tlbgAAAAAACmxvYW5fdG9rZW4AAAAAA+gAAAATAAAASk9ubHkgaW5jbHVkZSBvZmZlcnMgcmVxdWlyaW5nIGF0IG1vc3QgdGhpcyBjb2xsYXRlcmFsIHJhdGlvIChiYXNpcyBwb2ludHMpAAAAAAAUbWF4X2NvbGxhdGVyYWxfcmF0aW8AAAPoAAAABAAAAEZPbmx5IGluY2x1ZGUgb2ZmZXJzIHdpdGggYSB3ZWVrbHkgcmF0ZSBhdCBvciBiZWxvdyB0aGlzIChiYXNpcyBwb2ludHMpAAAAAAAIbWF4X3JhdGUAAAPoAAAABAAAAElPbmx5IGluY2x1ZGUgb2ZmZXJzIHdpdGggYXQgbGVhc3QgdGhpcyBtdWNoIG9mIHRoZWlyIGxvYW4gdG9rZW4gYXZhaWxhYmxlAAAAAAAACm1pbl9hbW91bnQAAAAAA+gAAAAL",
+        "AAAAAQAAAEtUaW1lLXRvLWxpdmUgcG9saWN5IGFwcGxpZWQgdG8gaW5zdGFuY2UgYW5kIHBlcnNpc3RlbnQgc3RvcmFnZSAoaW4gbGVkZ2VycykAAAAAAAAAAAlUdGxDb25maWcAAAAAAAACAAAAG1RUTCBlbnRyaWVzIGFyZSBleHRlbmRlZCB0bwAAAAAJZXh0ZW5kX3RvAAAAAAAABAAAAD5FbnRyaWVzIGFyZSBleHRlbmRlZCBvbmNlIHRoZWlyIHJlbWFpbmluZyBUVEwgZHJvcHMgYmVsb3cgdGhpcwAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
+        "AAAAAQAAAExEdXRjaCBhdWN0aW9uIHNlbGxpbmcgYSBsaXF1aWRhdGFibGUgbG9hbidzIGNvbGxhdGVyYWwgYXQgYSBncm93aW5nIGRpc2NvdW50AAAAAAAAAAdBdWN0aW9uAAAAAAMAAAAgQWRkcmVzcyB0aGF0IHN0YXJ0ZWQgdGhlIGF1Y3Rpb24AAAAJaW5pdGlhdG9yAAAAAAAAEwAAAChMb2FuIHdob3NlIGNvbGxhdGVyYWwgaXMgYmVpbmcgYXVjdGlvbmVkAAAAB2xvYW5faWQAAAAABgAAADtUaW1lc3RhbXAgdGhlIGF1Y3Rpb24gc3RhcnRlZCAoZGlzY291bnQgaXMgMCBhdCB0aGlzIHBvaW50KQAAAAAKc3RhcnRfdGltZQAAAAAABg==",
+        "AAAAAQAAABxEdXRjaCBhdWN0aW9uIGRpc2NvdW50IGN1cnZlAAAAAAAAAA1BdWN0aW9uQ29uZmlnAAAAAAAAAgAAAD9TZWNvbmRzIGZvciB0aGUgZGlzY291bnQgdG8gZ3JvdyBsaW5lYXJseSBmcm9tIDAgdG8gdGhlIG1heGltdW0AAAAACGR1cmF0aW9uAAAABgAAACtNYXhpbXVtIGNvbGxhdGVyYWwgZGlzY291bnQgaW4gYmFzaXMgcG9pbnRzAAAAABBtYXhfZGlzY291bnRfYnBzAAAABA==",
+        "AAAAAQAAACNQcm90b2NvbCBmZWVzIGNoYXJnZWQgYnkgdGhlIG1hcmtldAAAAAAAAAAACUZlZUNvbmZpZwAAAAAAAAIAAAA8RmVlIG9uIGRlYnQgcmVwYWlkIHRocm91Z2ggREVYIGxpcXVpZGF0aW9ucywgaW4gYmFzaXMgcG9pbnRzAAAAE2xpcXVpZGF0aW9uX2ZlZV9icHMAAAAABAAAAEBTaGFyZSBvZiBhbGwgaW50ZXJlc3QgcGFpZCBrZXB0IGJ5IHRoZSBwcm90b2NvbCwgaW4gYmFzaXMgcG9pbnRzAAAAEHByb3RvY29sX2ZlZV9icHMAAAAE",
+        "AAAAAQAAAERNYXJrZXQtd2lkZSByaXNrIGxpbWl0cywgYWRqdXN0YWJsZSBieSB0aGUgYWRtaW4gd2l0aG91dCByZWRlcGxveWluZwAAAAAAAAAKUmlza0NvbmZpZwAAAAAACQAAAD5IaWdoZXN0IGNvbGxhdGVyYWwgcmF0aW8gYW4gb2ZmZXIgbWF5IHJlcXVpcmUsIGluIGJhc2lzIHBvaW50cwAAAAAAFG1heF9jb2xsYXRlcmFsX3JhdGlvAAAABAAAAEZVcHBlciBib3VuZCBmb3IgYSBjb2xsYXRlcmFsIGFzc2V0J3MgbGlxdWlkYXRvciBib251cywgaW4gYmFzaXMgcG9pbnRzAAAAAAAZbWF4X2xpcXVpZGF0aW9uX2JvbnVzX2JwcwAAAAAAAAQAAAArTWF4aW11bSBudW1iZXIgb2YgbG9hbnMgYSBib3Jyb3dlciBjYW4gaG9sZAAAAAASbWF4X2xvYW5zX3Blcl91c2VyAAAAAAAEAAAAKk1heGltdW0gbnVtYmVyIG9mIG9mZmVycyBhIGxlbmRlciBjYW4gaG9sZAAAAAAAE21heF9vZmZlcnNfcGVyX3VzZXIAAAAABAAAADpNYXhpbXVtIG51bWJlciBvZiBvcGVuIGJvcnJvdyByZXF1ZXN0cyBhIGJvcnJvd2VyIGNhbiBob2xkAAAAAAAVbWF4X3JlcXVlc3RzX3Blcl91c2VyAAAAAAAABAAAADVMaXF1aWRhdGlvbiB0aHJlc2hvbGQgZm9yIHBvb2wgbG9hbnMsIGluIGJhc2lzIHBvaW50cwAAAAAAABpwb29sX2xpcXVpZGF0aW9uX3RocmVzaG9sZAAAAAAABAAAADhNaW5pbXVtIGNvbGxhdGVyYWwgcmF0aW8gZm9yIHBvb2wgbG9hbnMsIGluIGJhc2lzIHBvaW50cwAAABlwb29sX21pbl9jb2xsYXRlcmFsX3JhdGlvAAAAAAAABAAAAClNYXhpbXVtIGFnZSBvZiBhbiBvcmFjbGUgcHJpY2UgaW4gc2Vjb25kcwAAAAAAABlwcmljZV9zdGFsZW5lc3NfdGhyZXNob2xkAAAAAAAABgAAAFxIZWFkcm9vbSBhYm92ZSB0aGUgbGlxdWlkYXRpb24gdGhyZXNob2xkIGtlcHQgYWZ0ZXIgYSBjb2xsYXRlcmFsIHdpdGhkcmF3YWwsIGluIGJhc2lzIHBvaW50cwAAABV3aXRoZHJhd2FsX21hcmdpbl9icHMAAAAAAAAE",
+        "AAAAAQAAAHNPcGVyYXRpb25zIGhhbHRlZCBieSB0aGUgcGF1c2UgZmxhZ3MKQ29sbGF0ZXJhbCB0b3AtdXBzIGFyZSBuZXZlciBwYXVzZWQsIHNvIGJvcnJvd2VycyBjYW4gYWx3YXlzIGRlZmVuZCBhIHBvc2l0aW9uAAAAAAAAAAAKUGF1c2VTdGF0ZQAAAAAABQAAABZOZXcgUDJQIGFuZCBwb29sIGxvYW5zAAAAAAAHYm9ycm93cwAAAAABAAAAO0xpcXVpZGF0aW9ucywgYXVjdGlvbnMgYW5kIGxlbmRlciBjbGFpbXMgb24gZGVmYXVsdGVkIGxvYW5zAAAAAAxsaXF1aWRhdGlvbnMAAAABAAAAIE5ldyBQMlAgb2ZmZXJzIGFuZCBwb29sIGRlcG9zaXRzAAAABm9mZmVycwAAAAAAAQAAADVMb2FuIHJlcGF5bWVudHMsIGxlZnQgYXMgdGhleSBhcmUgYnkgYHBhdXNlX2NvbnRyYWN0YAAAAAAAAApyZXBheW1lbnRzAAAAAAABAAAAQFdpdGhkcmF3YWxzIG9mIGlkbGUgb2ZmZXIgZnVuZHMsIHBvb2wgc2hhcmVzIGFuZCBsb2FuIGNvbGxhdGVyYWwAAAALd2l0aGRyYXdhbHMAAAAAAQ==",
+        "AAAAAgAAAEZBZG1pbiBhY3Rpb24gdGhhdCBvbmx5IHRha2VzIGVmZmVjdCBvbmNlIHRoZSB0aW1lbG9jayBkZWxheSBoYXMgcGFzc2VkAAAAAAAAAAAADlRpbWVsb2NrQWN0aW9uAAAAAAAQAAAAAQAAABpSZXBsYWNlIHRoZSBwcmltYXJ5IG9yYWNsZQAAAAAAEFNldE9yYWNsZUFkZHJlc3MAAAABAAAAEwAAAAEAAAAgU2V0IG9yIGNsZWFyIHRoZSBmYWxsYmFjayBvcmFjbGUAAAARU2V0RmFsbGJhY2tPcmFjbGUAAAAAAAABAAAD6AAAABMAAAABAAAAjFJlcGxhY2UgdGhlIG1hcmtldC13aWRlIHJpc2sgbGltaXRzOyBvcGVuIFAyUCBsb2FucyBrZWVwIHRoZSBsaXF1aWRhdGlvbgp0aHJlc2hvbGQgdGhleSB3ZXJlIHRha2VuIHdpdGgsIHdoaWxlIHBvb2wgbG9hbnMgZm9sbG93IHRoZSBuZXcgb25lAAAAEFVwZGF0ZVJpc2tDb25maWcAAAABAAAH0AAAAApSaXNrQ29uZmlnAAAAAAABAAAAaVJlcGxhY2UgdGhlIGNvbnRyYWN0IGNvZGUgd2l0aCBhbiB1cGxvYWRlZCB3YXNtIGJ1aWxkLCB3aG9zZSBzdG9yYWdlIGlzCmJyb3VnaHQgdXAgdG8gZGF0ZSB3aXRoIGBtaWdyYXRlYAAAAAAAAAdVcGdyYWRlAAAAAAEAAAPuAAAAIAAAAAEAAAA5Q2hhbmdlIHRoZSBkZWxheSBvZiBhY3Rpb25zIHF1ZXVlZCBhZnRlcndhcmRzLCBpbiBzZWNvbmRzAAAAAAAAEFNldFRpbWVsb2NrRGVsYXkAAAABAAAABgAAAAEAAAB1TGlzdCBhIGNvbGxhdGVyYWwgdG9rZW4gb3IgdXBkYXRlIGl0cyByaXNrIHBhcmFtZXRlcnM7IG9wZW4gbG9hbnMga2VlcAp0aGUgbGlxdWlkYXRpb24gdGhyZXNob2xkIHRoZXkgd2VyZSB0YWtlbiB3aXRoAAAAAAAAE1NldENvbGxhdGVyYWxDb25maWcAAAAAAgAAABMAAAfQAAAAEENvbGxhdGVyYWxDb25maWcAAAABAAAAPUxpc3QgYSB0b2tlbiB0aGF0IG9mZmVycyBtYXkgbGVuZCwgb3IgdXBkYXRlIGhvdyBpdCBpcyBwcmljZWQAAAAAAAAMU2V0TG9hbkFzc2V0AAAAAgAAABMAAAfQAAAAD0xvYW5Bc3NldENvbmZpZwAAAAABAAAAhVJlcGxhY2UgdGhlIERFWCByb3V0ZXIgdXNlZCB0byBzZWxsIGNvbGxhdGVyYWwgb24gbGlxdWlkYXRpb24KVGhlIGNvbnN0cnVjdG9yIHNldHMgdGhlIGZpcnN0IHJvdXRlcjsgZXZlcnkgbGF0ZXIgY2hhbmdlIGlzIHRpbWVsb2NrZWQAAAAAAAAMU2V0RGV4Um91dGVyAAAAAQAAABMAAAABAAAAT0NoYW5nZSBob3cgZmFyIHRoZSBwcmltYXJ5IGFuZCBmYWxsYmFjayBwcmljZXMgbWF5IGRyaWZ0IGFwYXJ0LCBpbiBiYXNpcyBwb2ludHMAAAAAFVNldE1heE9yYWNsZURldmlhdGlvbgAAAAAAAAEAAAAEAAAAAQAAADlDaGFuZ2UgaG93IG1hbnkgb3JhY2xlIHJlY29yZHMgYXJlIGF2ZXJhZ2VkIGludG8gdGhlIFRXQVAAAAAAAAAOU2V0VHdhcFJlY29yZHMAAAAAAAEAAAAEAAAAAQAAAEpDaGFuZ2UgdGhlIG1heGltdW0gc2xpcHBhZ2UgYWNjZXB0ZWQgb24gbGlxdWlkYXRpb24gc3dhcHMsIGluIGJhc2lzIHBvaW50cwAAAAAADlNldE1heFNsaXBwYWdlAAAAAAABAAAABAAAAAEAAABRQ2hhbmdlIHRoZSBtYXhpbXVtIHNoYXJlIG9mIGEgbG9hbidzIGRlYnQgb25lIGxpcXVpZGF0aW9uIHJlcGF5cywgaW4gYmFzaXMgcG9pbnRzAAAAAAAADlNldENsb3NlRmFjdG9yAAAAAAABAAAABAAAAAEAAABXQ2hhbmdlIHRoZSBoaWdoZXN0IHdlZWtseSBpbnRlcmVzdCByYXRlIG9mZmVycyBhbmQgdGhlIHBvb2wgbWF5IGNoYXJnZSwgaW4gYmFzaXMgcG9pbnRzAAAAABJTZXRNYXhJbnRlcmVzdFJhdGUAAAAAAAEAAAAEAAAAAQAAAChSZXBsYWNlIHRoZSBEdXRjaCBhdWN0aW9uIGRpc2NvdW50IGN1cnZlAAAAEFNldEF1Y3Rpb25Db25maWcAAAABAAAH0AAAAA1BdWN0aW9uQ29uZmlnAAAAAAAAAQAAADZSZXBsYWNlIHRoZSBwcm90b2NvbCBmZWVzIG9uIGludGVyZXN0IGFuZCBsaXF1aWRhdGlvbnMAAAAAAAxTZXRGZWVDb25maWcAAAABAAAH0AAAAAlGZWVDb25maWcAAAAAAAABAAAAWFJlcGxhY2UgdGhlIHBvb2wncyB1dGlsaXphdGlvbiBjdXJ2ZTsgaW50ZXJlc3QgdXAgdG8gdGhlIGNoYW5nZSBhY2NydWVzIGF0IHRoZSBvbGQgcmF0ZXMAAAAMU2V0UmF0ZU1vZGVsAAAAAQAAB9AAAAAJUmF0ZU1vZGVsAAAA",
+        "AAAAAQAAACRBY3Rpb24gd2FpdGluZyBpbiB0aGUgdGltZWxvY2sgcXVldWUAAAAAAAAADFF1ZXVlZEFjdGlvbgAAAAQAAAAAAAAABmFjdGlvbgAAAAAH0AAAAA5UaW1lbG9ja0FjdGlvbgAAAAAAAAAAAAlhY3Rpb25faWQAAAAAAAAGAAAAJUVhcmxpZXN0IHRpbWVzdGFtcCB0aGUgYWN0aW9uIG1heSBydW4AAAAAAAADZXRhAAAAAAYAAAAeQWNjb3VudCB0aGF0IHF1ZXVlZCB0aGUgYWN0aW9uAAAAAAAIcHJvcG9zZXIAAAAT",
+        "AAAAAQAAADJSaXNrIHBhcmFtZXRlcnMgZm9yIGEgdG9rZW4gYWNjZXB0ZWQgYXMgY29sbGF0ZXJhbAAAAAAAAAAAABBDb2xsYXRlcmFsQ29uZmlnAAAABQAAAB9EZWNpbWFscyBvZiB0aGUgdG9rZW4ncyBhbW91bnRzAAAAAAhkZWNpbWFscwAAAAQAAABBQm9udXMgcGFpZCB0byBsaXF1aWRhdG9ycyBvbiB0aGUgZGVidCB0aGV5IHJlcGF5LCBpbiBiYXNpcyBwb2ludHMAAAAAAAAVbGlxdWlkYXRpb25fYm9udXNfYnBzAAAAAAAABAAAAH5IaWdoZXN0IGxvYW4tdG8tdmFsdWUgYSBwb3NpdGlvbiBtYXkgcmVhY2ggYmVmb3JlIGl0IGlzIGxpcXVpZGF0YWJsZSwgaW4KYmFzaXMgcG9pbnRzICg4MDAwID0gbGlxdWlkYXRhYmxlIGF0IDEyNSUgY29sbGF0ZXJhbCkAAAAAABlsaXF1aWRhdGlvbl90aHJlc2hvbGRfY2FwAAAAAAAABAAAAE5NYXhpbXVtIGxvYW4tdG8tdmFsdWUgd2hlbiBib3Jyb3dpbmcsIGluIGJhc2lzIHBvaW50cyAoNTAwMCA9IDIwMCUgY29sbGF0ZXJhbCkAAAAAAAttYXhfbHR2X2JwcwAAAAAEAAAAJlJlZmxlY3RvciBhc3NldCB0aGUgdG9rZW4gaXMgcHJpY2VkIGFzAAAAAAAMb3JhY2xlX2Fzc2V0AAAH0AAAAAVBc3NldAAAAA==",
+        "AAAAAQAAABpUb2tlbiB0aGF0IG9mZmVycyBtYXkgbGVuZAAAAAAAAAAAAA9Mb2FuQXNzZXRDb25maWcAAAAAAgAAAB9EZWNpbWFscyBvZiB0aGUgdG9rZW4ncyBhbW91bnRzAAAAAAhkZWNpbWFscwAAAAQAAAAmUmVmbGVjdG9yIGFzc2V0IHRoZSB0b2tlbiBpcyBwcmljZWQgYXMAAAAAAAxvcmFjbGVfYXNzZXQAAAfQAAAABUFzc2V0AAAA",
+        "AAAAAQAAAFJLaW5rZWQgdXRpbGl6YXRpb24gY3VydmUgZm9yIHRoZSB2YXJpYWJsZS1yYXRlIHBvb2wgKHdlZWtseSByYXRlcyBpbiBiYXNpcyBwb2ludHMpAAAAAAAAAAAACVJhdGVNb2RlbAAAAAAAAAQAAAAdQm9ycm93IHJhdGUgYXQgMCUgdXRpbGl6YXRpb24AAAAAAAAJYmFzZV9yYXRlAAAAAAAABAAAADFVdGlsaXphdGlvbiBpbiBiYXNpcyBwb2ludHMgd2hlcmUgdGhlIGN1cnZlIGtpbmtzAAAAAAAAE29wdGltYWxfdXRpbGl6YXRpb24AAAAABAAAAC1SYXRlIGFkZGVkIGJldHdlZW4gMCUgYW5kIG9wdGltYWwgdXRpbGl6YXRpb24AAAAAAAAGc2xvcGUxAAAAAAAEAAAAL1JhdGUgYWRkZWQgYmV0d2VlbiBvcHRpbWFsIGFuZCAxMDAlIHV0aWxpemF0aW9uAAAAAAZzbG9wZTIAAAAAAAQ=",
+        "AAAAAQAAADRBY2NvdW50aW5nIGZvciB0aGUgcG9vbGVkLCB2YXJpYWJsZS1yYXRlIFVTREMgbWFya2V0AAAAAAAAAAlQb29sU3RhdGUAAAAAAAAFAAAAMkN1bXVsYXRpdmUgYm9ycm93IGludGVyZXN0IGZhY3RvciB3aXRoIDE4IGRlY2ltYWxzAAAAAAAMYm9ycm93X2luZGV4AAAACwAAADlVU0RDIGhlbGQgYnkgdGhlIHBvb2wgYW5kIGF2YWlsYWJsZSB0byBib3Jyb3cgb3Igd2l0aGRyYXcAAAAAAAAEY2FzaAAAAAsAAAAjTGFzdCB0aW1lIHBvb2wgaW50ZXJlc3Qgd2FzIGFjY3J1ZWQAAAAAC2xhc3RfdXBkYXRlAAAAAAYAAAA9UHJvdG9jb2wgc2hhcmUgb2YgcG9vbCBpbnRlcmVzdCBub3QgeWV0IG1vdmVkIHRvIHRoZSB0cmVhc3VyeQAAAAAAAAhyZXNlcnZlcwAAAAsAAABCU3VtIG9mIGJvcnJvd2Vycycgc2NhbGVkIGRlYnQgKGRlYnQgPSBzY2FsZWQgZGVidCDDlyBib3Jyb3cgaW5kZXgpAAAAAAARdG90YWxfc2NhbGVkX2RlYnQAAAAAAAAL",
+        "AAAAAQAAACZWYXJpYWJsZS1yYXRlIGxvYW4gZHJhd24gZnJvbSB0aGUgcG9vbAAAAAAAAAAAAAhQb29sTG9hbgAAAAcAAAAXQWRkcmVzcyBvZiB0aGUgYm9ycm93ZXIAAAAACGJvcnJvd2VyAAAAEwAAAENBbW91bnQgb2YgY29sbGF0ZXJhbCBkZXBvc2l0ZWQgKGluIHRoZSBjb2xsYXRlcmFsIHRva2VuJ3MgZGVjaW1hbHMpAAAAABFjb2xsYXRlcmFsX2Ftb3VudAAAAAAAAAsAAAAaVG9rZW4gcG9zdGVkIGFzIGNvbGxhdGVyYWwAAAAAABBjb2xsYXRlcmFsX3Rva2VuAAAAEwAAABtXaGV0aGVyIHRoaXMgbG9hbiBpcyBhY3RpdmUAAAAACWlzX2FjdGl2ZQAAAAAAAAEAAAAkVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoaXMgcG9vbCBsb2FuAAAAB2xvYW5faWQAAAAABgAAAEFEZWJ0IGRpdmlkZWQgYnkgdGhlIGJvcnJvdyBpbmRleCB3aGVuIGl0IHdhcyB0YWtlbiBvciBsYXN0IHJlcGFpZAAAAAAAAAtzY2FsZWRfZGVidAAAAAALAAAAH1RpbWVzdGFtcCB3aGVuIGxvYW4gd2FzIGNyZWF0ZWQAAAAACnN0YXJ0X3RpbWUAAAAAAAY=",
+        "AAAAAQAAACdDdXJyZW50IHJhdGVzIG9mIHRoZSB2YXJpYWJsZS1yYXRlIHBvb2wAAAAAAAAAAAlQb29sUmF0ZXMAAAAAAAADAAAAIldlZWtseSBib3Jyb3cgcmF0ZSBpbiBiYXNpcyBwb2ludHMAAAAAAAtib3Jyb3dfcmF0ZQAAAAAEAAAALVdlZWtseSByYXRlIGVhcm5lZCBieSBsZW5kZXJzIGluIGJhc2lzIHBvaW50cwAAAAAAAAtzdXBwbHlfcmF0ZQAAAAAEAAAALVNoYXJlIG9mIHBvb2wgYXNzZXRzIGxlbnQgb3V0IGluIGJhc2lzIHBvaW50cwAAAAAAAAt1dGlsaXphdGlvbgAAAAAE",
+        "AAAAAgAAAB1TdG9yYWdlIGtleXMgZm9yIHRoZSBjb250cmFjdAAAAAAAAAAAAAAHRGF0YUtleQAAAAA+AAAAAAAAACVVU0RDIHRva2VuIGFkZHJlc3MgKHRoZSBwb29sJ3MgYXNzZXQpAAAAAAAACVVzZGNUb2tlbgAAAAAAAAAAAAAWVG9rZW5zIG9mZmVycyBtYXkgbGVuZAAAAAAACkxvYW5Bc3NldHMAAAAAAAEAAAAjUHJpY2luZyBkZXRhaWxzIG9mIGEgbGVuZGFibGUgdG9rZW4AAAAACUxvYW5Bc3NldAAAAAAAAAEAAAATAAAAAAAAAB9Ub2tlbnMgcmVnaXN0ZXJlZCBhcyBjb2xsYXRlcmFsAAAAABBDb2xsYXRlcmFsQXNzZXRzAAAAAQAAACVSaXNrIHBhcmFtZXRlcnMgb2YgYSBjb2xsYXRlcmFsIHRva2VuAAAAAAAAEENvbGxhdGVyYWxDb25maWcAAAABAAAAEwAAAAAAAAAhUmVmbGVjdG9yIG9yYWNsZSBjb250cmFjdCBhZGRyZXNzAAAAAAAADU9yYWNsZUFkZHJlc3MAAAAAAAAAAAAAMVNlY29uZGFyeSBvcmFjbGUgY29uc3VsdGVkIHdoZW4gdGhlIHByaW1hcnkgZmFpbHMAAAAAAAAORmFsbGJhY2tPcmFjbGUAAAAAAAAAAAAoT3JhY2xlIHRoYXQgc2VydmVkIHRoZSBtb3N0IHJlY2VudCBwcmljZQAAAAxPcmFjbGVTb3VyY2UAAAAAAAAAPE1heGltdW0gZGlzYWdyZWVtZW50IGJldHdlZW4gdGhlIHR3byBvcmFjbGVzIGluIGJhc2lzIHBvaW50cwAAABJNYXhPcmFjbGVEZXZpYXRpb24AAAAAAAAAAAAvTnVtYmVyIG9mIG9yYWNsZSByZWNvcmRzIGF2ZXJhZ2VkIGludG8gdGhlIFRXQVAAAAAAC1R3YXBSZWNvcmRzAAAAAAAAAAAxREVYIHJvdXRlciB1c2VkIHRvIHNlbGwgY29sbGF0ZXJhbCBvbiBsaXF1aWRhdGlvbgAAAAAAAAlEZXhSb3V0ZXIAAAAAAAAAAAAAPExvYW4gcG9zaXRpb24gTkZUIGNvbnRyYWN0IHdob3NlIGhvbGRlcnMgYXJlIHBhaWQgYXMgbGVuZGVycwAAAAtQb3NpdGlvbk5mdAAAAAAAAAAATkZpcnN0IGxvYW4gSUQgbWludGVkIGEgcG9zaXRpb24gdG9rZW47IGVhcmxpZXIgbG9hbnMgcGF5IHRoZWlyIG9yaWdpbmFsIGxlbmRlcgAAAAAAFFBvc2l0aW9uTmZ0Rmlyc3RMb2FuAAAAAAAAAD5NYXhpbXVtIHNsaXBwYWdlIGFjY2VwdGVkIG9uIGxpcXVpZGF0aW9uIHN3YXBzIGluIGJhc2lzIHBvaW50cwAAAAAAC01heFNsaXBwYWdlAAAAAAAAAABITWF4aW11bSBzaGFyZSBvZiBhIGxvYW4ncyBkZWJ0IHJlcGFpZCBieSBvbmUgbGlxdWlkYXRpb24gaW4gYmFzaXMgcG9pbnRzAAAAC0Nsb3NlRmFjdG9yAAAAAAAAAAAcRHV0Y2ggYXVjdGlvbiBkaXNjb3VudCBjdXJ2ZQAAAA1BdWN0aW9uQ29uZmlnAAAAAAAAAAAAABpQcm90b2NvbCBmZWUgY29uZmlndXJhdGlvbgAAAAAACUZlZUNvbmZpZwAAAAAAAAAAAAAXTWFya2V0LXdpZGUgcmlzayBsaW1pdHMAAAAAClJpc2tDb25maWcAAAAAAAEAAAA3VW5jbGFpbWVkIHByb3RvY29sIGZlZXMgaGVsZCBieSB0aGUgY29udHJhY3QgaW4gYSB0b2tlbgAAAAAMUHJvdG9jb2xGZWVzAAAAAQAAABMAAAAAAAAAJFZhcmlhYmxlLXJhdGUgcG9vbCB1dGlsaXphdGlvbiBjdXJ2ZQAAAAlSYXRlTW9kZWwAAAAAAAAAAAAAHVZhcmlhYmxlLXJhdGUgcG9vbCBhY2NvdW50aW5nAAAAAAAACVBvb2xTdGF0ZQAAAAAAAAAAAAAZTmV4dCBwb29sIGxvYW4gSUQgY291bnRlcgAAAAAAAA5OZXh0UG9vbExvYW5JZAAAAAAAAAAAABVOZXh0IG9mZmVyIElEIGNvdW50ZXIAAAAAAAALTmV4dE9mZmVySWQAAAAAAAAAABROZXh0IGxvYW4gSUQgY291bnRlcgAAAApOZXh0TG9hbklkAAAAAAAAAAAAHk5leHQgYm9ycm93IHJlcXVlc3QgSUQgY291bnRlcgAAAAAADU5leHRSZXF1ZXN0SWQAAAAAAAAAAAAAH05leHQgdGltZWxvY2sgYWN0aW9uIElEIGNvdW50ZXIAAAAADE5leHRBY3Rpb25JZAAAAAAAAAA7U2Vjb25kcyBiZXR3ZWVuIHF1ZXVlaW5nIGEgdGltZWxvY2tlZCBhY3Rpb24gYW5kIHJ1bm5pbmcgaXQAAAAADVRpbWVsb2NrRGVsYXkAAAAAAAAAAAAAMElEcyBvZiB0aGUgYWN0aW9ucyB3YWl0aW5nIGluIHRoZSB0aW1lbG9jayBxdWV1ZQAAAA1RdWV1ZWRBY3Rpb25zAAAAAAAAAQAAABdUaW1lbG9ja2VkIGFjdGlvbiBieSBJRAAAAAAMUXVldWVkQWN0aW9uAAAAAQAAAAYAAAAAAAAAQE1heGltdW0gYWxsb3dlZCBpbnRlcmVzdCByYXRlIGluIGJhc2lzIHBvaW50cyAoZS5nLiwgMzAwMCA9IDMwJSkAAAAPTWF4SW50ZXJlc3RSYXRlAAAAAAAAAAAZUGVyLW9wZXJhdGlvbiBwYXVzZSBmbGFncwAAAAAAAApQYXVzZVN0YXRlAAAAAAAAAAAAD1JlZW50cmFuY3kgbG9jawAAAAAGTG9ja2VkAAAAAAAAAAAAElN0b3JhZ2UgVFRMIHBvbGljeQAAAAAACVR0bENvbmZpZwAAAAAAAAAAAAAtTGF5b3V0IHZlcnNpb24gb2YgdGhlIHN0b3JlZCBsb2FucyBhbmQgb2ZmZXJzAAAAAAAADVNjaGVtYVZlcnNpb24AAAAAAAAAAAAAQUxhc3QgbG9hbiBhbmQgb2ZmZXIgSURzIHJld3JpdHRlbiBieSBhbiB1bmZpbmlzaGVkIG1pZ3JhdGlvbiBzdGVwAAAAAAAAD01pZ3JhdGlvbkN1cnNvcgAAAAABAAAAFkluZGl2aWR1YWwgb2ZmZXIgYnkgSUQAAAAAAAVPZmZlcgAAAAAAAAEAAAAGAAAAAQAAABVJbmRpdmlkdWFsIGxvYW4gYnkgSUQAAAAAAAAETG9hbgAAAAEAAAAGAAAAAQAAABxMaXN0IG9mIG9mZmVyIElEcyBmb3IgYSB1c2VyAAAAClVzZXJPZmZlcnMAAAAAAAEAAAATAAAAAQAAACxMaXN0IG9mIG9wZW4gbG9hbiBJRHMgd2hlcmUgdXNlciBpcyBib3Jyb3dlcgAAABNVc2VyTG9hbnNBc0JvcnJvd2VyAAAAAAEAAAATAAAAAQAAADpMaXN0IG9mIGxvYW4gSURzIGEgdXNlciBmdW5kZWQgdGhhdCBoYXZlIG5vIHBvc2l0aW9uIHRva2VuAAAAAAARVXNlckxvYW5zQXNMZW5kZXIAAAAAAAABAAAAEwAAAAEAAAAzTGlzdCBvZiBvdXRzdGFuZGluZyBsb2FuIElEcyB0YWtlbiBhZ2FpbnN0IGFuIG9mZmVyAAAAAApPZmZlckxvYW5zAAAAAAABAAAABgAAAAAAAAAaTnVtYmVyIG9mIGFjdGl2ZSBvZmZlciBJRHMAAAAAAA9BY3RpdmVPZmZlcnNMZW4AAAAAAQAAABhQYWdlIG9mIGFjdGl2ZSBvZmZlciBJRHMAAAAQQWN0aXZlT2ZmZXJzUGFnZQAAAAEAAAAEAAAAAQAAADFQb3NpdGlvbiBvZiBhbiBvZmZlciBJRCBpbiB0aGUgYWN0aXZlIG9mZmVyIGluZGV4AAAAAAAAE0FjdGl2ZU9mZmVyUG9zaXRpb24AAAAAAQAAAAYAAAAAAAAAGU51bWJlciBvZiBhY3RpdmUgbG9hbiBJRHMAAAAAAAAOQWN0aXZlTG9hbnNMZW4AAAAAAAEAAAAXUGFnZSBvZiBhY3RpdmUgbG9hbiBJRHMAAAAAD0FjdGl2ZUxvYW5zUGFnZQAAAAABAAAABAAAAAEAAAAuUG9zaXRpb24gb2YgYSBsb2FuIElEIGluIHRoZSBhY3RpdmUgbG9hbiBpbmRleAAAAAAAEkFjdGl2ZUxvYW5Qb3NpdGlvbgAAAAAAAQAAAAYAAAABAAAAIER1dGNoIGF1Y3Rpb24gcnVubmluZyBmb3IgYSBsb2FuAAAAB0F1Y3Rpb24AAAAAAQAAAAYAAAABAAAAGkluZGl2aWR1YWwgcG9vbCBsb2FuIGJ5IElEAAAAAAAIUG9vbExvYW4AAAABAAAABgAAAAEAAAAlTGlzdCBvZiBvcGVuIHBvb2wgbG9hbiBJRHMgZm9yIGEgdXNlcgAAAAAAAA1Vc2VyUG9vbExvYW5zAAAAAAAAAQAAABMAAAAAAAAAHk51bWJlciBvZiBhY3RpdmUgcG9vbCBsb2FuIElEcwAAAAAAEkFjdGl2ZVBvb2xMb2Fuc0xlbgAAAAAAAQAAABxQYWdlIG9mIGFjdGl2ZSBwb29sIGxvYW4gSURzAAAAE0FjdGl2ZVBvb2xMb2Fuc1BhZ2UAAAAAAQAAAAQAAAABAAAAOFBvc2l0aW9uIG9mIGEgcG9vbCBsb2FuIElEIGluIHRoZSBhY3RpdmUgcG9vbCBsb2FuIGluZGV4AAAAFkFjdGl2ZVBvb2xMb2FuUG9zaXRpb24AAAAAAAEAAAAGAAAAAQAAAB9JbmRpdmlkdWFsIGJvcnJvdyByZXF1ZXN0IGJ5IElEAAAAAA1Cb3Jyb3dSZXF1ZXN0AAAAAAAAAQAAAAYAAAABAAAAKkxpc3Qgb2Ygb3BlbiBib3Jyb3cgcmVxdWVzdCBJRHMgZm9yIGEgdXNlcgAAAAAAElVzZXJCb3Jyb3dSZXF1ZXN0cwAAAAAAAQAAABMAAAAAAAAAI051bWJlciBvZiBhY3RpdmUgYm9ycm93IHJlcXVlc3QgSURzAAAAABFBY3RpdmVSZXF1ZXN0c0xlbgAAAAAAAAEAAAAhUGFnZSBvZiBhY3RpdmUgYm9ycm93IHJlcXVlc3QgSURzAAAAAAAAEkFjdGl2ZVJlcXVlc3RzUGFnZQAAAAAAAQAAAAQAAAABAAAAO1Bvc2l0aW9uIG9mIGEgYm9ycm93IHJlcXVlc3QgSUQgaW4gdGhlIGFjdGl2ZSByZXF1ZXN0IGluZGV4AAAAABVBY3RpdmVSZXF1ZXN0UG9zaXRpb24AAAAAAAABAAAABgAAAAEAAABLV2Vla2x5IHJhdGVzIGF0IHdoaWNoIGEgbG9hbiB0b2tlbiBoYXMgb2ZmZXJzIHdpdGggZnVuZHMgbGVmdCwgbG93ZXN0IGZpcnN0AAAAAApPZmZlclJhdGVzAAAAAAABAAAAEwAAAAEAAABATnVtYmVyIG9mIGEgbG9hbiB0b2tlbidzIG9mZmVycyB3aXRoIGZ1bmRzIGxlZnQgYXQgYSB3ZWVrbHkgcmF0ZQAAAA9PZmZlcnNBdFJhdGVMZW4AAAAAAgAAABMAAAAEAAAAAQAAAElQYWdlIG9mIHRoZSBJRHMgb2YgYSBsb2FuIHRva2VuJ3Mgb2ZmZXJzIHdpdGggZnVuZHMgbGVmdCBhdCBhIHdlZWtseSByYXRlAAAAAAAAEE9mZmVyc0F0UmF0ZVBhZ2UAAAADAAAAEwAAAAQAAAAEAAAAAQAAADBQb3NpdGlvbiBvZiBhbiBvZmZlciBJRCBpbiB0aGUgaW5kZXggb2YgaXRzIHJhdGUAAAART2ZmZXJSYXRlUG9zaXRpb24AAAAAAAABAAAABg==",
         "AAAAAQAAABZQcmljZSBkYXRhIGZyb20gb3JhY2xlAAAAAAAAAAAACVByaWNlRGF0YQAAAAAAAAIAAAALUHJpY2UgdmFsdWUAAAAABXByaWNlAAAAAAAACwAAABZUaW1lc3RhbXAgb2YgdGhlIHByaWNlAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAB5UZXJtcyBvZiBhIG5ld2x5IGNyZWF0ZWQgb2ZmZXIAAAAAAAAAAAAQT2ZmZXJDcmVhdGVkRGF0YQAAAAgAAAAwQW1vdW50IG9mIHRoZSBsb2FuIHRva2VuIGVzY3Jvd2VkIGludG8gdGhlIG9mZmVyAAAABmFtb3VudAAAAAAACwAAACdDb2xsYXRlcmFsIHRva2VucyBhY2NlcHRlZCBieSB0aGUgb2ZmZXIAAAAAEWNvbGxhdGVyYWxfYXNzZXRzAAAAAAAD6gAAABMAAAA1SG93IGludGVyZXN0IGFjY3J1ZXMgb24gbG9hbnMgdGFrZW4gYWdhaW5zdCB0aGUgb2ZmZXIAAAAAAAANaW50ZXJlc3RfbW9kZQAAAAAAB9AAAAAMSW50ZXJlc3RNb2RlAAAAJUxpcXVpZGF0aW9uIHRocmVzaG9sZCBpbiBiYXNpcyBwb2ludHMAAAAAAAAVbGlxdWlkYXRpb25fdGhyZXNob2xkAAAAAAAABAAAABBUb2tlbiBiZWluZyBsZW50AAAACmxvYW5fdG9rZW4AAAAAABMAAAAeTWF4aW11bSBsb2FuIGR1cmF0aW9uIGluIHdlZWtzAAAAAAASbWF4X2R1cmF0aW9uX3dlZWtzAAAAAAAEAAAAKE1pbmltdW0gY29sbGF0ZXJhbCByYXRpbyBpbiBiYXNpcyBwb2ludHMAAAAUbWluX2NvbGxhdGVyYWxfcmF0aW8AAAAEAAAAJFdlZWtseSBpbnRlcmVzdCByYXRlIGluIGJhc2lzIHBvaW50cwAAABR3ZWVrbHlfaW50ZXJlc3RfcmF0ZQAAAAQ=",
+        "AAAAAQAAADRMb2FuIHRva2VucyBtb3ZlZCBvdXQgb2YgYW4gb2ZmZXIgYmFjayB0byBpdHMgbGVuZGVyAAAAAAAAAA5PZmZlckZ1bmRzRGF0YQAAAAAAAgAAAB1BbW91bnQgcmV0dXJuZWQgdG8gdGhlIGxlbmRlcgAAAAAAAAZhbW91bnQAAAAAAAsAAAAuQW1vdW50IHN0aWxsIGF2YWlsYWJsZSBpbiB0aGUgb2ZmZXIgYWZ0ZXJ3YXJkcwAAAAAACXJlbWFpbmluZwAAAAAAAAs=",
+        "AAAAAQAAABxUZXJtcyBvZiBhIG5ld2x5IG9wZW5lZCBsb2FuAAAAAAAAAA5Mb2FuT3BlbmVkRGF0YQAAAAAABwAAACFBbW91bnQgb2YgdGhlIGxvYW4gdG9rZW4gYm9ycm93ZWQAAAAAAAAPYm9ycm93ZWRfYW1vdW50AAAAAAsAAAAeQW1vdW50IG9mIGNvbGxhdGVyYWwgZGVwb3NpdGVkAAAAAAARY29sbGF0ZXJhbF9hbW91bnQAAAAAAAALAAAAGlRva2VuIHBvc3RlZCBhcyBjb2xsYXRlcmFsAAAAAAAQY29sbGF0ZXJhbF90b2tlbgAAABMAAAAsVGltZXN0YW1wIGFmdGVyIHdoaWNoIHRoZSBsb2FuIGlzIGluIGRlZmF1bHQAAAAIZHVlX3RpbWUAAAAGAAAAJFdlZWtseSBpbnRlcmVzdCByYXRlIGluIGJhc2lzIHBvaW50cwAAAA1pbnRlcmVzdF9yYXRlAAAAAAAABAAAABVBZGRyZXNzIG9mIHRoZSBsZW5kZXIAAAAAAAAGbGVuZGVyAAAAAAATAAAADlRva2VuIGJvcnJvd2VkAAAAAAAKbG9hbl90b2tlbgAAAAAAEw==",
+        "AAAAAQAAABhCcmVha2Rvd24gb2YgYSByZXBheW1lbnQAAAAAAAAADkxvYW5SZXBhaWREYXRhAAAAAAAGAAAAIVRvdGFsIGFtb3VudCBwYWlkIGJ5IHRoZSBib3Jyb3dlcgAAAAAAAAZhbW91bnQAAAAAAAsAAABKQ29sbGF0ZXJhbCByZWxlYXNlZCBiYWNrIHRvIHRoZSBib3Jyb3dlciAobm9uLXplcm8gb25seSBvbiBmdWxsIHJlcGF5bWVudCkAAAAAABNjb2xsYXRlcmFsX3JldHVybmVkAAAAAAsAAAAqUG9ydGlvbiBvZiB0aGUgcGF5bWVudCBhcHBsaWVkIHRvIGludGVyZXN0AAAAAAANaW50ZXJlc3RfcGFpZAAAAAAAAAsAAAArUG9ydGlvbiBvZiB0aGUgcGF5bWVudCBhcHBsaWVkIHRvIHByaW5jaXBhbAAAAAAOcHJpbmNpcGFsX3BhaWQAAAAAAAsAAAAzU2hhcmUgb2YgdGhlIGludGVyZXN0IGtlcHQgYnkgdGhlIHByb3RvY29sIHRyZWFzdXJ5AAAAAAxwcm90b2NvbF9mZWUAAAALAAAAG1ByaW5jaXBhbCBzdGlsbCBvdXRzdGFuZGluZwAAAAATcmVtYWluaW5nX3ByaW5jaXBhbAAAAAAL",
+        "AAAAAQAAACZDb2xsYXRlcmFsIG1vdmVkIGludG8gb3Igb3V0IG9mIGEgbG9hbgAAAAAAAAAAAA5Db2xsYXRlcmFsRGF0YQAAAAAAAgAAABpBbW91bnQgb2YgY29sbGF0ZXJhbCBtb3ZlZAAAAAAABmFtb3VudAAAAAAACwAAACZDb2xsYXRlcmFsIGhlbGQgYnkgdGhlIGxvYW4gYWZ0ZXJ3YXJkcwAAAAAAEWNvbGxhdGVyYWxfYW1vdW50AAAAAAAACw==",
+        "AAAAAQAAACtPdXRjb21lIG9mIGEgKHBvc3NpYmx5IHBhcnRpYWwpIGxpcXVpZGF0aW9uAAAAAAAAAAAPTGlxdWlkYXRpb25EYXRhAAAAAAoAAAArRXhjZXNzIGxvYW4gdG9rZW5zIHJldHVybmVkIHRvIHRoZSBib3Jyb3dlcgAAAAAPYm9ycm93ZXJfcmVmdW5kAAAAAAsAAABPVW5zb2xkIGNvbGxhdGVyYWwgcmV0dXJuZWQgdG8gdGhlIGJvcnJvd2VyIChub24temVybyBvbmx5IHdoZW4gdGhlIGxvYW4gY2xvc2VzKQAAAAATY29sbGF0ZXJhbF9yZXR1cm5lZAAAAAALAAAAGkNvbGxhdGVyYWwgc29sZCBvbiB0aGUgREVYAAAAAAARY29sbGF0ZXJhbF9zZWl6ZWQAAAAAAAALAAAASURlYnQgcmVwYWlkIHRvIHRoZSBsZW5kZXIgKHByaW5jaXBhbCArIGludGVyZXN0LCBiZWZvcmUgdGhlIHByb3RvY29sIGZlZSkAAAAAAAALZGVidF9yZXBhaWQAAAAACwAAACxQb3J0aW9uIG9mIHRoZSByZXBhaWQgZGVidCB0aGF0IHdhcyBpbnRlcmVzdAAAAA1pbnRlcmVzdF9wYWlkAAAAAAAACwAAADBCb251cyBwYWlkIHRvIHRoZSBsaXF1aWRhdG9yIChpbiB0aGUgbG9hbiB0b2tlbikAAAAQbGlxdWlkYXRvcl9ib251cwAAAAsAAAAtUG9ydGlvbiBvZiB0aGUgcmVwYWlkIGRlYnQgdGhhdCB3YXMgcHJpbmNpcGFsAAAAAAAADnByaW5jaXBhbF9wYWlkAAAAAAALAAAAO0ludGVyZXN0IGFuZCBsaXF1aWRhdGlvbiBmZWVzIGtlcHQgYnkgdGhlIHByb3RvY29sIHRyZWFzdXJ5AAAAAAxwcm90b2NvbF9mZWUAAAALAAAASURlYnQgbGVmdCBvbiB0aGUgbG9hbiAod3JpdHRlbiBvZmYgaWYgdGhlIGxvYW4gY2xvc2VkIHdpdGhvdXQgY29sbGF0ZXJhbCkAAAAAAAAOcmVtYWluaW5nX2RlYnQAAAAAAAsAAAAiTG9hbiB0b2tlbnMgcmVjZWl2ZWQgZnJvbSB0aGUgc3dhcAAAAAAADXN3YXBfcHJvY2VlZHMAAAAAAAAL",
+        "AAAAAQAAADxDb2xsYXRlcmFsIGNsYWltZWQgYnkgYSBsZW5kZXIgZnJvbSBhIGxvYW4gcGFzdCBpdHMgZHVlIHRpbWUAAAAAAAAAEERlZmF1bHRDbGFpbURhdGEAAAACAAAAJENvbGxhdGVyYWwgdHJhbnNmZXJyZWQgdG8gdGhlIGxlbmRlcgAAABFjb2xsYXRlcmFsX3NlaXplZAAAAAAAAAsAAABARGVidCBvdXRzdGFuZGluZyBhdCB0aGUgdGltZSBvZiB0aGUgY2xhaW0gKHByaW5jaXBhbCArIGludGVyZXN0KQAAABBkZWJ0X291dHN0YW5kaW5nAAAACw==",
+        "AAAAAQAAABtGaWxsIG9mIGEgRHV0Y2ggYXVjdGlvbiBiaWQAAAAAAAAAAA5BdWN0aW9uQmlkRGF0YQAAAAAACAAAAEVMb2FuIHRva2VucyBwYWlkIGJ5IHRoZSBiaWRkZXIgKHRvIHRoZSBsZW5kZXIsIGxlc3MgdGhlIHByb3RvY29sIGZlZSkAAAAAAAALYW1vdW50X3BhaWQAAAAACwAAAE9VbnNvbGQgY29sbGF0ZXJhbCByZXR1cm5lZCB0byB0aGUgYm9ycm93ZXIgKG5vbi16ZXJvIG9ubHkgd2hlbiB0aGUgbG9hbiBjbG9zZXMpAAAAABNjb2xsYXRlcmFsX3JldHVybmVkAAAAAAsAAAAkQ29sbGF0ZXJhbCB0cmFuc2ZlcnJlZCB0byB0aGUgYmlkZGVyAAAAD2NvbGxhdGVyYWxfc29sZAAAAAALAAAAQkRpc2NvdW50IGJlbG93IHRoZSBvcmFjbGUgcHJpY2UgdGhlIGJpZCBmaWxsZWQgYXQsIGluIGJhc2lzIHBvaW50cwAAAAAADGRpc2NvdW50X2JwcwAAAAQAAAAqUG9ydGlvbiBvZiB0aGUgcGF5bWVudCBhcHBsaWVkIHRvIGludGVyZXN0AAAAAAANaW50ZXJlc3RfcGFpZAAAAAAAAAsAAAArUG9ydGlvbiBvZiB0aGUgcGF5bWVudCBhcHBsaWVkIHRvIHByaW5jaXBhbAAAAAAOcHJpbmNpcGFsX3BhaWQAAAAAAAsAAAAzU2hhcmUgb2YgdGhlIGludGVyZXN0IGtlcHQgYnkgdGhlIHByb3RvY29sIHRyZWFzdXJ5AAAAAAxwcm90b2NvbF9mZWUAAAALAAAASURlYnQgbGVmdCBvbiB0aGUgbG9hbiAod3JpdHRlbiBvZmYgaWYgdGhlIGxvYW4gY2xvc2VkIHdpdGhvdXQgY29sbGF0ZXJhbCkAAAAAAAAOcmVtYWluaW5nX2RlYnQAAAAAAAs=",
+        "AAAAAQAAAC5VU0RDIG1vdmVkIGludG8gb3Igb3V0IG9mIHRoZSBwb29sIGJ5IGEgbGVuZGVyAAAAAAAAAAAADlBvb2xTaGFyZXNEYXRhAAAAAAACAAAAGlVTREMgZGVwb3NpdGVkIG9yIHJlZGVlbWVkAAAAAAAGYW1vdW50AAAAAAALAAAAHFBvb2wgc2hhcmVzIG1pbnRlZCBvciBidXJuZWQAAAAGc2hhcmVzAAAAAAAL",
+        "AAAAAQAAACFUZXJtcyBvZiBhIG5ld2x5IG9wZW5lZCBwb29sIGxvYW4AAAAAAAAAAAAAElBvb2xMb2FuT3BlbmVkRGF0YQAAAAAABAAAADNXZWVrbHkgYm9ycm93IHJhdGUgaW4gYmFzaXMgcG9pbnRzIGFmdGVyIHRoZSBib3Jyb3cAAAAAC2JvcnJvd19yYXRlAAAAAAQAAAAbVVNEQyBib3Jyb3dlZCBmcm9tIHRoZSBwb29sAAAAAA9ib3Jyb3dlZF9hbW91bnQAAAAACwAAABFDb2xsYXRlcmFsIGxvY2tlZAAAAAAAABFjb2xsYXRlcmFsX2Ftb3VudAAAAAAAAAsAAAAaVG9rZW4gcG9zdGVkIGFzIGNvbGxhdGVyYWwAAAAAABBjb2xsYXRlcmFsX3Rva2VuAAAAEw==",
+        "AAAAAQAAACBSZXBheW1lbnQgYXBwbGllZCB0byBhIHBvb2wgbG9hbgAAAAAAAAASUG9vbExvYW5SZXBhaWREYXRhAAAAAAADAAAAGVVTREMgcGFpZCBieSB0aGUgYm9ycm93ZXIAAAAAAAAGYW1vdW50AAAAAAALAAAASkNvbGxhdGVyYWwgcmVsZWFzZWQgYmFjayB0byB0aGUgYm9ycm93ZXIgKG5vbi16ZXJvIG9ubHkgb24gZnVsbCByZXBheW1lbnQpAAAAAAATY29sbGF0ZXJhbF9yZXR1cm5lZAAAAAALAAAAFkRlYnQgc3RpbGwgb3V0c3RhbmRpbmcAAAAAAA5yZW1haW5pbmdfZGVidAAAAAAACw==",
+        "AAAAAQAAADVPdXRjb21lIG9mIGEgKHBvc3NpYmx5IHBhcnRpYWwpIHBvb2wgbG9hbiBsaXF1aWRhdGlvbgAAAAAAAAAAAAATUG9vbExpcXVpZGF0aW9uRGF0YQAAAAAJAAAAPURlYnQgd3JpdHRlbiBvZmYgYWdhaW5zdCB0aGUgcG9vbCB3aGVuIHRoZSBjb2xsYXRlcmFsIHJhbiBvdXQAAAAAAAAIYmFkX2RlYnQAAAALAAAAJEV4Y2VzcyBVU0RDIHJldHVybmVkIHRvIHRoZSBib3Jyb3dlcgAAAA9ib3Jyb3dlcl9yZWZ1bmQAAAAACwAAAE9VbnNvbGQgY29sbGF0ZXJhbCByZXR1cm5lZCB0byB0aGUgYm9ycm93ZXIgKG5vbi16ZXJvIG9ubHkgd2hlbiB0aGUgbG9hbiBjbG9zZXMpAAAAABNjb2xsYXRlcmFsX3JldHVybmVkAAAAAAsAAAAaQ29sbGF0ZXJhbCBzb2xkIG9uIHRoZSBERVgAAAAAABFjb2xsYXRlcmFsX3NlaXplZAAAAAAAAAsAAAAXRGVidCByZXBhaWQgdG8gdGhlIHBvb2wAAAAAC2RlYnRfcmVwYWlkAAAAAAsAAAAmQm9udXMgcGFpZCB0byB0aGUgbGlxdWlkYXRvciAoaW4gVVNEQykAAAAAABBsaXF1aWRhdG9yX2JvbnVzAAAACwAAAC1MaXF1aWRhdGlvbiBmZWUga2VwdCBieSB0aGUgcHJvdG9jb2wgdHJlYXN1cnkAAAAAAAAMcHJvdG9jb2xfZmVlAAAACwAAABVEZWJ0IGxlZnQgb24gdGhlIGxvYW4AAAAAAAAOcmVtYWluaW5nX2RlYnQAAAAAAAsAAAAbVVNEQyByZWNlaXZlZCBmcm9tIHRoZSBzd2FwAAAAAA1zd2FwX3Byb2NlZWRzAAAAAAAACw==",
+        "AAAAAgAAABdRdW90ZWQgYXNzZXQgZGVmaW5pdGlvbgAAAAAAAAAABUFzc2V0AAAAAAAAAgAAAAEAAAAmRm9yIFN0ZWxsYXIgQ2xhc3NpYyBhbmQgU29yb2JhbiBhc3NldHMAAAAAAAdTdGVsbGFyAAAAAAEAAAATAAAAAQAAADFGb3IgYW55IGV4dGVybmFsIGN1cnJlbmNpZXMvdG9rZW5zL2Fzc2V0cy9zeW1ib2xzAAAAAAAABU90aGVyAAAAAAAAAQAAABE=",
+        "AAAABQAAAAAAAAAAAAAACkF1Y3Rpb25CaWQAAAAAAAEAAAALYXVjdGlvbl9iaWQAAAAAAwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAQAAAAAAAAAGYmlkZGVyAAAAAAATAAAAAQAAAAAAAAAEZGF0YQAAB9AAAAAOQXVjdGlvbkJpZERhdGEAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAACkxvYW5PcGVuZWQAAAAAAAEAAAALbG9hbl9vcGVuZWQAAAAABAAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAQAAAAAAAAAIb2ZmZXJfaWQAAAAGAAAAAQAAAAAAAAAIYm9ycm93ZXIAAAATAAAAAQAAAAAAAAAEZGF0YQAAB9AAAAAOTG9hbk9wZW5lZERhdGEAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAACkxvYW5SZXBhaWQAAAAAAAEAAAALbG9hbl9yZXBhaWQAAAAAAwAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAQAAAAAAAAAIYm9ycm93ZXIAAAATAAAAAQAAAAAAAAAEZGF0YQAAB9AAAAAOTG9hblJlcGFpZERhdGEAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAADEFjdGlvblF1ZXVlZAAAAAEAAAANYWN0aW9uX3F1ZXVlZAAAAAAAAAIAAAAAAAAACWFjdGlvbl9pZAAAAAAAAAYAAAABAAAAAAAAAAZxdWV1ZWQAAAAAB9AAAAAMUXVldWVkQWN0aW9uAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADEF1Y3Rpb25FbmRlZAAAAAEAAAANYXVjdGlvbl9lbmRlZAAAAAAAAAIAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAAAAAAACnN0YXJ0X3RpbWUAAAAAAAYAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAADE9mZmVyQ3JlYXRlZAAAAAEAAAANb2ZmZXJfY3JlYXRlZAAAAAAAAAMAAAAAAAAACG9mZmVyX2lkAAAABgAAAAEAAAAAAAAABmxlbmRlcgAAAAAAEwAAAAEAAAAAAAAABGRhdGEAAAfQAAAAEE9mZmVyQ3JlYXRlZERhdGEAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAADFBhdXNlVXBkYXRlZAAAAAEAAAANcGF1c2VfdXBkYXRlZAAAAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAABXN0YXRlAAAAAAAH0AAAAApQYXVzZVN0YXRlAAAAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAADFBvb2xSZWRlZW1lZAAAAAEAAAANcG9vbF9yZWRlZW1lZAAAAAAAAAIAAAAAAAAABmxlbmRlcgAAAAAAEwAAAAEAAAAAAAAABGRhdGEAAAfQAAAADlBvb2xTaGFyZXNEYXRhAAAAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAADUxvYW5EZWZhdWx0ZWQAAAAAAAABAAAADmxvYW5fZGVmYXVsdGVkAAAAAAADAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAAAAAAAAZsZW5kZXIAAAAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAABBEZWZhdWx0Q2xhaW1EYXRhAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADU9yYWNsZVVwZGF0ZWQAAAAAAAABAAAADm9yYWNsZV91cGRhdGVkAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAAZvcmFjbGUAAAAAABMAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAADVBvb2xEZXBvc2l0ZWQAAAAAAAABAAAADnBvb2xfZGVwb3NpdGVkAAAAAAACAAAAAAAAAAZsZW5kZXIAAAAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAAA5Qb29sU2hhcmVzRGF0YQAAAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADkFjdGlvbkV4ZWN1dGVkAAAAAAABAAAAD2FjdGlvbl9leGVjdXRlZAAAAAACAAAAAAAAAAlhY3Rpb25faWQAAAAAAAAGAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADkF1Y3Rpb25TdGFydGVkAAAAAAABAAAAD2F1Y3Rpb25fc3RhcnRlZAAAAAADAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAAAAAAAAlpbml0aWF0b3IAAAAAAAATAAAAAQAAAAAAAAAKc3RhcnRfdGltZQAAAAAABgAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAADkxvYW5MaXF1aWRhdGVkAAAAAAABAAAAD2xvYW5fbGlxdWlkYXRlZAAAAAADAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAAAAAAAApsaXF1aWRhdG9yAAAAAAATAAAAAQAAAAAAAAAEZGF0YQAAB9AAAAAPTGlxdWlkYXRpb25EYXRhAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAADk9mZmVyQ2FuY2VsbGVkAAAAAAABAAAAD29mZmVyX2NhbmNlbGxlZAAAAAADAAAAAAAAAAhvZmZlcl9pZAAAAAYAAAABAAAAAAAAAAZsZW5kZXIAAAAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAAA5PZmZlckZ1bmRzRGF0YQAAAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADk9mZmVyV2l0aGRyYXduAAAAAAABAAAAD29mZmVyX3dpdGhkcmF3bgAAAAADAAAAAAAAAAhvZmZlcl9pZAAAAAYAAAABAAAAAAAAAAZsZW5kZXIAAAAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAAA5PZmZlckZ1bmRzRGF0YQAAAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADk9yYWNsZVN3aXRjaGVkAAAAAAABAAAAD29yYWNsZV9zd2l0Y2hlZAAAAAACAAAAAAAAAAZvcmFjbGUAAAAAABMAAAABAAAAAAAAAAZzb3VyY2UAAAAAB9AAAAAMT3JhY2xlU291cmNlAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAADlBvb2xMb2FuT3BlbmVkAAAAAAABAAAAEHBvb2xfbG9hbl9vcGVuZWQAAAADAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAAAAAAAAhib3Jyb3dlcgAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAABJQb29sTG9hbk9wZW5lZERhdGEAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAADlBvb2xMb2FuUmVwYWlkAAAAAAABAAAAEHBvb2xfbG9hbl9yZXBhaWQAAAADAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAAAAAAAAhib3Jyb3dlcgAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAABJQb29sTG9hblJlcGFpZERhdGEAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAADlBvc2l0aW9uTmZ0U2V0AAAAAAABAAAAEHBvc2l0aW9uX25mdF9zZXQAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAANuZnQAAAAAEwAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAADlNjaGVtYU1pZ3JhdGVkAAAAAAABAAAAD3NjaGVtYV9taWdyYXRlZAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAD0FjdGlvbkNhbmNlbGxlZAAAAAABAAAAEGFjdGlvbl9jYW5jZWxsZWQAAAACAAAAAAAAAAlhY3Rpb25faWQAAAAAAAAGAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAD0NvbGxhdGVyYWxBZGRlZAAAAAABAAAAEGNvbGxhdGVyYWxfYWRkZWQAAAADAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAAAAAAAAhib3Jyb3dlcgAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAAA5Db2xsYXRlcmFsRGF0YQAAAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAEENvbnRyYWN0VXBncmFkZWQAAAABAAAAEWNvbnRyYWN0X3VwZ3JhZGVkAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAEERleFJvdXRlclVwZGF0ZWQAAAABAAAAEmRleF9yb3V0ZXJfdXBkYXRlZAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAGcm91dGVyAAAAAAATAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAEEZlZUNvbmZpZ1VwZGF0ZWQAAAABAAAAEmZlZV9jb25maWdfdXBkYXRlZAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAACUZlZUNvbmZpZwAAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAEExvYW5Bc3NldFVwZGF0ZWQAAAABAAAAEmxvYW5fYXNzZXRfdXBkYXRlZAAAAAAAAwAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAAD0xvYW5Bc3NldENvbmZpZwAAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAEFJhdGVNb2RlbFVwZGF0ZWQAAAABAAAAEnJhdGVfbW9kZWxfdXBkYXRlZAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAFbW9kZWwAAAAAAAfQAAAACVJhdGVNb2RlbAAAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAEFR0bENvbmZpZ1VwZGF0ZWQAAAABAAAAEnR0bF9jb25maWdfdXBkYXRlZAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAGY29uZmlnAAAAAAfQAAAACVR0bENvbmZpZwAAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAEVJpc2tDb25maWdVcGRhdGVkAAAAAAAAAQAAABNyaXNrX2NvbmZpZ191cGRhdGVkAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAABmNvbmZpZwAAAAAH0AAAAApSaXNrQ29uZmlnAAAAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAEkNsb3NlRmFjdG9yVXBkYXRlZAAAAAAAAQAAABRjbG9zZV9mYWN0b3JfdXBkYXRlZAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAAEGNsb3NlX2ZhY3Rvcl9icHMAAAAEAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAEk1heFNsaXBwYWdlVXBkYXRlZAAAAAAAAQAAABBzbGlwcGFnZV91cGRhdGVkAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAMc2xpcHBhZ2VfYnBzAAAABAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAElBvb2xMb2FuTGlxdWlkYXRlZAAAAAAAAQAAABRwb29sX2xvYW5fbGlxdWlkYXRlZAAAAAMAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAAAAAAACmxpcXVpZGF0b3IAAAAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAABNQb29sTGlxdWlkYXRpb25EYXRhAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAElR3YXBSZWNvcmRzVXBkYXRlZAAAAAAAAQAAABR0d2FwX3JlY29yZHNfdXBkYXRlZAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAAB3JlY29yZHMAAAAABAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAE0JvcnJvd1JlcXVlc3RGaWxsZWQAAAAAAQAAABVib3Jyb3dfcmVxdWVzdF9maWxsZWQAAAAAAAADAAAAAAAAAApyZXF1ZXN0X2lkAAAAAAAGAAAAAQAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAQAAAAAAAAAHbG9hbl9pZAAAAAAGAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAE0NvbGxhdGVyYWxXaXRoZHJhd24AAAAAAQAAABRjb2xsYXRlcmFsX3dpdGhkcmF3bgAAAAMAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAEAAAAAAAAABGRhdGEAAAfQAAAADkNvbGxhdGVyYWxEYXRhAAAAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAE1Bvb2xDb2xsYXRlcmFsQWRkZWQAAAAAAQAAABVwb29sX2NvbGxhdGVyYWxfYWRkZWQAAAAAAAADAAAAAAAAAAdsb2FuX2lkAAAAAAYAAAABAAAAAAAAAAhib3Jyb3dlcgAAABMAAAABAAAAAAAAAARkYXRhAAAH0AAAAA5Db2xsYXRlcmFsRGF0YQAAAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAE1Byb3RvY29sRmVlc0NsYWltZWQAAAAAAQAAABVwcm90b2NvbF9mZWVzX2NsYWltZWQAAAAAAAAEAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAABAAAAAAAAAAJ0bwAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAFEF1Y3Rpb25Db25maWdVcGRhdGVkAAAAAQAAABZhdWN0aW9uX2NvbmZpZ191cGRhdGVkAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAAZjb25maWcAAAAAB9AAAAANQXVjdGlvbkNvbmZpZwAAAAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAFEJvcnJvd1JlcXVlc3RDcmVhdGVkAAAAAQAAABZib3Jyb3dfcmVxdWVzdF9jcmVhdGVkAAAAAAADAAAAAAAAAApyZXF1ZXN0X2lkAAAAAAAGAAAAAQAAAAAAAAAIYm9ycm93ZXIAAAATAAAAAQAAAAAAAAAHcmVxdWVzdAAAAAfQAAAADUJvcnJvd1JlcXVlc3QAAAAAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAFFRpbWVsb2NrRGVsYXlVcGRhdGVkAAAAAQAAABZ0aW1lbG9ja19kZWxheV91cGRhdGVkAAAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAABAAAAAAAAAAVkZWxheQAAAAAAAAYAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAFUZhbGxiYWNrT3JhY2xlVXBkYXRlZAAAAAAAAAEAAAAXZmFsbGJhY2tfb3JhY2xlX3VwZGF0ZWQAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAGb3JhY2xlAAAAAAPoAAAAEwAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAFkJvcnJvd1JlcXVlc3RDYW5jZWxsZWQAAAAAAAEAAAAYYm9ycm93X3JlcXVlc3RfY2FuY2VsbGVkAAAAAwAAAAAAAAAKcmVxdWVzdF9pZAAAAAAABgAAAAEAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAEAAAAAAAAAE2NvbGxhdGVyYWxfcmV0dXJuZWQAAAAACwAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAFk1heEludGVyZXN0UmF0ZVVwZGF0ZWQAAAAAAAEAAAAQbWF4X3JhdGVfdXBkYXRlZAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAACG1heF9yYXRlAAAABAAAAAAAAAAA",
+        "AAAABQAAAAAAAAAAAAAAFk9yYWNsZURldmlhdGlvblVwZGF0ZWQAAAAAAAEAAAAYb3JhY2xlX2RldmlhdGlvbl91cGRhdGVkAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAANZGV2aWF0aW9uX2JwcwAAAAAAAAQAAAAAAAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAF0NvbGxhdGVyYWxDb25maWdVcGRhdGVkAAAAAAEAAAAZY29sbGF0ZXJhbF9jb25maWdfdXBkYXRlZAAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAEAAAAAAAAABmNvbmZpZwAAAAAH0AAAABBDb2xsYXRlcmFsQ29uZmlnAAAAAAAAAAA=",
+        "AAAABQAAAAAAAAAAAAAAF1Bvb2xDb2xsYXRlcmFsV2l0aGRyYXduAAAAAAEAAAAZcG9vbF9jb2xsYXRlcmFsX3dpdGhkcmF3bgAAAAAAAAMAAAAAAAAAB2xvYW5faWQAAAAABgAAAAEAAAAAAAAACGJvcnJvd2VyAAAAEwAAAAEAAAAAAAAABGRhdGEAAAfQAAAADkNvbGxhdGVyYWxEYXRhAAAAAAAAAAAAAA==",
       ]),
       options,
     );
@@ -265,26 +582,109 @@ export class Client extends ContractClient {
     cancel_offer: this.txFromJSON,
     withdraw_from_offer: this.txFromJSON,
     borrow: this.txFromJSON,
+    borrow_best: this.txFromJSON,
     repay: this.txFromJSON,
     add_collateral: this.txFromJSON,
     withdraw_collateral: this.txFromJSON,
+    create_borrow_request: this.txFromJSON,
+    cancel_borrow_request: this.txFromJSON,
+    fill_borrow_request: this.txFromJSON,
     is_liquidatable: this.txFromJSON,
     liquidate: this.txFromJSON,
+    start_auction: this.txFromJSON,
+    bid: this.txFromJSON,
+    claim_defaulted_loan: this.txFromJSON,
     batch_check_liquidations: this.txFromJSON,
+    deposit: this.txFromJSON,
+    redeem: this.txFromJSON,
+    pool_borrow: this.txFromJSON,
+    pool_repay: this.txFromJSON,
+    pool_add_collateral: this.txFromJSON,
+    pool_withdraw_collateral: this.txFromJSON,
+    pool_liquidate: this.txFromJSON,
+    get_pool_state: this.txFromJSON,
+    get_pool_rates: this.txFromJSON,
+    get_share_price: this.txFromJSON,
+    get_pool_balance: this.txFromJSON,
+    get_pool_loan: this.txFromJSON,
+    get_pool_loan_debt: this.txFromJSON,
+    get_user_pool_loans: this.txFromJSON,
+    get_active_pool_loans_page: this.txFromJSON,
+    get_active_pool_loan_count: this.txFromJSON,
+    get_pool_loan_health: this.txFromJSON,
+    bump_loan: this.txFromJSON,
+    bump_offer: this.txFromJSON,
+    bump_pool_loan: this.txFromJSON,
+    bump_borrow_request: this.txFromJSON,
     get_offer: this.txFromJSON,
     get_loan: this.txFromJSON,
+    get_borrow_request: this.txFromJSON,
+    get_auction: this.txFromJSON,
+    get_auction_discount: this.txFromJSON,
+    get_offer_apy: this.txFromJSON,
+    quote_borrow: this.txFromJSON,
     get_loan_health: this.txFromJSON,
     calculate_interest: this.txFromJSON,
+    get_collateral_price: this.txFromJSON,
     get_xlm_price: this.txFromJSON,
     get_user_offers: this.txFromJSON,
+    get_user_borrow_requests: this.txFromJSON,
     get_user_loans_as_borrower: this.txFromJSON,
     get_user_loans_as_lender: this.txFromJSON,
-    get_active_offers: this.txFromJSON,
-    get_active_loans: this.txFromJSON,
-    set_max_interest_rate: this.txFromJSON,
-    set_oracle_address: this.txFromJSON,
+    get_offer_loans: this.txFromJSON,
+    list_offers: this.txFromJSON,
+    get_active_borrow_requests_page: this.txFromJSON,
+    get_active_offers_page: this.txFromJSON,
+    get_active_loans_page: this.txFromJSON,
+    get_active_offer_count: this.txFromJSON,
+    get_active_loan_count: this.txFromJSON,
+    set_position_nft: this.txFromJSON,
+    get_collateral_config: this.txFromJSON,
+    get_collateral_assets: this.txFromJSON,
+    get_loan_asset: this.txFromJSON,
+    get_loan_assets: this.txFromJSON,
     pause_contract: this.txFromJSON,
     unpause_contract: this.txFromJSON,
+    set_pause_state: this.txFromJSON,
+    get_pause_state: this.txFromJSON,
+    set_ttl_config: this.txFromJSON,
+    get_ttl_config: this.txFromJSON,
+    get_dex_router: this.txFromJSON,
+    get_position_nft: this.txFromJSON,
+    get_max_slippage: this.txFromJSON,
+    get_fallback_oracle: this.txFromJSON,
+    get_oracle_source: this.txFromJSON,
+    get_max_oracle_deviation: this.txFromJSON,
+    get_twap_records: this.txFromJSON,
+    get_close_factor: this.txFromJSON,
+    get_auction_config: this.txFromJSON,
+    get_rate_model: this.txFromJSON,
+    get_fee_config: this.txFromJSON,
+    get_risk_config: this.txFromJSON,
+    claim_protocol_fees: this.txFromJSON,
+    get_protocol_fees: this.txFromJSON,
     admin: this.txFromJSON,
+    propose_admin: this.txFromJSON,
+    accept_admin: this.txFromJSON,
+    grant_role: this.txFromJSON,
+    revoke_role: this.txFromJSON,
+    has_role: this.txFromJSON,
+    queue_action: this.txFromJSON,
+    cancel_action: this.txFromJSON,
+    execute_action: this.txFromJSON,
+    get_queued_action: this.txFromJSON,
+    get_queued_actions: this.txFromJSON,
+    get_timelock_delay: this.txFromJSON,
+    migrate: this.txFromJSON,
+    get_schema_version: this.txFromJSON,
+    total_supply: this.txFromJSON,
+    balance: this.txFromJSON,
+    allowance: this.txFromJSON,
+    transfer: this.txFromJSON,
+    transfer_from: this.txFromJSON,
+    approve: this.txFromJSON,
+    decimals: this.txFromJSON,
+    name: this.txFromJSON,
+    symbol: this.txFromJSON,
   };
 }
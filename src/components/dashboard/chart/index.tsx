@@ -58,7 +58,7 @@ export default function DashboardChart() {
     }, 0);
 
     const totalLent = offers.reduce((sum, offer) => {
-      return sum + Number(offer.usdc_amount || BigInt(0)) / 1e7;
+      return sum + Number(offer.amount || BigInt(0)) / 1e7;
     }, 0);
 
     // Generate time-series data (simulated growth since we don't have historical data)
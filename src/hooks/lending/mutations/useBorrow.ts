@@ -2,11 +2,13 @@ import { useMutation, useQueryClient } from "@tanstack/react-query";
 import { useWallet } from "@/hooks/useWallet";
 import { useContractClients } from "../useContractClients";
 import { parseUsdc, parseXlm } from "@/lib/lending-utils";
+import nativeXlm from "@/contracts/native_xlm";
 
 export interface BorrowParams {
   offerId: string; // Offer ID to borrow from
   collateralXlm: string; // Human-readable XLM amount like "1000.00"
   borrowAmount: string; // Human-readable USDC amount like "100.00"
+  durationWeeks: number; // Loan term, at most the offer's max duration
 }
 
 /**
@@ -34,8 +36,10 @@ export function useBorrow() {
       const borrowTx = await lendingMarket.borrow({
         borrower: address,
         offer_id: offerId,
+        collateral_token: nativeXlm.options.contractId,
         collateral_amount: collateralAmount,
         borrow_amount: borrowAmount,
+        duration_weeks: params.durationWeeks,
       });
 
       // Sign and send the transaction
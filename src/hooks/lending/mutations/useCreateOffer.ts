@@ -1,6 +1,7 @@
 import { useMutation, useQueryClient } from "@tanstack/react-query";
 import { useWallet } from "@/hooks/useWallet";
 import { useContractClients } from "@/hooks/lending/useContractClients";
+import nativeXlm from "@/contracts/native_xlm";
 import { parseUsdc, percentageToBasisPoints } from "@/lib/lending-utils";
 
 export interface CreateOfferParams {
@@ -50,11 +51,14 @@ export function useCreateOffer() {
       // Step 2: Create offer
       const offerTx = await lendingMarket.create_offer({
         lender: address,
-        usdc_amount: usdcAmount,
+        loan_token: mockUsdc.options.contractId,
+        amount: usdcAmount,
         weekly_interest_rate: weeklyRateBps,
+        interest_mode: { tag: "Simple", values: undefined },
         min_collateral_ratio: minCollateralRatioBps,
         liquidation_threshold: liquidationThresholdBps,
         max_duration_weeks: params.maxDurationWeeks,
+        collateral_assets: [nativeXlm.options.contractId],
       });
 
       // Sign and send the create offer transaction
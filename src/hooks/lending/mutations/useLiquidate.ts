@@ -44,6 +44,7 @@ export function useLiquidate() {
       const { result } = await lendingMarket.liquidate({
         liquidator: address,
         loan_id: loanId,
+        debt_to_cover: debtAmount,
       });
 
       return result;
@@ -2,6 +2,7 @@ import { useQuery } from "@tanstack/react-query";
 import lendingMarket from "@/contracts/lending_market";
 import { useWallet } from "@/hooks/useWallet";
 import { formatUsdc } from "@/lib/lending-utils";
+import { fetchLoanIdsAsLender } from "./useUserLoans";
 
 export interface DashboardStats {
   // Borrowing stats
@@ -44,18 +45,17 @@ export function useDashboardStats() {
       // Fetch all user data in parallel
       const [
         borrowerLoansResult,
-        lenderLoansResult,
+        lenderLoanIds,
         userOffersResult,
         xlmPriceResult,
       ] = await Promise.all([
         lendingMarket.get_user_loans_as_borrower({ user: address }),
-        lendingMarket.get_user_loans_as_lender({ user: address }),
+        fetchLoanIdsAsLender(address),
         lendingMarket.get_user_offers({ user: address }),
         lendingMarket.get_xlm_price(),
       ]);
 
       const borrowerLoanIds = borrowerLoansResult.result || [];
-      const lenderLoanIds = lenderLoansResult.result || [];
       const offerIds = userOffersResult.result || [];
 
       // Calculate borrowing stats
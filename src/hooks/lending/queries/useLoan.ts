@@ -1,4 +1,5 @@
 import { useQuery } from "@tanstack/react-query";
+import type { InterestMode } from "lending_market";
 import lendingMarket from "@/contracts/lending_market";
 
 export interface Loan {
@@ -6,13 +7,17 @@ export interface Loan {
   offer_id: bigint;
   borrower: string;
   lender: string;
+  loan_token: string;
+  collateral_token: string;
   collateral_amount: bigint;
   borrowed_amount: bigint;
   interest_rate: number;
   start_time: bigint;
   last_interest_update: bigint;
   accumulated_interest: bigint;
+  interest_mode: InterestMode;
   liquidation_threshold: number;
+  due_time: bigint;
   is_active: boolean;
 }
 
@@ -2,11 +2,16 @@ import { useQuery } from "@tanstack/react-query";
 import lendingMarket from "@/contracts/lending_market";
 
 export interface LoanHealth {
-  collateral_value_usd: bigint;
-  debt_value_usd: bigint;
+  loan_id: bigint;
+  collateral_value: bigint;
+  debt_value: bigint;
   collateralization_ratio: number;
   health_factor: number;
   is_liquidatable: boolean;
+  liquidation_price: bigint;
+  due_time: bigint;
+  is_overdue: boolean;
+  time_remaining: bigint;
 }
 
 /**
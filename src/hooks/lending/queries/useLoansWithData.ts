@@ -2,6 +2,7 @@ import { useQueries, useQuery } from "@tanstack/react-query";
 import lendingMarket from "@/contracts/lending_market";
 import { useWallet } from "@/hooks/useWallet";
 import type { Loan } from "./useLoan";
+import { fetchLoanIdsAsLender } from "./useUserLoans";
 
 /**
  * Hook to fetch all loans where user is the borrower with full data
@@ -73,11 +74,7 @@ export function useUserLoansAsLenderWithData() {
     queryFn: async () => {
       if (!address) throw new Error("Wallet not connected");
 
-      const { result } = await lendingMarket.get_user_loans_as_lender({
-        user: address,
-      });
-
-      return result || [];
+      return fetchLoanIdsAsLender(address);
     },
     enabled: !!address,
     staleTime: 15000,
@@ -79,7 +79,7 @@ export function useNotifications() {
       // Process offers
       offers.forEach((offer) => {
         const offerId = offer.offer_id?.toString() || "unknown";
-        const amount = Number(offer.usdc_amount || BigInt(0)) / 1e7;
+        const amount = Number(offer.amount || BigInt(0)) / 1e7;
         const weeklyRate = (offer.weekly_interest_rate || 0) / 100;
 
         // Offer created notification (if recent)
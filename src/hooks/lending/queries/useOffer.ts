@@ -1,14 +1,18 @@
 import { useQuery } from "@tanstack/react-query";
+import type { InterestMode } from "lending_market";
 import lendingMarket from "@/contracts/lending_market";
 
 export interface LendingOffer {
   offer_id: bigint;
   lender: string;
-  usdc_amount: bigint;
+  loan_token: string;
+  amount: bigint;
   weekly_interest_rate: number;
+  interest_mode: InterestMode;
   min_collateral_ratio: number;
   liquidation_threshold: number;
   max_duration_weeks: number;
+  collateral_assets: string[];
   is_active: boolean;
   created_at: bigint;
 }
@@ -1,6 +1,45 @@
 import { useQuery } from "@tanstack/react-query";
 import lendingMarket from "@/contracts/lending_market";
 
+/** Most IDs one page of an active index holds */
+const ACTIVE_INDEX_PAGE_SIZE = 50;
+
+/**
+ * Read every page of an active index, one call per page
+ */
+async function fetchIndexPages(
+  count: number,
+  readPage: (page: number) => Promise<bigint[]>,
+): Promise<bigint[]> {
+  const pages = Math.ceil(count / ACTIVE_INDEX_PAGE_SIZE);
+  const ids = await Promise.all(
+    Array.from({ length: pages }, (_, page) => readPage(page)),
+  );
+  return ids.flat();
+}
+
+/**
+ * Fetch every active offer ID, one index page at a time
+ */
+export async function fetchActiveOfferIds(): Promise<bigint[]> {
+  const { result: count } = await lendingMarket.get_active_offer_count();
+  return fetchIndexPages(count || 0, async (page) => {
+    const { result } = await lendingMarket.get_active_offers_page({ page });
+    return result || [];
+  });
+}
+
+/**
+ * Fetch every active loan ID, one index page at a time
+ */
+export async function fetchActiveLoanIds(): Promise<bigint[]> {
+  const { result: count } = await lendingMarket.get_active_loan_count();
+  return fetchIndexPages(count || 0, async (page) => {
+    const { result } = await lendingMarket.get_active_loans_page({ page });
+    return result || [];
+  });
+}
+
 /**
  * Hook to fetch all active offers from the marketplace
  * @returns Query result with array of offer IDs
@@ -8,11 +47,7 @@ import lendingMarket from "@/contracts/lending_market";
 export function useActiveOffers() {
   return useQuery({
     queryKey: ["active-offers"],
-    queryFn: async () => {
-      const { result } = await lendingMarket.get_active_offers();
-
-      return result || [];
-    },
+    queryFn: fetchActiveOfferIds,
     staleTime: 20000, // 20 seconds
     retry: 2,
   });
@@ -25,11 +60,7 @@ export function useActiveOffers() {
 export function useActiveLoans() {
   return useQuery({
     queryKey: ["active-loans"],
-    queryFn: async () => {
-      const { result } = await lendingMarket.get_active_loans();
-
-      return result || [];
-    },
+    queryFn: fetchActiveLoanIds,
     staleTime: 20000, // 20 seconds
     retry: 2,
   });
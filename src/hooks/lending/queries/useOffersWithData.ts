@@ -2,6 +2,7 @@ import { useQueries, useQuery } from "@tanstack/react-query";
 import lendingMarket from "@/contracts/lending_market";
 import { useWallet } from "@/hooks/useWallet";
 import type { LendingOffer } from "./useOffer";
+import { fetchActiveOfferIds } from "./useOffers";
 
 /**
  * Hook to fetch all active offers with full data from the marketplace
@@ -11,10 +12,7 @@ export function useActiveOffersWithData() {
   // First, get all offer IDs
   const { data: offerIds = [], isLoading: isLoadingIds } = useQuery({
     queryKey: ["active-offers"],
-    queryFn: async () => {
-      const { result } = await lendingMarket.get_active_offers();
-      return result || [];
-    },
+    queryFn: fetchActiveOfferIds,
     staleTime: 20000,
     retry: 2,
   });
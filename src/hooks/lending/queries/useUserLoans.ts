@@ -2,6 +2,26 @@ import { useQuery } from "@tanstack/react-query";
 import lendingMarket from "@/contracts/lending_market";
 import { useWallet } from "@/hooks/useWallet";
 
+/** Most loan IDs `get_user_loans_as_lender` returns per call */
+const LENDER_LOANS_PAGE_SIZE = 50;
+
+/**
+ * Fetch every loan ID where `address` is the lender, one page at a time
+ */
+export async function fetchLoanIdsAsLender(address: string): Promise<bigint[]> {
+  const loanIds: bigint[] = [];
+  for (;;) {
+    const { result } = await lendingMarket.get_user_loans_as_lender({
+      user: address,
+      offset: loanIds.length,
+      limit: LENDER_LOANS_PAGE_SIZE,
+    });
+    const page = result.unwrap();
+    loanIds.push(...page);
+    if (page.length < LENDER_LOANS_PAGE_SIZE) return loanIds;
+  }
+}
+
 /**
  * Hook to fetch all loans where user is the borrower
  * @returns Query result with array of loans
@@ -38,11 +58,7 @@ export function useUserLoansAsLender() {
     queryFn: async () => {
       if (!address) throw new Error("Wallet not connected");
 
-      const { result } = await lendingMarket.get_user_loans_as_lender({
-        user: address,
-      });
-
-      return result || [];
+      return fetchLoanIdsAsLender(address);
     },
     enabled: !!address,
     staleTime: 15000, // 15 seconds
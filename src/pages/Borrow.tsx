@@ -112,6 +112,7 @@ export default function BorrowPage() {
         offerId: offerId!,
         collateralXlm: formData.collateralXLM,
         borrowAmount: formData.borrowAmount,
+        durationWeeks: offer.max_duration_weeks,
       });
       setCreatedLoanId(loanId.toString());
       setShowSuccessModal(true);
@@ -139,7 +140,7 @@ export default function BorrowPage() {
               <div className="bg-card rounded-lg p-3">
                 <p className="text-xs text-muted-foreground">Pool Size</p>
                 <p className="text-lg font-mono font-semibold">
-                  {formatUsdc(offer.usdc_amount)}
+                  {formatUsdc(offer.amount)}
                 </p>
               </div>
               <div className="bg-card rounded-lg p-3">
@@ -403,7 +403,7 @@ export default function LendingDashboard() {
                     {offers
                       .filter((offer) => offer && offer.offer_id !== undefined)
                       .map((offer) => {
-                        const availableAmount = offer.usdc_amount || BigInt(0);
+                        const availableAmount = offer.amount || BigInt(0);
                         const weeklyRate =
                           (offer.weekly_interest_rate || 0) / 100;
 
@@ -418,7 +418,7 @@ export default function LendingDashboard() {
                                   Pool Size
                                 </p>
                                 <p className="font-mono font-semibold text-foreground">
-                                  {formatUsdc(offer.usdc_amount || BigInt(0))}
+                                  {formatUsdc(offer.amount || BigInt(0))}
                                 </p>
                               </div>
                               <div>
@@ -567,7 +567,7 @@ export default function LendingDashboard() {
               .filter((offer) => offer && offer.offer_id !== undefined)
               .map((offer, index) => {
                 const availableUSDC =
-                  Number(offer.usdc_amount || BigInt(0)) / 1e7;
+                  Number(offer.amount || BigInt(0)) / 1e7;
                 const weeklyRate = (offer.weekly_interest_rate || 0) / 100;
                 return {
                   id: Number(offer.offer_id),
@@ -575,7 +575,7 @@ export default function LendingDashboard() {
                   handle: `${weeklyRate}% weekly`,
                   streak: `${(offer.min_collateral_ratio || 0) / 100}%`,
                   avatar: "/stellar-usdc-offer.jpg",
-                  points: Number(offer.usdc_amount || BigInt(0)) / 1e7,
+                  points: Number(offer.amount || BigInt(0)) / 1e7,
                   subtitle: `${(offer.min_collateral_ratio || 0) / 100}% collateral`,
                   featured: index === 0,
                 };
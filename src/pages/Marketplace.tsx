@@ -29,12 +29,12 @@ export default function MarketplacePage() {
       .map((offer) => ({
         id: offer.offer_id.toString(),
         lenderId: offer.lender,
-        amountUSTC: offer.usdc_amount || BigInt(0),
+        amountUSTC: offer.amount || BigInt(0),
         weeklyRate: (offer.weekly_interest_rate || 0) / 100,
         minCollateralRatio: (offer.min_collateral_ratio || 0) / 100,
         liquidationThreshold: (offer.liquidation_threshold || 0) / 100,
         maxDurationWeeks: offer.max_duration_weeks || 0,
-        availableAmount: offer.usdc_amount || BigInt(0),
+        availableAmount: offer.amount || BigInt(0),
         createdAt: Number(offer.created_at || BigInt(0)) * 1000,
         status: "active" as const,
       }));
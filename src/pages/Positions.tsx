@@ -256,7 +256,7 @@ export default function Positions() {
                   {offers
                     .filter((offer) => offer && offer.offer_id !== undefined)
                     .map((offer) => {
-                      const availableAmount = offer.usdc_amount || BigInt(0);
+                      const availableAmount = offer.amount || BigInt(0);
                       const weeklyRate =
                         (offer.weekly_interest_rate || 0) / 100;
 
@@ -271,7 +271,7 @@ export default function Positions() {
                                 Pool Size
                               </p>
                               <p className="font-mono font-semibold text-foreground">
-                                {formatUsdc(offer.usdc_amount || BigInt(0))}
+                                {formatUsdc(offer.amount || BigInt(0))}
                               </p>
                             </div>
                             <div>